@@ -4,7 +4,8 @@ use gpui::{
     StatefulInteractiveElement, Styled, Window, WindowOptions, div, px, relative, rems, rgb, rgba,
 };
 use lapislazuli::{
-    AutoFocusable, Disableable, LapislazuliProvider, ParentElementWithContext,
+    AutoFocusable, Disableable, FocusableStyled, LapislazuliProvider, MotionPreference,
+    ParentElementWithContext, Theme,
     components::{
         Switch,
         progress::{Progress, ProgressFill, ProgressTrack},
@@ -113,6 +114,7 @@ impl Render for Showcase {
         };
 
         let disabled = self.disabled;
+        let theme = cx.global::<Theme>().clone();
 
         v_flex()
             .id("showcase")
@@ -121,7 +123,7 @@ impl Render for Showcase {
             .relative()
             .font_family(".SystemUIFont")
             .track_focus(&self.focus_handle(cx))
-            .bg(rgb(0xf8fafc))
+            .bg(theme.colors.background)
             .min_h_full()
             .p(rems(3.0))
             .gap(rems(2.5))
@@ -132,12 +134,12 @@ impl Render for Showcase {
                         span("🌟 lapislazuli Component Showcase")
                             .text_size(rems(2.5))
                             .font_weight(FontWeight::BOLD)
-                            .text_color(rgb(0x1e293b))
+                            .text_color(theme.colors.foreground)
                     )
                     .child(
                         span("A headless component library for GPUI")
                             .text_size(rems(1.1))
-                            .text_color(rgb(0x64748b))
+                            .text_color(theme.colors.muted_foreground)
                     )
             )
             .child(
@@ -311,7 +313,7 @@ impl Render for Showcase {
                                     .child(
                                         button("increment")
                                             .border_2()
-                                            .focus(|this| this.border_color(rgb(0x000000)))
+                                            .when_focus_visible(|this| this.border_color(rgb(0x000000)))
                                             .bg(rgb(0x3b82f6))
                                             .hover(|this| this.bg(rgb(0x2563eb)))
                                             .disabled(self.disabled)
@@ -325,7 +327,7 @@ impl Render for Showcase {
                                     .child(
                                         button("decrement")
                                             .border_2()
-                                            .focus(|this| this.border_color(rgb(0x000000)))
+                                            .when_focus_visible(|this| this.border_color(rgb(0x000000)))
                                             .bg(rgb(0xf59e0b))
                                             .hover(|this| this.bg(rgb(0xd97706)))
                                             .disabled(self.disabled)
@@ -339,7 +341,7 @@ impl Render for Showcase {
                                     .child(
                                         button("reset")
                                             .border_2()
-                                            .focus(|this| this.border_color(rgb(0x000000)))
+                                            .when_focus_visible(|this| this.border_color(rgb(0x000000)))
                                             .bg(rgb(0xef4444))
                                             .hover(|this| this.bg(rgb(0xdc2626)))
                                             .disabled(self.disabled)
@@ -353,7 +355,7 @@ impl Render for Showcase {
                                     .child(
                                         button("complete")
                                             .border_2()
-                                            .focus(|this| this.border_color(rgb(0x000000)))
+                                            .when_focus_visible(|this| this.border_color(rgb(0x000000)))
                                             .bg(rgb(0x10b981))
                                             .hover(|this| this.bg(rgb(0x059669)))
                                             .disabled(self.disabled)
@@ -388,7 +390,7 @@ impl Render for Showcase {
                                                         .border_1()
                                                         .border_color(rgb(0xe2e8f0))
                                                         .overflow_hidden()
-                                                        .focus(|this| this.border_color(rgb(0x000000)))
+                                                        .when_focus_visible(|this| this.border_color(rgb(0x000000)))
                                                         .checked_indicator(span("✓")
                                                         .flex()
                                                                             .justify_center()
@@ -412,7 +414,7 @@ impl Render for Showcase {
                                                     this.thumb(|thumb|
                                                             thumb.bg(rgb(0xffffff)))
                                                         .bg(rgb(0x10b981)))
-                                                .with_animation(("checkbox", self.disabled as u32), Animation::new(Duration::from_millis(100)), move |this, delta| {
+                                                .with_animation(("checkbox", self.disabled as u32), Animation::new(MotionPreference::current(cx).duration(Duration::from_millis(100))), move |this, delta| {
                                                     this.thumb(|thumb| {
                                                         if disabled {
                                                             thumb.left(px( delta * 21.))
@@ -437,7 +439,7 @@ impl Render for Showcase {
                                         button("toggle_disabled")
                                             .auto_focus(true)
                                             .border_2()
-                                            .focus(|this| this.border_color(rgb(0x000000)))
+                                            .when_focus_visible(|this| this.border_color(rgb(0x000000)))
                                             .bg(rgb(0x6366f1))
                                             .hover(|this| this.bg(rgb(0x5b21b6)))
                                             .px(rems(1.5))
@@ -453,7 +455,7 @@ impl Render for Showcase {
                                     .child(
                                         button("reset_counter")
                                             .border_2()
-                                            .focus(|this| this.border_color(rgb(0x000000)))
+                                            .when_focus_visible(|this| this.border_color(rgb(0x000000)))
                                             .bg(rgb(0x64748b))
                                             .hover(|this| this.bg(rgb(0x475569)))
                                             .px(rems(1.5))
@@ -532,7 +534,7 @@ impl Render for Showcase {
                                                     .bg(progress_color)
                                                     .h_full()
                                                     .rounded_3xl()
-                                                    .with_animation(("progress", (percentage * 1000.) as u32), Animation::new(Duration::from_millis(200)), move |this, delta| {
+                                                    .with_animation(("progress", (percentage * 1000.) as u32), Animation::new(MotionPreference::current(cx).duration(Duration::from_millis(200))), move |this, delta| {
                                                         let previous_size = previous_percent;
                                                         let current_size = percentage;
                                                         let interpolated_size = previous_size + (current_size - previous_size) * delta;
@@ -620,7 +622,7 @@ impl Render for Showcase {
                                     .gap(rems(2.0))
                                     .flex_wrap()
                                     .child(
-                                        a("https://github.com/J0R6IT0/lapislazuli")
+                                        a("source", "https://github.com/J0R6IT0/lapislazuli")
                                             .bg(rgb(0x1f2937))
                                             .hover(|this| this.bg(rgb(0x111827)))
                                             .px(rems(1.5))
@@ -632,7 +634,7 @@ impl Render for Showcase {
                                             .text_decoration_none()
                                     )
                                     .child(
-                                        a("https://github.com/zed-industries/zed/tree/main/crates/gpui")
+                                        a("gpui", "https://github.com/zed-industries/zed/tree/main/crates/gpui")
                                             .bg(rgb(0x059669))
                                             .hover(|this| this.bg(rgb(0x047857)))
                                             .px(rems(1.5))
@@ -0,0 +1,39 @@
+use gpui::SharedString;
+
+/// The semantic role of an element, as exposed to assistive technologies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Role {
+    Button,
+    Checkbox,
+    Switch,
+    Tab,
+    TabList,
+    TextField,
+    Link,
+    Combobox,
+    Listbox,
+    ListboxOption,
+    Menu,
+    MenuItem,
+    Custom(SharedString),
+}
+
+/// Accessibility metadata attached to an element with the [`crate::Accessible`] builders.
+///
+/// gpui doesn't expose an accessibility tree yet, so this is only stored for now and
+/// will be surfaced to assistive technologies once it does.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccessibilityProps {
+    pub role: Option<Role>,
+    pub label: Option<SharedString>,
+    pub description: Option<SharedString>,
+}
+
+impl AccessibilityProps {
+    pub fn new(role: Role) -> Self {
+        Self {
+            role: Some(role),
+            ..Default::default()
+        }
+    }
+}
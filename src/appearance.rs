@@ -0,0 +1,39 @@
+use gpui::{App, Global, WindowAppearance};
+
+/// The light or dark appearance reported by the operating system.
+///
+/// Kept in sync with the window appearance by [`crate::LapislazuliProvider`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Appearance {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl Appearance {
+    /// Returns the current appearance, or [`Appearance::Light`] if the provider was not initialized.
+    pub fn current(app: &App) -> Self {
+        app.try_global::<Appearance>().copied().unwrap_or_default()
+    }
+
+    pub fn is_dark(&self) -> bool {
+        *self == Appearance::Dark
+    }
+}
+
+impl Global for Appearance {}
+
+impl From<WindowAppearance> for Appearance {
+    fn from(appearance: WindowAppearance) -> Self {
+        match appearance {
+            WindowAppearance::Light | WindowAppearance::VibrantLight => Appearance::Light,
+            WindowAppearance::Dark | WindowAppearance::VibrantDark => Appearance::Dark,
+        }
+    }
+}
+
+/// Emitted by [`crate::LapislazuliProvider`] when the system appearance changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AppearanceChanged {
+    pub appearance: Appearance,
+}
@@ -0,0 +1,343 @@
+use crate::{
+    Disableable, OpenProps, OpenReason, OpenState, Openable, is_focus_visible,
+    primitives::{collapsible, v_flex},
+    use_open_state,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+/// What an [`AccordionItem`]'s trigger and content slots are rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccordionItemContext {
+    pub key: SharedString,
+    pub index: usize,
+    pub open: bool,
+    pub disabled: bool,
+    /// Whether the item's trigger is focused after keyboard navigation.
+    pub focus_visible: bool,
+}
+
+/// A section of an [`Accordion`], made of a trigger that toggles it and its content.
+#[allow(clippy::type_complexity)]
+pub struct AccordionItem {
+    key: SharedString,
+    base: Div,
+    disabled: bool,
+    trigger: Option<Box<dyn FnOnce(&AccordionItemContext) -> AnyElement>>,
+    content: Option<Box<dyn FnOnce(&AccordionItemContext) -> AnyElement>>,
+    when_open_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    open_props: OpenProps,
+}
+
+impl AccordionItem {
+    pub fn new(key: impl Into<SharedString>) -> Self {
+        Self {
+            key: key.into(),
+            base: v_flex(),
+            disabled: false,
+            trigger: None,
+            content: None,
+            when_open_handler: None,
+            open_props: OpenProps::default(),
+        }
+    }
+
+    /// Renders the header that toggles the item when clicked, or with Enter/Space.
+    pub fn trigger<E: IntoElement>(
+        mut self,
+        trigger: impl FnOnce(&AccordionItemContext) -> E + 'static,
+    ) -> Self {
+        self.trigger = Some(Box::new(move |cx| trigger(cx).into_any_element()));
+        self
+    }
+
+    /// Renders the content shown below the trigger while the item is open.
+    ///
+    /// The content is wrapped in a [`crate::primitives::Collapsible`], so its height
+    /// animates when the item opens and closes.
+    pub fn content<E: IntoElement>(
+        mut self,
+        content: impl FnOnce(&AccordionItemContext) -> E + 'static,
+    ) -> Self {
+        self.content = Some(Box::new(move |cx| content(cx).into_any_element()));
+        self
+    }
+
+    /// Conditionally modify the item while it is open.
+    pub fn when_open(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_open_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Styled for AccordionItem {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl Openable for AccordionItem {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+impl Disableable for AccordionItem {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// A vertically stacked set of collapsible sections.
+///
+/// In single mode, opening an item closes the others; with `.multiple(true)` any number
+/// of items can be open at once. The open items are tracked by key, either by the
+/// accordion itself starting from `.default_value(..)`, or by the owner with `.value(..)`.
+///
+/// Each item is also [`Openable`] on its own: `.open(..)` on an item overrides the
+/// accordion's value for it, `.default_open(true)` opens it on first render, and its
+/// `on_open_change` is called whenever toggling opens or closes it.
+///
+/// Focus moves between the triggers with ArrowUp/ArrowDown/Home/End, and Enter/Space
+/// toggles the focused item.
+///
+/// # Examples
+///
+/// ```rust
+/// Accordion::new("faq")
+///     .default_value(["shipping"])
+///     .item(
+///         AccordionItem::new("shipping")
+///             .trigger(|cx| span(if cx.open { "▾ Shipping" } else { "▸ Shipping" }))
+///             .content(|_| span("Orders ship within two days.")),
+///     )
+///     .item(
+///         AccordionItem::new("returns")
+///             .trigger(|_| span("Returns"))
+///             .content(|_| span("Returns are free for 30 days.")),
+///     )
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Accordion {
+    id: ElementId,
+    base: Div,
+    items: Vec<AccordionItem>,
+    multiple: bool,
+    value: Option<Vec<SharedString>>,
+    default_value: Vec<SharedString>,
+    on_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+}
+
+/// Trigger focus handles kept across renders of an [`Accordion`].
+struct AccordionState {
+    focus_handles: Vec<FocusHandle>,
+}
+
+impl Accordion {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: v_flex(),
+            items: Vec::new(),
+            multiple: false,
+            value: None,
+            default_value: Vec::new(),
+            on_change: None,
+        }
+    }
+
+    pub fn item(mut self, item: AccordionItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Whether several items can be open at the same time, `false` by default.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Controls the keys of the open items.
+    pub fn value(mut self, keys: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.value = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// The keys of the items open on first render when the accordion is not controlled.
+    pub fn default_value(
+        mut self,
+        keys: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.default_value = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Called with the keys of the open items whenever an item is toggled.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+}
+
+impl Styled for Accordion {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Moves focus to the next enabled trigger in the given direction, wrapping around.
+fn focus_step(handles: &[Option<FocusHandle>], from: usize, forward: bool, window: &mut Window) {
+    let len = handles.len();
+    let next = (1..=len)
+        .map(|step| {
+            if forward {
+                (from + step) % len
+            } else {
+                (from + len - step) % len
+            }
+        })
+        .find_map(|ix| handles[ix].as_ref());
+    if let Some(handle) = next {
+        window.focus(handle);
+    }
+}
+
+impl RenderOnce for Accordion {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| AccordionState {
+            focus_handles: Vec::new(),
+        });
+
+        let len = self.items.len();
+        let focus_handles = state.update(app, |state, cx| {
+            while state.focus_handles.len() < len {
+                state.focus_handles.push(cx.focus_handle().tab_stop(true));
+            }
+            state.focus_handles.clone()
+        });
+
+        // Every item keeps its own open state, which the accordion's value controls
+        // unless the item is controlled itself.
+        let open_states: Rc<[(SharedString, Entity<OpenState>)]> = self
+            .items
+            .iter()
+            .map(|item| {
+                let mut props = item.open_props.clone();
+                if props.open.is_none() {
+                    props.open = self.value.as_ref().map(|value| value.contains(&item.key));
+                }
+                props.default_open |= self.default_value.contains(&item.key);
+                let id = ElementId::NamedChild(Box::new(self.id.clone()), item.key.clone());
+                (item.key.clone(), use_open_state(id, &props, window, app))
+            })
+            .collect();
+        let expanded: Vec<SharedString> = open_states
+            .iter()
+            .filter(|(_, open_state)| open_state.read(app).is_open())
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        let enabled_handles: Rc<[Option<FocusHandle>]> = self
+            .items
+            .iter()
+            .zip(&focus_handles)
+            .map(|(item, handle)| (!item.disabled).then(|| handle.clone()))
+            .collect();
+
+        let multiple = self.multiple;
+        let on_change = self.on_change;
+        let toggle = Rc::new(
+            move |key: SharedString, window: &mut Window, app: &mut App| {
+                let mut expanded: Vec<SharedString> = open_states
+                    .iter()
+                    .filter(|(_, open_state)| open_state.read(app).is_open())
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                if let Some(ix) = expanded.iter().position(|open| *open == key) {
+                    expanded.remove(ix);
+                } else if multiple {
+                    expanded.push(key);
+                } else {
+                    expanded = vec![key];
+                }
+
+                for (key, open_state) in open_states.iter() {
+                    let open = expanded.contains(key);
+                    open_state.update(app, |state, cx| {
+                        state.set_open(open, OpenReason::Trigger, window, cx)
+                    });
+                }
+                if let Some(on_change) = on_change.as_ref() {
+                    on_change(&expanded, window, app);
+                }
+            },
+        );
+
+        let mut items = Vec::with_capacity(len);
+        for (ix, mut item) in self.items.into_iter().enumerate() {
+            let focus_handle = &focus_handles[ix];
+            let context = AccordionItemContext {
+                key: item.key.clone(),
+                index: ix,
+                open: expanded.contains(&item.key),
+                disabled: item.disabled,
+                focus_visible: focus_handle.is_focused(window) && is_focus_visible(app),
+            };
+
+            if context.open {
+                if let Some(handler) = item.when_open_handler.take() {
+                    item = handler(item);
+                }
+            }
+
+            let trigger = div()
+                .id(("accordion-trigger", ix))
+                .when(!item.disabled, |this| {
+                    let key = item.key.clone();
+                    let handles = enabled_handles.clone();
+                    let toggle = toggle.clone();
+                    this.track_focus(focus_handle)
+                        .on_click({
+                            let key = key.clone();
+                            let toggle = toggle.clone();
+                            move |_, window, app| toggle(key.clone(), window, app)
+                        })
+                        .on_key_down(move |event, window, app| {
+                            match event.keystroke.key.as_str() {
+                                "enter" | "space" => toggle(key.clone(), window, app),
+                                "down" => focus_step(&handles, ix, true, window),
+                                "up" => focus_step(&handles, ix, false, window),
+                                "home" => focus_step(&handles, len - 1, true, window),
+                                "end" => focus_step(&handles, 0, false, window),
+                                _ => return,
+                            }
+                            app.stop_propagation();
+                        })
+                })
+                .when_some(item.trigger, |this, trigger| this.child(trigger(&context)));
+
+            items.push(
+                item.base
+                    .child(trigger)
+                    .when_some(item.content, |this, content| {
+                        this.child(
+                            collapsible(("accordion-content", ix))
+                                .open(context.open)
+                                .child(content(&context)),
+                        )
+                    }),
+            );
+        }
+
+        self.base.id(self.id).children(items)
+    }
+}
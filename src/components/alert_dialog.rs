@@ -0,0 +1,178 @@
+use crate::{
+    AutoFocusable, OpenProps, OpenReason, OpenState, Openable,
+    components::Dialog,
+    primitives::{Button, button, h_flex},
+    use_open_state,
+};
+use gpui::*;
+use smallvec::SmallVec;
+use std::rc::Rc;
+
+/// Which action of an [`AlertDialog`] receives focus when it opens.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AlertDialogAction {
+    #[default]
+    Cancel,
+    Confirm,
+}
+
+/// A [`Dialog`] that asks the user to confirm an action, typically a destructive one.
+///
+/// Unlike a plain dialog, it can't be dismissed with Escape or by clicking outside by
+/// default, so the user has to pick one of the actions.
+///
+/// # Examples
+///
+/// ```rust
+/// AlertDialog::new("delete-file")
+///     .open(self.confirming)
+///     .child("Delete this file?")
+///     .cancel(|button| button.child("Cancel"))
+///     .confirm(|button| button.child("Delete"))
+///     .on_confirm(cx.listener(|this, _, _, cx| this.delete_file(cx)))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct AlertDialog {
+    id: ElementId,
+    dialog: Dialog,
+    children: SmallVec<[AnyElement; 2]>,
+    open_props: OpenProps,
+    actions: Div,
+    cancel: Option<Box<dyn FnOnce(Button) -> Button>>,
+    confirm: Option<Box<dyn FnOnce(Button) -> Button>>,
+    default_action: AlertDialogAction,
+    on_cancel: Option<Rc<dyn Fn(&mut Window, &mut App) + 'static>>,
+    on_confirm: Option<Rc<dyn Fn(&mut Window, &mut App) + 'static>>,
+}
+
+impl AlertDialog {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        let id = id.into();
+        Self {
+            dialog: Dialog::new(ElementId::NamedChild(Box::new(id.clone()), "dialog".into()))
+                .close_on_escape(false)
+                .close_on_outside_click(false),
+            id,
+            children: SmallVec::new(),
+            open_props: OpenProps::default(),
+            actions: h_flex(),
+            cancel: None,
+            confirm: None,
+            default_action: AlertDialogAction::Cancel,
+            on_cancel: None,
+            on_confirm: None,
+        }
+    }
+
+    /// Configures the underlying dialog, e.g. its backdrop or dismissal behavior.
+    pub fn dialog(mut self, handler: impl FnOnce(Dialog) -> Dialog) -> Self {
+        self.dialog = handler(self.dialog);
+        self
+    }
+
+    /// Styles the row that holds the cancel and confirm buttons.
+    pub fn actions(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.actions = handler(self.actions);
+        self
+    }
+
+    /// Builds the cancel button.
+    pub fn cancel(mut self, handler: impl FnOnce(Button) -> Button + 'static) -> Self {
+        self.cancel = Some(Box::new(handler));
+        self
+    }
+
+    /// Builds the confirm button.
+    pub fn confirm(mut self, handler: impl FnOnce(Button) -> Button + 'static) -> Self {
+        self.confirm = Some(Box::new(handler));
+        self
+    }
+
+    /// Which action is focused when the dialog opens, [`AlertDialogAction::Cancel`] by default.
+    pub fn default_action(mut self, action: AlertDialogAction) -> Self {
+        self.default_action = action;
+        self
+    }
+
+    pub fn on_cancel(mut self, on_cancel: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_cancel = Some(Rc::new(on_cancel));
+        self
+    }
+
+    pub fn on_confirm(mut self, on_confirm: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_confirm = Some(Rc::new(on_confirm));
+        self
+    }
+}
+
+impl Openable for AlertDialog {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+impl ParentElement for AlertDialog {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for AlertDialog {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.dialog.style()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn action_button(
+    id: ElementId,
+    handler: Option<Box<dyn FnOnce(Button) -> Button>>,
+    auto_focus: bool,
+    callback: Option<Rc<dyn Fn(&mut Window, &mut App) + 'static>>,
+    open_state: Entity<OpenState>,
+) -> Option<Button> {
+    let handler = handler?;
+    Some(
+        handler(button(id).auto_focus(auto_focus)).on_click(move |_, window, app| {
+            if let Some(callback) = callback.as_ref() {
+                callback(window, app);
+            }
+            open_state.update(app, |state, cx| {
+                state.close(OpenReason::Trigger, window, cx)
+            });
+        }),
+    )
+}
+
+impl RenderOnce for AlertDialog {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let open_state = use_open_state(self.id.clone(), &self.open_props, window, app);
+        let open = open_state.read(app).is_open();
+
+        let cancel = action_button(
+            ElementId::NamedChild(Box::new(self.id.clone()), "cancel".into()),
+            self.cancel,
+            self.default_action == AlertDialogAction::Cancel,
+            self.on_cancel,
+            open_state.clone(),
+        );
+        let confirm = action_button(
+            ElementId::NamedChild(Box::new(self.id), "confirm".into()),
+            self.confirm,
+            self.default_action == AlertDialogAction::Confirm,
+            self.on_confirm,
+            open_state.clone(),
+        );
+
+        self.dialog
+            .open(open)
+            .on_open_change(move |event, window, app| {
+                open_state.update(app, |state, cx| {
+                    state.set_open(event.open, event.reason, window, cx)
+                });
+            })
+            .children(self.children)
+            .child(self.actions.children(cancel).children(confirm))
+    }
+}
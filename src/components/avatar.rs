@@ -0,0 +1,184 @@
+use gpui::{prelude::FluentBuilder, *};
+use std::{rc::Rc, time::Duration};
+
+/// How far along an [`Avatar`]'s image is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AvatarStatus {
+    Loading,
+    Loaded,
+    /// The image failed to load, or no image was set.
+    Error,
+}
+
+/// A picture representing a user, with a fallback such as their initials.
+///
+/// The fallback is rendered when the image is missing or fails to load, and while it is
+/// loading once [`Avatar::fallback_delay`] elapsed, so images that load quickly don't
+/// flash the fallback first.
+///
+/// # Examples
+///
+/// ```rust
+/// Avatar::new("author")
+///     .size_8()
+///     .rounded_full()
+///     .bg(rgb(0xe2e8f0))
+///     .src("https://example.com/avatar.png")
+///     .fallback_delay(Duration::from_millis(300))
+///     .fallback(|_, _, _| span("JD").text_sm())
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Avatar {
+    id: ElementId,
+    base: Div,
+    src: Option<ImageSource>,
+    fallback: Option<Box<dyn FnOnce(AvatarStatus, &mut Window, &mut App) -> AnyElement>>,
+    fallback_delay: Duration,
+    on_status_change: Option<Rc<dyn Fn(&AvatarStatus, &mut Window, &mut App) + 'static>>,
+}
+
+/// The image status and fallback delay kept across renders of an [`Avatar`].
+struct AvatarState {
+    status: Option<AvatarStatus>,
+    fallback_visible: bool,
+    epoch: usize,
+}
+
+impl AvatarState {
+    /// Shows the fallback once the delay elapsed, unless the status changed meanwhile.
+    fn delay_fallback(&mut self, delay: Duration, cx: &mut Context<Self>) {
+        self.epoch += 1;
+        let epoch = self.epoch;
+        self.fallback_visible = delay.is_zero();
+        if self.fallback_visible {
+            return;
+        }
+
+        cx.spawn(async move |this, cx| {
+            Timer::after(delay).await;
+            this.update(cx, |state, cx| {
+                if state.epoch == epoch {
+                    state.fallback_visible = true;
+                    cx.notify();
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+}
+
+impl Avatar {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div()
+                .flex()
+                .flex_none()
+                .items_center()
+                .justify_center()
+                .overflow_hidden(),
+            src: None,
+            fallback: None,
+            fallback_delay: Duration::ZERO,
+            on_status_change: None,
+        }
+    }
+
+    pub fn src(mut self, src: impl Into<ImageSource>) -> Self {
+        self.src = Some(src.into());
+        self
+    }
+
+    /// Renders the element shown in place of the image, given why it is shown.
+    pub fn fallback<E: IntoElement>(
+        mut self,
+        render: impl FnOnce(AvatarStatus, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.fallback = Some(Box::new(move |status, window, app| {
+            render(status, window, app).into_any_element()
+        }));
+        self
+    }
+
+    /// How long to wait for the image before showing the fallback, none by default.
+    pub fn fallback_delay(mut self, delay: Duration) -> Self {
+        self.fallback_delay = delay;
+        self
+    }
+
+    pub fn on_status_change(
+        mut self,
+        on_change: impl Fn(&AvatarStatus, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_status_change = Some(Rc::new(on_change));
+        self
+    }
+}
+
+impl Styled for Avatar {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Returns the status of the image, starting to load it if needed.
+///
+/// Images share gpui's asset cache with the `img` element rendering them.
+fn image_status(src: &ImageSource, window: &mut Window, app: &mut App) -> AvatarStatus {
+    let data = match src {
+        ImageSource::Resource(resource) => window.use_asset::<ImgResourceLoader>(resource, app),
+        ImageSource::Custom(load) => load(window, app),
+        ImageSource::Render(_) | ImageSource::Image(_) => return AvatarStatus::Loaded,
+    };
+    match data {
+        None => AvatarStatus::Loading,
+        Some(Ok(_)) => AvatarStatus::Loaded,
+        Some(Err(_)) => AvatarStatus::Error,
+    }
+}
+
+impl RenderOnce for Avatar {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| AvatarState {
+            status: None,
+            fallback_visible: false,
+            epoch: 0,
+        });
+
+        let status = match &self.src {
+            Some(src) => image_status(src, window, app),
+            None => AvatarStatus::Error,
+        };
+        let delay = self.fallback_delay;
+        let (changed, fallback_visible) = state.update(app, |state, cx| {
+            let changed = state.status != Some(status);
+            if changed {
+                state.status = Some(status);
+                if status == AvatarStatus::Loading {
+                    state.delay_fallback(delay, cx);
+                } else {
+                    state.epoch += 1;
+                    state.fallback_visible = status == AvatarStatus::Error;
+                }
+            }
+            (changed, state.fallback_visible)
+        });
+        if changed && let Some(on_status_change) = self.on_status_change {
+            window.defer(app, move |window, app| {
+                on_status_change(&status, window, app)
+            });
+        }
+
+        self.base
+            .id(self.id)
+            .when_some(
+                self.src.filter(|_| status == AvatarStatus::Loaded),
+                |this, src| this.child(img(src).size_full().object_fit(ObjectFit::Cover)),
+            )
+            .when(fallback_visible, |this| {
+                this.children(self.fallback.map(|fallback| fallback(status, window, app)))
+            })
+    }
+}
@@ -0,0 +1,173 @@
+use gpui::{prelude::FluentBuilder, *};
+
+const DEFAULT_MAX: usize = 99;
+
+/// What the indicator of a [`Badge`] is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BadgeContext {
+    pub count: Option<usize>,
+    /// The formatted count, such as "99+", or `None` for a dot.
+    pub label: Option<SharedString>,
+    /// Whether the count is over the maximum.
+    pub overflowed: bool,
+}
+
+/// A small indicator pinned to a corner of its children, usually a count of unread items.
+///
+/// Counts over [`Badge::max`] are shown as the maximum followed by "+". The badge hides at
+/// a count of zero unless [`Badge::show_zero`] is set, and shows an empty dot in
+/// [`Badge::dot`] mode. The indicator is centered on the corner.
+///
+/// # Examples
+///
+/// ```rust
+/// Badge::new()
+///     .count(unread)
+///     .indicator(|indicator, _| {
+///         indicator
+///             .px_1()
+///             .min_w_4()
+///             .h_4()
+///             .rounded_full()
+///             .bg(rgb(0xef4444))
+///             .text_xs()
+///             .text_color(white())
+///     })
+///     .child(Avatar::new("inbox").size_8())
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Badge {
+    base: Div,
+    count: Option<usize>,
+    max: usize,
+    dot: bool,
+    show_zero: bool,
+    corner: Corner,
+    indicator: Option<Box<dyn FnOnce(Div, &BadgeContext) -> Div>>,
+}
+
+impl Badge {
+    pub fn new() -> Self {
+        Self {
+            base: div().relative().flex_none(),
+            count: None,
+            max: DEFAULT_MAX,
+            dot: false,
+            show_zero: false,
+            corner: Corner::TopRight,
+            indicator: None,
+        }
+    }
+
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// The largest count shown as is, 99 by default.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Shows a dot without the count. A dot is shown when no count is set, and hidden at
+    /// a count of zero.
+    pub fn dot(mut self, dot: bool) -> Self {
+        self.dot = dot;
+        self
+    }
+
+    /// Keeps the badge visible at a count of zero.
+    pub fn show_zero(mut self, show_zero: bool) -> Self {
+        self.show_zero = show_zero;
+        self
+    }
+
+    /// The corner the indicator is pinned to, the top right by default.
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Styles the indicator, which already contains the formatted count.
+    pub fn indicator(mut self, render: impl FnOnce(Div, &BadgeContext) -> Div + 'static) -> Self {
+        self.indicator = Some(Box::new(render));
+        self
+    }
+
+    fn context(&self) -> Option<BadgeContext> {
+        if self.count == Some(0) && !self.show_zero {
+            return None;
+        }
+
+        let label = self.count.filter(|_| !self.dot).map(|count| {
+            if count > self.max {
+                format!("{}+", self.max).into()
+            } else {
+                count.to_string().into()
+            }
+        });
+        Some(BadgeContext {
+            count: self.count,
+            label,
+            overflowed: self.count.is_some_and(|count| count > self.max),
+        })
+    }
+}
+
+impl Default for Badge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Styled for Badge {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for Badge {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.base.extend(elements);
+    }
+}
+
+impl RenderOnce for Badge {
+    fn render(mut self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let Some(context) = self.context() else {
+            return self.base;
+        };
+
+        let indicator = div()
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .whitespace_nowrap()
+            .when(context.label.is_none(), |this| this.size_2())
+            .children(context.label.clone());
+        let indicator = match self.indicator.take() {
+            Some(render) => render(indicator, &context),
+            None => indicator,
+        };
+
+        // A zero sized anchor on the corner, centering the indicator on it.
+        let anchor = div()
+            .absolute()
+            .size_0()
+            .flex()
+            .items_center()
+            .justify_center()
+            .map(|this| match self.corner {
+                Corner::TopLeft => this.top_0().left_0(),
+                Corner::TopRight => this.top_0().right_0(),
+                Corner::BottomLeft => this.bottom_0().left_0(),
+                Corner::BottomRight => this.bottom_0().right_0(),
+            })
+            .child(indicator);
+
+        self.base.child(anchor)
+    }
+}
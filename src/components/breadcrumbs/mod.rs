@@ -0,0 +1,433 @@
+use crate::{
+    components::{Menu, MenuEntries, MenuItem},
+    is_focus_visible,
+    primitives::h_flex,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::{ops::Range, rc::Rc};
+
+#[cfg(test)]
+mod tests;
+
+/// A step of the path shown by [`Breadcrumbs`].
+#[derive(Clone, Debug)]
+pub struct BreadcrumbItem {
+    id: SharedString,
+    label: SharedString,
+}
+
+impl BreadcrumbItem {
+    /// Creates an item, the label is rendered by default and listed in the overflow menu.
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// What a [`Breadcrumbs`] item is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BreadcrumbItemContext {
+    pub id: SharedString,
+    pub label: SharedString,
+    pub index: usize,
+    /// Whether this is the last item, the current location.
+    pub current: bool,
+    /// Whether the item is focused after keyboard navigation.
+    pub focus_visible: bool,
+}
+
+/// A navigation path, such as the folders leading to a file.
+///
+/// When the items don't fit the width of the element, the ones in the middle are
+/// collapsed into an overflow trigger that lists them in a [`Menu`]. The first and last
+/// items always stay visible. Give the element a width, for instance with `w_full()`,
+/// for the items to collapse.
+///
+/// Each item is a tab stop, ArrowLeft/ArrowRight/Home/End move focus across the items
+/// and the overflow trigger and Enter selects the focused item.
+///
+/// # Examples
+///
+/// ```rust
+/// Breadcrumbs::new("path")
+///     .w_full()
+///     .gap_1()
+///     .items([
+///         BreadcrumbItem::new("home", "Home"),
+///         BreadcrumbItem::new("projects", "Projects"),
+///         BreadcrumbItem::new("lapislazuli", "lapislazuli"),
+///     ])
+///     .separator(|_, _| span("›").text_color(rgb(0x94a3b8)))
+///     .render_item(|item, _, _| {
+///         span(item.label.clone()).when(item.current, |this| this.font_weight(FontWeight::BOLD))
+///     })
+///     .on_select(|id, _, _| println!("navigate to {id}"))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Breadcrumbs {
+    id: ElementId,
+    base: Div,
+    items: Vec<BreadcrumbItem>,
+    separator: Option<Box<dyn Fn(&mut Window, &mut App) -> AnyElement>>,
+    render_item: Option<Box<dyn Fn(&BreadcrumbItemContext, &mut Window, &mut App) -> AnyElement>>,
+    render_overflow: Option<Box<dyn FnOnce(usize, &mut Window, &mut App) -> AnyElement>>,
+    overflow_menu: Option<Box<dyn FnOnce(Menu) -> Menu>>,
+    on_select: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+/// Focus handles and measurements kept across renders of [`Breadcrumbs`].
+struct BreadcrumbsState {
+    focus_handles: Vec<FocusHandle>,
+    overflow_focus_handle: FocusHandle,
+    /// The width of each item the last time it was rendered.
+    widths: Vec<Option<Pixels>>,
+    separator_width: Option<Pixels>,
+    overflow_width: Option<Pixels>,
+    collapsed: Range<usize>,
+}
+
+impl Breadcrumbs {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: h_flex().items_center(),
+            items: Vec::new(),
+            separator: None,
+            render_item: None,
+            render_overflow: None,
+            overflow_menu: None,
+            on_select: None,
+        }
+    }
+
+    pub fn item(mut self, item: BreadcrumbItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = BreadcrumbItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Renders the separator placed between items, a slash when this isn't set.
+    pub fn separator<E: IntoElement>(
+        mut self,
+        render: impl Fn(&mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.separator = Some(Box::new(move |window, app| {
+            render(window, app).into_any_element()
+        }));
+        self
+    }
+
+    /// Renders each item, the label is rendered when this isn't set.
+    pub fn render_item<E: IntoElement>(
+        mut self,
+        render: impl Fn(&BreadcrumbItemContext, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.render_item = Some(Box::new(move |item, window, app| {
+            render(item, window, app).into_any_element()
+        }));
+        self
+    }
+
+    /// Renders the trigger standing in for the collapsed items, given how many there are.
+    /// An ellipsis is rendered when this isn't set.
+    pub fn render_overflow<E: IntoElement>(
+        mut self,
+        render: impl FnOnce(usize, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.render_overflow = Some(Box::new(move |count, window, app| {
+            render(count, window, app).into_any_element()
+        }));
+        self
+    }
+
+    /// Styles the menu listing the collapsed items.
+    pub fn overflow_menu(mut self, style: impl FnOnce(Menu) -> Menu + 'static) -> Self {
+        self.overflow_menu = Some(Box::new(style));
+        self
+    }
+
+    /// Called with the id of the item the user picked.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(on_select));
+        self
+    }
+}
+
+impl Styled for Breadcrumbs {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Returns the items to hide for the path to fit the available width, keeping the first
+/// and last items.
+fn collapsed_range(
+    widths: &[Option<Pixels>],
+    separator_width: Pixels,
+    overflow_width: Pixels,
+    available: Pixels,
+) -> Range<usize> {
+    let len = widths.len();
+    // Items that were never rendered are assumed to fit until they are measured.
+    let width = |ix: usize| widths[ix].unwrap_or_default();
+    let mut total = (0..len).fold(px(0.), |total, ix| total + width(ix))
+        + separator_width * len.saturating_sub(1) as f32;
+    if total <= available || len <= 2 {
+        return 1..1;
+    }
+
+    // The overflow trigger takes the place of the hidden items.
+    total += overflow_width + separator_width;
+    for end in 2..len - 1 {
+        total -= width(end - 1) + separator_width;
+        if total <= available {
+            return 1..end;
+        }
+    }
+    1..len - 1
+}
+
+/// A position in the rendered path.
+enum Slot {
+    Item(usize),
+    Overflow,
+}
+
+impl RenderOnce for Breadcrumbs {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let len = self.items.len();
+        let state = window.use_keyed_state(self.id.clone(), app, |_, cx| BreadcrumbsState {
+            focus_handles: Vec::new(),
+            overflow_focus_handle: cx.focus_handle().tab_stop(true),
+            widths: Vec::new(),
+            separator_width: None,
+            overflow_width: None,
+            collapsed: 1..1,
+        });
+        let (focus_handles, overflow_focus_handle, collapsed) = state.update(app, |state, cx| {
+            while state.focus_handles.len() < len {
+                state.focus_handles.push(cx.focus_handle().tab_stop(true));
+            }
+            state.widths.resize(len, None);
+            if state.collapsed.end >= len {
+                state.collapsed = 1..1;
+            }
+            (
+                state.focus_handles.clone(),
+                state.overflow_focus_handle.clone(),
+                state.collapsed.clone(),
+            )
+        });
+
+        let mut slots = Vec::with_capacity(len);
+        for ix in 0..len {
+            if collapsed.contains(&ix) {
+                if ix == collapsed.start {
+                    slots.push(Slot::Overflow);
+                }
+            } else {
+                slots.push(Slot::Item(ix));
+            }
+        }
+
+        // Focus moves across the rendered slots, in order.
+        let handles: Rc<[FocusHandle]> = slots
+            .iter()
+            .map(|slot| match slot {
+                Slot::Item(ix) => focus_handles[*ix].clone(),
+                Slot::Overflow => overflow_focus_handle.clone(),
+            })
+            .collect();
+
+        let mut render_overflow = self.render_overflow;
+        let mut overflow_menu = self.overflow_menu;
+        let mut children = Vec::with_capacity(slots.len() * 2);
+        for (position, slot) in slots.iter().enumerate() {
+            if position > 0 {
+                let separator = match &self.separator {
+                    Some(separator) => separator(window, app),
+                    None => "/".into_any_element(),
+                };
+                children.push(
+                    div()
+                        .relative()
+                        .flex_none()
+                        .child(separator)
+                        .when(position == 1, |this| {
+                            let state = state.clone();
+                            this.child(
+                                canvas(
+                                    move |bounds, _, app| {
+                                        state.update(app, |state, _| {
+                                            state.separator_width = Some(bounds.size.width)
+                                        })
+                                    },
+                                    |_, _, _, _| {},
+                                )
+                                .absolute()
+                                .size_full(),
+                            )
+                        })
+                        .into_any_element(),
+                );
+            }
+
+            match slot {
+                Slot::Item(ix) => {
+                    let ix = *ix;
+                    let item = &self.items[ix];
+                    let focus_handle = &focus_handles[ix];
+                    let context = BreadcrumbItemContext {
+                        id: item.id.clone(),
+                        label: item.label.clone(),
+                        index: ix,
+                        current: ix + 1 == len,
+                        focus_visible: focus_handle.is_focused(window) && is_focus_visible(app),
+                    };
+                    let content = match &self.render_item {
+                        Some(render_item) => render_item(&context, window, app),
+                        None => item.label.clone().into_any_element(),
+                    };
+
+                    children.push(
+                        div()
+                            .id(("breadcrumb", ix))
+                            .relative()
+                            .flex_none()
+                            .track_focus(focus_handle)
+                            .when_some(self.on_select.clone(), |this, on_select| {
+                                let id = item.id.clone();
+                                this.on_click({
+                                    let on_select = on_select.clone();
+                                    let id = id.clone();
+                                    move |_, window, app| on_select(&id, window, app)
+                                })
+                                .on_key_down(
+                                    move |event, window, app| {
+                                        if event.keystroke.key == "enter" {
+                                            app.stop_propagation();
+                                            on_select(&id, window, app);
+                                        }
+                                    },
+                                )
+                            })
+                            .child(content)
+                            .child({
+                                let state = state.clone();
+                                canvas(
+                                    move |bounds, _, app| {
+                                        state.update(app, |state, _| {
+                                            state.widths[ix] = Some(bounds.size.width)
+                                        })
+                                    },
+                                    |_, _, _, _| {},
+                                )
+                                .absolute()
+                                .size_full()
+                            })
+                            .into_any_element(),
+                    );
+                }
+                Slot::Overflow => {
+                    let trigger = match render_overflow.take() {
+                        Some(render_overflow) => render_overflow(collapsed.len(), window, app),
+                        None => "…".into_any_element(),
+                    };
+
+                    let mut menu = Menu::new(ElementId::NamedChild(
+                        Box::new(self.id.clone()),
+                        "overflow".into(),
+                    ))
+                    .trigger(
+                        div()
+                            .id("breadcrumb-overflow")
+                            .relative()
+                            .track_focus(&overflow_focus_handle)
+                            .child(trigger)
+                            .child({
+                                let state = state.clone();
+                                canvas(
+                                    move |bounds, _, app| {
+                                        state.update(app, |state, _| {
+                                            state.overflow_width = Some(bounds.size.width)
+                                        })
+                                    },
+                                    |_, _, _, _| {},
+                                )
+                                .absolute()
+                                .size_full()
+                            }),
+                    );
+                    for item in &self.items[collapsed.clone()] {
+                        let on_select = self.on_select.clone();
+                        let id = item.id.clone();
+                        menu = menu.item(MenuItem::new(item.label.clone()).on_select(
+                            move |window, app| {
+                                if let Some(on_select) = &on_select {
+                                    on_select(&id, window, app);
+                                }
+                            },
+                        ));
+                    }
+                    if let Some(style) = overflow_menu.take() {
+                        menu = style(menu);
+                    }
+
+                    children.push(div().flex_none().child(menu).into_any_element());
+                }
+            }
+        }
+
+        self.base
+            .id(self.id)
+            .relative()
+            .overflow_hidden()
+            .on_key_down(move |event, window, app| {
+                let Some(from) = handles.iter().position(|handle| handle.is_focused(window)) else {
+                    return;
+                };
+                let to = match event.keystroke.key.as_str() {
+                    "right" => (from + 1).min(handles.len() - 1),
+                    "left" => from.saturating_sub(1),
+                    "home" => 0,
+                    "end" => handles.len() - 1,
+                    _ => return,
+                };
+                app.stop_propagation();
+                window.focus(&handles[to]);
+            })
+            .children(children)
+            .child(
+                // Measured after the items, to collapse them once their widths are known.
+                canvas(
+                    move |bounds, window, app| {
+                        state.update(app, |state, _| {
+                            let collapsed = collapsed_range(
+                                &state.widths,
+                                state.separator_width.unwrap_or_default(),
+                                state.overflow_width.unwrap_or_default(),
+                                bounds.size.width,
+                            );
+                            if collapsed != state.collapsed {
+                                state.collapsed = collapsed;
+                                window.request_animation_frame();
+                            }
+                        })
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod collapsed_range {
+    use crate::components::breadcrumbs::collapsed_range;
+    use gpui::{Pixels, px};
+    use std::ops::Range;
+
+    const SEPARATOR: Pixels = px(10.);
+    const OVERFLOW: Pixels = px(20.);
+
+    /// Five 50px items, which take 290px with their separators.
+    fn collapse(available: f32) -> Range<usize> {
+        collapsed_range(&[Some(px(50.)); 5], SEPARATOR, OVERFLOW, px(available))
+    }
+
+    #[test]
+    fn hides_nothing_when_the_path_fits() {
+        assert_eq!(collapse(290.), 1..1);
+        assert_eq!(collapse(1000.), 1..1);
+    }
+
+    #[test]
+    fn hides_the_items_after_the_first_until_the_path_fits() {
+        assert_eq!(collapse(280.), 1..2);
+        assert_eq!(collapse(200.), 1..3);
+    }
+
+    #[test]
+    fn keeps_the_first_and_last_items() {
+        assert_eq!(collapse(100.), 1..4);
+        assert_eq!(collapse(0.), 1..4);
+        let widths = [Some(px(500.)), Some(px(500.))];
+        assert_eq!(collapsed_range(&widths, SEPARATOR, OVERFLOW, px(10.)), 1..1);
+    }
+
+    #[test]
+    fn assumes_unmeasured_items_fit() {
+        let widths = [Some(px(50.)), None, Some(px(50.))];
+        assert_eq!(
+            collapsed_range(&widths, SEPARATOR, OVERFLOW, px(120.)),
+            1..1
+        );
+        assert_eq!(
+            collapsed_range(&widths, SEPARATOR, OVERFLOW, px(119.)),
+            1..2
+        );
+    }
+}
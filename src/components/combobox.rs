@@ -0,0 +1,472 @@
+use super::listbox::listbox_item_target;
+use crate::{
+    AccessibilityProps, Accessible, Disableable, OpenProps, OpenReason, OpenState, Openable,
+    OptionSelected, Role,
+    components::{Listbox, ListboxItem, ListboxItemContext},
+    primitives::{
+        anchored::AnchoredOptions,
+        anchored_popover,
+        text_field::{TextField, actions::Enter, text_field},
+    },
+    publish_event, scroll_into_view, selection, use_open_state,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+/// A text field suggesting the options whose label matches the typed text, in a list
+/// below it.
+///
+/// Typing opens the list and highlights the first enabled match, ArrowUp/ArrowDown move the
+/// highlight while focus stays in the field, and Enter or a click picks the highlighted
+/// option, filling the field with its label. The matches are rendered by a [`Listbox`] in
+/// an [`anchored_popover`], which Escape and outside clicks close. Options are matched
+/// by a case-insensitive substring of their label unless a [`Combobox::filter_fn`] is set.
+/// Every pick calls [`Combobox::on_select`] and publishes an [`OptionSelected`] event.
+///
+/// # Examples
+///
+/// ```rust
+/// Combobox::new("city")
+///     .placeholder("Search a city")
+///     .items(self.cities.iter().map(|city| ListboxItem::new(city.id.clone(), city.name.clone())))
+///     .on_select(cx.listener(|this, value: &SharedString, _, cx| {
+///         this.city = Some(value.clone());
+///         cx.notify();
+///     }))
+///     .list(|list| list.bg(rgb(0xffffff)).p_1())
+///     .render_item(|item, _, _| {
+///         div()
+///             .px_2()
+///             .when(item.highlighted, |this| this.bg(rgb(0xf1f5f9)))
+///             .child(item.label.clone())
+///     })
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Combobox {
+    id: ElementId,
+    base: Div,
+    items: Vec<ListboxItem>,
+    value: Option<SharedString>,
+    default_value: Option<SharedString>,
+    placeholder: Option<SharedString>,
+    open_props: OpenProps,
+    options: AnchoredOptions,
+    filter_fn: Option<Rc<dyn Fn(&str, &ListboxItemContext) -> bool>>,
+    on_select: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    list: Option<Box<dyn FnOnce(Div) -> Div>>,
+    render_item: Option<Box<dyn Fn(&ListboxItemContext, &mut Window, &mut App) -> AnyElement>>,
+    field: Option<Box<dyn FnOnce(TextField) -> TextField>>,
+    accessibility: AccessibilityProps,
+}
+
+/// The typed text, highlight and selection kept across renders of a [`Combobox`].
+struct ComboboxState {
+    query: SharedString,
+    /// Position of the highlighted option among the matching ones.
+    highlighted: Option<usize>,
+    /// Set by typing, to highlight the first enabled match once the list is filtered.
+    highlight_first: bool,
+    selected: Option<SharedString>,
+}
+
+impl Combobox {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            items: Vec::new(),
+            value: None,
+            default_value: None,
+            placeholder: None,
+            open_props: OpenProps::default(),
+            options: AnchoredOptions::default(),
+            filter_fn: None,
+            on_select: None,
+            list: None,
+            render_item: None,
+            field: None,
+            accessibility: AccessibilityProps::new(Role::Combobox),
+        }
+    }
+
+    pub fn item(mut self, item: ListboxItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = ListboxItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Controls the value of the selected option.
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// The value selected on first render when the combobox is not controlled.
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.default_value = Some(value.into());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Positions the list relative to the field, below it by default.
+    pub fn options(mut self, options: AnchoredOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Decides whether an option matches the typed text.
+    pub fn filter_fn(
+        mut self,
+        filter: impl Fn(&str, &ListboxItemContext) -> bool + 'static,
+    ) -> Self {
+        self.filter_fn = Some(Rc::new(filter));
+        self
+    }
+
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(on_select));
+        self
+    }
+
+    /// Styles the panel listing the matching options.
+    pub fn list(mut self, list: impl FnOnce(Div) -> Div + 'static) -> Self {
+        self.list = Some(Box::new(list));
+        self
+    }
+
+    /// Renders each matching option, the label is rendered when this isn't set.
+    pub fn render_item<E: IntoElement>(
+        mut self,
+        render: impl Fn(&ListboxItemContext, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.render_item = Some(Box::new(move |item, window, app| {
+            render(item, window, app).into_any_element()
+        }));
+        self
+    }
+
+    /// Customizes the text field the query is typed in.
+    pub fn field(mut self, field: impl FnOnce(TextField) -> TextField + 'static) -> Self {
+        self.field = Some(Box::new(field));
+        self
+    }
+}
+
+impl Accessible for Combobox {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl Styled for Combobox {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl Openable for Combobox {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+/// Matches labels containing the query, ignoring case.
+fn default_filter(query: &str, item: &ListboxItemContext) -> bool {
+    item.label.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// The matching options of a rendered combobox together with its state, shared by its
+/// listeners.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct ComboboxController {
+    id: ElementId,
+    matches: Rc<[ListboxItemContext]>,
+    state: Entity<ComboboxState>,
+    open_state: Entity<OpenState>,
+    controlled: bool,
+    on_select: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+impl ComboboxController {
+    fn set_open(&self, open: bool, reason: OpenReason, window: &mut Window, app: &mut App) {
+        self.open_state
+            .update(app, |state, cx| state.set_open(open, reason, window, cx));
+    }
+
+    fn list_id(&self) -> ElementId {
+        ElementId::NamedChild(Box::new(self.id.clone()), "list".into())
+    }
+
+    fn position(&self, value: &SharedString) -> Option<usize> {
+        self.matches.iter().position(|item| item.value == *value)
+    }
+
+    fn highlight(&self, ix: usize, app: &mut App) {
+        self.state.update(app, |state, cx| {
+            state.highlighted = Some(ix);
+            cx.notify();
+        });
+    }
+
+    /// Moves the highlight to the next enabled match in the given direction, wrapping
+    /// around.
+    fn step(&self, forward: bool, window: &mut Window, app: &mut App) {
+        let from = self.state.read(app).highlighted;
+        let next = selection::step(self.matches.len(), from, forward, true, |ix| {
+            !self.matches[ix].disabled
+        });
+        if let Some(ix) = next {
+            self.highlight(ix, app);
+            let target = listbox_item_target(&self.list_id(), &self.matches[ix].value);
+            scroll_into_view(target, window, app);
+        }
+    }
+
+    fn pick(&self, ix: usize, window: &mut Window, app: &mut App) {
+        let item = &self.matches[ix];
+        if item.disabled {
+            return;
+        }
+
+        let changed = self.state.update(app, |state, cx| {
+            state.query = item.label.clone();
+            state.highlighted = None;
+            let changed = state.selected.as_ref() != Some(&item.value);
+            if !self.controlled {
+                state.selected = Some(item.value.clone());
+            }
+            cx.notify();
+            changed
+        });
+        self.set_open(false, OpenReason::Trigger, window, app);
+        if !changed {
+            return;
+        }
+
+        if let Some(on_select) = &self.on_select {
+            on_select(&item.value, window, app);
+        }
+        publish_event(
+            OptionSelected {
+                id: self.id.clone(),
+                value: item.value.clone(),
+            },
+            app,
+        );
+    }
+
+    /// Handles a key press in the field, returning whether it was consumed.
+    fn key_down(
+        &self,
+        event: &KeyDownEvent,
+        open: bool,
+        window: &mut Window,
+        app: &mut App,
+    ) -> bool {
+        match event.keystroke.key.as_str() {
+            "down" | "up" if !open => {
+                self.set_open(true, OpenReason::Trigger, window, app);
+                self.step(event.keystroke.key == "down", window, app);
+            }
+            "down" => self.step(true, window, app),
+            "up" => self.step(false, window, app),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl RenderOnce for Combobox {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let open_state = use_open_state(self.id.clone(), &self.open_props, window, app);
+        let open = open_state.read(app).is_open();
+
+        let items = self.items;
+        let default_value = self.default_value;
+        let state = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.id.clone()), "combobox".into()),
+            app,
+            |_, _| {
+                let query = default_value
+                    .as_ref()
+                    .and_then(|value| items.iter().find(|item| item.value == *value))
+                    .map(|item| item.label.clone())
+                    .unwrap_or_default();
+                ComboboxState {
+                    query,
+                    highlighted: None,
+                    highlight_first: false,
+                    selected: default_value,
+                }
+            },
+        );
+        let (query, highlighted, highlight_first, selected) = state.update(app, |state, _| {
+            if let Some(value) = self.value.clone()
+                && state.selected.as_ref() != Some(&value)
+            {
+                if let Some(item) = items.iter().find(|item| item.value == value) {
+                    state.query = item.label.clone();
+                }
+                state.selected = Some(value);
+            }
+            (
+                state.query.clone(),
+                state.highlighted,
+                std::mem::take(&mut state.highlight_first),
+                state.selected.clone(),
+            )
+        });
+
+        // The label of the selected option lists every option, until the text is edited.
+        let filter = self.filter_fn.unwrap_or_else(|| Rc::new(default_filter));
+        let shows_selection = selected
+            .as_ref()
+            .and_then(|value| items.iter().find(|item| item.value == *value))
+            .is_some_and(|item| item.label == query);
+        let matches: Rc<[ListboxItemContext]> = items
+            .into_iter()
+            .map(|item| ListboxItemContext {
+                selected: selected.as_ref() == Some(&item.value),
+                value: item.value,
+                label: item.label,
+                index: 0,
+                highlighted: false,
+                disabled: item.disabled,
+            })
+            .filter(|item| shows_selection || query.is_empty() || filter(&query, item))
+            .collect();
+        let highlighted = if highlight_first {
+            let first =
+                selection::step(matches.len(), None, true, false, |ix| !matches[ix].disabled);
+            state.update(app, |state, _| state.highlighted = first);
+            first
+        } else {
+            highlighted.filter(|&ix| ix < matches.len())
+        };
+
+        let controller = ComboboxController {
+            id: self.id.clone(),
+            matches,
+            state: state.clone(),
+            open_state,
+            controlled: self.value.is_some(),
+            on_select: self.on_select,
+        };
+
+        let field = text_field(ElementId::NamedChild(
+            Box::new(self.id.clone()),
+            "field".into(),
+        ))
+        .w_full()
+        .value(query)
+        .when_some(self.placeholder, |this, placeholder| {
+            this.placeholder(placeholder)
+        })
+        .on_input({
+            let controller = controller.clone();
+            move |event, window, app| {
+                controller.state.update(app, |state, cx| {
+                    state.query = event.value.clone();
+                    state.highlighted = None;
+                    state.highlight_first = !event.value.is_empty();
+                    cx.notify();
+                });
+                controller.set_open(true, OpenReason::Trigger, window, app);
+            }
+        });
+        let field = match self.field {
+            Some(customize) => customize(field),
+            None => field,
+        };
+
+        let list = Listbox::new(controller.list_id())
+            .items(controller.matches.iter().map(|item| {
+                ListboxItem::new(item.value.clone(), item.label.clone()).disabled(item.disabled)
+            }))
+            .selected(selected)
+            .highlighted(highlighted.map(|ix| controller.matches[ix].value.clone()))
+            .on_highlight({
+                let controller = controller.clone();
+                move |value, _, app| {
+                    if let Some(ix) = controller.position(value) {
+                        controller.highlight(ix, app);
+                    }
+                }
+            })
+            .on_selection_change({
+                let controller = controller.clone();
+                move |values, window, app| {
+                    if let Some(ix) = values.first().and_then(|value| controller.position(value)) {
+                        controller.pick(ix, window, app);
+                    }
+                }
+            })
+            .when_some(self.render_item, |list, render_item| {
+                list.render_item(move |item, window, app| render_item(item, window, app))
+            });
+        let panel = match self.list {
+            Some(style) => style(div()),
+            None => div(),
+        };
+
+        let has_matches = !controller.matches.is_empty();
+        let trigger = self
+            .base
+            .id(self.id.clone())
+            .capture_action({
+                let controller = controller.clone();
+                move |_: &Enter, window, app| {
+                    let highlighted = controller.state.read(app).highlighted;
+                    if let Some(ix) = highlighted.filter(|_| open) {
+                        controller.pick(ix, window, app);
+                        app.stop_propagation();
+                    }
+                }
+            })
+            .on_key_down({
+                let controller = controller.clone();
+                move |event, window, app| {
+                    if controller.key_down(event, open, window, app) {
+                        app.stop_propagation();
+                    }
+                }
+            })
+            .child(field);
+
+        anchored_popover(ElementId::NamedChild(
+            Box::new(self.id.clone()),
+            "popover".into(),
+        ))
+        .trigger(trigger)
+        .options(self.options)
+        .open(open && has_matches)
+        .on_open_change(move |event, window, app| {
+            // Clicking the field keeps the list as it is.
+            if event.reason != OpenReason::Trigger {
+                controller.set_open(event.open, event.reason, window, app);
+            }
+        })
+        .child(
+            panel
+                .id(ElementId::NamedChild(Box::new(self.id), "panel".into()))
+                .child(list),
+        )
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{
+    Layer, OpenProps, OpenReason, OpenState, Openable, WindowInfo, primitives::focus_trap,
+    use_open_state, use_overlay,
+};
+use gpui::{prelude::FluentBuilder, *};
+use smallvec::SmallVec;
+
+/// A headless modal dialog.
+///
+/// While open, the dialog renders on the [`Layer::Modal`] layer with a full-window
+/// backdrop that blocks pointer interaction and scrolling underneath, and Escape closes
+/// it when it is the topmost overlay. Focus moves into the dialog and stays trapped in
+/// it, and goes back to the previously focused element on close.
+///
+/// # Examples
+///
+/// ```rust
+/// Dialog::new("delete-dialog")
+///     .open(self.dialog_open)
+///     .on_open_change(cx.listener(|this, event: &OpenChangeEvent, _, cx| {
+///         this.dialog_open = event.open;
+///         cx.notify();
+///     }))
+///     .backdrop(|backdrop| backdrop.bg(rgba(0x00000080)))
+///     .bg(rgb(0xffffff))
+///     .p_4()
+///     .child("Are you sure?")
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Dialog {
+    id: ElementId,
+    base: Div,
+    children: SmallVec<[AnyElement; 2]>,
+    open_props: OpenProps,
+    backdrop: Option<Box<dyn FnOnce(Div) -> Div>>,
+    close_on_escape: bool,
+    close_on_outside_click: bool,
+    restore_focus: bool,
+}
+
+/// Focus bookkeeping kept across renders of a [`Dialog`].
+struct DialogState {
+    focus_handle: FocusHandle,
+    previous_focus: Option<FocusHandle>,
+    was_open: bool,
+}
+
+impl Dialog {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            children: SmallVec::new(),
+            open_props: OpenProps::default(),
+            backdrop: None,
+            close_on_escape: true,
+            close_on_outside_click: true,
+            restore_focus: true,
+        }
+    }
+
+    /// Styles the backdrop rendered behind the dialog.
+    pub fn backdrop(mut self, handler: impl FnOnce(Div) -> Div + 'static) -> Self {
+        self.backdrop = Some(Box::new(handler));
+        self
+    }
+
+    /// Whether pressing Escape asks the dialog to close, `true` by default.
+    pub fn close_on_escape(mut self, close: bool) -> Self {
+        self.close_on_escape = close;
+        self
+    }
+
+    /// Whether clicking the backdrop asks the dialog to close, `true` by default.
+    pub fn close_on_outside_click(mut self, close: bool) -> Self {
+        self.close_on_outside_click = close;
+        self
+    }
+
+    /// Whether focus returns to the previously focused element on close, `true` by default.
+    pub fn restore_focus(mut self, restore: bool) -> Self {
+        self.restore_focus = restore;
+        self
+    }
+}
+
+impl Openable for Dialog {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+impl ParentElement for Dialog {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Dialog {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+fn request_close(
+    open_state: &Entity<OpenState>,
+    reason: OpenReason,
+    window: &mut Window,
+    app: &mut App,
+) {
+    open_state.update(app, |state, cx| state.close(reason, window, cx));
+}
+
+impl RenderOnce for Dialog {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let open_state = use_open_state(self.id.clone(), &self.open_props, window, app);
+        let open = open_state.read(app).is_open();
+
+        let state = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.id.clone()), "dialog".into()),
+            app,
+            |_, cx| DialogState {
+                focus_handle: cx.focus_handle(),
+                previous_focus: None,
+                was_open: false,
+            },
+        );
+
+        let focus_handle = state.update(app, |state, cx| {
+            if open && !state.was_open {
+                state.previous_focus = window.focused(cx);
+                window.focus(&state.focus_handle);
+            } else if !open
+                && state.was_open
+                && let Some(previous_focus) = state.previous_focus.take()
+                && self.restore_focus
+            {
+                window.focus(&previous_focus);
+            }
+            state.was_open = open;
+            state.focus_handle.clone()
+        });
+
+        let content = open.then(|| {
+            let viewport = WindowInfo::current(window, app).viewport_size;
+            let mut backdrop = div().absolute().inset_0();
+            if let Some(handler) = self.backdrop {
+                backdrop = handler(backdrop);
+            }
+
+            div()
+                .relative()
+                .w(viewport.width)
+                .h(viewport.height)
+                .child(
+                    backdrop
+                        .id(ElementId::NamedChild(
+                            Box::new(self.id.clone()),
+                            "backdrop".into(),
+                        ))
+                        .occlude()
+                        .when(self.close_on_outside_click, |this| {
+                            let open_state = open_state.clone();
+                            this.on_mouse_down(MouseButton::Left, move |_, window, app| {
+                                request_close(&open_state, OpenReason::OutsideClick, window, app)
+                            })
+                        }),
+                )
+                .child(
+                    div()
+                        .absolute()
+                        .inset_0()
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .child(
+                            focus_trap(self.id.clone())
+                                .focus_handle(&focus_handle)
+                                .occlude()
+                                .child(self.base.children(self.children)),
+                        ),
+                )
+                .into_any_element()
+        });
+
+        // The backdrop handles outside clicks itself, so Escape is the only way the
+        // layer stack dismisses the dialog.
+        use_overlay(
+            &self.id,
+            Layer::Modal,
+            content,
+            |overlay| {
+                overlay
+                    .modal(true)
+                    .dismiss_on_outside_click(false)
+                    .dismiss_on_escape(self.close_on_escape)
+                    .on_dismiss(move |window, app| {
+                        request_close(&open_state, OpenReason::EscapeKey, window, app)
+                    })
+            },
+            window,
+            app,
+        );
+
+        Empty
+    }
+}
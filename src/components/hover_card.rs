@@ -0,0 +1,236 @@
+use crate::{
+    Layer, OpenProps, OpenReason, OpenState, Openable,
+    primitives::anchored::{Align, AnchoredOptions, Side, floating},
+    use_open_state, use_overlay,
+};
+use gpui::*;
+use smallvec::SmallVec;
+use std::time::Duration;
+
+const DEFAULT_OPEN_DELAY: Duration = Duration::from_millis(700);
+const DEFAULT_CLOSE_DELAY: Duration = Duration::from_millis(300);
+
+/// A card that opens while the pointer rests on a trigger element.
+///
+/// The card opens after the open delay, stays open while the pointer is over either
+/// the trigger or the card, and closes after the close delay once it leaves both.
+///
+/// # Examples
+///
+/// ```rust
+/// HoverCard::new("user-card")
+///     .trigger(a("jane", "https://example.com/@jane").child("@jane"))
+///     .side(Side::Top)
+///     .bg(rgb(0xffffff))
+///     .p_2()
+///     .child("Jane Doe")
+/// ```
+#[derive(IntoElement)]
+pub struct HoverCard {
+    id: ElementId,
+    base: Div,
+    trigger: Option<AnyElement>,
+    children: SmallVec<[AnyElement; 2]>,
+    open_props: OpenProps,
+    options: AnchoredOptions,
+    open_delay: Duration,
+    close_delay: Duration,
+}
+
+/// Hover tracking and the pending open/close timer of a [`HoverCard`].
+struct HoverCardState {
+    trigger_bounds: Option<Bounds<Pixels>>,
+    trigger_hovered: bool,
+    card_hovered: bool,
+    epoch: usize,
+}
+
+impl HoverCardState {
+    /// Schedules the card to open or close once the pointer settles.
+    fn schedule(
+        &mut self,
+        open_state: Entity<OpenState>,
+        open_delay: Duration,
+        close_delay: Duration,
+        window: &Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.epoch += 1;
+        let epoch = self.epoch;
+        let open = self.trigger_hovered || self.card_hovered;
+        if open_state.read(cx).is_open() == open {
+            return;
+        }
+
+        let delay = if open { open_delay } else { close_delay };
+        let window_handle = window.window_handle();
+        cx.spawn(async move |this, cx| {
+            Timer::after(delay).await;
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            window_handle
+                .update(cx, |_, window, app| {
+                    if this.read(app).epoch == epoch {
+                        open_state.update(app, |state, cx| {
+                            state.set_open(open, OpenReason::Hover, window, cx)
+                        });
+                    }
+                })
+                .ok();
+        })
+        .detach();
+    }
+}
+
+impl HoverCard {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            trigger: None,
+            children: SmallVec::new(),
+            open_props: OpenProps::default(),
+            options: AnchoredOptions::default(),
+            open_delay: DEFAULT_OPEN_DELAY,
+            close_delay: DEFAULT_CLOSE_DELAY,
+        }
+    }
+
+    /// The element that opens the card when hovered.
+    pub fn trigger(mut self, trigger: impl IntoElement) -> Self {
+        self.trigger = Some(trigger.into_any_element());
+        self
+    }
+
+    /// How long the pointer has to rest on the trigger before the card opens.
+    pub fn open_delay(mut self, delay: Duration) -> Self {
+        self.open_delay = delay;
+        self
+    }
+
+    /// How long the card stays open after the pointer leaves it.
+    pub fn close_delay(mut self, delay: Duration) -> Self {
+        self.close_delay = delay;
+        self
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.options.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.options.align = align;
+        self
+    }
+
+    /// Distance between the trigger and the card.
+    pub fn offset(mut self, offset: Pixels) -> Self {
+        self.options.offset = offset;
+        self
+    }
+}
+
+impl Openable for HoverCard {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+impl ParentElement for HoverCard {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for HoverCard {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for HoverCard {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let open_state = use_open_state(self.id.clone(), &self.open_props, window, app);
+        let open = open_state.read(app).is_open();
+
+        let state = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.id.clone()), "hover-card".into()),
+            app,
+            |_, _| HoverCardState {
+                trigger_bounds: None,
+                trigger_hovered: false,
+                card_hovered: false,
+                epoch: 0,
+            },
+        );
+        let trigger_bounds = state.read(app).trigger_bounds;
+
+        let (open_delay, close_delay) = (self.open_delay, self.close_delay);
+        let on_hover = |card: bool| {
+            let state = state.clone();
+            let open_state = open_state.clone();
+            move |hovered: &bool, window: &mut Window, app: &mut App| {
+                state.update(app, |state, cx| {
+                    if card {
+                        state.card_hovered = *hovered;
+                    } else {
+                        state.trigger_hovered = *hovered;
+                    }
+                    state.schedule(open_state.clone(), open_delay, close_delay, window, cx);
+                });
+            }
+        };
+
+        let card = trigger_bounds.filter(|_| open).map(|bounds| {
+            floating(bounds)
+                .options(self.options)
+                .child(
+                    self.base
+                        .id(ElementId::NamedChild(
+                            Box::new(self.id.clone()),
+                            "card".into(),
+                        ))
+                        .occlude()
+                        .on_hover(on_hover(true))
+                        .children(self.children),
+                )
+                .into_any_element()
+        });
+        use_overlay(
+            &self.id,
+            Layer::Popover,
+            card,
+            |overlay| {
+                let open_state = open_state.clone();
+                overlay
+                    .dismiss_on_outside_click(false)
+                    .on_dismiss(move |window, app| {
+                        open_state.update(app, |state, cx| {
+                            state.close(OpenReason::EscapeKey, window, cx)
+                        })
+                    })
+            },
+            window,
+            app,
+        );
+
+        div()
+            .id(self.id.clone())
+            .relative()
+            .on_hover(on_hover(false))
+            .child({
+                let state = state.clone();
+                canvas(
+                    move |bounds, _, app| {
+                        state.update(app, |state, _| state.trigger_bounds = Some(bounds))
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .children(self.trigger)
+    }
+}
@@ -0,0 +1,171 @@
+use gpui::*;
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+const DEFAULT_THRESHOLD: Pixels = px(200.);
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Loads more content as a scroll container nears its end, as in feeds and search results.
+///
+/// [`InfiniteScroll::on_load_more`] is called when the container is scrolled within
+/// [`InfiniteScroll::threshold`] of its end, or when the content doesn't fill it, unless
+/// more content is [`InfiniteScroll::loading`] or there is no more of it. Calls are at least
+/// [`InfiniteScroll::debounce`] apart.
+///
+/// Observe a [`crate::primitives::VirtualList`] with
+/// [`crate::primitives::VirtualListScrollHandle::scroll_handle`].
+///
+/// # Examples
+///
+/// ```rust
+/// InfiniteScroll::new("feed", &list_handle.scroll_handle())
+///     .size_full()
+///     .loading(self.loading)
+///     .has_more(self.cursor.is_some())
+///     .on_load_more(move |_, app| feed.update(app, |feed, cx| feed.fetch_next_page(cx)))
+///     .child(
+///         virtual_list("posts", self.posts.len(), render_posts)
+///             .size_full()
+///             .track_scroll(&list_handle),
+///     )
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct InfiniteScroll {
+    id: ElementId,
+    base: Div,
+    scroll_handle: ScrollHandle,
+    threshold: Pixels,
+    debounce: Duration,
+    loading: bool,
+    has_more: bool,
+    on_load_more: Option<Rc<dyn Fn(&mut Window, &mut App) + 'static>>,
+}
+
+/// When more content was last requested, kept across renders of an [`InfiniteScroll`].
+#[derive(Default)]
+struct InfiniteScrollState {
+    last_load: Option<Instant>,
+    recheck_scheduled: bool,
+}
+
+impl InfiniteScrollState {
+    /// Returns whether to load now, or renders again once the debounce elapsed to check
+    /// whether to load then.
+    fn try_load(&mut self, debounce: Duration, cx: &mut Context<Self>) -> bool {
+        let now = Instant::now();
+        let elapsed = self
+            .last_load
+            .map_or(debounce, |last| now.duration_since(last));
+        if elapsed >= debounce {
+            self.last_load = Some(now);
+            return true;
+        }
+
+        if !self.recheck_scheduled {
+            self.recheck_scheduled = true;
+            cx.spawn(async move |this, cx| {
+                Timer::after(debounce - elapsed).await;
+                this.update(cx, |state, cx| {
+                    state.recheck_scheduled = false;
+                    cx.notify();
+                })
+                .ok();
+            })
+            .detach();
+        }
+        false
+    }
+}
+
+impl InfiniteScroll {
+    pub fn new(id: impl Into<ElementId>, scroll_handle: &ScrollHandle) -> Self {
+        Self {
+            id: id.into(),
+            base: div().relative(),
+            scroll_handle: scroll_handle.clone(),
+            threshold: DEFAULT_THRESHOLD,
+            debounce: DEFAULT_DEBOUNCE,
+            loading: false,
+            has_more: true,
+            on_load_more: None,
+        }
+    }
+
+    /// How close to the end more content is loaded, 200px by default.
+    pub fn threshold(mut self, threshold: Pixels) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// The least time between two loads, 200ms by default.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Whether more content is being loaded, pausing further loads.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Whether there is more content to load, `true` by default.
+    pub fn has_more(mut self, has_more: bool) -> Self {
+        self.has_more = has_more;
+        self
+    }
+
+    pub fn on_load_more(mut self, on_load_more: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_load_more = Some(Rc::new(on_load_more));
+        self
+    }
+}
+
+impl ParentElement for InfiniteScroll {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.base.extend(elements);
+    }
+}
+
+impl Styled for InfiniteScroll {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for InfiniteScroll {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id, app, |_, _| InfiniteScrollState::default());
+        let paused = self.loading || !self.has_more;
+        let (handle, threshold, debounce) = (self.scroll_handle, self.threshold, self.debounce);
+        let on_load_more = self.on_load_more.filter(|_| !paused);
+
+        self.base.child(
+            canvas(
+                move |_, window, app| {
+                    let Some(on_load_more) = on_load_more else {
+                        return;
+                    };
+                    // Wait for the container to be laid out.
+                    if handle.bounds().size.height <= px(0.) {
+                        return;
+                    }
+                    let remaining = handle.max_offset().height + handle.offset().y;
+                    if remaining > threshold {
+                        return;
+                    }
+
+                    if state.update(app, |state, cx| state.try_load(debounce, cx)) {
+                        window.defer(app, move |window, app| on_load_more(window, app));
+                    }
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full(),
+        )
+    }
+}
@@ -0,0 +1,499 @@
+use crate::{
+    AccessibilityProps, Accessible, Disableable, Role, SelectionMode,
+    primitives::v_flex,
+    scroll_container, scroll_into_view, scroll_target,
+    selection::{self, pick, toggle},
+    typeahead::Typeahead,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+/// An option of a [`Listbox`].
+#[derive(Clone, Debug)]
+pub struct ListboxItem {
+    pub(super) value: SharedString,
+    pub(super) label: SharedString,
+    pub(super) disabled: bool,
+    accessibility: AccessibilityProps,
+}
+
+impl ListboxItem {
+    /// Creates an item, the label is used for typeahead and rendered by default.
+    pub fn new(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            disabled: false,
+            accessibility: AccessibilityProps::new(Role::ListboxOption),
+        }
+    }
+}
+
+impl Accessible for ListboxItem {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl Disableable for ListboxItem {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// What a [`Listbox`] item is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListboxItemContext {
+    pub value: SharedString,
+    pub label: SharedString,
+    pub index: usize,
+    pub selected: bool,
+    /// Whether the item has the keyboard cursor or the pointer.
+    pub highlighted: bool,
+    pub disabled: bool,
+}
+
+/// A list of options with single or multiple selection.
+///
+/// ArrowUp/ArrowDown/Home/End move the highlight across enabled items, typing jumps to the
+/// next item whose label starts with the typed text, and Enter/Space select the
+/// highlighted item. In [`SelectionMode::Multiple`], Space toggles the item, Shift extends
+/// the selection from the last selected item and the secondary modifier (Cmd on macOS,
+/// Ctrl elsewhere) toggles clicked items.
+///
+/// Selects, comboboxes and command palettes render their options with a listbox; pass
+/// `.focus_handle(..)` to drive it from a handle they own, or `.highlighted(..)` to keep
+/// focus and keyboard navigation to themselves, as a combobox does in its text field.
+///
+/// # Examples
+///
+/// ```rust
+/// Listbox::new("fruits")
+///     .selection_mode(SelectionMode::Multiple)
+///     .items([
+///         ListboxItem::new("apple", "Apple"),
+///         ListboxItem::new("banana", "Banana").disabled(true),
+///         ListboxItem::new("cherry", "Cherry"),
+///     ])
+///     .on_selection_change(|values, _, _| println!("{values:?}"))
+///     .render_item(|item, _, _| {
+///         div()
+///             .px_2()
+///             .when(item.highlighted, |this| this.bg(rgb(0xf1f5f9)))
+///             .when(item.selected, |this| this.font_weight(FontWeight::BOLD))
+///             .child(item.label.clone())
+///     })
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Listbox {
+    id: ElementId,
+    base: Div,
+    items: Vec<ListboxItem>,
+    selection_mode: SelectionMode,
+    selected: Option<Vec<SharedString>>,
+    default_selected: Vec<SharedString>,
+    focus_handle: Option<FocusHandle>,
+    highlighted: Option<Option<SharedString>>,
+    on_selection_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+    on_highlight: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    render_item: Option<Box<dyn Fn(&ListboxItemContext, &mut Window, &mut App) -> AnyElement>>,
+    accessibility: AccessibilityProps,
+}
+
+/// Selection, highlight and typeahead kept across renders of a [`Listbox`].
+struct ListboxState {
+    focus_handle: FocusHandle,
+    selected: Vec<SharedString>,
+    anchor: Option<SharedString>,
+    highlighted: Option<SharedString>,
+    typeahead: Typeahead,
+    scroll_handle: ScrollHandle,
+}
+
+impl Listbox {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: v_flex(),
+            items: Vec::new(),
+            selection_mode: SelectionMode::Single,
+            selected: None,
+            default_selected: Vec::new(),
+            focus_handle: None,
+            highlighted: None,
+            on_selection_change: None,
+            on_highlight: None,
+            render_item: None,
+            accessibility: AccessibilityProps::new(Role::Listbox),
+        }
+    }
+
+    pub fn item(mut self, item: ListboxItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = ListboxItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Controls the values of the selected items.
+    pub fn selected(mut self, values: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.selected = Some(values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// The values selected on first render when the listbox is not controlled.
+    pub fn default_selected(
+        mut self,
+        values: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.default_selected = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Tracks the given handle instead of an internal one.
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
+    /// Controls the highlighted item, leaving keyboard navigation to the owner: the
+    /// listbox then neither takes focus nor handles keys.
+    pub fn highlighted(mut self, value: Option<impl Into<SharedString>>) -> Self {
+        self.highlighted = Some(value.map(Into::into));
+        self
+    }
+
+    pub fn on_selection_change(
+        mut self,
+        on_change: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_selection_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Called with the value of the item the pointer highlights.
+    pub fn on_highlight(
+        mut self,
+        on_highlight: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_highlight = Some(Rc::new(on_highlight));
+        self
+    }
+
+    /// Renders each item, the label is rendered when this isn't set.
+    pub fn render_item<E: IntoElement>(
+        mut self,
+        render: impl Fn(&ListboxItemContext, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.render_item = Some(Box::new(move |item, window, app| {
+            render(item, window, app).into_any_element()
+        }));
+        self
+    }
+}
+
+impl Accessible for Listbox {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl Styled for Listbox {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// The items of a rendered listbox together with its state, shared by its listeners.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct ListboxController {
+    id: ElementId,
+    items: Rc<[ListboxItem]>,
+    state: Entity<ListboxState>,
+    selection_mode: SelectionMode,
+    controlled: bool,
+    on_selection_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+}
+
+impl ListboxController {
+    fn highlighted(&self, app: &App) -> Option<usize> {
+        let highlighted = self.state.read(app).highlighted.as_ref()?;
+        self.items
+            .iter()
+            .position(|item| item.value == *highlighted)
+    }
+
+    fn highlight(&self, ix: usize, app: &mut App) {
+        let value = self.items[ix].value.clone();
+        self.state.update(app, |state, cx| {
+            state.highlighted = Some(value);
+            cx.notify();
+        });
+    }
+
+    /// Identifies an item to [`scroll_into_view`].
+    fn item_target(&self, ix: usize) -> ElementId {
+        listbox_item_target(&self.id, &self.items[ix].value)
+    }
+
+    /// Highlights an item moved to with the keyboard, scrolling it into view.
+    fn reveal(&self, ix: usize, window: &mut Window, app: &mut App) {
+        self.highlight(ix, app);
+        scroll_into_view(self.item_target(ix), window, app);
+    }
+
+    /// Returns the next enabled item in the given direction, without wrapping.
+    fn step(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        selection::step(self.items.len(), from, forward, false, |ix| {
+            !self.items[ix].disabled
+        })
+    }
+
+    fn set_selection(
+        &self,
+        selected: Vec<SharedString>,
+        anchor: Option<SharedString>,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        self.state.update(app, |state, cx| {
+            if !self.controlled {
+                state.selected = selected.clone();
+            }
+            if anchor.is_some() {
+                state.anchor = anchor;
+            }
+            cx.notify();
+        });
+        if let Some(on_selection_change) = &self.on_selection_change {
+            on_selection_change(&selected, window, app);
+        }
+    }
+
+    fn select(&self, ix: usize, modifiers: &Modifiers, window: &mut Window, app: &mut App) {
+        let order: Vec<SharedString> = self.items.iter().map(|item| item.value.clone()).collect();
+        let state = self.state.read(app);
+        let (selected, anchor) = pick(
+            self.selection_mode,
+            &state.selected,
+            state.anchor.as_ref(),
+            &self.items[ix].value,
+            &order,
+            modifiers,
+        );
+        self.set_selection(selected, Some(anchor), window, app);
+    }
+
+    fn toggle_selected(&self, ix: usize, window: &mut Window, app: &mut App) {
+        let value = &self.items[ix].value;
+        let selected = toggle(&self.state.read(app).selected, value);
+        self.set_selection(selected, Some(value.clone()), window, app);
+    }
+
+    fn select_all(&self, window: &mut Window, app: &mut App) {
+        let selected = self
+            .items
+            .iter()
+            .filter(|item| !item.disabled)
+            .map(|item| item.value.clone())
+            .collect();
+        self.set_selection(selected, None, window, app);
+    }
+
+    /// Moves the highlight, extending the selection when Shift is held.
+    fn move_to(
+        &self,
+        ix: Option<usize>,
+        modifiers: &Modifiers,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        let Some(ix) = ix else {
+            return;
+        };
+        self.reveal(ix, window, app);
+        if modifiers.shift && self.selection_mode == SelectionMode::Multiple {
+            self.select(ix, modifiers, window, app);
+        }
+    }
+
+    /// Handles a key press on the focused listbox, returning whether it was consumed.
+    fn key_down(&self, event: &KeyDownEvent, window: &mut Window, app: &mut App) -> bool {
+        let modifiers = &event.keystroke.modifiers;
+        let highlighted = self.highlighted(app);
+        match event.keystroke.key.as_str() {
+            "down" => self.move_to(self.step(highlighted, true), modifiers, window, app),
+            "up" => self.move_to(self.step(highlighted, false), modifiers, window, app),
+            "home" => self.move_to(self.step(None, true), modifiers, window, app),
+            "end" => self.move_to(self.step(None, false), modifiers, window, app),
+            "a" if modifiers.secondary() && self.selection_mode == SelectionMode::Multiple => {
+                self.select_all(window, app)
+            }
+            "enter" => {
+                let Some(ix) = highlighted else {
+                    return false;
+                };
+                self.select(ix, modifiers, window, app);
+            }
+            "space" => {
+                let Some(ix) = highlighted else {
+                    return false;
+                };
+                if self.selection_mode == SelectionMode::Multiple {
+                    self.toggle_selected(ix, window, app);
+                } else {
+                    self.select(ix, modifiers, window, app);
+                }
+            }
+            _ => {
+                let Some(text) = Typeahead::input(&event.keystroke) else {
+                    return false;
+                };
+                let query = self
+                    .state
+                    .update(app, |state, _| state.typeahead.push(text).to_string());
+                let found = Typeahead::find(&query, self.items.len(), highlighted, |ix| {
+                    let item = &self.items[ix];
+                    (!item.disabled).then_some(item.label.as_ref())
+                });
+                if let Some(ix) = found {
+                    self.reveal(ix, window, app);
+                }
+            }
+        }
+        true
+    }
+}
+
+/// Identifies the item with the given value in the listbox with the given id, to
+/// [`scroll_into_view`].
+pub(super) fn listbox_item_target(id: &ElementId, value: &SharedString) -> ElementId {
+    ElementId::NamedChild(Box::new(id.clone()), value.clone())
+}
+
+impl RenderOnce for Listbox {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let default_selected = self.default_selected;
+        let state = window.use_keyed_state(self.id.clone(), app, |_, cx| ListboxState {
+            focus_handle: cx.focus_handle().tab_stop(true),
+            selected: default_selected,
+            anchor: None,
+            highlighted: None,
+            typeahead: Typeahead::default(),
+            scroll_handle: ScrollHandle::new(),
+        });
+        let driven = self.highlighted.is_some();
+        let (focus_handle, selected, scroll_handle) = state.update(app, |state, _| {
+            if let Some(selected) = self.selected.clone() {
+                state.selected = selected;
+            }
+            if let Some(highlighted) = self.highlighted {
+                state.highlighted = highlighted;
+            }
+            if let Some(focus_handle) = self.focus_handle {
+                state.focus_handle = focus_handle;
+            }
+            (
+                state.focus_handle.clone(),
+                state.selected.clone(),
+                state.scroll_handle.clone(),
+            )
+        });
+
+        let controller = ListboxController {
+            id: self.id.clone(),
+            items: self.items.into(),
+            state: state.clone(),
+            selection_mode: self.selection_mode,
+            controlled: self.selected.is_some(),
+            on_selection_change: self.on_selection_change,
+        };
+        let highlighted = controller.highlighted(app);
+
+        let mut rows = Vec::with_capacity(controller.items.len());
+        for (ix, item) in controller.items.iter().enumerate() {
+            let context = ListboxItemContext {
+                value: item.value.clone(),
+                label: item.label.clone(),
+                index: ix,
+                selected: selected.contains(&item.value),
+                highlighted: highlighted == Some(ix),
+                disabled: item.disabled,
+            };
+            let content = match &self.render_item {
+                Some(render_item) => render_item(&context, window, app),
+                None => item.label.clone().into_any_element(),
+            };
+
+            rows.push(
+                div()
+                    .id(("listbox-item", ix))
+                    .relative()
+                    .when(!item.disabled, |this| {
+                        this.map(|this| {
+                            let controller = controller.clone();
+                            let on_highlight = self.on_highlight.clone();
+                            this.on_hover(move |hovered, window, app| {
+                                if *hovered {
+                                    controller.highlight(ix, app);
+                                    if let Some(on_highlight) = &on_highlight {
+                                        on_highlight(&controller.items[ix].value, window, app);
+                                    }
+                                }
+                            })
+                        })
+                        .map(|this| {
+                            let controller = controller.clone();
+                            let focus_handle = focus_handle.clone();
+                            this.on_click(move |event, window, app| {
+                                if !driven {
+                                    window.focus(&focus_handle);
+                                }
+                                controller.highlight(ix, app);
+                                controller.select(ix, &event.modifiers(), window, app);
+                            })
+                        })
+                    })
+                    .child(content)
+                    .child(scroll_target(controller.item_target(ix))),
+            );
+        }
+
+        self.base
+            .id(self.id)
+            .track_scroll(&scroll_handle)
+            .when(!driven, |this| {
+                this.track_focus(&focus_handle)
+                    .on_key_down(move |event, window, app| {
+                        if controller.key_down(event, window, app) {
+                            app.stop_propagation();
+                        }
+                    })
+            })
+            .children(rows)
+            .child(scroll_container(&scroll_handle))
+    }
+}
@@ -0,0 +1,326 @@
+use super::{MenuEntry, MenuItem};
+use crate::Disableable;
+use gpui::*;
+use std::{collections::HashMap, rc::Rc};
+
+/// Checked values of the uncontrolled checkbox items and radio groups of a menu.
+///
+/// Kept by the menu itself rather than its panels, so the values survive the menu
+/// closing and opening again.
+#[derive(Default)]
+pub(super) struct MenuSelection {
+    checked: HashMap<ElementId, bool>,
+    values: HashMap<ElementId, SharedString>,
+}
+
+pub(super) fn use_menu_selection(
+    id: &ElementId,
+    window: &mut Window,
+    app: &mut App,
+) -> Entity<MenuSelection> {
+    window.use_keyed_state(
+        ElementId::NamedChild(Box::new(id.clone()), "selection".into()),
+        app,
+        |_, _| MenuSelection::default(),
+    )
+}
+
+/// Prepends the indicator to the item, keeping the label when it has no other children.
+fn with_indicator(
+    mut item: MenuItem,
+    indicator: Option<Box<dyn FnOnce(bool) -> AnyElement>>,
+    checked: bool,
+) -> MenuItem {
+    if let Some(indicator) = indicator {
+        if item.children.is_empty() {
+            item.children.push(item.label.clone().into_any_element());
+        }
+        item.children.insert(0, indicator(checked));
+    }
+    item
+}
+
+/// A menu item that toggles a checked value when activated.
+///
+/// The value is kept by the menu unless it is controlled with `.checked(..)`.
+#[allow(clippy::type_complexity)]
+pub struct MenuCheckboxItem {
+    id: ElementId,
+    item: MenuItem,
+    checked: Option<bool>,
+    default_checked: bool,
+    on_change: Option<Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+    indicator: Option<Box<dyn FnOnce(bool) -> AnyElement>>,
+}
+
+impl MenuCheckboxItem {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            item: MenuItem::new(label),
+            checked: None,
+            default_checked: false,
+            on_change: None,
+            indicator: None,
+        }
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn default_checked(mut self, checked: bool) -> Self {
+        self.default_checked = checked;
+        self
+    }
+
+    pub fn on_change(mut self, on_change: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Renders the indicator in front of the label, given whether the item is checked.
+    pub fn indicator<E: IntoElement>(
+        mut self,
+        indicator: impl FnOnce(bool) -> E + 'static,
+    ) -> Self {
+        self.indicator = Some(Box::new(move |checked| {
+            indicator(checked).into_any_element()
+        }));
+        self
+    }
+
+    /// Whether toggling the item closes the menu, `true` by default.
+    pub fn close_on_select(mut self, close: bool) -> Self {
+        self.item = self.item.close_on_select(close);
+        self
+    }
+
+    /// Conditionally modify the underlying item while it is highlighted.
+    pub fn when_highlighted(
+        mut self,
+        handler: impl FnOnce(MenuItem) -> MenuItem + 'static,
+    ) -> Self {
+        self.item = self.item.when_highlighted(handler);
+        self
+    }
+
+    pub(super) fn into_item(self, selection: &Entity<MenuSelection>, app: &App) -> MenuItem {
+        let controlled = self.checked.is_some();
+        let checked = self.checked.unwrap_or_else(|| {
+            let stored = selection.read(app).checked.get(&self.id).copied();
+            stored.unwrap_or(self.default_checked)
+        });
+
+        let id = self.id;
+        let selection = selection.clone();
+        let on_change = self.on_change;
+        with_indicator(self.item, self.indicator, checked).on_select(move |window, app| {
+            if !controlled {
+                selection.update(app, |selection, cx| {
+                    selection.checked.insert(id.clone(), !checked);
+                    cx.notify();
+                });
+            }
+            if let Some(on_change) = &on_change {
+                on_change(&!checked, window, app);
+            }
+        })
+    }
+}
+
+impl ParentElement for MenuCheckboxItem {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.item.extend(elements);
+    }
+}
+
+impl Styled for MenuCheckboxItem {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.item.style()
+    }
+}
+
+impl Disableable for MenuCheckboxItem {
+    fn is_disabled(&self) -> bool {
+        self.item.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.item.disabled = disabled;
+        self
+    }
+}
+
+/// A set of mutually exclusive [`MenuRadioItem`]s sharing a selected value.
+///
+/// The value is kept by the menu unless it is controlled with `.value(..)`.
+#[allow(clippy::type_complexity)]
+pub struct MenuRadioGroup {
+    id: ElementId,
+    value: Option<SharedString>,
+    default_value: Option<SharedString>,
+    on_change: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    items: Vec<MenuRadioItem>,
+}
+
+impl MenuRadioGroup {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: None,
+            default_value: None,
+            on_change: None,
+            items: Vec::new(),
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.default_value = Some(value.into());
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+
+    pub fn item(mut self, item: MenuRadioItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub(super) fn into_items(
+        self,
+        selection: &Entity<MenuSelection>,
+        app: &App,
+    ) -> impl Iterator<Item = MenuItem> {
+        let controlled = self.value.is_some();
+        let value = self.value.or_else(|| {
+            let stored = selection.read(app).values.get(&self.id).cloned();
+            stored.or(self.default_value)
+        });
+
+        let id = self.id;
+        let selection = selection.clone();
+        let on_change = self.on_change;
+        self.items.into_iter().map(move |radio| {
+            let checked = value.as_ref() == Some(&radio.value);
+            let id = id.clone();
+            let selection = selection.clone();
+            let on_change = on_change.clone();
+            let radio_value = radio.value;
+            with_indicator(radio.item, radio.indicator, checked).on_select(move |window, app| {
+                if checked {
+                    return;
+                }
+                if !controlled {
+                    selection.update(app, |selection, cx| {
+                        selection.values.insert(id.clone(), radio_value.clone());
+                        cx.notify();
+                    });
+                }
+                if let Some(on_change) = &on_change {
+                    on_change(&radio_value, window, app);
+                }
+            })
+        })
+    }
+}
+
+/// An item of a [`MenuRadioGroup`] that selects its value when activated.
+#[allow(clippy::type_complexity)]
+pub struct MenuRadioItem {
+    value: SharedString,
+    item: MenuItem,
+    indicator: Option<Box<dyn FnOnce(bool) -> AnyElement>>,
+}
+
+impl MenuRadioItem {
+    pub fn new(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            item: MenuItem::new(label),
+            indicator: None,
+        }
+    }
+
+    /// Renders the indicator in front of the label, given whether the item is selected.
+    pub fn indicator<E: IntoElement>(
+        mut self,
+        indicator: impl FnOnce(bool) -> E + 'static,
+    ) -> Self {
+        self.indicator = Some(Box::new(move |checked| {
+            indicator(checked).into_any_element()
+        }));
+        self
+    }
+
+    /// Whether selecting the item closes the menu, `true` by default.
+    pub fn close_on_select(mut self, close: bool) -> Self {
+        self.item = self.item.close_on_select(close);
+        self
+    }
+
+    /// Conditionally modify the underlying item while it is highlighted.
+    pub fn when_highlighted(
+        mut self,
+        handler: impl FnOnce(MenuItem) -> MenuItem + 'static,
+    ) -> Self {
+        self.item = self.item.when_highlighted(handler);
+        self
+    }
+}
+
+impl ParentElement for MenuRadioItem {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.item.extend(elements);
+    }
+}
+
+impl Styled for MenuRadioItem {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.item.style()
+    }
+}
+
+impl Disableable for MenuRadioItem {
+    fn is_disabled(&self) -> bool {
+        self.item.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.item.disabled = disabled;
+        self
+    }
+}
+
+/// Replaces checkbox items and radio groups with plain items bound to the selection.
+pub(super) fn resolve_entries(
+    entries: Vec<MenuEntry>,
+    selection: &Entity<MenuSelection>,
+    app: &App,
+) -> Vec<MenuEntry> {
+    let mut resolved = Vec::with_capacity(entries.len());
+    for entry in entries {
+        match entry {
+            MenuEntry::CheckboxItem(item) => {
+                resolved.push(MenuEntry::Item(item.into_item(selection, app)))
+            }
+            MenuEntry::RadioGroup(group) => {
+                resolved.extend(group.into_items(selection, app).map(MenuEntry::Item))
+            }
+            entry => resolved.push(entry),
+        }
+    }
+    resolved
+}
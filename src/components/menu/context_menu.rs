@@ -0,0 +1,220 @@
+use super::{
+    MenuEntries, MenuEntry,
+    checkable::use_menu_selection,
+    list::{CloseMenu, menu_layer},
+};
+use crate::{
+    OpenProps, OpenReason, Openable,
+    primitives::anchored::{Align, AnchoredOptions},
+    use_open_state,
+};
+use gpui::*;
+use smallvec::SmallVec;
+use std::rc::Rc;
+
+/// The panel and entries of a [`ContextMenu`], built each time the menu is rendered.
+pub struct MenuContent {
+    base: Div,
+    entries: Vec<MenuEntry>,
+}
+
+impl Styled for MenuContent {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl MenuEntries for MenuContent {
+    fn entries_mut(&mut self) -> &mut Vec<MenuEntry> {
+        &mut self.entries
+    }
+}
+
+/// Opens a menu at the pointer when its children are right-clicked.
+///
+/// The menu can also be opened with the Menu key or Shift-F10 while focus is inside the
+/// children, in which case it opens at their top-left corner. Its entries are built only
+/// while it is open, so they can depend on the state of the clicked target.
+///
+/// # Examples
+///
+/// ```rust
+/// ContextMenu::new(("file-context-menu", ix))
+///     .menu(move |menu, _, _| {
+///         menu.bg(rgb(0xffffff))
+///             .item(MenuItem::new("Rename").on_select(move |_, _| rename(ix)))
+///             .item(MenuItem::new("Delete").disabled(read_only))
+///     })
+///     .child(file_row)
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct ContextMenu {
+    id: ElementId,
+    base: Div,
+    children: SmallVec<[AnyElement; 2]>,
+    menu: Option<Box<dyn FnOnce(MenuContent, &mut Window, &mut App) -> MenuContent>>,
+    open_props: OpenProps,
+}
+
+/// Menu position and focus bookkeeping kept across renders of a [`ContextMenu`].
+struct ContextMenuState {
+    position: Option<Point<Pixels>>,
+    target_bounds: Option<Bounds<Pixels>>,
+    previous_focus: Option<FocusHandle>,
+    was_open: bool,
+}
+
+impl ContextMenu {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            children: SmallVec::new(),
+            menu: None,
+            open_props: OpenProps::default(),
+        }
+    }
+
+    /// Builds the menu when it opens.
+    pub fn menu(
+        mut self,
+        builder: impl FnOnce(MenuContent, &mut Window, &mut App) -> MenuContent + 'static,
+    ) -> Self {
+        self.menu = Some(Box::new(builder));
+        self
+    }
+}
+
+impl Openable for ContextMenu {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+impl ParentElement for ContextMenu {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for ContextMenu {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+fn is_menu_key(keystroke: &Keystroke) -> bool {
+    keystroke.key == "menu" || (keystroke.key == "f10" && keystroke.modifiers.shift)
+}
+
+impl RenderOnce for ContextMenu {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let open_state = use_open_state(self.id.clone(), &self.open_props, window, app);
+        let open = open_state.read(app).is_open();
+
+        let state = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.id.clone()), "context-menu".into()),
+            app,
+            |_, _| ContextMenuState {
+                position: None,
+                target_bounds: None,
+                previous_focus: None,
+                was_open: false,
+            },
+        );
+
+        // Opened programmatically, the menu falls back to the corner of the target.
+        let (position, focus) = state.update(app, |state, cx| {
+            let position = state
+                .position
+                .or(state.target_bounds.map(|bounds| bounds.origin));
+            let open = open && position.is_some();
+            let focus = open && !state.was_open;
+            if focus {
+                state.previous_focus = window.focused(cx);
+            } else if !open
+                && state.was_open
+                && let Some(previous_focus) = state.previous_focus.take()
+            {
+                window.focus(&previous_focus);
+            }
+            state.was_open = open;
+            (position.filter(|_| open), focus)
+        });
+
+        let selection = use_menu_selection(&self.id, window, app);
+        let close_menu: CloseMenu = {
+            let open_state = open_state.clone();
+            Rc::new(move |reason, window, app| {
+                open_state.update(app, |state, cx| state.close(reason, window, cx))
+            })
+        };
+
+        let open_at = {
+            let state = state.clone();
+            let open_state = open_state.clone();
+            move |position: Option<Point<Pixels>>, window: &mut Window, app: &mut App| {
+                state.update(app, |state, _| state.position = position);
+                open_state.update(app, |state, cx| state.open(OpenReason::Trigger, window, cx));
+            }
+        };
+
+        if let Some(position) = position {
+            let mut content = MenuContent {
+                base: div(),
+                entries: Vec::new(),
+            };
+            if let Some(menu) = self.menu {
+                content = menu(content, window, app);
+            }
+
+            menu_layer(
+                self.id.clone(),
+                Bounds::new(position, Size::default()),
+                AnchoredOptions {
+                    align: Align::Start,
+                    offset: px(0.),
+                    ..AnchoredOptions::default()
+                },
+                content.base,
+                content.entries,
+                close_menu,
+                &selection,
+                None,
+                focus,
+                window,
+                app,
+            );
+        }
+
+        self.base
+            .id(self.id)
+            .relative()
+            .on_mouse_down(MouseButton::Right, {
+                let open_at = open_at.clone();
+                move |event, window, app| {
+                    app.stop_propagation();
+                    open_at(Some(event.position), window, app);
+                }
+            })
+            .on_key_down(move |event, window, app| {
+                if !open && is_menu_key(&event.keystroke) {
+                    app.stop_propagation();
+                    open_at(None, window, app);
+                }
+            })
+            .child({
+                let state = state.clone();
+                canvas(
+                    move |bounds, _, app| {
+                        state.update(app, |state, _| state.target_bounds = Some(bounds))
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .children(self.children)
+    }
+}
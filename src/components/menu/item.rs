@@ -0,0 +1,194 @@
+use super::{MenuCheckboxItem, MenuRadioGroup};
+use crate::{AccessibilityProps, Accessible, Disableable, Role, primitives::Kbd};
+use gpui::*;
+use smallvec::SmallVec;
+use std::rc::Rc;
+
+/// A row of a [`super::Menu`] that runs a callback when activated.
+///
+/// The label is rendered when the item has no children, and is always used for
+/// typeahead.
+#[allow(clippy::type_complexity)]
+pub struct MenuItem {
+    pub(super) label: SharedString,
+    pub(super) base: Div,
+    pub(super) children: SmallVec<[AnyElement; 2]>,
+    pub(super) shortcut: Option<Kbd>,
+    pub(super) disabled: bool,
+    pub(super) close_on_select: bool,
+    pub(super) on_select: Option<Rc<dyn Fn(&mut Window, &mut App) + 'static>>,
+    pub(super) when_highlighted_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    pub(super) when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    accessibility: AccessibilityProps,
+}
+
+impl MenuItem {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            base: div(),
+            children: SmallVec::new(),
+            shortcut: None,
+            disabled: false,
+            close_on_select: true,
+            on_select: None,
+            when_highlighted_handler: None,
+            when_disabled_handler: None,
+            accessibility: AccessibilityProps::new(Role::MenuItem),
+        }
+    }
+
+    pub fn on_select(mut self, on_select: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_select = Some(Rc::new(on_select));
+        self
+    }
+
+    /// Shows a keyboard shortcut at the end of the item, such as `kbd("cmd-s")` or
+    /// [`Kbd::for_action`]. It is only a hint, the shortcut is bound like any other.
+    pub fn shortcut(mut self, shortcut: Kbd) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Whether activating the item closes the menu, `true` by default.
+    pub fn close_on_select(mut self, close: bool) -> Self {
+        self.close_on_select = close;
+        self
+    }
+
+    /// Conditionally modify the item while it is highlighted by the pointer or keyboard.
+    pub fn when_highlighted(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_highlighted_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl ParentElement for MenuItem {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for MenuItem {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl Accessible for MenuItem {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl Disableable for MenuItem {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+/// A nested menu opened from an item of its parent menu.
+///
+/// The submenu opens when its item is hovered, or with ArrowRight and Enter while the item
+/// is highlighted, and closes again with ArrowLeft or Escape.
+pub struct Submenu {
+    pub(super) item: MenuItem,
+    pub(super) base: Div,
+    pub(super) entries: Vec<MenuEntry>,
+}
+
+impl Submenu {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            item: MenuItem::new(label).close_on_select(false),
+            base: div(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Customizes the item that opens the submenu in its parent menu.
+    pub fn trigger(mut self, handler: impl FnOnce(MenuItem) -> MenuItem) -> Self {
+        self.item = handler(self.item);
+        self
+    }
+}
+
+impl Styled for Submenu {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl Disableable for Submenu {
+    fn is_disabled(&self) -> bool {
+        self.item.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.item.disabled = disabled;
+        self
+    }
+}
+
+impl MenuEntries for Submenu {
+    fn entries_mut(&mut self) -> &mut Vec<MenuEntry> {
+        &mut self.entries
+    }
+}
+
+/// An entry of a menu or submenu.
+pub enum MenuEntry {
+    Item(MenuItem),
+    CheckboxItem(MenuCheckboxItem),
+    RadioGroup(MenuRadioGroup),
+    Separator(Div),
+    Submenu(Box<Submenu>),
+}
+
+/// Builders shared by [`super::Menu`] and [`Submenu`] to fill their list of entries.
+pub trait MenuEntries: Sized {
+    fn entries_mut(&mut self) -> &mut Vec<MenuEntry>;
+
+    fn item(mut self, item: MenuItem) -> Self {
+        self.entries_mut().push(MenuEntry::Item(item));
+        self
+    }
+
+    fn checkbox_item(mut self, item: MenuCheckboxItem) -> Self {
+        self.entries_mut().push(MenuEntry::CheckboxItem(item));
+        self
+    }
+
+    /// Adds the items of the group, in order.
+    fn radio_group(mut self, group: MenuRadioGroup) -> Self {
+        self.entries_mut().push(MenuEntry::RadioGroup(group));
+        self
+    }
+
+    /// Adds a separator, styled with the given handler.
+    fn separator(mut self, handler: impl FnOnce(Div) -> Div) -> Self {
+        self.entries_mut()
+            .push(MenuEntry::Separator(handler(div())));
+        self
+    }
+
+    fn submenu(mut self, submenu: Submenu) -> Self {
+        self.entries_mut()
+            .push(MenuEntry::Submenu(Box::new(submenu)));
+        self
+    }
+}
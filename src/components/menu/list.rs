@@ -0,0 +1,532 @@
+use super::{
+    MenuEntry, MenuItem, Submenu,
+    checkable::{MenuSelection, resolve_entries},
+};
+use crate::{
+    Direction, Layer, OpenReason, WindowInfo,
+    primitives::{
+        anchored::{Align, AnchoredOptions, Side, floating},
+        h_flex,
+    },
+    scroll_container, scroll_into_view, scroll_target, selection,
+    typeahead::Typeahead,
+    use_overlay,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::{collections::HashMap, rc::Rc};
+
+/// Closes the whole menu, including every open submenu.
+pub(super) type CloseMenu = Rc<dyn Fn(OpenReason, &mut Window, &mut App)>;
+
+/// Highlight, typeahead and submenu bookkeeping of one menu or submenu panel.
+pub(super) struct MenuListState {
+    focus_handle: FocusHandle,
+    highlighted: Option<usize>,
+    open_submenu: Option<usize>,
+    focus_submenu: bool,
+    submenu_bounds: HashMap<usize, Bounds<Pixels>>,
+    typeahead: Typeahead,
+    scroll_handle: ScrollHandle,
+}
+
+impl MenuListState {
+    fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            highlighted: None,
+            open_submenu: None,
+            focus_submenu: false,
+            submenu_bounds: HashMap::new(),
+            typeahead: Typeahead::default(),
+            scroll_handle: ScrollHandle::new(),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+enum EntryKind {
+    Item {
+        on_select: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+        close_on_select: bool,
+    },
+    Submenu,
+    Separator,
+}
+
+/// What the keyboard handlers need to know about an entry once it has been rendered.
+#[derive(Clone)]
+struct NavEntry {
+    label: SharedString,
+    disabled: bool,
+    kind: EntryKind,
+}
+
+impl NavEntry {
+    fn new(entry: &MenuEntry) -> Self {
+        match entry {
+            MenuEntry::Item(item) => Self {
+                label: item.label.clone(),
+                disabled: item.disabled,
+                kind: EntryKind::Item {
+                    on_select: item.on_select.clone(),
+                    close_on_select: item.close_on_select,
+                },
+            },
+            MenuEntry::Submenu(submenu) => Self {
+                label: submenu.item.label.clone(),
+                disabled: submenu.item.disabled,
+                kind: EntryKind::Submenu,
+            },
+            MenuEntry::Separator(_) => Self {
+                label: SharedString::default(),
+                disabled: true,
+                kind: EntryKind::Separator,
+            },
+            MenuEntry::CheckboxItem(_) | MenuEntry::RadioGroup(_) => {
+                unreachable!("checkable entries are resolved before rendering")
+            }
+        }
+    }
+
+    fn is_navigable(&self) -> bool {
+        !self.disabled && !matches!(self.kind, EntryKind::Separator)
+    }
+}
+
+/// What a horizontal arrow key does to the submenus of a panel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum SubmenuMove {
+    Open,
+    Close,
+}
+
+/// Returns the submenu move of an arrow key, pointing towards the side submenus open on,
+/// which is the left in RTL.
+pub(super) fn submenu_move(key: &str, direction: Direction) -> Option<SubmenuMove> {
+    match direction.arrow_forward(key)? {
+        true => Some(SubmenuMove::Open),
+        false => Some(SubmenuMove::Close),
+    }
+}
+
+/// The entries of a rendered panel together with its state, shared by its listeners.
+#[derive(Clone)]
+struct MenuList {
+    id: ElementId,
+    entries: Rc<[NavEntry]>,
+    state: Entity<MenuListState>,
+    parent: Option<Entity<MenuListState>>,
+    close_menu: CloseMenu,
+}
+
+impl MenuList {
+    fn first(&self) -> Option<usize> {
+        self.entries.iter().position(NavEntry::is_navigable)
+    }
+
+    fn last(&self) -> Option<usize> {
+        self.entries.iter().rposition(NavEntry::is_navigable)
+    }
+
+    /// Returns the next navigable entry after `from` in the given direction, wrapping around.
+    fn step(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        selection::step(self.entries.len(), from, forward, true, |ix| {
+            self.entries[ix].is_navigable()
+        })
+    }
+
+    fn highlight(&self, ix: Option<usize>, app: &mut App) {
+        self.state.update(app, |state, cx| {
+            state.highlighted = ix;
+            state.open_submenu = None;
+            cx.notify();
+        });
+    }
+
+    /// Identifies an entry to [`scroll_into_view`].
+    fn entry_target(&self, ix: usize) -> ElementId {
+        ElementId::NamedChild(Box::new(self.id.clone()), format!("item-{ix}").into())
+    }
+
+    /// Highlights an entry moved to with the keyboard, scrolling it into view.
+    fn reveal(&self, ix: Option<usize>, window: &mut Window, app: &mut App) {
+        self.highlight(ix, app);
+        if let Some(ix) = ix {
+            scroll_into_view(self.entry_target(ix), window, app);
+        }
+    }
+
+    fn hover(&self, ix: usize, app: &mut App) {
+        let submenu = matches!(self.entries[ix].kind, EntryKind::Submenu);
+        self.state.update(app, |state, cx| {
+            state.highlighted = Some(ix);
+            state.open_submenu = submenu.then_some(ix);
+            cx.notify();
+        });
+    }
+
+    fn open_submenu(&self, ix: usize, focus: bool, app: &mut App) {
+        self.state.update(app, |state, cx| {
+            state.highlighted = Some(ix);
+            state.open_submenu = Some(ix);
+            state.focus_submenu = focus;
+            cx.notify();
+        });
+    }
+
+    /// Closes this submenu and moves focus back to the parent panel.
+    fn close_submenu(&self, window: &mut Window, app: &mut App) {
+        if let Some(parent) = &self.parent {
+            let focus_handle = parent.update(app, |parent, cx| {
+                parent.open_submenu = None;
+                cx.notify();
+                parent.focus_handle.clone()
+            });
+            window.focus(&focus_handle);
+        }
+    }
+
+    fn activate(&self, ix: usize, keyboard: bool, window: &mut Window, app: &mut App) {
+        let entry = &self.entries[ix];
+        if !entry.is_navigable() {
+            return;
+        }
+
+        match &entry.kind {
+            EntryKind::Item {
+                on_select,
+                close_on_select,
+            } => {
+                if let Some(on_select) = on_select {
+                    on_select(window, app);
+                }
+                if *close_on_select {
+                    (self.close_menu)(OpenReason::Trigger, window, app);
+                }
+            }
+            EntryKind::Submenu => self.open_submenu(ix, keyboard, app),
+            EntryKind::Separator => {}
+        }
+    }
+
+    /// Highlights the next entry whose label starts with the typed text.
+    fn typeahead(&self, text: &str, window: &mut Window, app: &mut App) {
+        let (query, highlighted) = self.state.update(app, |state, _| {
+            (state.typeahead.push(text).to_string(), state.highlighted)
+        });
+
+        let found = Typeahead::find(&query, self.entries.len(), highlighted, |ix| {
+            let entry = &self.entries[ix];
+            entry.is_navigable().then_some(entry.label.as_ref())
+        });
+        if found.is_some() {
+            self.reveal(found, window, app);
+        }
+    }
+
+    /// Handles a key press on the focused panel, returning whether it was consumed.
+    fn key_down(&self, event: &KeyDownEvent, window: &mut Window, app: &mut App) -> bool {
+        let highlighted = self.state.read(app).highlighted;
+        match event.keystroke.key.as_str() {
+            "down" => self.reveal(self.step(highlighted, true), window, app),
+            "up" => self.reveal(self.step(highlighted, false), window, app),
+            "home" => self.reveal(self.first(), window, app),
+            "end" => self.reveal(self.last(), window, app),
+            "enter" | "space" => {
+                if let Some(ix) = highlighted {
+                    self.activate(ix, true, window, app);
+                }
+            }
+            key @ ("left" | "right") => {
+                match (submenu_move(key, Direction::current(app)), highlighted) {
+                    (Some(SubmenuMove::Open), Some(ix))
+                        if matches!(self.entries[ix].kind, EntryKind::Submenu) =>
+                    {
+                        self.open_submenu(ix, true, app)
+                    }
+                    (Some(SubmenuMove::Close), _) if self.parent.is_some() => {
+                        self.close_submenu(window, app)
+                    }
+                    _ => return false,
+                }
+            }
+            "escape" if self.parent.is_some() => self.close_submenu(window, app),
+            "escape" => (self.close_menu)(OpenReason::EscapeKey, window, app),
+            _ => match Typeahead::input(&event.keystroke) {
+                Some(text) => self.typeahead(text, window, app),
+                None => return false,
+            },
+        }
+        true
+    }
+
+    fn render_row(&self, ix: usize, mut item: MenuItem, highlighted: bool) -> Stateful<Div> {
+        if item.disabled {
+            if let Some(handler) = item.when_disabled_handler.take() {
+                item = handler(item);
+            }
+        }
+        if highlighted {
+            if let Some(handler) = item.when_highlighted_handler.take() {
+                item = handler(item);
+            }
+        }
+
+        let label = item.label;
+        let children = item.children;
+        let shortcut = item.shortcut;
+        item.base
+            .id(("menu-item", ix))
+            .relative()
+            .when(!item.disabled, |this| {
+                this.map(|this| {
+                    let list = self.clone();
+                    this.on_hover(move |hovered, _, app| {
+                        if *hovered {
+                            list.hover(ix, app);
+                        }
+                    })
+                })
+                .map(|this| {
+                    let list = self.clone();
+                    this.on_click(move |_, window, app| list.activate(ix, false, window, app))
+                })
+            })
+            .map(|this| {
+                let Some(shortcut) = shortcut else {
+                    return if children.is_empty() {
+                        this.child(label)
+                    } else {
+                        this.children(children)
+                    };
+                };
+                let content = if children.is_empty() {
+                    div().child(label)
+                } else {
+                    div().children(children)
+                };
+                this.child(
+                    h_flex()
+                        .items_center()
+                        .justify_between()
+                        .gap_4()
+                        .child(content)
+                        .child(shortcut),
+                )
+            })
+            .child(scroll_target(self.entry_target(ix)))
+    }
+}
+
+/// Renders a menu panel next to the anchor, pushing it and the panels of its open
+/// submenus onto `panels`.
+#[allow(clippy::too_many_arguments)]
+fn render_menu_list(
+    id: ElementId,
+    anchor: Bounds<Pixels>,
+    options: AnchoredOptions,
+    base: Div,
+    entries: Vec<MenuEntry>,
+    close_menu: CloseMenu,
+    selection: &Entity<MenuSelection>,
+    parent: Option<Entity<MenuListState>>,
+    focus: bool,
+    panels: &mut Vec<AnyElement>,
+    window: &mut Window,
+    app: &mut App,
+) {
+    let entries = resolve_entries(entries, selection, app);
+    let state = window.use_keyed_state(id.clone(), app, |_, cx| MenuListState::new(cx));
+    let list = MenuList {
+        id: id.clone(),
+        entries: entries.iter().map(NavEntry::new).collect(),
+        state: state.clone(),
+        parent,
+        close_menu: close_menu.clone(),
+    };
+
+    if focus {
+        let first = list.first();
+        let focus_handle = state.update(app, |state, _| {
+            state.highlighted = first;
+            state.focus_handle.clone()
+        });
+        window.focus(&focus_handle);
+    }
+
+    let (focus_handle, scroll_handle, highlighted, open_submenu, focus_submenu) =
+        state.update(app, |state, _| {
+            let focus_submenu = std::mem::take(&mut state.focus_submenu);
+            (
+                state.focus_handle.clone(),
+                state.scroll_handle.clone(),
+                state.highlighted,
+                state.open_submenu,
+                focus_submenu,
+            )
+        });
+
+    let mut rows = Vec::with_capacity(entries.len());
+    let mut submenu = None;
+    for (ix, entry) in entries.into_iter().enumerate() {
+        let highlighted = highlighted == Some(ix);
+        match entry {
+            MenuEntry::Item(item) => {
+                rows.push(list.render_row(ix, item, highlighted).into_any_element())
+            }
+            MenuEntry::Separator(separator) => rows.push(separator.into_any_element()),
+            MenuEntry::CheckboxItem(_) | MenuEntry::RadioGroup(_) => {}
+            MenuEntry::Submenu(menu) => {
+                let Submenu {
+                    item,
+                    base,
+                    entries,
+                } = *menu;
+                let state = state.clone();
+                rows.push(
+                    list.render_row(ix, item, highlighted)
+                        .relative()
+                        .child(
+                            canvas(
+                                move |bounds, _, app| {
+                                    state.update(app, |state, _| {
+                                        state.submenu_bounds.insert(ix, bounds);
+                                    })
+                                },
+                                |_, _, _, _| {},
+                            )
+                            .absolute()
+                            .size_full(),
+                        )
+                        .into_any_element(),
+                );
+                if open_submenu == Some(ix) {
+                    submenu = Some((ix, base, entries));
+                }
+            }
+        }
+    }
+
+    panels.push(
+        floating(anchor)
+            .options(options)
+            .child(
+                base.id(id.clone())
+                    .track_focus(&focus_handle)
+                    .track_scroll(&scroll_handle)
+                    .occlude()
+                    .on_key_down({
+                        let list = list.clone();
+                        move |event, window, app| {
+                            if list.key_down(event, window, app) {
+                                app.stop_propagation();
+                            }
+                        }
+                    })
+                    .children(rows)
+                    .child(scroll_container(&scroll_handle)),
+            )
+            .into_any_element(),
+    );
+
+    let submenu_anchor =
+        open_submenu.and_then(|ix| state.read(app).submenu_bounds.get(&ix).copied());
+    if let Some(((ix, base, entries), anchor)) = submenu.zip(submenu_anchor) {
+        let options = AnchoredOptions {
+            side: if Direction::current(app).is_rtl() {
+                Side::Left
+            } else {
+                Side::Right
+            },
+            align: Align::Start,
+            ..AnchoredOptions::default()
+        };
+        render_menu_list(
+            ElementId::NamedChild(Box::new(id), format!("submenu-{ix}").into()),
+            anchor,
+            options,
+            base,
+            entries,
+            close_menu,
+            selection,
+            Some(state),
+            focus_submenu,
+            panels,
+            window,
+            app,
+        );
+    }
+}
+
+/// Renders an open menu on the [`Layer::Popover`] layer.
+///
+/// Pressing a mouse button outside of every panel closes the menu, except inside
+/// `exclude`, which lets the trigger toggle the menu itself.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn menu_layer(
+    id: ElementId,
+    anchor: Bounds<Pixels>,
+    options: AnchoredOptions,
+    base: Div,
+    entries: Vec<MenuEntry>,
+    close_menu: CloseMenu,
+    selection: &Entity<MenuSelection>,
+    exclude: Option<Bounds<Pixels>>,
+    focus: bool,
+    window: &mut Window,
+    app: &mut App,
+) {
+    let mut panels = Vec::new();
+    render_menu_list(
+        ElementId::NamedChild(Box::new(id.clone()), "list".into()),
+        anchor,
+        options,
+        base,
+        entries,
+        close_menu.clone(),
+        selection,
+        None,
+        focus,
+        &mut panels,
+        window,
+        app,
+    );
+
+    let viewport = WindowInfo::current(window, app).viewport_size;
+    let content = div()
+        .child(
+            div()
+                .id(ElementId::NamedChild(
+                    Box::new(id.clone()),
+                    "outside".into(),
+                ))
+                .absolute()
+                .w(viewport.width)
+                .h(viewport.height)
+                .on_any_mouse_down({
+                    let close_menu = close_menu.clone();
+                    move |event, window, app| {
+                        if !exclude.is_some_and(|bounds| bounds.contains(&event.position)) {
+                            close_menu(OpenReason::OutsideClick, window, app);
+                        }
+                    }
+                }),
+        )
+        .children(panels)
+        .into_any_element();
+
+    // Escape is handled by the focused panel first, to close submenus one at a time.
+    use_overlay(
+        &id,
+        Layer::Popover,
+        Some(content),
+        |overlay| {
+            overlay
+                .dismiss_on_outside_click(false)
+                .on_dismiss(move |window, app| close_menu(OpenReason::EscapeKey, window, app))
+        },
+        window,
+        app,
+    );
+}
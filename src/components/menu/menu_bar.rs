@@ -0,0 +1,333 @@
+use super::{
+    MenuEntries, MenuEntry,
+    checkable::use_menu_selection,
+    list::{CloseMenu, menu_layer},
+};
+use crate::{
+    Direction, OpenReason,
+    focus_group::FocusGroup,
+    primitives::{
+        anchored::{Align, AnchoredOptions},
+        h_flex,
+    },
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::{collections::HashMap, rc::Rc};
+
+/// A menu of a [`MenuBar`], opened from a trigger showing its label.
+#[allow(clippy::type_complexity)]
+pub struct MenuBarMenu {
+    label: SharedString,
+    base: Div,
+    entries: Vec<MenuEntry>,
+    trigger: Option<Box<dyn FnOnce(Div) -> Div>>,
+    when_open_handler: Option<Box<dyn FnOnce(Div) -> Div>>,
+}
+
+impl MenuBarMenu {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            base: div(),
+            entries: Vec::new(),
+            trigger: None,
+            when_open_handler: None,
+        }
+    }
+
+    /// Customizes the trigger, which renders the label.
+    pub fn trigger(mut self, handler: impl FnOnce(Div) -> Div + 'static) -> Self {
+        self.trigger = Some(Box::new(handler));
+        self
+    }
+
+    /// Conditionally modify the trigger while the menu is open.
+    pub fn when_open(mut self, handler: impl FnOnce(Div) -> Div + 'static) -> Self {
+        self.when_open_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Styled for MenuBarMenu {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl MenuEntries for MenuBarMenu {
+    fn entries_mut(&mut self) -> &mut Vec<MenuEntry> {
+        &mut self.entries
+    }
+}
+
+/// A row of menu triggers, as in the File and Edit menus of desktop applications.
+///
+/// The triggers share a single tab stop: ArrowLeft/ArrowRight/Home/End move focus across
+/// them and ArrowDown, Enter or Space opens the focused one's menu. While a menu is open,
+/// ArrowLeft/ArrowRight switch to the adjacent menu, unless they open or close a submenu,
+/// and hovering another trigger opens its menu instead. Escape closes the menu and focuses
+/// its trigger again. Inside each menu the keyboard works as in [`super::Menu`].
+///
+/// # Examples
+///
+/// ```rust
+/// MenuBar::new("app-menu-bar")
+///     .gap_1()
+///     .menu(
+///         MenuBarMenu::new("File")
+///             .bg(rgb(0xffffff))
+///             .item(MenuItem::new("Save").shortcut(kbd("cmd-s")).on_select(|_, _| save()))
+///             .item(MenuItem::new("Close").shortcut(kbd("cmd-w"))),
+///     )
+///     .menu(
+///         MenuBarMenu::new("Edit")
+///             .when_open(|trigger| trigger.bg(rgb(0xe5e7eb)))
+///             .item(MenuItem::new("Undo").shortcut(kbd("cmd-z"))),
+///     )
+/// ```
+#[derive(IntoElement)]
+pub struct MenuBar {
+    id: ElementId,
+    base: Div,
+    menus: Vec<MenuBarMenu>,
+}
+
+/// The open menu and focus bookkeeping kept across renders of a [`MenuBar`].
+struct MenuBarState {
+    focus_handles: Vec<FocusHandle>,
+    /// The trigger that is the bar's tab stop.
+    active: usize,
+    open: Option<usize>,
+    /// Whether the open menu was just switched to and should take focus.
+    focus_menu: bool,
+    trigger_bounds: HashMap<usize, Bounds<Pixels>>,
+    bounds: Option<Bounds<Pixels>>,
+    previous_focus: Option<FocusHandle>,
+}
+
+impl MenuBarState {
+    fn open(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if self.open == Some(ix) {
+            return;
+        }
+        if self.open.is_none() {
+            self.previous_focus = window.focused(cx);
+        }
+        self.open = Some(ix);
+        self.active = ix;
+        self.focus_menu = true;
+        cx.notify();
+    }
+
+    /// Closes the open menu, focusing its trigger again when closed with Escape.
+    fn close(&mut self, reason: OpenReason, window: &mut Window, cx: &mut Context<Self>) {
+        if self.open.take().is_none() {
+            return;
+        }
+        let previous_focus = self.previous_focus.take();
+        if reason == OpenReason::EscapeKey {
+            window.focus(&self.focus_handles[self.active]);
+        } else if let Some(previous_focus) = previous_focus {
+            window.focus(&previous_focus);
+        }
+        cx.notify();
+    }
+}
+
+impl MenuBar {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: h_flex().items_center(),
+            menus: Vec::new(),
+        }
+    }
+
+    pub fn menu(mut self, menu: MenuBarMenu) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    pub fn menus(mut self, menus: impl IntoIterator<Item = MenuBarMenu>) -> Self {
+        self.menus.extend(menus);
+        self
+    }
+}
+
+impl Styled for MenuBar {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for MenuBar {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let len = self.menus.len();
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| MenuBarState {
+            focus_handles: Vec::new(),
+            active: 0,
+            open: None,
+            focus_menu: false,
+            trigger_bounds: HashMap::new(),
+            bounds: None,
+            previous_focus: None,
+        });
+
+        let (group, open, focus_menu, bar_bounds) = state.update(app, |state, cx| {
+            while state.focus_handles.len() < len {
+                state.focus_handles.push(cx.focus_handle());
+            }
+            state.focus_handles.truncate(len);
+            state.open = state.open.filter(|&ix| ix < len);
+
+            // The tab stop follows focus.
+            if let Some(focused) = state
+                .focus_handles
+                .iter()
+                .position(|handle| handle.is_focused(window))
+            {
+                state.active = focused;
+            }
+            state.active = state.active.min(len.saturating_sub(1));
+            let group = FocusGroup::new(state.focus_handles.clone(), state.active).wrap(true);
+            let focus_menu = std::mem::take(&mut state.focus_menu);
+            (group, state.open, focus_menu, state.bounds)
+        });
+
+        let close_menu: CloseMenu = {
+            let state = state.clone();
+            Rc::new(move |reason, window, app| {
+                state.update(app, |state, cx| state.close(reason, window, cx))
+            })
+        };
+
+        let mut triggers = Vec::with_capacity(len);
+        for (ix, menu) in self.menus.into_iter().enumerate() {
+            let is_open = open == Some(ix);
+            let mut trigger = div().child(menu.label);
+            if let Some(handler) = menu.trigger {
+                trigger = handler(trigger);
+            }
+            if is_open && let Some(handler) = menu.when_open_handler {
+                trigger = handler(trigger);
+            }
+
+            let menu_id =
+                ElementId::NamedChild(Box::new(self.id.clone()), format!("menu-{ix}").into());
+            let selection = use_menu_selection(&menu_id, window, app);
+            let anchor = state.read(app).trigger_bounds.get(&ix).copied();
+            if let Some(anchor) = anchor.filter(|_| is_open) {
+                let options = AnchoredOptions {
+                    align: Align::Start,
+                    ..AnchoredOptions::default()
+                };
+                // Pressing on the bar is left to the triggers, to switch menus.
+                menu_layer(
+                    menu_id.clone(),
+                    anchor,
+                    options,
+                    menu.base,
+                    menu.entries,
+                    close_menu.clone(),
+                    &selection,
+                    bar_bounds,
+                    focus_menu,
+                    window,
+                    app,
+                );
+            }
+            triggers.push(
+                trigger
+                    .id(menu_id.clone())
+                    .relative()
+                    .track_focus(group.handle(ix))
+                    .on_click({
+                        let state = state.clone();
+                        move |_, window, app| {
+                            state.update(app, |state, cx| {
+                                if state.open == Some(ix) {
+                                    state.close(OpenReason::Trigger, window, cx);
+                                } else {
+                                    state.open(ix, window, cx);
+                                }
+                            })
+                        }
+                    })
+                    .on_hover({
+                        let state = state.clone();
+                        move |hovered, window, app| {
+                            state.update(app, |state, cx| {
+                                if *hovered && state.open.is_some() {
+                                    state.open(ix, window, cx);
+                                }
+                            })
+                        }
+                    })
+                    .on_key_down({
+                        let state = state.clone();
+                        move |event, window, app| {
+                            let key = event.keystroke.key.as_str();
+                            if !is_open && matches!(key, "down" | "enter" | "space") {
+                                app.stop_propagation();
+                                state.update(app, |state, cx| state.open(ix, window, cx));
+                            }
+                        }
+                    })
+                    .child({
+                        let state = state.clone();
+                        canvas(
+                            move |bounds, _, app| {
+                                state.update(app, |state, _| {
+                                    state.trigger_bounds.insert(ix, bounds);
+                                })
+                            },
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full()
+                    })
+                    .into_any_element(),
+            );
+        }
+
+        self.base
+            .id(self.id)
+            .relative()
+            .when(Direction::current(app).is_rtl(), |this| {
+                this.flex_row_reverse()
+            })
+            .on_key_down({
+                let state = state.clone();
+                move |event, window, app| {
+                    if group.key_down(event, window, app) {
+                        app.stop_propagation();
+                        return;
+                    }
+
+                    // Keys the open menu didn't handle itself.
+                    let key = event.keystroke.key.as_str();
+                    let Some((open, forward)) =
+                        open.zip(Direction::current(app).arrow_forward(key))
+                    else {
+                        return;
+                    };
+                    app.stop_propagation();
+                    let to = if forward {
+                        (open + 1) % len
+                    } else {
+                        (open + len - 1) % len
+                    };
+                    state.update(app, |state, cx| state.open(to, window, cx));
+                }
+            })
+            .children(triggers)
+            .child(
+                canvas(
+                    move |bounds, _, app| state.update(app, |state, _| state.bounds = Some(bounds)),
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    OpenProps, OpenReason, Openable,
+    primitives::anchored::{Align, AnchoredOptions, Side},
+    use_open_state,
+};
+use gpui::*;
+use std::rc::Rc;
+
+mod checkable;
+mod context_menu;
+mod item;
+mod list;
+mod menu_bar;
+
+use checkable::use_menu_selection;
+pub use checkable::{MenuCheckboxItem, MenuRadioGroup, MenuRadioItem};
+pub use context_menu::*;
+pub use item::{MenuEntries, MenuEntry, MenuItem, Submenu};
+use list::{CloseMenu, menu_layer};
+pub use menu_bar::{MenuBar, MenuBarMenu};
+
+#[cfg(test)]
+mod tests;
+
+/// A dropdown menu opened from a trigger element.
+///
+/// While open, focus moves into the menu. ArrowUp/ArrowDown/Home/End move the highlight
+/// across enabled items, typing jumps to the item whose label starts with the typed text,
+/// Enter/Space activates the highlighted item and Escape or a click outside closes the menu.
+/// ArrowRight opens the highlighted submenu and ArrowLeft closes it, swapped in RTL where
+/// submenus open to the left.
+///
+/// # Examples
+///
+/// ```rust
+/// Menu::new("file-menu")
+///     .trigger(button("file-menu-trigger").child("File"))
+///     .bg(rgb(0xffffff))
+///     .item(MenuItem::new("New").on_select(|_, _| println!("new")))
+///     .item(MenuItem::new("Save").disabled(true))
+///     .separator(|separator| separator.h_px().bg(rgb(0xe5e5e5)))
+///     .submenu(Submenu::new("Export").item(MenuItem::new("PDF")))
+/// ```
+#[derive(IntoElement)]
+pub struct Menu {
+    id: ElementId,
+    base: Div,
+    trigger: Option<AnyElement>,
+    entries: Vec<MenuEntry>,
+    open_props: OpenProps,
+    options: AnchoredOptions,
+}
+
+/// Trigger bounds and focus bookkeeping kept across renders of a [`Menu`].
+struct MenuState {
+    trigger_bounds: Option<Bounds<Pixels>>,
+    previous_focus: Option<FocusHandle>,
+    was_open: bool,
+}
+
+impl Menu {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            trigger: None,
+            entries: Vec::new(),
+            open_props: OpenProps::default(),
+            options: AnchoredOptions {
+                align: Align::Start,
+                ..AnchoredOptions::default()
+            },
+        }
+    }
+
+    /// The element that toggles the menu when clicked, or opened with ArrowDown,
+    /// Enter or Space while it has focus.
+    pub fn trigger(mut self, trigger: impl IntoElement) -> Self {
+        self.trigger = Some(trigger.into_any_element());
+        self
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.options.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.options.align = align;
+        self
+    }
+
+    /// Distance between the trigger and the menu.
+    pub fn offset(mut self, offset: Pixels) -> Self {
+        self.options.offset = offset;
+        self
+    }
+}
+
+impl Openable for Menu {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+impl MenuEntries for Menu {
+    fn entries_mut(&mut self) -> &mut Vec<MenuEntry> {
+        &mut self.entries
+    }
+}
+
+impl Styled for Menu {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Menu {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let open_state = use_open_state(self.id.clone(), &self.open_props, window, app);
+        let open = open_state.read(app).is_open();
+
+        let state = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.id.clone()), "menu".into()),
+            app,
+            |_, _| MenuState {
+                trigger_bounds: None,
+                previous_focus: None,
+                was_open: false,
+            },
+        );
+
+        // The menu can only be placed once the trigger has been laid out.
+        let (trigger_bounds, focus) = state.update(app, |state, cx| {
+            let open = open && state.trigger_bounds.is_some();
+            let focus = open && !state.was_open;
+            if focus {
+                state.previous_focus = window.focused(cx);
+            } else if !open
+                && state.was_open
+                && let Some(previous_focus) = state.previous_focus.take()
+            {
+                window.focus(&previous_focus);
+            }
+            state.was_open = open;
+            (state.trigger_bounds.filter(|_| open), focus)
+        });
+
+        let selection = use_menu_selection(&self.id, window, app);
+        let close_menu: CloseMenu = {
+            let open_state = open_state.clone();
+            Rc::new(move |reason, window, app| {
+                open_state.update(app, |state, cx| state.close(reason, window, cx))
+            })
+        };
+
+        if let Some(bounds) = trigger_bounds {
+            menu_layer(
+                self.id.clone(),
+                bounds,
+                self.options,
+                self.base,
+                self.entries,
+                close_menu,
+                &selection,
+                Some(bounds),
+                focus,
+                window,
+                app,
+            );
+        }
+
+        div()
+            .id(self.id)
+            .relative()
+            .on_click({
+                let open_state = open_state.clone();
+                move |_, window, app| {
+                    open_state.update(app, |state, cx| {
+                        state.toggle(OpenReason::Trigger, window, cx)
+                    })
+                }
+            })
+            .on_key_down(move |event, window, app| {
+                let key = event.keystroke.key.as_str();
+                if !open && matches!(key, "down" | "enter" | "space") {
+                    app.stop_propagation();
+                    open_state.update(app, |state, cx| state.open(OpenReason::Trigger, window, cx));
+                }
+            })
+            .child({
+                let state = state.clone();
+                canvas(
+                    move |bounds, _, app| {
+                        state.update(app, |state, _| state.trigger_bounds = Some(bounds))
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .children(self.trigger)
+    }
+}
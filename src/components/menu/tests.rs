@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod submenu_keys {
+    use crate::{
+        Direction,
+        components::menu::list::{SubmenuMove, submenu_move},
+    };
+
+    #[test]
+    fn ltr_opens_to_the_right() {
+        assert_eq!(
+            submenu_move("right", Direction::Ltr),
+            Some(SubmenuMove::Open)
+        );
+        assert_eq!(
+            submenu_move("left", Direction::Ltr),
+            Some(SubmenuMove::Close)
+        );
+    }
+
+    #[test]
+    fn rtl_opens_to_the_left() {
+        assert_eq!(
+            submenu_move("left", Direction::Rtl),
+            Some(SubmenuMove::Open)
+        );
+        assert_eq!(
+            submenu_move("right", Direction::Rtl),
+            Some(SubmenuMove::Close)
+        );
+    }
+
+    #[test]
+    fn vertical_keys_do_not_move_submenus() {
+        for direction in [Direction::Ltr, Direction::Rtl] {
+            assert_eq!(submenu_move("up", direction), None);
+            assert_eq!(submenu_move("down", direction), None);
+        }
+    }
+}
@@ -1,5 +1,55 @@
+mod accordion;
+mod alert_dialog;
+mod avatar;
+mod badge;
+mod breadcrumbs;
+mod combobox;
+mod dialog;
+mod hover_card;
+mod infinite_scroll;
+mod listbox;
+mod menu;
+mod panel_group;
 pub mod progress;
+mod scroll_area;
+mod scrollspy;
+mod select;
+mod shortcut_field;
+mod sortable_list;
+mod stepper;
 mod switch;
+mod table;
 pub mod tabs;
+mod tag_input;
+mod toast;
+mod toolbar;
+mod tree;
 
+pub use crate::primitives::{Button, button};
+pub use accordion::{Accordion, AccordionItem, AccordionItemContext};
+pub use alert_dialog::{AlertDialog, AlertDialogAction};
+pub use avatar::{Avatar, AvatarStatus};
+pub use badge::{Badge, BadgeContext};
+pub use breadcrumbs::{BreadcrumbItem, BreadcrumbItemContext, Breadcrumbs};
+pub use combobox::Combobox;
+pub use dialog::Dialog;
+pub use hover_card::HoverCard;
+pub use infinite_scroll::InfiniteScroll;
+pub use listbox::{Listbox, ListboxItem, ListboxItemContext};
+pub use menu::*;
+pub use panel_group::{
+    Panel, PanelGroup, PanelHandleContext, PanelLayout, PanelLayoutStorage, SplitPane,
+    set_panel_layout_storage,
+};
+pub use scroll_area::{ScrollArea, ScrollAxes, ScrollbarContext, ScrollbarVisibility};
+pub use scrollspy::Scrollspy;
+pub use select::Select;
+pub use shortcut_field::ShortcutField;
+pub use sortable_list::{SortableItem, SortableList};
+pub use stepper::Stepper;
 pub use switch::Switch;
+pub use table::*;
+pub use tag_input::{TagContext, TagInput};
+pub use toast::{Toast, ToastContext, ToastHandle, ToastManager};
+pub use toolbar::{Toolbar, ToolbarItem};
+pub use tree::{Tree, TreeNode, TreeNodeContext};
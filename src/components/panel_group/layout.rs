@@ -0,0 +1,75 @@
+use gpui::{App, Global, SharedString};
+use std::rc::Rc;
+
+/// The sizes of a [`super::PanelGroup`]'s panels, as saved to a [`PanelLayoutStorage`].
+///
+/// A saved layout is only restored to a group with the same panels, so adding or removing a
+/// panel starts over from the default sizes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PanelLayout {
+    pub panels: Vec<SharedString>,
+    pub sizes: Vec<f32>,
+}
+
+/// Where panel groups with a [`super::PanelGroup::layout_id`] save their layout, for instance
+/// a settings file, so it survives restarts.
+///
+/// # Examples
+///
+/// ```rust
+/// struct Settings;
+///
+/// impl PanelLayoutStorage for Settings {
+///     fn load(&self, layout_id: &str, app: &App) -> Option<PanelLayout> {
+///         app.global::<AppSettings>().layouts.get(layout_id).cloned()
+///     }
+///
+///     fn save(&self, layout_id: &str, layout: &PanelLayout, app: &mut App) {
+///         app.global_mut::<AppSettings>().layouts.insert(layout_id.into(), layout.clone());
+///     }
+/// }
+///
+/// set_panel_layout_storage(Settings, app);
+/// ```
+pub trait PanelLayoutStorage: 'static {
+    fn load(&self, layout_id: &str, app: &App) -> Option<PanelLayout>;
+    /// Called when the user finishes resizing the panels.
+    fn save(&self, layout_id: &str, layout: &PanelLayout, app: &mut App);
+}
+
+struct GlobalPanelLayoutStorage(Rc<dyn PanelLayoutStorage>);
+
+impl Global for GlobalPanelLayoutStorage {}
+
+/// Sets the storage panel layouts are saved to and restored from.
+pub fn set_panel_layout_storage(storage: impl PanelLayoutStorage, app: &mut App) {
+    app.set_global(GlobalPanelLayoutStorage(Rc::new(storage)));
+}
+
+fn storage(app: &App) -> Option<Rc<dyn PanelLayoutStorage>> {
+    app.try_global::<GlobalPanelLayoutStorage>()
+        .map(|storage| storage.0.clone())
+}
+
+/// Returns the saved sizes of the panels, if a layout was saved for them.
+pub(super) fn load_sizes(layout_id: &str, panels: &[SharedString], app: &App) -> Option<Vec<f32>> {
+    let layout = storage(app)?.load(layout_id, app)?;
+    (layout.panels == panels
+        && layout.sizes.len() == panels.len()
+        && layout
+            .sizes
+            .iter()
+            .all(|size| size.is_finite() && *size >= 0.))
+    .then_some(layout.sizes)
+}
+
+pub(super) fn save_sizes(layout_id: &str, panels: &[SharedString], sizes: &[f32], app: &mut App) {
+    if let Some(storage) = storage(app) {
+        let layout = PanelLayout {
+            panels: panels.to_vec(),
+            sizes: sizes.to_vec(),
+        };
+        storage.save(layout_id, &layout, app);
+    }
+}
@@ -0,0 +1,429 @@
+use crate::is_focus_visible;
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+mod layout;
+mod panel;
+mod split_pane;
+#[cfg(test)]
+mod tests;
+
+pub use layout::{PanelLayout, PanelLayoutStorage, set_panel_layout_storage};
+use layout::{load_sizes, save_sizes};
+pub use panel::Panel;
+use panel::{PanelLimits, initial_sizes, normalize, resize};
+pub use split_pane::SplitPane;
+
+const DEFAULT_KEYBOARD_STEP: f32 = 0.05;
+
+/// What a resize handle of a [`PanelGroup`] is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PanelHandleContext {
+    /// Position of the handle, the handle after the first panel being 0.
+    pub index: usize,
+    pub axis: Axis,
+    pub dragging: bool,
+    /// Whether the handle is focused after keyboard navigation.
+    pub focus_visible: bool,
+}
+
+/// Panels laid out along an axis, separated by handles that resize them.
+///
+/// Dragging a handle moves space between the panels on each side of it, within their
+/// minimum and maximum sizes. [`Panel::collapsible`] panels collapse to zero when dragged
+/// below half their minimum size, and double-clicking a handle resets the panels on each
+/// side to their default sizes. Handles are tab stops: the arrow keys along the axis move
+/// a focused handle, Home/End shrink or grow the panel before it as far as it goes and
+/// Enter collapses or restores that panel.
+///
+/// Give the group a [`PanelGroup::layout_id`] to save its sizes to the
+/// [`PanelLayoutStorage`] and restore them the next time it is created.
+///
+/// # Examples
+///
+/// ```rust
+/// PanelGroup::new("workspace")
+///     .child(Panel::new("sidebar").default_size(0.25).min_size(0.15).collapsible(true))
+///     .child(Panel::new("editor").min_size(0.3))
+///     .handle(|handle, context| {
+///         handle.bg(rgb(0xe2e8f0)).when(context.dragging, |this| this.bg(rgb(0x3b82f6)))
+///     })
+///     .on_resize(|sizes, _, _| println!("sizes: {sizes:?}"))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct PanelGroup {
+    id: ElementId,
+    base: Div,
+    axis: Axis,
+    panels: Vec<Panel>,
+    handle: Option<Rc<dyn Fn(Div, &PanelHandleContext) -> Div>>,
+    keyboard_step: f32,
+    layout_id: Option<SharedString>,
+    on_resize: Option<Rc<dyn Fn(&[f32], &mut Window, &mut App) + 'static>>,
+}
+
+/// A handle being dragged.
+struct PanelDrag {
+    handle: usize,
+    start: Pixels,
+    start_sizes: Vec<f32>,
+}
+
+/// Panel sizes and handle focus kept across renders of a [`PanelGroup`].
+struct PanelGroupState {
+    /// The panels the sizes were computed for, sizes start over when they change.
+    panel_ids: Vec<SharedString>,
+    sizes: Vec<f32>,
+    /// The size of each collapsed panel before it collapsed, restored by Enter.
+    restore_sizes: Vec<Option<f32>>,
+    /// The length of each panel along the axis the last time it was rendered.
+    lengths: Vec<Pixels>,
+    focus_handles: Vec<FocusHandle>,
+    drag: Option<PanelDrag>,
+}
+
+impl PanelGroup {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div().relative().flex().size_full(),
+            axis: Axis::Horizontal,
+            panels: Vec::new(),
+            handle: None,
+            keyboard_step: DEFAULT_KEYBOARD_STEP,
+            layout_id: None,
+            on_resize: None,
+        }
+    }
+
+    /// The axis panels are laid out along, horizontal by default.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn child(mut self, panel: Panel) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    pub fn children(mut self, panels: impl IntoIterator<Item = Panel>) -> Self {
+        self.panels.extend(panels);
+        self
+    }
+
+    /// Styles the handles, which span the cross axis and are 4px thick by default.
+    pub fn handle(mut self, style: impl Fn(Div, &PanelHandleContext) -> Div + 'static) -> Self {
+        self.handle = Some(Rc::new(style));
+        self
+    }
+
+    /// How much an arrow key resizes the panels, 0.05 by default.
+    pub fn keyboard_step(mut self, step: f32) -> Self {
+        self.keyboard_step = step;
+        self
+    }
+
+    /// Saves the sizes under this id when the user resizes the panels, and restores them
+    /// when the group is created. See [`set_panel_layout_storage`].
+    pub fn layout_id(mut self, layout_id: impl Into<SharedString>) -> Self {
+        self.layout_id = Some(layout_id.into());
+        self
+    }
+
+    /// Called with the size of each panel when the user resizes them.
+    pub fn on_resize(
+        mut self,
+        on_resize: impl Fn(&[f32], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Rc::new(on_resize));
+        self
+    }
+}
+
+impl Styled for PanelGroup {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// The limits of a rendered group's panels together with its state, shared by its
+/// listeners.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct PanelGroupController {
+    state: Entity<PanelGroupState>,
+    limits: Rc<[PanelLimits]>,
+    /// The sizes the panels start with, which double-clicking a handle goes back to.
+    default_sizes: Rc<[f32]>,
+    axis: Axis,
+    keyboard_step: f32,
+    layout_id: Option<SharedString>,
+    on_resize: Option<Rc<dyn Fn(&[f32], &mut Window, &mut App) + 'static>>,
+}
+
+impl PanelGroupController {
+    fn set_sizes(&self, sizes: Vec<f32>, window: &mut Window, app: &mut App) {
+        let changed = self.state.update(app, |state, cx| {
+            if state.sizes == sizes {
+                return false;
+            }
+            for (ix, (&old, &new)) in state.sizes.iter().zip(&sizes).enumerate() {
+                if old > 0. && new == 0. {
+                    state.restore_sizes[ix] = Some(old);
+                }
+            }
+            state.sizes = sizes.clone();
+            cx.notify();
+            true
+        });
+        if changed && let Some(on_resize) = &self.on_resize {
+            on_resize(&sizes, window, app);
+        }
+    }
+
+    /// Resizes the panels on each side of a handle.
+    fn resize(&self, handle: usize, delta: f32, window: &mut Window, app: &mut App) {
+        let sizes = resize(&self.state.read(app).sizes, &self.limits, handle, delta);
+        self.set_sizes(sizes, window, app);
+    }
+
+    /// Shares the space of the panels on each side of a handle as their default sizes do.
+    fn reset(&self, handle: usize, window: &mut Window, app: &mut App) {
+        let sizes = &self.state.read(app).sizes;
+        let (before, after) = (sizes[handle], sizes[handle + 1]);
+        let (first, second) = (self.default_sizes[handle], self.default_sizes[handle + 1]);
+        if first + second <= 0. {
+            return;
+        }
+        let target = first / (first + second) * (before + after);
+        self.resize(handle, target - before, window, app);
+        self.save_layout(app);
+    }
+
+    fn start_drag(&self, handle: usize, position: Point<Pixels>, app: &mut App) {
+        self.state.update(app, |state, cx| {
+            state.drag = Some(PanelDrag {
+                handle,
+                start: position.along(self.axis),
+                start_sizes: state.sizes.clone(),
+            });
+            cx.notify();
+        });
+    }
+
+    fn drag_to(&self, position: Point<Pixels>, window: &mut Window, app: &mut App) {
+        let state = self.state.read(app);
+        let Some(drag) = &state.drag else {
+            return;
+        };
+        let total = state
+            .lengths
+            .iter()
+            .fold(px(0.), |total, length| total + *length);
+        if total <= px(0.) {
+            return;
+        }
+
+        let delta = (position.along(self.axis) - drag.start) / total;
+        let sizes = resize(&drag.start_sizes, &self.limits, drag.handle, delta);
+        self.set_sizes(sizes, window, app);
+    }
+
+    fn end_drag(&self, app: &mut App) {
+        let dragged = self.state.update(app, |state, cx| {
+            cx.notify();
+            state.drag.take().is_some()
+        });
+        if dragged {
+            self.save_layout(app);
+        }
+    }
+
+    fn save_layout(&self, app: &mut App) {
+        let Some(layout_id) = &self.layout_id else {
+            return;
+        };
+        let state = self.state.read(app);
+        let (panels, sizes) = (state.panel_ids.clone(), state.sizes.clone());
+        save_sizes(layout_id, &panels, &sizes, app);
+    }
+
+    /// Resizes the panels around a focused handle, returning whether the key was handled.
+    fn key_down(
+        &self,
+        handle: usize,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        app: &mut App,
+    ) -> bool {
+        let state = self.state.read(app);
+        let (before, after) = (state.sizes[handle], state.sizes[handle + 1]);
+        let delta = match (event.keystroke.key.as_str(), self.axis) {
+            ("left", Axis::Horizontal) | ("up", Axis::Vertical) => -self.keyboard_step,
+            ("right", Axis::Horizontal) | ("down", Axis::Vertical) => self.keyboard_step,
+            ("home", _) => -before,
+            ("end", _) => after,
+            ("enter", _) if self.limits[handle].collapsible => {
+                if before > 0. {
+                    -before
+                } else {
+                    state.restore_sizes[handle].unwrap_or(self.limits[handle].min_size)
+                }
+            }
+            _ => return false,
+        };
+        self.resize(handle, delta, window, app);
+        self.save_layout(app);
+        true
+    }
+}
+
+impl RenderOnce for PanelGroup {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let panel_ids: Vec<SharedString> =
+            self.panels.iter().map(|panel| panel.id.clone()).collect();
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| PanelGroupState {
+            panel_ids: Vec::new(),
+            sizes: Vec::new(),
+            restore_sizes: Vec::new(),
+            lengths: Vec::new(),
+            focus_handles: Vec::new(),
+            drag: None,
+        });
+        let (sizes, focus_handles, dragging) = state.update(app, |state, cx| {
+            if state.panel_ids != panel_ids {
+                state.sizes = self
+                    .layout_id
+                    .as_ref()
+                    .and_then(|layout_id| load_sizes(layout_id, &panel_ids, cx))
+                    .map(normalize)
+                    .unwrap_or_else(|| initial_sizes(&self.panels));
+                state.restore_sizes = vec![None; panel_ids.len()];
+                state.lengths = vec![px(0.); panel_ids.len()];
+                state.drag = None;
+                state.panel_ids = panel_ids;
+            }
+            let handles = state.sizes.len().saturating_sub(1);
+            while state.focus_handles.len() < handles {
+                state.focus_handles.push(cx.focus_handle().tab_stop(true));
+            }
+            (
+                state.sizes.clone(),
+                state.focus_handles.clone(),
+                state.drag.as_ref().map(|drag| drag.handle),
+            )
+        });
+
+        let axis = self.axis;
+        let controller = PanelGroupController {
+            state: state.clone(),
+            limits: self.panels.iter().map(|panel| panel.limits).collect(),
+            default_sizes: initial_sizes(&self.panels).into(),
+            axis,
+            keyboard_step: self.keyboard_step,
+            layout_id: self.layout_id,
+            on_resize: self.on_resize,
+        };
+
+        let mut children = Vec::with_capacity(self.panels.len() * 2);
+        for (ix, panel) in self.panels.into_iter().enumerate() {
+            if ix > 0 {
+                let index = ix - 1;
+                let focus_handle = &focus_handles[index];
+                let context = PanelHandleContext {
+                    index,
+                    axis,
+                    dragging: dragging == Some(index),
+                    focus_visible: focus_handle.is_focused(window) && is_focus_visible(app),
+                };
+                let handle = div().flex_none().map(|this| match axis {
+                    Axis::Horizontal => this.w_1().h_full().cursor_col_resize(),
+                    Axis::Vertical => this.h_1().w_full().cursor_row_resize(),
+                });
+                let handle = match &self.handle {
+                    Some(style) => style(handle, &context),
+                    None => handle,
+                };
+                children.push(
+                    handle
+                        .id(("panel-handle", index))
+                        .track_focus(focus_handle)
+                        .on_mouse_down(MouseButton::Left, {
+                            let controller = controller.clone();
+                            move |event, window, app| {
+                                app.stop_propagation();
+                                if event.click_count == 2 {
+                                    controller.reset(index, window, app);
+                                } else {
+                                    controller.start_drag(index, event.position, app);
+                                }
+                            }
+                        })
+                        .on_key_down({
+                            let controller = controller.clone();
+                            move |event, window, app| {
+                                if controller.key_down(index, event, window, app) {
+                                    app.stop_propagation();
+                                }
+                            }
+                        })
+                        .into_any_element(),
+                );
+            }
+
+            let mut base = panel.base.flex_basis(px(0.)).min_w_0().min_h_0();
+            base.style().flex_grow = Some(sizes[ix]);
+            base.style().flex_shrink = Some(1.);
+            children.push(
+                base.child({
+                    let state = state.clone();
+                    canvas(
+                        move |bounds, _, app| {
+                            state
+                                .update(app, |state, _| state.lengths[ix] = bounds.size.along(axis))
+                        },
+                        |_, _, _, _| {},
+                    )
+                    .absolute()
+                    .size_full()
+                })
+                .into_any_element(),
+            );
+        }
+
+        self.base
+            .id(self.id)
+            .when(axis == Axis::Vertical, |this| this.flex_col())
+            .children(children)
+            .when(dragging.is_some(), |this| {
+                this.child(
+                    canvas(
+                        |_, _, _| {},
+                        move |_, _, window, _| {
+                            window.set_window_cursor_style(match axis {
+                                Axis::Horizontal => CursorStyle::ResizeColumn,
+                                Axis::Vertical => CursorStyle::ResizeRow,
+                            });
+                            window.on_mouse_event({
+                                let controller = controller.clone();
+                                move |event: &MouseMoveEvent, phase, window, app| {
+                                    if phase.bubble() {
+                                        controller.drag_to(event.position, window, app);
+                                    }
+                                }
+                            });
+                            window.on_mouse_event(move |event: &MouseUpEvent, phase, _, app| {
+                                if phase.bubble() && event.button == MouseButton::Left {
+                                    controller.end_drag(app);
+                                }
+                            });
+                        },
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+            })
+    }
+}
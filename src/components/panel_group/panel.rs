@@ -0,0 +1,122 @@
+use gpui::*;
+
+/// A panel of a [`super::PanelGroup`].
+///
+/// Sizes are fractions of the space shared by the panels of the group, between 0 and 1.
+pub struct Panel {
+    pub(super) id: SharedString,
+    pub(super) base: Div,
+    pub(super) default_size: Option<f32>,
+    pub(super) limits: PanelLimits,
+}
+
+impl Panel {
+    pub fn new(id: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            base: div().relative().overflow_hidden(),
+            default_size: None,
+            limits: PanelLimits {
+                min_size: 0.,
+                max_size: 1.,
+                collapsible: false,
+            },
+        }
+    }
+
+    /// The size of the panel until it is resized. Panels without one share the space left
+    /// by the others.
+    pub fn default_size(mut self, size: f32) -> Self {
+        self.default_size = Some(size);
+        self
+    }
+
+    pub fn min_size(mut self, size: f32) -> Self {
+        self.limits.min_size = size;
+        self
+    }
+
+    pub fn max_size(mut self, size: f32) -> Self {
+        self.limits.max_size = size;
+        self
+    }
+
+    /// Lets the panel collapse to zero when it is resized below half its minimum size.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.limits.collapsible = collapsible;
+        self
+    }
+}
+
+impl ParentElement for Panel {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.base.extend(elements);
+    }
+}
+
+impl Styled for Panel {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// The sizes a panel can be resized to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct PanelLimits {
+    pub(super) min_size: f32,
+    pub(super) max_size: f32,
+    pub(super) collapsible: bool,
+}
+
+impl PanelLimits {
+    /// Returns the closest size the panel can take, snapping to zero when it collapses.
+    pub(super) fn snap(&self, size: f32) -> f32 {
+        if self.collapsible && size < self.min_size / 2. {
+            0.
+        } else {
+            size.clamp(self.min_size, self.max_size.max(self.min_size))
+        }
+    }
+}
+
+/// Returns the sizes panels start with, sharing the space left by the panels with a default
+/// size among the others.
+pub(super) fn initial_sizes(panels: &[Panel]) -> Vec<f32> {
+    let defaults: f32 = panels.iter().filter_map(|panel| panel.default_size).sum();
+    let unset = panels
+        .iter()
+        .filter(|panel| panel.default_size.is_none())
+        .count();
+    let rest = (1. - defaults).max(0.) / unset.max(1) as f32;
+    let sizes: Vec<f32> = panels
+        .iter()
+        .map(|panel| panel.limits.snap(panel.default_size.unwrap_or(rest)))
+        .collect();
+    normalize(sizes)
+}
+
+/// Scales the sizes to add up to one.
+pub(super) fn normalize(mut sizes: Vec<f32>) -> Vec<f32> {
+    let total: f32 = sizes.iter().sum();
+    if total > 0. {
+        for size in &mut sizes {
+            *size /= total;
+        }
+    }
+    sizes
+}
+
+/// Moves the handle after panel `ix` by `delta`, taking the space from the next panel or
+/// giving it to it.
+pub(super) fn resize(sizes: &[f32], limits: &[PanelLimits], ix: usize, delta: f32) -> Vec<f32> {
+    let mut sizes = sizes.to_vec();
+    let total = sizes[ix] + sizes[ix + 1];
+    let (first, second) = (limits[ix], limits[ix + 1]);
+
+    let size = first.snap((sizes[ix] + delta).clamp(0., total));
+    let size = total - second.snap(total - size);
+    let size = first.snap(size).min(total);
+    sizes[ix] = size;
+    sizes[ix + 1] = total - size;
+    sizes
+}
@@ -0,0 +1,120 @@
+use super::{Panel, PanelGroup, PanelHandleContext};
+use gpui::*;
+use std::rc::Rc;
+
+/// Two panes laid out along an axis, separated by a handle that resizes them.
+///
+/// A [`PanelGroup`] of two panels, sized by the fraction of the space the first pane
+/// takes. Dragging the handle or moving it with the arrow keys resizes the panes within the
+/// first pane's minimum and maximum sizes, and double-clicking it resets them to the
+/// default size.
+///
+/// # Examples
+///
+/// ```rust
+/// SplitPane::new("editor-split")
+///     .axis(Axis::Vertical)
+///     .default_size(0.7)
+///     .min_size(0.2)
+///     .max_size(0.9)
+///     .first(editor)
+///     .second(terminal)
+///     .handle(|handle, context| {
+///         handle.bg(rgb(0xe2e8f0)).when(context.dragging, |this| this.bg(rgb(0x3b82f6)))
+///     })
+///     .on_resize(cx.listener(|this, size: &f32, _, _| this.editor_size = *size))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct SplitPane {
+    group: PanelGroup,
+    first: Panel,
+    second: Panel,
+    on_resize: Option<Rc<dyn Fn(&f32, &mut Window, &mut App) + 'static>>,
+}
+
+impl SplitPane {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            group: PanelGroup::new(id),
+            first: Panel::new("first").default_size(0.5),
+            second: Panel::new("second"),
+            on_resize: None,
+        }
+    }
+
+    /// The axis the panes are laid out along, horizontal by default.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.group = self.group.axis(axis);
+        self
+    }
+
+    /// The size of the first pane until it is resized, and after a double-click on the
+    /// handle, 0.5 by default.
+    pub fn default_size(mut self, size: f32) -> Self {
+        self.first = self.first.default_size(size);
+        self
+    }
+
+    /// The smallest size of the first pane.
+    pub fn min_size(mut self, size: f32) -> Self {
+        self.first = self.first.min_size(size);
+        self
+    }
+
+    /// The largest size of the first pane.
+    pub fn max_size(mut self, size: f32) -> Self {
+        self.first = self.first.max_size(size);
+        self
+    }
+
+    pub fn first(mut self, content: impl IntoElement) -> Self {
+        self.first = self.first.child(content);
+        self
+    }
+
+    pub fn second(mut self, content: impl IntoElement) -> Self {
+        self.second = self.second.child(content);
+        self
+    }
+
+    /// Styles the handle, which spans the cross axis and is 4px thick by default.
+    pub fn handle(mut self, style: impl Fn(Div, &PanelHandleContext) -> Div + 'static) -> Self {
+        self.group = self.group.handle(style);
+        self
+    }
+
+    /// How much an arrow key resizes the panes, 0.05 by default.
+    pub fn keyboard_step(mut self, step: f32) -> Self {
+        self.group = self.group.keyboard_step(step);
+        self
+    }
+
+    /// Saves the size under this id, see [`PanelGroup::layout_id`].
+    pub fn layout_id(mut self, layout_id: impl Into<SharedString>) -> Self {
+        self.group = self.group.layout_id(layout_id);
+        self
+    }
+
+    /// Called with the size of the first pane when the user resizes the panes.
+    pub fn on_resize(mut self, on_resize: impl Fn(&f32, &mut Window, &mut App) + 'static) -> Self {
+        self.on_resize = Some(Rc::new(on_resize));
+        self
+    }
+}
+
+impl Styled for SplitPane {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.group.style()
+    }
+}
+
+impl RenderOnce for SplitPane {
+    fn render(self, _: &mut Window, _: &mut App) -> impl IntoElement {
+        let mut group = self.group.child(self.first).child(self.second);
+        if let Some(on_resize) = self.on_resize {
+            group = group.on_resize(move |sizes, window, app| on_resize(&sizes[0], window, app));
+        }
+        group
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod resize {
+    use crate::components::panel_group::panel::{PanelLimits, resize};
+
+    const FREE: PanelLimits = PanelLimits {
+        min_size: 0.,
+        max_size: 1.,
+        collapsible: false,
+    };
+
+    fn assert_sizes(sizes: Vec<f32>, expected: &[f32]) {
+        assert_eq!(sizes.len(), expected.len(), "{sizes:?}");
+        for (size, expected) in sizes.iter().zip(expected) {
+            assert!((size - expected).abs() < 1e-6, "{sizes:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn moves_space_between_the_panels_around_the_handle() {
+        assert_sizes(resize(&[0.5, 0.5], &[FREE; 2], 0, 0.1), &[0.6, 0.4]);
+        assert_sizes(
+            resize(&[0.2, 0.3, 0.5], &[FREE; 3], 1, 0.1),
+            &[0.2, 0.4, 0.4],
+        );
+    }
+
+    #[test]
+    fn stops_at_the_limits_of_either_panel() {
+        let first = PanelLimits {
+            max_size: 0.55,
+            ..FREE
+        };
+        assert_sizes(resize(&[0.5, 0.5], &[first, FREE], 0, 0.1), &[0.55, 0.45]);
+
+        let second = PanelLimits {
+            min_size: 0.3,
+            ..FREE
+        };
+        assert_sizes(resize(&[0.5, 0.5], &[FREE, second], 0, 0.3), &[0.7, 0.3]);
+    }
+
+    #[test]
+    fn collapses_below_half_the_minimum_size() {
+        let collapsible = PanelLimits {
+            min_size: 0.2,
+            collapsible: true,
+            ..FREE
+        };
+        let limits = [collapsible, FREE];
+        assert_sizes(resize(&[0.3, 0.7], &limits, 0, -0.15), &[0.2, 0.8]);
+        assert_sizes(resize(&[0.3, 0.7], &limits, 0, -0.25), &[0., 1.]);
+        assert_sizes(resize(&[0., 1.], &limits, 0, 0.15), &[0.2, 0.8]);
+    }
+
+    #[test]
+    fn keeps_a_panel_that_cannot_collapse_at_its_minimum() {
+        let limits = [
+            PanelLimits {
+                min_size: 0.2,
+                ..FREE
+            },
+            FREE,
+        ];
+        assert_sizes(resize(&[0.3, 0.7], &limits, 0, -0.3), &[0.2, 0.8]);
+    }
+}
+
+#[cfg(test)]
+mod initial_sizes {
+    use crate::components::panel_group::{
+        Panel,
+        panel::{initial_sizes, normalize},
+    };
+
+    #[test]
+    fn shares_the_space_left_by_default_sizes() {
+        let panels = [
+            Panel::new("a").default_size(0.5),
+            Panel::new("b"),
+            Panel::new("c"),
+        ];
+        assert_eq!(initial_sizes(&panels), [0.5, 0.25, 0.25]);
+    }
+
+    #[test]
+    fn scales_default_sizes_that_overflow() {
+        let panels = [
+            Panel::new("a").default_size(0.8),
+            Panel::new("b").default_size(0.8),
+        ];
+        assert_eq!(initial_sizes(&panels), [0.5, 0.5]);
+    }
+
+    #[test]
+    fn normalize_keeps_all_zero_sizes() {
+        assert_eq!(normalize(vec![0., 0.]), [0., 0.]);
+        assert_eq!(normalize(vec![1., 3.]), [0.25, 0.75]);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    Theme,
+    components::progress::context::{ProgressContext, arc_points},
+};
+use gpui::*;
+use smallvec::SmallVec;
+use std::f32::consts::TAU;
+
+/// A ring showing the progress as an arc, clockwise from the top.
+///
+/// The ring fills the element, give it a size. Children are rendered over it, for instance
+/// to show the value in the middle.
+///
+/// # Examples
+///
+/// ```rust
+/// Progress::new().value(40.).child_with_context(|context| {
+///     ProgressCircle::new(context)
+///         .size_10()
+///         .thickness(px(4.))
+///         .child(span(context.value_label()).text_xs())
+/// })
+/// ```
+#[derive(IntoElement)]
+pub struct ProgressCircle {
+    base: Div,
+    children: SmallVec<[AnyElement; 2]>,
+    context: ProgressContext,
+    thickness: Pixels,
+    color: Option<Hsla>,
+    track_color: Option<Hsla>,
+}
+
+impl ProgressCircle {
+    pub fn new(context: &ProgressContext) -> Self {
+        Self {
+            base: div().relative().flex().items_center().justify_center(),
+            children: SmallVec::new(),
+            context: context.clone(),
+            thickness: px(4.),
+            color: None,
+            track_color: None,
+        }
+    }
+
+    /// The width of the ring, 4px by default.
+    pub fn thickness(mut self, thickness: Pixels) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// The color of the arc, the theme's primary color by default.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// The color of the rest of the ring, the theme's muted color by default.
+    pub fn track_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.track_color = Some(color.into());
+        self
+    }
+}
+
+impl ParentElement for ProgressCircle {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for ProgressCircle {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Strokes a polyline, skipping lines too short to be drawn.
+fn paint_polyline(points: &[Point<Pixels>], thickness: Pixels, color: Hsla, window: &mut Window) {
+    let Some((first, rest)) = points.split_first() else {
+        return;
+    };
+    let mut path = PathBuilder::stroke(thickness);
+    path.move_to(*first);
+    for point in rest {
+        path.line_to(*point);
+    }
+    if let Ok(path) = path.build() {
+        window.paint_path(path, color);
+    }
+}
+
+impl RenderOnce for ProgressCircle {
+    fn render(self, _window: &mut Window, app: &mut App) -> impl IntoElement {
+        let theme = Theme::of(app);
+        let color = self.color.unwrap_or(theme.colors.primary);
+        let track_color = self.track_color.unwrap_or(theme.colors.muted);
+        let thickness = self.thickness;
+        let context = self.context;
+
+        self.base
+            .child(
+                canvas(
+                    |_, _, _| {},
+                    move |bounds, _, window, _| {
+                        let center = bounds.center();
+                        let radius =
+                            bounds.size.width.min(bounds.size.height) / 2. - thickness / 2.;
+                        if radius <= px(0.) {
+                            return;
+                        }
+                        let track = arc_points(center, radius, TAU);
+                        paint_polyline(&track, thickness, track_color, window);
+                        let arc = context.arc_points(center, radius);
+                        paint_polyline(&arc, thickness, color, window);
+                    },
+                )
+                .absolute()
+                .size_full(),
+            )
+            .children(self.children)
+    }
+}
@@ -1,4 +1,5 @@
-use std::rc::Rc;
+use gpui::{Pixels, Point, point};
+use std::{f32::consts::TAU, rc::Rc};
 
 #[derive(Clone)]
 pub struct ProgressContext {
@@ -41,6 +42,23 @@ impl ProgressContext {
         self.max_value
     }
 
+    /// The angle of an arc showing the progress, in radians, a full turn when complete.
+    pub fn sweep_angle(&self) -> f32 {
+        self.percentage() * TAU
+    }
+
+    /// The length of a circle of the given radius left unfilled, the dash offset of a
+    /// dashed stroke showing the progress.
+    pub fn stroke_dash_offset(&self, radius: f32) -> f32 {
+        TAU * radius * (1.0 - self.percentage())
+    }
+
+    /// Returns the points of the arc showing the progress on a circle, clockwise from the
+    /// top, close enough to each other to be joined with straight lines.
+    pub fn arc_points(&self, center: Point<Pixels>, radius: Pixels) -> Vec<Point<Pixels>> {
+        arc_points(center, radius, self.sweep_angle())
+    }
+
     pub fn value_label(&self) -> String {
         if let Some(label_fn) = &self.value_label {
             label_fn(self)
@@ -49,3 +67,23 @@ impl ProgressContext {
         }
     }
 }
+
+/// How many segments a full circle is drawn with.
+const CIRCLE_SEGMENTS: f32 = 96.0;
+
+/// Returns the points of an arc of `sweep` radians, clockwise from the top of the circle.
+pub(super) fn arc_points(center: Point<Pixels>, radius: Pixels, sweep: f32) -> Vec<Point<Pixels>> {
+    if sweep <= 0.0 {
+        return Vec::new();
+    }
+    let segments = (sweep / TAU * CIRCLE_SEGMENTS).ceil().max(1.0) as usize;
+    (0..=segments)
+        .map(|ix| {
+            let angle = sweep * ix as f32 / segments as f32;
+            point(
+                center.x + radius * angle.sin(),
+                center.y - radius * angle.cos(),
+            )
+        })
+        .collect()
+}
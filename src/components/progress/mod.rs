@@ -1,13 +1,18 @@
-use crate::components::progress::context::ProgressContext;
 use crate::traits::ParentElementWithContext;
 use gpui::*;
 use smallvec::SmallVec;
 use std::rc::Rc;
 
+mod circle;
 mod context;
 mod fill;
 mod track;
 
+#[cfg(test)]
+mod tests;
+
+pub use circle::*;
+pub use context::ProgressContext;
 pub use fill::*;
 pub use track::*;
 
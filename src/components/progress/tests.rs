@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod circle_geometry {
+    use crate::components::progress::{ProgressContext, context::arc_points};
+    use gpui::{point, px};
+    use std::f32::consts::{PI, TAU};
+
+    fn context(value: f32) -> ProgressContext {
+        ProgressContext {
+            value,
+            min_value: 0.0,
+            max_value: 100.0,
+            value_label: None,
+        }
+    }
+
+    #[test]
+    fn sweep_angle_follows_the_percentage() {
+        assert_eq!(context(0.0).sweep_angle(), 0.0);
+        assert_eq!(context(50.0).sweep_angle(), PI);
+        assert_eq!(context(150.0).sweep_angle(), TAU);
+    }
+
+    #[test]
+    fn dash_offset_is_the_unfilled_length() {
+        assert_eq!(context(0.0).stroke_dash_offset(10.0), TAU * 10.0);
+        assert_eq!(context(100.0).stroke_dash_offset(10.0), 0.0);
+        assert!((context(75.0).stroke_dash_offset(10.0) - TAU * 2.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn arc_starts_at_the_top_and_turns_clockwise() {
+        let center = point(px(10.), px(10.));
+        let points = arc_points(center, px(10.), PI / 2.);
+        let first = points.first().unwrap();
+        let last = points.last().unwrap();
+        assert!((first.x - px(10.)).abs() < px(1e-3) && first.y.abs() < px(1e-3));
+        assert!((last.x - px(20.)).abs() < px(1e-3) && (last.y - px(10.)).abs() < px(1e-3));
+    }
+
+    #[test]
+    fn empty_progress_has_no_arc() {
+        assert!(arc_points(point(px(0.), px(0.)), px(10.), 0.0).is_empty());
+    }
+}
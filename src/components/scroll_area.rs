@@ -0,0 +1,448 @@
+use crate::scroll_container;
+use gpui::{prelude::FluentBuilder, *};
+use std::{rc::Rc, time::Duration};
+
+const DEFAULT_SCROLLBAR_SIZE: Pixels = px(8.);
+const DEFAULT_HIDE_DELAY: Duration = Duration::from_millis(600);
+const MIN_THUMB_LENGTH: Pixels = px(16.);
+
+/// The directions a [`ScrollArea`] scrolls in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollAxes {
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl ScrollAxes {
+    fn contains(self, axis: Axis) -> bool {
+        matches!(
+            (self, axis),
+            (ScrollAxes::Both, _)
+                | (ScrollAxes::Vertical, Axis::Vertical)
+                | (ScrollAxes::Horizontal, Axis::Horizontal)
+        )
+    }
+}
+
+/// When the scrollbars of a [`ScrollArea`] are shown. Scrollbars are never shown when the
+/// content fits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollbarVisibility {
+    Always,
+    /// While the pointer is over the area, and for a moment after it scrolled.
+    #[default]
+    Hover,
+    /// While the area scrolls, and for a moment after.
+    Scroll,
+}
+
+/// What a scrollbar track or thumb is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScrollbarContext {
+    pub axis: Axis,
+    /// Whether the thumb is being dragged.
+    pub dragging: bool,
+}
+
+/// A scrolling container with scrollbars that can be styled like any other element.
+///
+/// The thumb length is proportional to the visible part of the content, dragging it
+/// scrolls the content and clicking the track scrolls by a page towards the click.
+///
+/// # Examples
+///
+/// ```rust
+/// ScrollArea::new("log")
+///     .h_64()
+///     .axes(ScrollAxes::Both)
+///     .thumb(|thumb, _| thumb.rounded_full().bg(rgb(0x94a3b8)))
+///     .children(lines.iter().map(|line| span(line.clone())))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct ScrollArea {
+    id: ElementId,
+    base: Div,
+    content: Div,
+    axes: ScrollAxes,
+    visibility: ScrollbarVisibility,
+    hide_delay: Duration,
+    scrollbar_size: Pixels,
+    scroll_handle: Option<ScrollHandle>,
+    track: Option<Rc<dyn Fn(Div, &ScrollbarContext) -> Div>>,
+    thumb: Option<Rc<dyn Fn(Div, &ScrollbarContext) -> Div>>,
+}
+
+/// A thumb being dragged.
+struct ThumbDrag {
+    axis: Axis,
+    start_position: Pixels,
+    start_offset: Pixels,
+}
+
+/// Scrollbar visibility and dragging kept across renders of a [`ScrollArea`].
+struct ScrollAreaState {
+    scroll_handle: ScrollHandle,
+    hovered: bool,
+    /// Whether the area scrolled recently.
+    scrolling: bool,
+    last_offset: Point<Pixels>,
+    epoch: usize,
+    drag: Option<ThumbDrag>,
+    /// The length of the vertical and horizontal tracks the last time they were rendered.
+    track_lengths: Size<Pixels>,
+}
+
+impl ScrollAreaState {
+    /// Shows the scrollbars after the area scrolled, hiding them once the delay elapsed.
+    fn scrolled(&mut self, delay: Duration, cx: &mut Context<Self>) {
+        self.scrolling = true;
+        self.epoch += 1;
+        let epoch = self.epoch;
+        cx.spawn(async move |this, cx| {
+            Timer::after(delay).await;
+            this.update(cx, |state, cx| {
+                if state.epoch == epoch {
+                    state.scrolling = false;
+                    cx.notify();
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+}
+
+/// The position and length of a thumb along its track.
+#[derive(Clone, Copy)]
+struct ThumbGeometry {
+    offset: Pixels,
+    length: Pixels,
+}
+
+impl ThumbGeometry {
+    /// Derives the thumb from the scroll handle, or `None` when the content fits.
+    fn new(handle: &ScrollHandle, axis: Axis, track_length: Pixels) -> Option<Self> {
+        let viewport = handle.bounds().size.along(axis);
+        let max_offset = handle.max_offset().along(axis);
+        if max_offset <= px(0.) || viewport <= px(0.) {
+            return None;
+        }
+
+        let length = (track_length * (viewport / (viewport + max_offset)))
+            .max(MIN_THUMB_LENGTH)
+            .min(track_length);
+        let progress = (-handle.offset().along(axis) / max_offset).clamp(0., 1.);
+        Some(Self {
+            offset: (track_length - length) * progress,
+            length,
+        })
+    }
+}
+
+/// Scrolls the handle to an offset along the axis, keeping it within the content.
+fn scroll_to(handle: &ScrollHandle, axis: Axis, offset: Pixels) {
+    let max_offset = handle.max_offset().along(axis);
+    let position = handle
+        .offset()
+        .apply_along(axis, |_| offset.clamp(-max_offset, px(0.)));
+    handle.set_offset(position);
+}
+
+impl ScrollArea {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div().relative().overflow_hidden(),
+            content: div(),
+            axes: ScrollAxes::default(),
+            visibility: ScrollbarVisibility::default(),
+            hide_delay: DEFAULT_HIDE_DELAY,
+            scrollbar_size: DEFAULT_SCROLLBAR_SIZE,
+            scroll_handle: None,
+            track: None,
+            thumb: None,
+        }
+    }
+
+    /// The directions the content scrolls in, vertical by default.
+    pub fn axes(mut self, axes: ScrollAxes) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    pub fn visibility(mut self, visibility: ScrollbarVisibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// How long scrollbars stay visible after scrolling, 600ms by default.
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = delay;
+        self
+    }
+
+    /// The thickness of the scrollbars, 8px by default.
+    pub fn scrollbar_size(mut self, size: Pixels) -> Self {
+        self.scrollbar_size = size;
+        self
+    }
+
+    /// Scrolls with the given handle, to scroll the area or read its offset from elsewhere.
+    pub fn track_scroll(mut self, handle: &ScrollHandle) -> Self {
+        self.scroll_handle = Some(handle.clone());
+        self
+    }
+
+    /// Styles the content, which is laid out as a column by default.
+    pub fn content(mut self, style: impl FnOnce(Div) -> Div) -> Self {
+        self.content = style(self.content);
+        self
+    }
+
+    /// Styles the tracks the thumbs move along.
+    pub fn track(mut self, style: impl Fn(Div, &ScrollbarContext) -> Div + 'static) -> Self {
+        self.track = Some(Rc::new(style));
+        self
+    }
+
+    pub fn thumb(mut self, style: impl Fn(Div, &ScrollbarContext) -> Div + 'static) -> Self {
+        self.thumb = Some(Rc::new(style));
+        self
+    }
+}
+
+impl ParentElement for ScrollArea {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.content.extend(elements);
+    }
+}
+
+impl Styled for ScrollArea {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for ScrollArea {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let scroll_handle = self.scroll_handle;
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| ScrollAreaState {
+            scroll_handle: scroll_handle.clone().unwrap_or_default(),
+            hovered: false,
+            scrolling: false,
+            last_offset: Point::default(),
+            epoch: 0,
+            drag: None,
+            track_lengths: Size::default(),
+        });
+
+        let delay = self.hide_delay;
+        let (handle, visible, dragging, track_lengths) = state.update(app, |state, cx| {
+            if let Some(scroll_handle) = scroll_handle {
+                state.scroll_handle = scroll_handle;
+            }
+            let offset = state.scroll_handle.offset();
+            if offset != state.last_offset {
+                state.last_offset = offset;
+                state.scrolled(delay, cx);
+            }
+            let visible = match self.visibility {
+                ScrollbarVisibility::Always => true,
+                ScrollbarVisibility::Hover => state.hovered || state.scrolling,
+                ScrollbarVisibility::Scroll => state.scrolling,
+            } || state.drag.is_some();
+            (
+                state.scroll_handle.clone(),
+                visible,
+                state.drag.as_ref().map(|drag| drag.axis),
+                state.track_lengths,
+            )
+        });
+
+        let scrollbar_size = self.scrollbar_size;
+        let both = self.axes == ScrollAxes::Both;
+        let scrollbars = [Axis::Vertical, Axis::Horizontal]
+            .into_iter()
+            .filter(|axis| visible && self.axes.contains(*axis))
+            .filter_map(|axis| {
+                let track_length = track_lengths.along(axis);
+                let geometry = ThumbGeometry::new(&handle, axis, track_length)?;
+                let context = ScrollbarContext {
+                    axis,
+                    dragging: dragging == Some(axis),
+                };
+
+                let thumb = div().absolute().map(|this| match axis {
+                    Axis::Vertical => this
+                        .left_0()
+                        .right_0()
+                        .top(geometry.offset)
+                        .h(geometry.length),
+                    Axis::Horizontal => this
+                        .top_0()
+                        .bottom_0()
+                        .left(geometry.offset)
+                        .w(geometry.length),
+                });
+                let thumb = match &self.thumb {
+                    Some(style) => style(thumb, &context),
+                    None => thumb,
+                };
+                let thumb = thumb.on_mouse_down(MouseButton::Left, {
+                    let state = state.clone();
+                    let handle = handle.clone();
+                    move |event, _, app| {
+                        app.stop_propagation();
+                        state.update(app, |state, cx| {
+                            state.drag = Some(ThumbDrag {
+                                axis,
+                                start_position: event.position.along(axis),
+                                start_offset: handle.offset().along(axis),
+                            });
+                            cx.notify();
+                        });
+                    }
+                });
+
+                let track = div().absolute().map(|this| match axis {
+                    Axis::Vertical => this
+                        .top_0()
+                        .right_0()
+                        .bottom(if both { scrollbar_size } else { px(0.) })
+                        .w(scrollbar_size),
+                    Axis::Horizontal => this
+                        .left_0()
+                        .bottom_0()
+                        .right(if both { scrollbar_size } else { px(0.) })
+                        .h(scrollbar_size),
+                });
+                let track = match &self.track {
+                    Some(style) => style(track, &context),
+                    None => track,
+                };
+                Some(
+                    track
+                        .id(match axis {
+                            Axis::Vertical => "scrollbar-vertical",
+                            Axis::Horizontal => "scrollbar-horizontal",
+                        })
+                        .on_mouse_down(MouseButton::Left, {
+                            let handle = handle.clone();
+                            let state = state.clone();
+                            move |event, _, app| {
+                                // Pages towards the click, the thumb handles its own clicks.
+                                let page = handle.bounds().size.along(axis);
+                                let offset = handle.offset().along(axis);
+                                let before = event.position.along(axis)
+                                    < handle.bounds().origin.along(axis) + geometry.offset;
+                                scroll_to(
+                                    &handle,
+                                    axis,
+                                    if before { offset + page } else { offset - page },
+                                );
+                                state.update(app, |_, cx| cx.notify());
+                            }
+                        })
+                        .child(thumb),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        self.base
+            .id(self.id)
+            .on_hover({
+                let state = state.clone();
+                move |hovered, _, app| {
+                    state.update(app, |state, cx| {
+                        state.hovered = *hovered;
+                        cx.notify();
+                    })
+                }
+            })
+            .child(
+                div()
+                    .id("scroll-area-viewport")
+                    .size_full()
+                    .map(|this| match self.axes {
+                        ScrollAxes::Vertical => this.overflow_y_scroll(),
+                        ScrollAxes::Horizontal => this.overflow_x_scroll(),
+                        ScrollAxes::Both => this.overflow_scroll(),
+                    })
+                    .relative()
+                    .track_scroll(&handle)
+                    .child(self.content.flex().flex_col())
+                    .child(scroll_container(&handle)),
+            )
+            .children(scrollbars)
+            .child({
+                // Measures the tracks, which span the area minus the corner left for the
+                // other scrollbar.
+                let state = state.clone();
+                canvas(
+                    move |bounds, _, app| {
+                        let corner = if both { scrollbar_size } else { px(0.) };
+                        state.update(app, |state, _| {
+                            state.track_lengths =
+                                size(bounds.size.width - corner, bounds.size.height - corner)
+                        })
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .when(dragging.is_some(), |this| {
+                this.child(
+                    canvas(
+                        |_, _, _| {},
+                        move |_, _, window, _| {
+                            window.on_mouse_event({
+                                let state = state.clone();
+                                move |event: &MouseMoveEvent, phase, window, app| {
+                                    if !phase.bubble() {
+                                        return;
+                                    }
+                                    let state = state.read(app);
+                                    let Some(drag) = &state.drag else {
+                                        return;
+                                    };
+                                    let handle = &state.scroll_handle;
+                                    let axis = drag.axis;
+                                    let Some(geometry) = ThumbGeometry::new(
+                                        handle,
+                                        axis,
+                                        state.track_lengths.along(axis),
+                                    ) else {
+                                        return;
+                                    };
+                                    let travel = state.track_lengths.along(axis) - geometry.length;
+                                    if travel <= px(0.) {
+                                        return;
+                                    }
+                                    // The content moves by the scrollable length as the thumb
+                                    // travels the track.
+                                    let ratio = handle.max_offset().along(axis) / travel;
+                                    let delta = event.position.along(axis) - drag.start_position;
+                                    scroll_to(handle, axis, drag.start_offset - delta * ratio);
+                                    window.refresh();
+                                }
+                            });
+                            window.on_mouse_event(move |event: &MouseUpEvent, phase, _, app| {
+                                if phase.bubble() && event.button == MouseButton::Left {
+                                    state.update(app, |state, cx| {
+                                        state.drag = None;
+                                        cx.notify();
+                                    });
+                                }
+                            });
+                        },
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+            })
+    }
+}
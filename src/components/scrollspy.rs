@@ -0,0 +1,170 @@
+use crate::{SectionActivated, publish_event};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+/// Follows which section of a scroll container is being read, to highlight it in a
+/// navigation such as a docs sidebar.
+///
+/// Sections are the children of the element tracking the scroll handle, in order, and
+/// [`Scrollspy::sections`] gives their ids. The active section is the last one whose top
+/// edge scrolled past the top of the container, plus [`Scrollspy::offset`], or the last
+/// section once the container is scrolled to the end. When it changes, [`Scrollspy::on_change`]
+/// is called and a [`SectionActivated`] event is published.
+///
+/// # Examples
+///
+/// ```rust
+/// h_flex()
+///     .child(
+///         Scrollspy::new("docs-nav", &scroll_handle)
+///             .sections(["install", "usage", "api"])
+///             .render(|active, _, _| {
+///                 v_flex().children(["install", "usage", "api"].map(|id| {
+///                     span(id).when(active.is_some_and(|active| active == id), |this| {
+///                         this.font_weight(FontWeight::BOLD)
+///                     })
+///                 }))
+///             }),
+///     )
+///     .child(
+///         v_flex()
+///             .id("docs")
+///             .overflow_y_scroll()
+///             .track_scroll(&scroll_handle)
+///             .children(sections),
+///     )
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Scrollspy {
+    id: ElementId,
+    base: Div,
+    scroll_handle: ScrollHandle,
+    sections: Vec<SharedString>,
+    offset: Pixels,
+    render: Option<Box<dyn FnOnce(Option<&SharedString>, &mut Window, &mut App) -> AnyElement>>,
+    on_change: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+impl Scrollspy {
+    pub fn new(id: impl Into<ElementId>, scroll_handle: &ScrollHandle) -> Self {
+        Self {
+            id: id.into(),
+            base: div().relative(),
+            scroll_handle: scroll_handle.clone(),
+            sections: Vec::new(),
+            offset: px(0.),
+            render: None,
+            on_change: None,
+        }
+    }
+
+    /// The ids of the sections, in the order they are laid out.
+    pub fn sections(mut self, ids: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.sections = ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// How far below the top of the container a section becomes active, for instance to
+    /// account for a sticky header.
+    pub fn offset(mut self, offset: Pixels) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Renders the content of the element given the active section, typically the navigation
+    /// listing the sections.
+    pub fn render<E: IntoElement>(
+        mut self,
+        render: impl FnOnce(Option<&SharedString>, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.render = Some(Box::new(move |active, window, app| {
+            render(active, window, app).into_any_element()
+        }));
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+}
+
+impl Styled for Scrollspy {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Returns the position of the section being read in the container.
+fn active_section(handle: &ScrollHandle, count: usize, offset: Pixels) -> Option<usize> {
+    let viewport = handle.bounds();
+    let scroll_top = -handle.offset().y;
+    let measured = (0..count)
+        .take_while(|ix| handle.bounds_for_item(*ix).is_some())
+        .count();
+    if measured == 0 {
+        return None;
+    }
+    if scroll_top > px(0.) && scroll_top >= handle.max_offset().height - px(1.) {
+        return Some(measured - 1);
+    }
+
+    // Child bounds are laid out before the content is scrolled.
+    let active = (0..measured)
+        .take_while(|ix| {
+            handle
+                .bounds_for_item(*ix)
+                .is_some_and(|bounds| bounds.top() - viewport.top() <= scroll_top + offset)
+        })
+        .count();
+    Some(active.saturating_sub(1))
+}
+
+impl RenderOnce for Scrollspy {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| None::<SharedString>);
+        let active = state.read(app).clone();
+
+        let id = self.id.clone();
+        let sections: Rc<[SharedString]> = self.sections.into();
+        let (handle, offset, on_change) = (self.scroll_handle, self.offset, self.on_change);
+        self.base
+            .when_some(self.render, |this, render| {
+                this.child(render(active.as_ref(), window, app))
+            })
+            .child(
+                canvas(
+                    move |_, window, app| {
+                        let Some(section) = active_section(&handle, sections.len(), offset)
+                            .map(|ix| sections[ix].clone())
+                        else {
+                            return;
+                        };
+                        let changed = state.update(app, |active, _| {
+                            let changed = active.as_ref() != Some(&section);
+                            *active = Some(section.clone());
+                            changed
+                        });
+                        if !changed {
+                            return;
+                        }
+
+                        window.request_animation_frame();
+                        window.defer(app, move |window, app| {
+                            if let Some(on_change) = on_change {
+                                on_change(&section, window, app);
+                            }
+                            publish_event(SectionActivated { id, section }, app);
+                        });
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+    }
+}
@@ -0,0 +1,430 @@
+use crate::{
+    AccessibilityProps, Accessible, Disableable, FocusableStyled, FormField, FormValue, OpenProps,
+    OpenReason, OpenState, Openable, OptionSelected, Role,
+    components::{Listbox, ListboxItem},
+    is_focus_visible, is_in_disabled_scope,
+    primitives::{anchored::AnchoredOptions, anchored_popover},
+    publish_event, register_form_field, selection,
+    typeahead::Typeahead,
+    use_open_state,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+/// A trigger showing the selected option, which opens a [`Listbox`] of the options below it.
+///
+/// While collapsed, the focused trigger changes the selection without opening the list:
+/// typing jumps to the option whose label starts with the typed text, and ArrowUp/ArrowDown/
+/// Home/End step through the enabled options. Alt+ArrowDown, Enter or Space open the list,
+/// and picking an option or pressing Escape closes it. Every change calls
+/// [`Select::on_change`] and publishes an [`OptionSelected`] event.
+///
+/// The list opens in an [`anchored_popover`], on the popover layer. A disabled select
+/// can't be focused or opened.
+///
+/// # Examples
+///
+/// ```rust
+/// Select::new("country")
+///     .placeholder("Choose a country")
+///     .items([
+///         ListboxItem::new("fr", "France"),
+///         ListboxItem::new("de", "Germany"),
+///         ListboxItem::new("it", "Italy"),
+///     ])
+///     .value(self.country.clone())
+///     .on_change(cx.listener(|this, value: &SharedString, _, cx| {
+///         this.country = value.clone();
+///         cx.notify();
+///     }))
+///     .list(|list| list.bg(rgb(0xffffff)).p_1())
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Select {
+    id: ElementId,
+    base: Div,
+    items: Vec<ListboxItem>,
+    value: Option<SharedString>,
+    default_value: Option<SharedString>,
+    placeholder: Option<SharedString>,
+    name: Option<SharedString>,
+    open_props: OpenProps,
+    options: AnchoredOptions,
+    list: Option<Box<dyn FnOnce(Listbox) -> Listbox>>,
+    on_change: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    disabled: bool,
+    accessibility: AccessibilityProps,
+    when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_focus_visible_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+}
+
+/// Selection and focus bookkeeping kept across renders of a [`Select`].
+struct SelectState {
+    trigger_focus: FocusHandle,
+    list_focus: FocusHandle,
+    selected: Option<SharedString>,
+    typeahead: Typeahead,
+    was_open: bool,
+}
+
+impl Select {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            items: Vec::new(),
+            value: None,
+            default_value: None,
+            placeholder: None,
+            name: None,
+            open_props: OpenProps::default(),
+            options: AnchoredOptions::default(),
+            list: None,
+            on_change: None,
+            disabled: false,
+            accessibility: AccessibilityProps::new(Role::Combobox),
+            when_disabled_handler: None,
+            when_focus_visible_handler: None,
+        }
+    }
+
+    pub fn item(mut self, item: ListboxItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = ListboxItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Controls the value of the selected option.
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// The value selected on first render when the select is not controlled.
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.default_value = Some(value.into());
+        self
+    }
+
+    /// Shown in the trigger while no option is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Registers the select with the enclosing [`crate::Form`] under this name.
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Positions the list relative to the trigger, below it by default.
+    pub fn options(mut self, options: AnchoredOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Customizes the listbox of the options, for instance to style it or render its items.
+    pub fn list(mut self, list: impl FnOnce(Listbox) -> Listbox + 'static) -> Self {
+        self.list = Some(Box::new(list));
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+}
+
+impl Accessible for Select {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl Disableable for Select {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl FocusableStyled for Select {
+    fn when_focus_visible(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_focus_visible_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Styled for Select {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl Openable for Select {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+/// The options of a rendered select together with its state, shared by its listeners.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct SelectController {
+    id: ElementId,
+    items: Rc<[ListboxItem]>,
+    state: Entity<SelectState>,
+    open_state: Entity<OpenState>,
+    controlled: bool,
+    on_change: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+impl SelectController {
+    fn selected(&self, app: &App) -> Option<usize> {
+        let selected = self.state.read(app).selected.as_ref()?;
+        self.items.iter().position(|item| item.value == *selected)
+    }
+
+    fn select(&self, value: &SharedString, window: &mut Window, app: &mut App) {
+        let changed = self.state.update(app, |state, cx| {
+            let changed = state.selected.as_ref() != Some(value);
+            if changed && !self.controlled {
+                state.selected = Some(value.clone());
+                cx.notify();
+            }
+            changed
+        });
+        if !changed {
+            return;
+        }
+
+        if let Some(on_change) = &self.on_change {
+            on_change(value, window, app);
+        }
+        publish_event(
+            OptionSelected {
+                id: self.id.clone(),
+                value: value.clone(),
+            },
+            app,
+        );
+    }
+
+    fn set_open(&self, open: bool, reason: OpenReason, window: &mut Window, app: &mut App) {
+        self.open_state
+            .update(app, |state, cx| state.set_open(open, reason, window, cx));
+    }
+
+    /// Returns the next enabled option in the given direction, without wrapping.
+    fn step(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        selection::step(self.items.len(), from, forward, false, |ix| {
+            !self.items[ix].disabled
+        })
+    }
+
+    fn select_step(&self, ix: Option<usize>, window: &mut Window, app: &mut App) {
+        if let Some(ix) = ix {
+            let value = self.items[ix].value.clone();
+            self.select(&value, window, app);
+        }
+    }
+
+    /// Handles a key press on the collapsed trigger, returning whether it was consumed.
+    fn key_down(&self, event: &KeyDownEvent, window: &mut Window, app: &mut App) -> bool {
+        let keystroke = &event.keystroke;
+        let selected = self.selected(app);
+        match keystroke.key.as_str() {
+            "down" if keystroke.modifiers.alt => {
+                self.set_open(true, OpenReason::Trigger, window, app)
+            }
+            "enter" | "space" => self.set_open(true, OpenReason::Trigger, window, app),
+            "down" => self.select_step(self.step(selected, true), window, app),
+            "up" => self.select_step(self.step(selected, false), window, app),
+            "home" => self.select_step(self.step(None, true), window, app),
+            "end" => self.select_step(self.step(None, false), window, app),
+            _ => {
+                let Some(text) = Typeahead::input(keystroke) else {
+                    return false;
+                };
+                let query = self
+                    .state
+                    .update(app, |state, _| state.typeahead.push(text).to_string());
+                let found = Typeahead::find(&query, self.items.len(), selected, |ix| {
+                    let item = &self.items[ix];
+                    (!item.disabled).then_some(item.label.as_ref())
+                });
+                self.select_step(found, window, app);
+            }
+        }
+        true
+    }
+}
+
+impl RenderOnce for Select {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        if is_in_disabled_scope(app) {
+            self.disabled = true;
+        }
+        if self.disabled
+            && let Some(handler) = self.when_disabled_handler.take()
+        {
+            self = handler(self);
+        }
+
+        let open_state = use_open_state(self.id.clone(), &self.open_props, window, app);
+        let open = open_state.read(app).is_open() && !self.disabled;
+
+        let default_value = self.default_value.take();
+        let state = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.id.clone()), "select".into()),
+            app,
+            |_, cx| SelectState {
+                trigger_focus: cx.focus_handle().tab_stop(true),
+                list_focus: cx.focus_handle(),
+                selected: default_value,
+                typeahead: Typeahead::default(),
+                was_open: false,
+            },
+        );
+        let (trigger_focus, list_focus, selected, opened, closed) =
+            state.update(app, |state, _| {
+                if let Some(value) = self.value.clone() {
+                    state.selected = Some(value);
+                }
+                let opened = open && !state.was_open;
+                let closed = !open && state.was_open;
+                state.was_open = open;
+                (
+                    state.trigger_focus.clone(),
+                    state.list_focus.clone(),
+                    state.selected.clone(),
+                    opened,
+                    closed,
+                )
+            });
+
+        if trigger_focus.is_focused(window)
+            && is_focus_visible(app)
+            && let Some(handler) = self.when_focus_visible_handler.take()
+        {
+            self = handler(self);
+        }
+
+        if let Some(name) = self.name {
+            let field = FormField {
+                value: Rc::new({
+                    let state = state.clone();
+                    move |app| FormValue::Selected(state.read(app).selected.clone())
+                }),
+                validate: None,
+                focus_handle: Some(trigger_focus.clone()),
+            };
+            register_form_field(name, field, app);
+        }
+
+        // Focus follows the list while it is open and returns to the trigger after.
+        if opened {
+            window.focus(&list_focus);
+        } else if closed && list_focus.contains_focused(window, app) {
+            window.focus(&trigger_focus);
+        }
+
+        let controller = SelectController {
+            id: self.id.clone(),
+            items: self.items.into(),
+            state: state.clone(),
+            open_state,
+            controlled: self.value.is_some(),
+            on_change: self.on_change,
+        };
+        let label = selected
+            .as_ref()
+            .and_then(|value| controller.items.iter().find(|item| item.value == *value))
+            .map(|item| item.label.clone())
+            .or(self.placeholder);
+
+        let list_id = ElementId::NamedChild(Box::new(self.id.clone()), "list".into());
+        let list = Listbox::new(list_id)
+            .items(controller.items.iter().cloned())
+            .selected(selected.clone())
+            .focus_handle(&list_focus)
+            .on_selection_change({
+                let controller = controller.clone();
+                move |values, window, app| {
+                    if let Some(value) = values.first() {
+                        controller.select(value, window, app);
+                    }
+                    controller.set_open(false, OpenReason::Trigger, window, app);
+                }
+            });
+        let list = match self.list {
+            Some(customize) => customize(list),
+            None => list,
+        };
+
+        let disabled = self.disabled;
+        let trigger = self
+            .base
+            .id(self.id.clone())
+            .when(!disabled, |this| this.track_focus(&trigger_focus))
+            .when(!open && !disabled, |this| {
+                let controller = controller.clone();
+                this.on_key_down(move |event, window, app| {
+                    if controller.key_down(event, window, app) {
+                        app.stop_propagation();
+                    }
+                })
+            })
+            .children(label);
+
+        // Escape and outside clicks are handled by the popover.
+        anchored_popover(ElementId::NamedChild(
+            Box::new(self.id.clone()),
+            "popover".into(),
+        ))
+        .trigger(trigger)
+        .options(self.options)
+        .open(open)
+        .on_open_change({
+            let controller = controller.clone();
+            move |event, window, app| {
+                if !disabled {
+                    controller.set_open(event.open, event.reason, window, app);
+                }
+            }
+        })
+        .child(
+            div()
+                .id(ElementId::NamedChild(Box::new(self.id), "panel".into()))
+                .on_key_down(move |event, window, app| {
+                    if event.keystroke.key == "tab" {
+                        controller.set_open(false, OpenReason::Trigger, window, app);
+                        app.stop_propagation();
+                    }
+                })
+                .child(list),
+        )
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    AccessibilityProps, Accessible, Disableable, FocusableStyled, Role, is_focus_visible,
+    is_in_disabled_scope,
+    primitives::{Kbd, h_flex, kbd},
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+
+/// A field that records a keyboard shortcut, as in the key binding settings of an app.
+///
+/// While focused, the next keystroke is recorded instead of typed or dispatched to its
+/// binding, and shown the way the platform writes shortcuts with [`Kbd`]. Keys pressed on
+/// their own count, so function keys can be recorded, except Tab and Shift-Tab, which move
+/// focus as usual, and Escape, which leaves the field.
+///
+/// # Examples
+///
+/// ```rust
+/// ShortcutField::new("command-palette-shortcut")
+///     .value(self.palette_shortcut.clone())
+///     .placeholder("Press a shortcut")
+///     .px_2()
+///     .border_1()
+///     .when_recording(|this| this.border_color(rgb(0x3b82f6)))
+///     .on_change(cx.listener(|this, keystroke: &Keystroke, _, cx| {
+///         this.palette_shortcut = keystroke.clone();
+///         cx.notify();
+///     }))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct ShortcutField {
+    id: ElementId,
+    base: Div,
+    value: Option<Keystroke>,
+    default_value: Option<Keystroke>,
+    placeholder: Option<SharedString>,
+    disabled: bool,
+    kbd: Option<Box<dyn FnOnce(Kbd) -> Kbd>>,
+    on_change: Option<Rc<dyn Fn(&Keystroke, &mut Window, &mut App) + 'static>>,
+    when_recording_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_focus_visible_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    accessibility: AccessibilityProps,
+}
+
+/// What a keystroke does in a focused [`ShortcutField`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ShortcutInput {
+    /// Left to its binding, such as Tab moving focus.
+    Ignore,
+    /// Leaves the field.
+    Blur,
+    Record(Keystroke),
+}
+
+/// Decides what a keystroke does in a focused [`ShortcutField`].
+///
+/// Tab and Shift-Tab move focus and Escape leaves the field. Modifiers pressed on their
+/// own are ignored, since they only start a shortcut.
+pub(crate) fn shortcut_input(keystroke: &Keystroke) -> ShortcutInput {
+    let modifiers = keystroke.modifiers;
+    match keystroke.key.as_str() {
+        "tab" if !modifiers.modified() || modifiers == Modifiers::shift() => ShortcutInput::Ignore,
+        "escape" if !modifiers.modified() => ShortcutInput::Blur,
+        "shift" | "control" | "alt" | "platform" | "function" => ShortcutInput::Ignore,
+        key => ShortcutInput::Record(Keystroke {
+            modifiers,
+            key: key.into(),
+            key_char: None,
+        }),
+    }
+}
+
+/// The recorded keystroke and the interceptor recording it, kept across renders of a
+/// [`ShortcutField`].
+#[allow(clippy::type_complexity)]
+struct ShortcutFieldState {
+    focus_handle: FocusHandle,
+    value: Option<Keystroke>,
+    controlled: bool,
+    disabled: bool,
+    on_change: Option<Rc<dyn Fn(&Keystroke, &mut Window, &mut App) + 'static>>,
+    _interceptor: Subscription,
+}
+
+impl ShortcutFieldState {
+    /// Records the keystroke while the field is focused, keeping it from its binding.
+    fn intercept(&mut self, event: &KeystrokeEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if self.disabled || !self.focus_handle.is_focused(window) {
+            return;
+        }
+
+        let keystroke = match shortcut_input(&event.keystroke) {
+            ShortcutInput::Ignore => return,
+            ShortcutInput::Blur => {
+                cx.stop_propagation();
+                window.blur();
+                return;
+            }
+            ShortcutInput::Record(keystroke) => keystroke,
+        };
+        cx.stop_propagation();
+
+        if !self.controlled {
+            self.value = Some(keystroke.clone());
+            cx.notify();
+        }
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&keystroke, window, cx);
+        }
+    }
+}
+
+impl ShortcutField {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: h_flex().items_center(),
+            value: None,
+            default_value: None,
+            placeholder: None,
+            disabled: false,
+            kbd: None,
+            on_change: None,
+            when_recording_handler: None,
+            when_focus_visible_handler: None,
+            when_disabled_handler: None,
+            accessibility: AccessibilityProps::new(Role::TextField),
+        }
+    }
+
+    /// Controls the recorded shortcut.
+    pub fn value(mut self, value: Keystroke) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// The shortcut shown on first render when the value is not controlled.
+    pub fn default_value(mut self, value: Keystroke) -> Self {
+        self.default_value = Some(value);
+        self
+    }
+
+    /// Shown while no shortcut is recorded.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Customizes the [`Kbd`] showing the shortcut.
+    pub fn kbd(mut self, kbd: impl FnOnce(Kbd) -> Kbd + 'static) -> Self {
+        self.kbd = Some(Box::new(kbd));
+        self
+    }
+
+    /// Called with each recorded keystroke.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&Keystroke, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Conditionally modify the field while it is focused and recording.
+    pub fn when_recording(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_recording_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Disableable for ShortcutField {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Accessible for ShortcutField {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl FocusableStyled for ShortcutField {
+    fn when_focus_visible(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_focus_visible_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Styled for ShortcutField {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for ShortcutField {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        if is_in_disabled_scope(app) {
+            self.disabled = true;
+        }
+        if self.disabled
+            && let Some(handler) = self.when_disabled_handler.take()
+        {
+            self = handler(self);
+        }
+
+        let default_value = self.default_value.take();
+        let state = window.use_keyed_state(self.id.clone(), app, |_, cx| {
+            let state = cx.weak_entity();
+            ShortcutFieldState {
+                focus_handle: cx.focus_handle().tab_stop(true),
+                value: default_value,
+                controlled: false,
+                disabled: false,
+                on_change: None,
+                _interceptor: cx.intercept_keystrokes(move |event, window, app| {
+                    state
+                        .update(app, |state: &mut ShortcutFieldState, cx| {
+                            state.intercept(event, window, cx)
+                        })
+                        .ok();
+                }),
+            }
+        });
+        let (focus_handle, value) = state.update(app, |state, _| {
+            state.controlled = self.value.is_some();
+            if let Some(value) = self.value.take() {
+                state.value = Some(value);
+            }
+            state.disabled = self.disabled;
+            state.on_change = self.on_change.take();
+            (state.focus_handle.clone(), state.value.clone())
+        });
+
+        if !self.disabled
+            && focus_handle.is_focused(window)
+            && let Some(handler) = self.when_recording_handler.take()
+        {
+            self = handler(self);
+        }
+        if !self.disabled
+            && focus_handle.is_focused(window)
+            && is_focus_visible(app)
+            && let Some(handler) = self.when_focus_visible_handler.take()
+        {
+            self = handler(self);
+        }
+
+        let shortcut = value.map(|keystroke| {
+            let shortcut = kbd(keystroke.unparse());
+            match self.kbd {
+                Some(customize) => customize(shortcut),
+                None => shortcut,
+            }
+        });
+
+        self.base
+            .id(self.id)
+            .when(!self.disabled, |this| this.track_focus(&focus_handle))
+            .map(|this| match shortcut {
+                Some(shortcut) => this.child(shortcut),
+                None => this.children(self.placeholder),
+            })
+    }
+}
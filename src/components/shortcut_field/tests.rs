@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod shortcut_input {
+    use crate::components::shortcut_field::{ShortcutInput, shortcut_input};
+    use gpui::Keystroke;
+
+    fn input(keystroke: &str) -> ShortcutInput {
+        shortcut_input(&Keystroke::parse(keystroke).unwrap())
+    }
+
+    fn recorded(keystroke: &str) -> ShortcutInput {
+        ShortcutInput::Record(Keystroke::parse(keystroke).unwrap())
+    }
+
+    #[test]
+    fn tab_and_shift_tab_move_focus() {
+        assert_eq!(input("tab"), ShortcutInput::Ignore);
+        assert_eq!(input("shift-tab"), ShortcutInput::Ignore);
+        assert_eq!(input("ctrl-tab"), recorded("ctrl-tab"));
+    }
+
+    #[test]
+    fn escape_leaves_the_field() {
+        assert_eq!(input("escape"), ShortcutInput::Blur);
+        assert_eq!(input("cmd-escape"), recorded("cmd-escape"));
+    }
+
+    #[test]
+    fn modifiers_alone_are_ignored() {
+        for modifier in ["shift", "control", "alt", "platform", "function"] {
+            assert_eq!(input(modifier), ShortcutInput::Ignore);
+        }
+    }
+
+    #[test]
+    fn records_keys_without_the_typed_character() {
+        assert_eq!(input("cmd-shift-p"), recorded("cmd-shift-p"));
+        assert_eq!(input("f5"), recorded("f5"));
+        let ShortcutInput::Record(keystroke) = shortcut_input(&Keystroke {
+            key_char: Some("a".into()),
+            ..Keystroke::parse("a").unwrap()
+        }) else {
+            panic!("a key on its own is recorded");
+        };
+        assert_eq!(keystroke.key_char, None);
+    }
+}
@@ -0,0 +1,550 @@
+use crate::{
+    FocusableStyled,
+    drag::{DRAG_THRESHOLD, auto_scroll_delta, drop_target, moved_index},
+    focus_group::FocusGroup,
+    is_focus_visible,
+    primitives::v_flex,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::{collections::HashMap, rc::Rc};
+
+/// An item of a [`SortableList`].
+#[allow(clippy::type_complexity)]
+pub struct SortableItem {
+    id: SharedString,
+    base: Div,
+    children: Vec<AnyElement>,
+    /// The drag handle, with the number of children before it.
+    handle: Option<(usize, AnyElement)>,
+    ghost: Option<AnyElement>,
+    when_dragging_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_focus_visible_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+}
+
+impl SortableItem {
+    /// Creates an item, the id keeps focus on the item as it moves.
+    pub fn new(id: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            base: div().relative(),
+            children: Vec::new(),
+            handle: None,
+            ghost: None,
+            when_dragging_handler: None,
+            when_focus_visible_handler: None,
+        }
+    }
+
+    /// Adds a child that starts dragging the item when pressed, instead of the whole item.
+    pub fn handle(mut self, handle: impl IntoElement) -> Self {
+        self.handle = Some((self.children.len(), handle.into_any_element()));
+        self
+    }
+
+    /// Rendered at the pointer while the item is dragged, where it was grabbed.
+    pub fn ghost(mut self, ghost: impl IntoElement) -> Self {
+        self.ghost = Some(ghost.into_any_element());
+        self
+    }
+
+    /// Styles the item while it is dragged.
+    pub fn when_dragging(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_dragging_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl FocusableStyled for SortableItem {
+    fn when_focus_visible(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_focus_visible_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl ParentElement for SortableItem {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for SortableItem {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// A list whose items can be dragged to new positions.
+///
+/// While an item is dragged, a drop indicator shows where it would land and the item's
+/// ghost, if any, follows the pointer. Dragging near an edge of the container tracking
+/// [`SortableList::scroll_handle`] scrolls it. The list only reports the move through
+/// [`SortableList::on_reorder`], render the items in their new order for it to take
+/// effect.
+///
+/// Items share a single tab stop, the arrow keys move focus across them and Alt with an
+/// arrow key moves the focused item.
+///
+/// # Examples
+///
+/// ```rust
+/// SortableList::new("playlist")
+///     .gap_1()
+///     .items(self.songs.iter().map(|song| {
+///         SortableItem::new(song.id.clone())
+///             .handle(div().child("⠿"))
+///             .child(song.title.clone())
+///             .ghost(div().bg(rgb(0xffffff)).shadow_md().child(song.title.clone()))
+///             .when_dragging(|this| this.opacity(0.5))
+///     }))
+///     .scroll_handle(&self.scroll_handle)
+///     .drop_indicator(|indicator| indicator.h(px(2.)).bg(rgb(0x3b82f6)))
+///     .on_reorder(cx.listener(|this, (from, to), _, _| {
+///         let song = this.songs.remove(*from);
+///         this.songs.insert(*to, song);
+///     }))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct SortableList {
+    id: ElementId,
+    base: Div,
+    axis: Axis,
+    items: Vec<SortableItem>,
+    drop_indicator: Option<Box<dyn FnOnce(Div) -> Div>>,
+    scroll_handle: Option<ScrollHandle>,
+    on_reorder: Option<Rc<dyn Fn(&(usize, usize), &mut Window, &mut App) + 'static>>,
+}
+
+/// An item being dragged.
+struct ItemDrag {
+    from: usize,
+    start: Point<Pixels>,
+    position: Point<Pixels>,
+    /// Where the item was grabbed, from its origin.
+    grab_offset: Point<Pixels>,
+    /// How many items the dragged one goes after, once the pointer moved far enough.
+    target: Option<usize>,
+}
+
+/// Focus handles, item bounds and dragging kept across renders of a [`SortableList`].
+struct SortableListState {
+    focus_handles: HashMap<SharedString, FocusHandle>,
+    /// The bounds of each item the last time it was rendered.
+    bounds: Vec<Bounds<Pixels>>,
+    list_bounds: Bounds<Pixels>,
+    active: usize,
+    drag: Option<ItemDrag>,
+}
+
+impl SortableList {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: v_flex(),
+            axis: Axis::Vertical,
+            items: Vec::new(),
+            drop_indicator: None,
+            scroll_handle: None,
+            on_reorder: None,
+        }
+    }
+
+    /// The axis items are laid out along, vertical by default.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self.base = match axis {
+            Axis::Vertical => self.base.flex_col(),
+            Axis::Horizontal => self.base.flex_row(),
+        };
+        self
+    }
+
+    pub fn item(mut self, item: SortableItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = SortableItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Styles the line showing where the dragged item would land. It spans the cross axis
+    /// and has no thickness by default.
+    pub fn drop_indicator(mut self, style: impl FnOnce(Div) -> Div + 'static) -> Self {
+        self.drop_indicator = Some(Box::new(style));
+        self
+    }
+
+    /// The scroll handle of the container the list scrolls in, which dragging an item near
+    /// its edges scrolls.
+    pub fn scroll_handle(mut self, handle: &ScrollHandle) -> Self {
+        self.scroll_handle = Some(handle.clone());
+        self
+    }
+
+    /// Called with the position an item moved from and the position it moved to.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(&(usize, usize), &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Rc::new(on_reorder));
+        self
+    }
+}
+
+impl Styled for SortableList {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// The list state together with its callback, shared by its listeners.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct SortableListController {
+    state: Entity<SortableListState>,
+    axis: Axis,
+    len: usize,
+    scroll_handle: Option<ScrollHandle>,
+    on_reorder: Option<Rc<dyn Fn(&(usize, usize), &mut Window, &mut App) + 'static>>,
+}
+
+impl SortableListController {
+    fn reorder(&self, from: usize, to: usize, window: &mut Window, app: &mut App) {
+        if from != to
+            && let Some(on_reorder) = &self.on_reorder
+        {
+            on_reorder(&(from, to), window, app);
+        }
+    }
+
+    fn start_drag(&self, from: usize, start: Point<Pixels>, app: &mut App) {
+        self.state.update(app, |state, cx| {
+            let origin = state.bounds.get(from).map_or(start, |bounds| bounds.origin);
+            state.drag = Some(ItemDrag {
+                from,
+                start,
+                position: start,
+                grab_offset: start - origin,
+                target: None,
+            });
+            cx.notify();
+        });
+    }
+
+    fn drag_to(&self, position: Point<Pixels>, app: &mut App) {
+        self.state.update(app, |state, cx| {
+            let Some(drag) = &mut state.drag else {
+                return;
+            };
+            if drag.position != position {
+                drag.position = position;
+                cx.notify();
+            }
+            let distance = (position.along(self.axis) - drag.start.along(self.axis)).abs();
+            if drag.target.is_none() && distance < DRAG_THRESHOLD {
+                return;
+            }
+            let target = drop_target(
+                state.bounds.iter().copied(),
+                position.along(self.axis),
+                self.axis,
+            );
+            if drag.target != Some(target) {
+                drag.target = Some(target);
+                cx.notify();
+            }
+        });
+    }
+
+    fn end_drag(&self, window: &mut Window, app: &mut App) {
+        let drag = self.state.update(app, |state, cx| {
+            cx.notify();
+            state.drag.take()
+        });
+        if let Some(ItemDrag {
+            from,
+            target: Some(target),
+            ..
+        }) = drag
+        {
+            self.reorder(from, moved_index(from, target), window, app);
+        }
+    }
+
+    /// Scrolls the container while an item is dragged near its edges, retargeting the drop
+    /// as the items move under the pointer.
+    ///
+    /// Runs before a frame is drawn rather than while painting, as it updates the state and
+    /// the scroll offset.
+    fn auto_scroll(&self, window: &mut Window, app: &mut App) {
+        let Some(handle) = &self.scroll_handle else {
+            return;
+        };
+        let Some(position) = self
+            .state
+            .read(app)
+            .drag
+            .as_ref()
+            .filter(|drag| drag.target.is_some())
+            .map(|drag| drag.position)
+        else {
+            return;
+        };
+        self.drag_to(position, app);
+
+        let axis = self.axis;
+        let viewport = handle.bounds();
+        let start = viewport.origin.along(axis);
+        let delta = auto_scroll_delta(
+            start,
+            start + viewport.size.along(axis),
+            position.along(axis),
+        );
+        let offset = handle.offset();
+        let max_offset = handle.max_offset().along(axis);
+        let scrolled = (offset.along(axis) - delta).clamp(-max_offset, px(0.));
+        if scrolled != offset.along(axis) {
+            handle.set_offset(offset.apply_along(axis, |_| scrolled));
+            window.refresh();
+        }
+    }
+
+    /// Moves the focused item with Alt and an arrow key, returning whether the key was
+    /// handled.
+    fn key_down(
+        &self,
+        ix: usize,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        app: &mut App,
+    ) -> bool {
+        let keystroke = &event.keystroke;
+        if !keystroke.modifiers.alt {
+            return false;
+        }
+        let to = match (keystroke.key.as_str(), self.axis) {
+            ("up", Axis::Vertical) | ("left", Axis::Horizontal) => ix.checked_sub(1),
+            ("down", Axis::Vertical) | ("right", Axis::Horizontal) => {
+                Some(ix + 1).filter(|to| *to < self.len)
+            }
+            _ => return false,
+        };
+        if let Some(to) = to {
+            self.reorder(ix, to, window, app);
+        }
+        true
+    }
+}
+
+impl RenderOnce for SortableList {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let len = self.items.len();
+        let ids: Vec<SharedString> = self.items.iter().map(|item| item.id.clone()).collect();
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| SortableListState {
+            focus_handles: HashMap::new(),
+            bounds: Vec::new(),
+            list_bounds: Bounds::default(),
+            active: 0,
+            drag: None,
+        });
+
+        let (group, dragging, ghost_origin, indicator_offset) = state.update(app, |state, cx| {
+            state.focus_handles.retain(|id, _| ids.contains(id));
+            let handles: Vec<FocusHandle> = ids
+                .iter()
+                .map(|id| {
+                    state
+                        .focus_handles
+                        .entry(id.clone())
+                        .or_insert_with(|| cx.focus_handle())
+                        .clone()
+                })
+                .collect();
+            state.bounds.truncate(len);
+            if state.drag.as_ref().is_some_and(|drag| drag.from >= len) {
+                state.drag = None;
+            }
+
+            if let Some(focused) = handles.iter().position(|handle| handle.is_focused(window)) {
+                state.active = focused;
+            }
+            state.active = state.active.min(len.saturating_sub(1));
+
+            // The indicator sits on the leading edge of the item the dragged one goes
+            // before, or the trailing edge of the last item.
+            let axis = self.axis;
+            let indicator_offset = state
+                .drag
+                .as_ref()
+                .and_then(|drag| drag.target)
+                .and_then(|target| match state.bounds.get(target) {
+                    Some(bounds) => Some(bounds.origin.along(axis)),
+                    None => state
+                        .bounds
+                        .last()
+                        .map(|bounds| bounds.bottom_right().along(axis)),
+                })
+                .map(|edge| edge - state.list_bounds.origin.along(axis));
+
+            let ghost_origin = state
+                .drag
+                .as_ref()
+                .filter(|drag| drag.target.is_some())
+                .map(|drag| drag.position - drag.grab_offset);
+
+            (
+                FocusGroup::new(handles, state.active).axis(axis),
+                state.drag.as_ref().map(|drag| drag.from),
+                ghost_origin,
+                indicator_offset,
+            )
+        });
+
+        let controller = SortableListController {
+            state: state.clone(),
+            axis: self.axis,
+            len,
+            scroll_handle: self.scroll_handle,
+            on_reorder: self.on_reorder,
+        };
+
+        let mut ghost = None;
+        let mut children = Vec::with_capacity(len);
+        for (ix, mut item) in self.items.into_iter().enumerate() {
+            let focus_handle = group.handle(ix).clone();
+            if dragging == Some(ix)
+                && let Some(handler) = item.when_dragging_handler.take()
+            {
+                item = handler(item);
+            }
+            if focus_handle.is_focused(window)
+                && is_focus_visible(app)
+                && let Some(handler) = item.when_focus_visible_handler.take()
+            {
+                item = handler(item);
+            }
+
+            if dragging == Some(ix) {
+                ghost = item.ghost.take();
+            }
+
+            let start_drag = {
+                let controller = controller.clone();
+                move |event: &MouseDownEvent, _: &mut Window, app: &mut App| {
+                    controller.start_drag(ix, event.position, app)
+                }
+            };
+            let mut item_children = item.children;
+            let has_handle = item.handle.is_some();
+            if let Some((position, handle)) = item.handle {
+                let handle = div()
+                    .id(("sortable-handle", ix))
+                    .cursor(CursorStyle::OpenHand)
+                    .on_mouse_down(MouseButton::Left, start_drag.clone())
+                    .child(handle)
+                    .into_any_element();
+                item_children.insert(position.min(item_children.len()), handle);
+            }
+
+            children.push(
+                item.base
+                    .id(("sortable-item", ix))
+                    .track_focus(&focus_handle)
+                    .when(!has_handle, |this| {
+                        this.on_mouse_down(MouseButton::Left, start_drag)
+                    })
+                    .children(item_children)
+                    .on_key_down({
+                        let controller = controller.clone();
+                        move |event, window, app| {
+                            if controller.key_down(ix, event, window, app) {
+                                app.stop_propagation();
+                            }
+                        }
+                    })
+                    .child({
+                        let state = state.clone();
+                        canvas(
+                            move |bounds, _, app| {
+                                state.update(app, |state, _| {
+                                    if state.bounds.len() <= ix {
+                                        state.bounds.resize(ix + 1, Bounds::default());
+                                    }
+                                    state.bounds[ix] = bounds;
+                                })
+                            },
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full()
+                    })
+                    .into_any_element(),
+            );
+        }
+
+        let axis = self.axis;
+        self.base
+            .id(self.id)
+            .relative()
+            .on_key_down(move |event, window, app| {
+                if group.key_down(event, window, app) {
+                    app.stop_propagation();
+                }
+            })
+            .children(children)
+            .when_some(indicator_offset, |this, offset| {
+                let indicator = div().absolute().map(|this| match axis {
+                    Axis::Vertical => this.left_0().right_0().top(offset),
+                    Axis::Horizontal => this.top_0().bottom_0().left(offset),
+                });
+                this.child(match self.drop_indicator {
+                    Some(style) => style(indicator),
+                    None => indicator,
+                })
+            })
+            .child({
+                let state = state.clone();
+                canvas(
+                    move |bounds, _, app| state.update(app, |state, _| state.list_bounds = bounds),
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .when_some(ghost.zip(ghost_origin), |this, (ghost, origin)| {
+                this.child(deferred(anchored().position(origin).child(ghost)).with_priority(1))
+            })
+            .when(dragging.is_some(), |this| {
+                this.child(
+                    canvas(
+                        |_, _, _| {},
+                        move |_, _, window, _| {
+                            window.on_next_frame({
+                                let controller = controller.clone();
+                                move |window, app| controller.auto_scroll(window, app)
+                            });
+                            window.on_mouse_event({
+                                let controller = controller.clone();
+                                move |event: &MouseMoveEvent, phase, _, app| {
+                                    if phase.bubble() {
+                                        controller.drag_to(event.position, app);
+                                    }
+                                }
+                            });
+                            window.on_mouse_event(
+                                move |event: &MouseUpEvent, phase, window, app| {
+                                    if phase.bubble() && event.button == MouseButton::Left {
+                                        controller.end_drag(window, app);
+                                    }
+                                },
+                            );
+                        },
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+            })
+    }
+}
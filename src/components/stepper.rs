@@ -0,0 +1,292 @@
+use crate::{
+    Disableable, is_in_disabled_scope,
+    primitives::{Button, button, h_flex, text_field::NumberFormat},
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::{rc::Rc, time::Duration};
+
+/// How long a button is held before the value starts repeating.
+const REPEAT_DELAY: Duration = Duration::from_millis(400);
+/// The interval between the first repeated steps, which shrinks the longer the button is
+/// held, down to [`MIN_REPEAT_INTERVAL`].
+const REPEAT_INTERVAL: Duration = Duration::from_millis(120);
+const MIN_REPEAT_INTERVAL: Duration = Duration::from_millis(30);
+const REPEAT_ACCELERATION: f32 = 0.85;
+
+/// A value between a decrement and an increment button, for numbers picked with a few
+/// presses rather than typed, such as a quantity.
+///
+/// Holding a button, with the mouse or with Space or Enter, repeats the step faster and
+/// faster until it is released or the value reaches its bound. The value is clamped to the
+/// `min` and `max` of its [`NumberFormat`] and shown formatted by it, unless a label is
+/// given. For a number the user can also type, use a text field with
+/// [`crate::primitives::text_field::TextField::number`].
+///
+/// # Examples
+///
+/// ```rust
+/// Stepper::new("guests")
+///     .value(self.guests)
+///     .min(1.)
+///     .max(12.)
+///     .gap_2()
+///     .decrement(|button| button.px_2().rounded_md().bg(rgb(0xe5e7eb)))
+///     .increment(|button| button.px_2().rounded_md().bg(rgb(0xe5e7eb)))
+///     .label(|value| div().w(px(24.)).child(format!("{value}")))
+///     .on_change(cx.listener(|this, value: &f64, _, cx| {
+///         this.guests = *value;
+///         cx.notify();
+///     }))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Stepper {
+    id: ElementId,
+    base: Div,
+    value: Option<f64>,
+    default_value: f64,
+    format: NumberFormat,
+    disabled: bool,
+    on_change: Option<Rc<dyn Fn(&f64, &mut Window, &mut App) + 'static>>,
+    decrement: Option<Box<dyn FnOnce(Button) -> Button>>,
+    increment: Option<Box<dyn FnOnce(Button) -> Button>>,
+    label: Option<Box<dyn FnOnce(f64) -> AnyElement>>,
+    when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+}
+
+/// The value and the repeating step of a held button, kept across renders of a
+/// [`Stepper`].
+#[allow(clippy::type_complexity)]
+struct StepperState {
+    value: f64,
+    format: NumberFormat,
+    on_change: Option<Rc<dyn Fn(&f64, &mut Window, &mut App) + 'static>>,
+    /// Steps the value while a button is held, cancelled when dropped.
+    repeat: Option<Task<()>>,
+}
+
+impl StepperState {
+    /// Steps the value up or down, returning whether it changed.
+    fn step(&mut self, up: bool, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let value = match up {
+            true => self.value + self.format.step,
+            false => self.value - self.format.step,
+        };
+        // Formatting drops the floating point error that steps such as 0.1 accumulate.
+        let value = self.format.clamp(value);
+        let value = self
+            .format
+            .parse(&self.format.format(value))
+            .unwrap_or(value);
+        if value == self.value {
+            return false;
+        }
+
+        self.value = value;
+        cx.notify();
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&value, window, cx);
+        }
+        true
+    }
+
+    /// Steps once, then keeps stepping at a growing pace until [`Self::stop_repeat`].
+    fn start_repeat(&mut self, up: bool, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.step(up, window, cx) {
+            return;
+        }
+
+        self.repeat = Some(cx.spawn_in(window, async move |this, cx| {
+            Timer::after(REPEAT_DELAY).await;
+            let mut interval = REPEAT_INTERVAL;
+            loop {
+                let stepped = this
+                    .update_in(cx, |state, window, cx| state.step(up, window, cx))
+                    .unwrap_or(false);
+                if !stepped {
+                    break;
+                }
+                Timer::after(interval).await;
+                interval = interval
+                    .mul_f32(REPEAT_ACCELERATION)
+                    .max(MIN_REPEAT_INTERVAL);
+            }
+        }));
+    }
+
+    fn stop_repeat(&mut self) {
+        self.repeat = None;
+    }
+}
+
+impl Stepper {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: h_flex().items_center(),
+            value: None,
+            default_value: 0.,
+            format: NumberFormat::default(),
+            disabled: false,
+            on_change: None,
+            decrement: None,
+            increment: None,
+            label: None,
+            when_disabled_handler: None,
+        }
+    }
+
+    /// Controls the value.
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// The value on first render when it is not controlled, 0 by default.
+    pub fn default_value(mut self, value: f64) -> Self {
+        self.default_value = value;
+        self
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.format = self.format.min(min);
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.format = self.format.max(max);
+        self
+    }
+
+    /// How much each press changes the value, 1 by default.
+    pub fn step(mut self, step: f64) -> Self {
+        self.format = self.format.step(step);
+        self
+    }
+
+    /// Sets the bounds, step and decimal separator at once.
+    pub fn format(mut self, format: NumberFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Called with the value after each step, including those repeated while a button is
+    /// held.
+    pub fn on_change(mut self, on_change: impl Fn(&f64, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Customizes the decrement button, which shows `−` by default.
+    pub fn decrement(mut self, handler: impl FnOnce(Button) -> Button + 'static) -> Self {
+        self.decrement = Some(Box::new(handler));
+        self
+    }
+
+    /// Customizes the increment button, which shows `+` by default.
+    pub fn increment(mut self, handler: impl FnOnce(Button) -> Button + 'static) -> Self {
+        self.increment = Some(Box::new(handler));
+        self
+    }
+
+    /// Renders the value between the buttons, instead of the value formatted by the
+    /// [`NumberFormat`].
+    pub fn label<E: IntoElement>(mut self, label: impl FnOnce(f64) -> E + 'static) -> Self {
+        self.label = Some(Box::new(move |value| label(value).into_any_element()));
+        self
+    }
+}
+
+impl Disableable for Stepper {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Styled for Stepper {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Stepper {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        if is_in_disabled_scope(app) {
+            self.disabled = true;
+        }
+        if self.disabled
+            && let Some(handler) = self.when_disabled_handler.take()
+        {
+            self = handler(self);
+        }
+
+        let format = self.format;
+        let default_value = format.clamp(self.default_value);
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| StepperState {
+            value: default_value,
+            format,
+            on_change: None,
+            repeat: None,
+        });
+        let value = state.update(app, |state, _| {
+            if let Some(value) = self.value {
+                state.value = value;
+            }
+            state.format = format;
+            state.on_change = self.on_change.take();
+            if self.disabled {
+                state.stop_repeat();
+            }
+            state.value
+        });
+
+        let at_min = format.min.is_some_and(|min| value <= min);
+        let at_max = format.max.is_some_and(|max| value >= max);
+        let stepper_button = |up: bool, disabled: bool| {
+            let name = if up { "increment" } else { "decrement" };
+            button(ElementId::NamedChild(
+                Box::new(self.id.clone()),
+                name.into(),
+            ))
+            .disabled(self.disabled || disabled)
+            .child(if up { "+" } else { "−" })
+            .on_press_start({
+                let state = state.clone();
+                move |_, window, app| {
+                    state.update(app, |state, cx| state.start_repeat(up, window, cx))
+                }
+            })
+            .on_press_end({
+                let state = state.clone();
+                move |_, _, app| state.update(app, |state, _| state.stop_repeat())
+            })
+        };
+        let mut decrement = stepper_button(false, at_min);
+        if let Some(handler) = self.decrement {
+            decrement = handler(decrement);
+        }
+        let mut increment = stepper_button(true, at_max);
+        if let Some(handler) = self.increment {
+            increment = handler(increment);
+        }
+
+        self.base
+            .id(self.id)
+            .child(decrement)
+            .map(|this| match self.label {
+                Some(label) => this.child(label(value)),
+                None => this.child(format.format(value)),
+            })
+            .child(increment)
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{Disableable, primitives::h_flex};
+use crate::{
+    AccessibilityProps, Accessible, AutoFocusable, ControlSize, Density, Disableable,
+    FocusableStyled, InteractiveStateHandlers, InteractiveStateStyled, Role, Sizeable, Theme,
+    apply_interactive_state, is_focus_visible, is_in_disabled_scope, primitives::h_flex,
+    track_interaction,
+};
 use gpui::{prelude::FluentBuilder, *};
 
 /// A toggle switch component that allows users to switch between on/off states.
@@ -6,6 +11,7 @@ use gpui::{prelude::FluentBuilder, *};
 /// The switch provides a boolean input control similar to a checkbox but with a different
 /// visual appearance, typically showing a sliding thumb that moves between positions.
 /// It supports disabled states, custom thumb styling, and change callbacks.
+/// Like a checkbox, it is a tab stop and toggles with Space or Enter while focused.
 ///
 /// # Examples
 ///
@@ -27,7 +33,7 @@ use gpui::{prelude::FluentBuilder, *};
 ///     .thumb(|thumb| thumb.bg(rgb(0xffffff)).rounded_full().size(px(20)))
 ///     .rounded_full()
 ///     .p(px(2))
-///     .with_animation(("checkbox", self.disabled as u32), Animation::new(Duration::from_millis(100)), move |this, delta| {
+///     .with_animation(("checkbox", self.disabled as u32), Animation::new(MotionPreference::current(cx).duration(Duration::from_millis(100))), move |this, delta| {
 ///         this.thumb(|thumb| {
 ///             if disabled {
 ///                 thumb.left(px(delta * LEFT_OFFSET))
@@ -41,12 +47,21 @@ use gpui::{prelude::FluentBuilder, *};
 #[allow(clippy::type_complexity)]
 #[derive(IntoElement)]
 pub struct Switch {
+    id: ElementId,
     base: Stateful<Div>,
     disabled: bool,
     checked: bool,
     on_change: Option<Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
     thumb: SwitchThumb,
+    auto_focus: bool,
+    tab_index: isize,
+    tab_stop: bool,
     when_checked_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    accessibility: AccessibilityProps,
+    when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_focus_visible_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    interactive_state: InteractiveStateHandlers<Self>,
+    size: Option<ControlSize>,
 }
 
 impl Switch {
@@ -64,13 +79,23 @@ impl Switch {
     /// let switch = Switch::new("user-notifications");
     /// ```
     pub fn new(id: impl Into<ElementId>) -> Self {
+        let id = id.into();
         Self {
+            id: id.clone(),
             base: h_flex().id(id),
             disabled: false,
             checked: false,
             on_change: None,
             thumb: SwitchThumb::new(),
+            auto_focus: false,
+            tab_index: 0,
+            tab_stop: true,
             when_checked_handler: None,
+            accessibility: AccessibilityProps::new(Role::Switch),
+            when_disabled_handler: None,
+            when_focus_visible_handler: None,
+            interactive_state: InteractiveStateHandlers::default(),
+            size: None,
         }
     }
 
@@ -159,10 +184,59 @@ impl Switch {
         self.on_change = Some(Box::new(on_change));
         self
     }
+
+    /// Whether the switch can be reached with Tab, `true` by default.
+    pub fn tab_stop(mut self, tab_stop: bool) -> Self {
+        self.tab_stop = tab_stop;
+        self
+    }
+
+    /// The position of the switch in the Tab order, relative to other tab stops.
+    pub fn tab_index(mut self, tab_index: isize) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+}
+
+impl AutoFocusable for Switch {
+    fn auto_focus(mut self, auto_focus: bool) -> Self {
+        self.auto_focus = auto_focus;
+        self
+    }
 }
 
 impl StatefulInteractiveElement for Switch {}
 
+impl FocusableStyled for Switch {
+    fn when_focus_visible(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_focus_visible_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl InteractiveStateStyled for Switch {
+    fn interactive_state_handlers_mut(&mut self) -> &mut InteractiveStateHandlers<Self> {
+        &mut self.interactive_state
+    }
+}
+
+impl Accessible for Switch {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl Sizeable for Switch {
+    fn with_size(mut self, size: ControlSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
 impl Disableable for Switch {
     fn is_disabled(&self) -> bool {
         self.disabled
@@ -172,6 +246,11 @@ impl Disableable for Switch {
         self.disabled = disabled;
         self
     }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
 }
 
 impl Styled for Switch {
@@ -187,21 +266,84 @@ impl InteractiveElement for Switch {
 }
 
 impl RenderOnce for Switch {
-    fn render(mut self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let auto_focus = self.auto_focus;
+        let mut focus_handle = window
+            .use_keyed_state(
+                ElementId::NamedChild(Box::new(self.id.clone()), "focus".into()),
+                app,
+                |window, cx| {
+                    let focus_handle = cx.focus_handle();
+                    if auto_focus {
+                        focus_handle.focus(window);
+                    }
+                    focus_handle
+                },
+            )
+            .read(app)
+            .clone();
+        if focus_handle.tab_stop != self.tab_stop {
+            focus_handle = focus_handle.tab_stop(self.tab_stop);
+        }
+        if focus_handle.tab_index != self.tab_index {
+            focus_handle = focus_handle.tab_index(self.tab_index);
+        }
+
+        if is_in_disabled_scope(app) {
+            self.disabled = true;
+        }
+        if self.disabled {
+            if let Some(handler) = self.when_disabled_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        if let Some(size) = self.size {
+            let theme = Theme::of(app);
+            let indicator = size.indicator(&theme);
+            self.base = self.base.h(indicator).w(rems(indicator.0 * 2.));
+        }
+
         if self.checked {
             if let Some(handler) = self.when_checked_handler.take() {
                 self = handler(self);
             }
         }
 
-        self.base
+        if focus_handle.is_focused(window) && is_focus_visible(app) {
+            if let Some(handler) = self.when_focus_visible_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        let focused = focus_handle.is_focused(window);
+        let (this, interaction) = apply_interactive_state(
+            self.id.clone(),
+            self.disabled,
+            false,
+            focused,
+            self,
+            window,
+            app,
+        );
+        self = this;
+
+        let style = self.base.style();
+        if style.min_size.width.is_none() && style.min_size.height.is_none() {
+            let hit_target = Density::current(app).hit_target();
+            self.base = self.base.min_w(hit_target * 2.).min_h(hit_target);
+        }
+
+        track_interaction(self.base, interaction)
+            .when(!self.disabled, |this| this.track_focus(&focus_handle))
             .when_some(
                 self.on_change.filter(|_| !self.disabled),
-                |this, on_click| {
+                |this, on_change| {
+                    // gpui also clicks focused elements on Space and Enter.
                     this.on_click(move |_, window, cx| {
                         cx.stop_propagation();
                         let checked = !self.checked;
-                        on_click(&checked, window, cx);
+                        on_change(&checked, window, cx);
                     })
                 },
             )
@@ -0,0 +1,205 @@
+use gpui::*;
+use std::{cmp::Ordering, rc::Rc};
+
+const DEFAULT_MIN_WIDTH: Pixels = px(40.);
+
+/// The order rows are sorted in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// The column a [`super::Table`] is sorted by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableSort {
+    pub column: SharedString,
+    pub direction: SortDirection,
+}
+
+impl TableSort {
+    pub fn ascending(column: impl Into<SharedString>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    pub fn descending(column: impl Into<SharedString>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Descending,
+        }
+    }
+
+    /// Returns the sort after the header of `column` is clicked: a new column sorts
+    /// ascending, then descending, then the sort is cleared.
+    pub(super) fn cycle(sort: Option<&TableSort>, column: &SharedString) -> Option<TableSort> {
+        match sort {
+            Some(sort) if sort.column == *column => match sort.direction {
+                SortDirection::Ascending => Some(Self::descending(column.clone())),
+                SortDirection::Descending => None,
+            },
+            _ => Some(Self::ascending(column.clone())),
+        }
+    }
+}
+
+/// Whether every selectable row of a [`super::Table`] is selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectAllState {
+    #[default]
+    None,
+    /// Some rows are selected, usually rendered as an indeterminate checkbox.
+    Some,
+    All,
+}
+
+/// What a header cell is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableHeaderContext {
+    pub column: SharedString,
+    pub index: usize,
+    pub sortable: bool,
+    /// The direction rows are sorted in, if they are sorted by this column.
+    pub sort_direction: Option<SortDirection>,
+    pub select_all: SelectAllState,
+    /// Whether the column is being resized.
+    pub resizing: bool,
+    /// Whether the column is being dragged to a new position.
+    pub dragging: bool,
+}
+
+/// What a body cell is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableCellContext {
+    pub column: SharedString,
+    /// The row key, see [`super::Table::row_key`].
+    pub row: SharedString,
+    /// Position of the row after sorting.
+    pub index: usize,
+    pub selected: bool,
+    /// Whether the row has the keyboard cursor.
+    pub active: bool,
+}
+
+/// A column of a [`super::Table`] over rows of type `R`.
+///
+/// Clicking the header of a [`TableColumn::sortable`] column cycles its sort, clicking the
+/// header of a [`TableColumn::selection`] column selects or deselects every row and
+/// clicking its cells toggles their row.
+///
+/// Columns without a width share the space left by the others, in proportion to their
+/// [`TableColumn::flex`]. Dragging the edge of a
+/// [`TableColumn::resizable`] header gives the column the dragged width.
+#[allow(clippy::type_complexity)]
+pub struct TableColumn<R> {
+    pub(super) id: SharedString,
+    pub(super) header:
+        Option<Box<dyn Fn(&TableHeaderContext, &mut Window, &mut App) -> AnyElement>>,
+    pub(super) cell: Option<Rc<dyn Fn(&R, &TableCellContext, &mut Window, &mut App) -> AnyElement>>,
+    pub(super) compare: Option<Rc<dyn Fn(&R, &R) -> Ordering>>,
+    pub(super) selection: bool,
+    pub(super) width: Option<Pixels>,
+    pub(super) flex: f32,
+    pub(super) min_width: Pixels,
+    pub(super) max_width: Option<Pixels>,
+    pub(super) resizable: bool,
+}
+
+impl<R: 'static> TableColumn<R> {
+    pub fn new(id: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            header: None,
+            cell: None,
+            compare: None,
+            selection: false,
+            width: None,
+            flex: 1.,
+            min_width: DEFAULT_MIN_WIDTH,
+            max_width: None,
+            resizable: false,
+        }
+    }
+
+    /// A column that toggles row selection, typically rendering checkboxes.
+    pub fn selection(id: impl Into<SharedString>) -> Self {
+        Self {
+            selection: true,
+            ..Self::new(id)
+        }
+    }
+
+    /// Renders the header cell, the column id is rendered when this isn't set.
+    pub fn header<E: IntoElement>(
+        mut self,
+        render: impl Fn(&TableHeaderContext, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.header = Some(Box::new(move |context, window, app| {
+            render(context, window, app).into_any_element()
+        }));
+        self
+    }
+
+    /// Renders the cell of this column for a row.
+    pub fn cell<E: IntoElement>(
+        mut self,
+        render: impl Fn(&R, &TableCellContext, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.cell = Some(Rc::new(move |row, context, window, app| {
+            render(row, context, window, app).into_any_element()
+        }));
+        self
+    }
+
+    /// Makes the column sortable, ordering rows with the comparator when ascending.
+    pub fn sortable(mut self, compare: impl Fn(&R, &R) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Makes the column sortable by a key extracted from each row.
+    pub fn sortable_by_key<K: Ord>(self, key: impl Fn(&R) -> K + 'static) -> Self {
+        self.sortable(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// The width of the column until the user resizes it.
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// The share of the remaining space the column takes while it has no width, relative
+    /// to the other such columns, 1 by default.
+    pub fn flex(mut self, flex: f32) -> Self {
+        self.flex = flex;
+        self
+    }
+
+    /// The narrowest the column can be resized to, 40px by default.
+    pub fn min_width(mut self, width: Pixels) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    pub fn max_width(mut self, width: Pixels) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Adds a drag handle to the trailing edge of the header cell to resize the column.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+/// Keeps a width between a column's minimum and maximum widths.
+pub(super) fn clamp_width(width: Pixels, min_width: Pixels, max_width: Option<Pixels>) -> Pixels {
+    let width = width.max(min_width);
+    match max_width {
+        Some(max_width) => width.min(max_width),
+        None => width,
+    }
+}
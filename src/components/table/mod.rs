@@ -0,0 +1,957 @@
+use crate::{
+    SelectionMode,
+    drag::{DRAG_THRESHOLD, drop_target, moved_index},
+    primitives::{h_flex, v_flex},
+    scroll_container, scroll_into_view, scroll_target,
+    selection::{self, pick, toggle},
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::{collections::HashMap, rc::Rc};
+
+mod column;
+#[cfg(test)]
+mod tests;
+
+pub use column::*;
+
+const RESIZE_HANDLE_WIDTH: Pixels = px(6.);
+
+/// What a body row is styled with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableRowContext {
+    /// The row key, see [`Table::row_key`].
+    pub key: SharedString,
+    /// Position of the row after sorting.
+    pub index: usize,
+    pub selected: bool,
+    /// Whether the row has the keyboard cursor.
+    pub active: bool,
+}
+
+/// A headless data table.
+///
+/// Columns decide how their header and cells render, the table lays them out in rows,
+/// sorts the rows by the sortable column the user picked and tracks which rows are
+/// selected. The table is a single tab stop: ArrowUp/ArrowDown/Home/End move the active
+/// row and Enter selects it. In [`SelectionMode::Multiple`], Space toggles the active row,
+/// Shift extends the selection and the secondary modifier (Cmd on macOS, Ctrl elsewhere)
+/// with A selects every row.
+///
+/// Resizable columns and, with [`Table::reorderable_columns`], column positions can be
+/// changed by dragging the headers. Report the changes to the app with
+/// [`Table::on_column_resize`] and [`Table::on_column_reorder`] to persist the layout.
+///
+/// # Examples
+///
+/// ```rust
+/// Table::new("people")
+///     .rows(self.people.clone())
+///     .row_key(|person: &Person| person.id.clone())
+///     .selection_mode(SelectionMode::Multiple)
+///     .column(TableColumn::selection("select").cell(|_, cell, _, _| checkbox(cell.selected)))
+///     .column(
+///         TableColumn::new("name")
+///             .sortable_by_key(|person: &Person| person.name.clone())
+///             .header(|header, _, _| {
+///                 h_flex().child("Name").when_some(header.sort_direction, |this, direction| {
+///                     this.child(match direction {
+///                         SortDirection::Ascending => "▲",
+///                         SortDirection::Descending => "▼",
+///                     })
+///                 })
+///             })
+///             .cell(|person, _, _, _| person.name.clone()),
+///     )
+///     .row(|row, context| row.when(context.selected, |this| this.bg(rgb(0xdbeafe))))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Table<R: 'static> {
+    id: ElementId,
+    base: Div,
+    columns: Vec<TableColumn<R>>,
+    rows: Vec<R>,
+    row_key: Option<Box<dyn Fn(&R) -> SharedString>>,
+    selection_mode: Option<SelectionMode>,
+    selected: Option<Vec<SharedString>>,
+    default_selected: Vec<SharedString>,
+    sort: Option<Option<TableSort>>,
+    default_sort: Option<TableSort>,
+    on_selection_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+    on_sort_change: Option<Rc<dyn Fn(Option<&TableSort>, &mut Window, &mut App) + 'static>>,
+    header_row: Option<Box<dyn FnOnce(Div) -> Div>>,
+    row: Option<Box<dyn Fn(Div, &TableRowContext) -> Div>>,
+    reorderable_columns: bool,
+    column_order: Option<Vec<SharedString>>,
+    on_column_resize: Option<Rc<dyn Fn(&SharedString, Pixels, &mut Window, &mut App) + 'static>>,
+    on_column_reorder: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+    resize_handle: Option<Box<dyn Fn(Div, &TableHeaderContext) -> Div>>,
+    drop_indicator: Option<Box<dyn FnOnce(Div) -> Div>>,
+}
+
+/// A header drag in progress.
+#[derive(Clone)]
+enum ColumnDrag {
+    Resize {
+        column: SharedString,
+        start_x: Pixels,
+        start_width: Pixels,
+        min_width: Pixels,
+        max_width: Option<Pixels>,
+    },
+    Reorder {
+        column: SharedString,
+        start_x: Pixels,
+        /// The position the column would be inserted at, once the drag passed the threshold.
+        target: Option<usize>,
+    },
+}
+
+/// Sort, selection, cursor and column layout kept across renders of a [`Table`].
+struct TableState {
+    focus_handle: FocusHandle,
+    sort: Option<TableSort>,
+    selected: Vec<SharedString>,
+    anchor: Option<SharedString>,
+    active: Option<SharedString>,
+    widths: HashMap<SharedString, Pixels>,
+    column_order: Vec<SharedString>,
+    header_bounds: HashMap<SharedString, Bounds<Pixels>>,
+    drag: Option<ColumnDrag>,
+    /// Set when a reorder ends on a header, whose click should be ignored.
+    suppress_click: bool,
+    scroll_handle: ScrollHandle,
+}
+
+impl<R: 'static> Table<R> {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: v_flex(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            row_key: None,
+            selection_mode: None,
+            selected: None,
+            default_selected: Vec::new(),
+            sort: None,
+            default_sort: None,
+            on_selection_change: None,
+            on_sort_change: None,
+            header_row: None,
+            row: None,
+            reorderable_columns: false,
+            column_order: None,
+            on_column_resize: None,
+            on_column_reorder: None,
+            resize_handle: None,
+            drop_indicator: None,
+        }
+    }
+
+    pub fn column(mut self, column: TableColumn<R>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    pub fn columns(mut self, columns: impl IntoIterator<Item = TableColumn<R>>) -> Self {
+        self.columns.extend(columns);
+        self
+    }
+
+    pub fn rows(mut self, rows: impl IntoIterator<Item = R>) -> Self {
+        self.rows.extend(rows);
+        self
+    }
+
+    /// Identifies each row for selection, rows are identified by their position when
+    /// this isn't set.
+    pub fn row_key(mut self, key: impl Fn(&R) -> SharedString + 'static) -> Self {
+        self.row_key = Some(Box::new(key));
+        self
+    }
+
+    /// Enables row selection, rows can't be selected when this isn't set.
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = Some(mode);
+        self
+    }
+
+    /// Controls the keys of the selected rows.
+    pub fn selected(mut self, keys: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.selected = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// The keys selected on first render when the table is not controlled.
+    pub fn default_selected(
+        mut self,
+        keys: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.default_selected = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Controls the sort, `None` keeps the rows in the order they were given.
+    pub fn sort(mut self, sort: Option<TableSort>) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// The sort applied on first render when the sort is not controlled.
+    pub fn default_sort(mut self, sort: TableSort) -> Self {
+        self.default_sort = Some(sort);
+        self
+    }
+
+    pub fn on_selection_change(
+        mut self,
+        on_change: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_selection_change = Some(Rc::new(on_change));
+        self
+    }
+
+    pub fn on_sort_change(
+        mut self,
+        on_change: impl Fn(Option<&TableSort>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_sort_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Styles the row of header cells.
+    pub fn header_row(mut self, style: impl FnOnce(Div) -> Div + 'static) -> Self {
+        self.header_row = Some(Box::new(style));
+        self
+    }
+
+    /// Styles each body row.
+    pub fn row(mut self, style: impl Fn(Div, &TableRowContext) -> Div + 'static) -> Self {
+        self.row = Some(Box::new(style));
+        self
+    }
+
+    /// Lets the user drag header cells to reorder the columns.
+    pub fn reorderable_columns(mut self, reorderable: bool) -> Self {
+        self.reorderable_columns = reorderable;
+        self
+    }
+
+    /// Controls the order of the columns by id, columns missing from it follow in the
+    /// order they were added.
+    pub fn column_order(mut self, ids: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.column_order = Some(ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Called with the column id and its new width when the user finishes resizing it.
+    pub fn on_column_resize(
+        mut self,
+        on_resize: impl Fn(&SharedString, Pixels, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_column_resize = Some(Rc::new(on_resize));
+        self
+    }
+
+    /// Called with the column ids in their new order when the user drops a column.
+    pub fn on_column_reorder(
+        mut self,
+        on_reorder: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_column_reorder = Some(Rc::new(on_reorder));
+        self
+    }
+
+    /// Styles the drag handle on the trailing edge of resizable header cells.
+    pub fn resize_handle(
+        mut self,
+        style: impl Fn(Div, &TableHeaderContext) -> Div + 'static,
+    ) -> Self {
+        self.resize_handle = Some(Box::new(style));
+        self
+    }
+
+    /// Styles the line marking where a dragged column will be dropped.
+    pub fn drop_indicator(mut self, style: impl FnOnce(Div) -> Div + 'static) -> Self {
+        self.drop_indicator = Some(Box::new(style));
+        self
+    }
+}
+
+impl<R: 'static> Styled for Table<R> {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// The sorted row keys of a rendered table together with its state, shared by its
+/// listeners.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct TableController {
+    id: ElementId,
+    keys: Rc<[SharedString]>,
+    state: Entity<TableState>,
+    selection_mode: Option<SelectionMode>,
+    controlled_selection: bool,
+    controlled_sort: bool,
+    on_selection_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+    on_sort_change: Option<Rc<dyn Fn(Option<&TableSort>, &mut Window, &mut App) + 'static>>,
+    /// Column ids in the order they are rendered.
+    columns: Rc<[SharedString]>,
+    controlled_column_order: bool,
+    on_column_resize: Option<Rc<dyn Fn(&SharedString, Pixels, &mut Window, &mut App) + 'static>>,
+    on_column_reorder: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+}
+
+impl TableController {
+    fn active(&self, app: &App) -> Option<usize> {
+        let active = self.state.read(app).active.as_ref()?;
+        self.keys.iter().position(|key| key == active)
+    }
+
+    fn set_active(&self, ix: usize, app: &mut App) {
+        let key = self.keys[ix].clone();
+        self.state.update(app, |state, cx| {
+            state.active = Some(key);
+            cx.notify();
+        });
+    }
+
+    /// Identifies a row to [`scroll_into_view`].
+    fn row_target(&self, ix: usize) -> ElementId {
+        ElementId::NamedChild(Box::new(self.id.clone()), self.keys[ix].clone())
+    }
+
+    fn select_all_state(&self, app: &App) -> SelectAllState {
+        let selected = &self.state.read(app).selected;
+        let count = self
+            .keys
+            .iter()
+            .filter(|key| selected.contains(key))
+            .count();
+        match count {
+            0 => SelectAllState::None,
+            count if count == self.keys.len() => SelectAllState::All,
+            _ => SelectAllState::Some,
+        }
+    }
+
+    fn set_sort(&self, column: &SharedString, window: &mut Window, app: &mut App) {
+        let sort = TableSort::cycle(self.state.read(app).sort.as_ref(), column);
+        self.state.update(app, |state, cx| {
+            if !self.controlled_sort {
+                state.sort = sort.clone();
+            }
+            cx.notify();
+        });
+        if let Some(on_sort_change) = &self.on_sort_change {
+            on_sort_change(sort.as_ref(), window, app);
+        }
+    }
+
+    fn set_selection(
+        &self,
+        selected: Vec<SharedString>,
+        anchor: Option<SharedString>,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        self.state.update(app, |state, cx| {
+            if !self.controlled_selection {
+                state.selected = selected.clone();
+            }
+            if anchor.is_some() {
+                state.anchor = anchor;
+            }
+            cx.notify();
+        });
+        if let Some(on_selection_change) = &self.on_selection_change {
+            on_selection_change(&selected, window, app);
+        }
+    }
+
+    fn select(&self, ix: usize, modifiers: &Modifiers, window: &mut Window, app: &mut App) {
+        let Some(mode) = self.selection_mode else {
+            return;
+        };
+        let state = self.state.read(app);
+        let (selected, anchor) = pick(
+            mode,
+            &state.selected,
+            state.anchor.as_ref(),
+            &self.keys[ix],
+            &self.keys,
+            modifiers,
+        );
+        self.set_selection(selected, Some(anchor), window, app);
+    }
+
+    fn toggle_selected(&self, ix: usize, window: &mut Window, app: &mut App) {
+        match self.selection_mode {
+            Some(SelectionMode::Multiple) => {
+                let key = &self.keys[ix];
+                let selected = toggle(&self.state.read(app).selected, key);
+                self.set_selection(selected, Some(key.clone()), window, app);
+            }
+            Some(SelectionMode::Single) => {
+                self.select(ix, &Modifiers::default(), window, app);
+            }
+            None => {}
+        }
+    }
+
+    /// Selects every row, or clears the selection if every row is already selected.
+    fn toggle_all(&self, window: &mut Window, app: &mut App) {
+        if self.selection_mode != Some(SelectionMode::Multiple) {
+            return;
+        }
+        let selected = match self.select_all_state(app) {
+            SelectAllState::All => Vec::new(),
+            _ => self.keys.to_vec(),
+        };
+        self.set_selection(selected, None, window, app);
+    }
+
+    fn start_resize(
+        &self,
+        column: &SharedString,
+        min_width: Pixels,
+        max_width: Option<Pixels>,
+        x: Pixels,
+        app: &mut App,
+    ) {
+        self.state.update(app, |state, cx| {
+            let start_width = state
+                .widths
+                .get(column)
+                .copied()
+                .or_else(|| {
+                    state
+                        .header_bounds
+                        .get(column)
+                        .map(|bounds| bounds.size.width)
+                })
+                .unwrap_or(min_width);
+            state.drag = Some(ColumnDrag::Resize {
+                column: column.clone(),
+                start_x: x,
+                start_width,
+                min_width,
+                max_width,
+            });
+            cx.notify();
+        });
+    }
+
+    fn start_reorder(&self, column: &SharedString, x: Pixels, app: &mut App) {
+        self.state.update(app, |state, cx| {
+            state.drag = Some(ColumnDrag::Reorder {
+                column: column.clone(),
+                start_x: x,
+                target: None,
+            });
+            state.suppress_click = false;
+            cx.notify();
+        });
+    }
+
+    fn drag_to(&self, x: Pixels, app: &mut App) {
+        self.state.update(app, |state, cx| {
+            match &mut state.drag {
+                Some(ColumnDrag::Resize {
+                    column,
+                    start_x,
+                    start_width,
+                    min_width,
+                    max_width,
+                }) => {
+                    let width = clamp_width(*start_width + x - *start_x, *min_width, *max_width);
+                    state.widths.insert(column.clone(), width);
+                }
+                Some(ColumnDrag::Reorder {
+                    start_x, target, ..
+                }) => {
+                    if target.is_none() && (x - *start_x).abs() < DRAG_THRESHOLD {
+                        return;
+                    }
+                    let bounds = self
+                        .columns
+                        .iter()
+                        .filter_map(|id| state.header_bounds.get(id).copied());
+                    *target = Some(drop_target(bounds, x, Axis::Horizontal));
+                }
+                None => return,
+            }
+            cx.notify();
+        });
+    }
+
+    fn end_drag(&self, window: &mut Window, app: &mut App) {
+        let Some(drag) = self.state.update(app, |state, cx| {
+            cx.notify();
+            state.drag.take()
+        }) else {
+            return;
+        };
+
+        match drag {
+            ColumnDrag::Resize { column, .. } => {
+                let width = self.state.read(app).widths.get(&column).copied();
+                if let Some(width) = width
+                    && let Some(on_column_resize) = &self.on_column_resize
+                {
+                    on_column_resize(&column, width, window, app);
+                }
+            }
+            ColumnDrag::Reorder {
+                column,
+                target: Some(target),
+                ..
+            } => {
+                let Some(from) = self.columns.iter().position(|id| *id == column) else {
+                    return;
+                };
+                let mut order = self.columns.to_vec();
+                let id = order.remove(from);
+                order.insert(moved_index(from, target), id);
+
+                self.state.update(app, |state, _| {
+                    state.suppress_click = true;
+                    if !self.controlled_column_order {
+                        state.column_order = order.clone();
+                    }
+                });
+                if order[..] != self.columns[..]
+                    && let Some(on_column_reorder) = &self.on_column_reorder
+                {
+                    on_column_reorder(&order, window, app);
+                }
+            }
+            ColumnDrag::Reorder { target: None, .. } => {}
+        }
+    }
+
+    /// Returns whether a header click ends a reorder and should be ignored.
+    fn take_suppressed_click(&self, app: &mut App) -> bool {
+        self.state
+            .update(app, |state, _| std::mem::take(&mut state.suppress_click))
+    }
+
+    /// Moves the cursor, extending the selection when Shift is held, and scrolls its row
+    /// into view.
+    fn move_to(&self, ix: usize, modifiers: &Modifiers, window: &mut Window, app: &mut App) {
+        self.set_active(ix, app);
+        scroll_into_view(self.row_target(ix), window, app);
+        if modifiers.shift && self.selection_mode == Some(SelectionMode::Multiple) {
+            self.select(ix, modifiers, window, app);
+        }
+    }
+
+    /// Handles a key press on the focused table, returning whether it was consumed.
+    fn key_down(&self, event: &KeyDownEvent, window: &mut Window, app: &mut App) -> bool {
+        if self.keys.is_empty() {
+            return false;
+        }
+
+        let modifiers = &event.keystroke.modifiers;
+        let active = self.active(app);
+        let last = self.keys.len() - 1;
+        match event.keystroke.key.as_str() {
+            "down" => {
+                let ix = selection::step(self.keys.len(), active, true, false, |_| true);
+                self.move_to(ix.unwrap_or(last), modifiers, window, app);
+            }
+            "up" => {
+                let ix = selection::step(self.keys.len(), active, false, false, |_| true);
+                self.move_to(ix.unwrap_or(0), modifiers, window, app);
+            }
+            "home" => self.move_to(0, modifiers, window, app),
+            "end" => self.move_to(last, modifiers, window, app),
+            "a" if modifiers.secondary()
+                && self.selection_mode == Some(SelectionMode::Multiple) =>
+            {
+                self.set_selection(self.keys.to_vec(), None, window, app);
+            }
+            "enter" if self.selection_mode.is_some() => {
+                let Some(ix) = active else {
+                    return false;
+                };
+                self.select(ix, modifiers, window, app);
+            }
+            "space" if self.selection_mode.is_some() => {
+                let Some(ix) = active else {
+                    return false;
+                };
+                self.toggle_selected(ix, window, app);
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Gives a cell its column's width, or its share of the remaining space.
+fn column_size<E: Styled>(mut cell: E, width: Option<Pixels>, flex: f32) -> E {
+    match width {
+        Some(width) => cell.flex_none().w(width),
+        None => {
+            let style = cell.style();
+            style.flex_grow = Some(flex);
+            style.flex_shrink = Some(1.);
+            style.flex_basis = Some(relative(0.).into());
+            cell.min_w_0()
+        }
+    }
+}
+
+impl<R: 'static> RenderOnce for Table<R> {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let default_selected = self.default_selected;
+        let default_sort = self.default_sort;
+        let state = window.use_keyed_state(self.id.clone(), app, |_, cx| TableState {
+            focus_handle: cx.focus_handle().tab_stop(true),
+            sort: default_sort,
+            selected: default_selected,
+            anchor: None,
+            active: None,
+            widths: HashMap::new(),
+            column_order: Vec::new(),
+            header_bounds: HashMap::new(),
+            drag: None,
+            suppress_click: false,
+            scroll_handle: ScrollHandle::new(),
+        });
+        let scroll_handle = state.read(app).scroll_handle.clone();
+        let (focus_handle, sort, selected, widths, column_order, drag) =
+            state.update(app, |state, _| {
+                if let Some(selected) = self.selected.clone() {
+                    state.selected = selected;
+                }
+                if let Some(sort) = self.sort.clone() {
+                    state.sort = sort;
+                }
+                if let Some(column_order) = self.column_order.clone() {
+                    state.column_order = column_order;
+                }
+                (
+                    state.focus_handle.clone(),
+                    state.sort.clone(),
+                    state.selected.clone(),
+                    state.widths.clone(),
+                    state.column_order.clone(),
+                    state.drag.clone(),
+                )
+            });
+
+        let mut columns: Vec<usize> = column_order
+            .iter()
+            .filter_map(|id| self.columns.iter().position(|column| column.id == *id))
+            .collect();
+        for ix in 0..self.columns.len() {
+            if !columns.contains(&ix) {
+                columns.push(ix);
+            }
+        }
+        let column_widths: Vec<Option<Pixels>> = self
+            .columns
+            .iter()
+            .map(|column| {
+                widths
+                    .get(&column.id)
+                    .copied()
+                    .or(column.width)
+                    .map(|width| clamp_width(width, column.min_width, column.max_width))
+            })
+            .collect();
+
+        let rows = self.rows;
+        let mut order: Vec<usize> = (0..rows.len()).collect();
+        if let Some(sort) = &sort
+            && let Some(compare) = self
+                .columns
+                .iter()
+                .find(|column| column.id == sort.column)
+                .and_then(|column| column.compare.as_ref())
+        {
+            order.sort_by(|a, b| {
+                let ordering = compare(&rows[*a], &rows[*b]);
+                match sort.direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+        let keys: Rc<[SharedString]> = order
+            .iter()
+            .map(|ix| match &self.row_key {
+                Some(row_key) => row_key(&rows[*ix]),
+                None => ix.to_string().into(),
+            })
+            .collect();
+
+        let controller = TableController {
+            id: self.id.clone(),
+            keys,
+            state: state.clone(),
+            selection_mode: self.selection_mode,
+            controlled_selection: self.selected.is_some(),
+            controlled_sort: self.sort.is_some(),
+            on_selection_change: self.on_selection_change,
+            on_sort_change: self.on_sort_change,
+            columns: columns
+                .iter()
+                .map(|ix| self.columns[*ix].id.clone())
+                .collect(),
+            controlled_column_order: self.column_order.is_some(),
+            on_column_resize: self.on_column_resize,
+            on_column_reorder: self.on_column_reorder,
+        };
+        let active = controller.active(app);
+        let select_all = controller.select_all_state(app);
+
+        let (resizing, dragging, drop_target) = match &drag {
+            Some(ColumnDrag::Resize { column, .. }) => (Some(column), None, None),
+            Some(ColumnDrag::Reorder { column, target, .. }) => {
+                let from = controller.columns.iter().position(|id| id == column);
+                // Dropping next to its current position wouldn't move the column.
+                let target = target.filter(|target| {
+                    from.is_some_and(|from| *target != from && *target != from + 1)
+                });
+                (None, Some(column), target)
+            }
+            None => (None, None, None),
+        };
+        let mut drop_indicator = self.drop_indicator;
+
+        let mut header_cells = Vec::with_capacity(columns.len());
+        for (position, column_ix) in columns.iter().copied().enumerate() {
+            let column = &self.columns[column_ix];
+            let context = TableHeaderContext {
+                column: column.id.clone(),
+                index: position,
+                sortable: column.compare.is_some(),
+                sort_direction: sort
+                    .as_ref()
+                    .filter(|sort| sort.column == column.id)
+                    .map(|sort| sort.direction),
+                select_all,
+                resizing: resizing == Some(&column.id),
+                dragging: dragging == Some(&column.id),
+            };
+            let content = match &column.header {
+                Some(header) => header(&context, window, app),
+                None => column.id.clone().into_any_element(),
+            };
+
+            let resize_handle = column.resizable.then(|| {
+                let handle = div()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .right_0()
+                    .w(RESIZE_HANDLE_WIDTH)
+                    .cursor_col_resize();
+                let handle = match &self.resize_handle {
+                    Some(style) => style(handle, &context),
+                    None => handle,
+                };
+                let controller = controller.clone();
+                let column_id = column.id.clone();
+                let (min_width, max_width) = (column.min_width, column.max_width);
+                handle.on_mouse_down(MouseButton::Left, move |event, _, app| {
+                    // Keep the header from starting a click or a reorder.
+                    app.stop_propagation();
+                    controller.start_resize(
+                        &column_id,
+                        min_width,
+                        max_width,
+                        event.position.x,
+                        app,
+                    );
+                })
+            });
+
+            let indicator = if drop_target == Some(position) {
+                Some(div().left(px(-1.)))
+            } else if drop_target == Some(columns.len()) && position + 1 == columns.len() {
+                Some(div().right(px(-1.)))
+            } else {
+                None
+            }
+            .map(|indicator| {
+                let indicator = indicator.absolute().top_0().bottom_0().w(px(2.));
+                match drop_indicator.take() {
+                    Some(style) => style(indicator),
+                    None => indicator,
+                }
+            });
+
+            header_cells.push(
+                div()
+                    .id(("table-header", column_ix))
+                    .relative()
+                    .map(|this| column_size(this, column_widths[column_ix], column.flex))
+                    .when(self.reorderable_columns, |this| {
+                        let controller = controller.clone();
+                        let column = column.id.clone();
+                        this.on_mouse_down(MouseButton::Left, move |event, _, app| {
+                            controller.start_reorder(&column, event.position.x, app);
+                        })
+                    })
+                    .when(context.sortable || column.selection, |this| {
+                        let controller = controller.clone();
+                        let (sortable, selection) = (context.sortable, column.selection);
+                        let column = column.id.clone();
+                        this.on_click(move |_, window, app| {
+                            if controller.take_suppressed_click(app) {
+                                return;
+                            }
+                            if sortable {
+                                controller.set_sort(&column, window, app);
+                            }
+                            if selection {
+                                controller.toggle_all(window, app);
+                            }
+                        })
+                    })
+                    .child(content)
+                    .child(
+                        canvas(
+                            {
+                                let state = state.clone();
+                                let column = column.id.clone();
+                                move |bounds, _, app| {
+                                    state.update(app, |state, _| {
+                                        state.header_bounds.insert(column, bounds);
+                                    })
+                                }
+                            },
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full(),
+                    )
+                    .children(resize_handle)
+                    .children(indicator),
+            );
+        }
+
+        let mut body_rows = Vec::with_capacity(order.len());
+        for (ix, row_ix) in order.iter().enumerate() {
+            let row = &rows[*row_ix];
+            let key = controller.keys[ix].clone();
+            let context = TableRowContext {
+                selected: selected.contains(&key),
+                active: active == Some(ix),
+                key,
+                index: ix,
+            };
+
+            let mut cells = Vec::with_capacity(columns.len());
+            for column_ix in columns.iter().copied() {
+                let column = &self.columns[column_ix];
+                let cell_context = TableCellContext {
+                    column: column.id.clone(),
+                    row: context.key.clone(),
+                    index: ix,
+                    selected: context.selected,
+                    active: context.active,
+                };
+                let content = column
+                    .cell
+                    .as_ref()
+                    .map(|cell| cell(row, &cell_context, window, app));
+                cells.push(
+                    div()
+                        .id(("table-cell", column_ix))
+                        .map(|this| column_size(this, column_widths[column_ix], column.flex))
+                        .when(column.selection, |this| {
+                            let controller = controller.clone();
+                            let focus_handle = focus_handle.clone();
+                            this.on_click(move |_, window, app| {
+                                window.focus(&focus_handle);
+                                controller.set_active(ix, app);
+                                controller.toggle_selected(ix, window, app);
+                                app.stop_propagation();
+                            })
+                        })
+                        .children(content),
+                );
+            }
+
+            let row = h_flex().w_full();
+            let row = match &self.row {
+                Some(style) => style(row, &context),
+                None => row,
+            };
+            body_rows.push(
+                row.id(("table-row", ix))
+                    .map(|this| {
+                        let controller = controller.clone();
+                        let focus_handle = focus_handle.clone();
+                        this.on_click(move |event, window, app| {
+                            window.focus(&focus_handle);
+                            controller.set_active(ix, app);
+                            controller.select(ix, &event.modifiers(), window, app);
+                        })
+                    })
+                    .relative()
+                    .children(cells)
+                    .child(scroll_target(controller.row_target(ix))),
+            );
+        }
+
+        let header_row = h_flex().w_full();
+        let header_row = match self.header_row {
+            Some(style) => style(header_row),
+            None => header_row,
+        };
+        let header_row =
+            header_row
+                .relative()
+                .children(header_cells)
+                .when(drag.is_some(), |this| {
+                    // Follow the pointer anywhere in the window until the button is released.
+                    let controller = controller.clone();
+                    this.child(
+                        canvas(
+                            |_, _, _| {},
+                            move |_, _, window, _| {
+                                window.on_mouse_event({
+                                    let controller = controller.clone();
+                                    move |event: &MouseMoveEvent, phase, _, app| {
+                                        if phase.bubble() {
+                                            controller.drag_to(event.position.x, app);
+                                        }
+                                    }
+                                });
+                                window.on_mouse_event(
+                                    move |event: &MouseUpEvent, phase, window, app| {
+                                        if phase.bubble() && event.button == MouseButton::Left {
+                                            controller.end_drag(window, app);
+                                        }
+                                    },
+                                );
+                            },
+                        )
+                        .absolute()
+                        .size_full(),
+                    )
+                });
+
+        self.base
+            .id(self.id)
+            .track_focus(&focus_handle)
+            .track_scroll(&scroll_handle)
+            .on_key_down(move |event, window, app| {
+                if controller.key_down(event, window, app) {
+                    app.stop_propagation();
+                }
+            })
+            .child(header_row)
+            .children(body_rows)
+            .child(scroll_container(&scroll_handle))
+    }
+}
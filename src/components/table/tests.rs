@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod sort {
+    use crate::components::table::{SortDirection, TableSort};
+    use gpui::SharedString;
+
+    fn cycle(sort: Option<TableSort>, column: &'static str) -> Option<TableSort> {
+        TableSort::cycle(sort.as_ref(), &SharedString::from(column))
+    }
+
+    #[test]
+    fn builds_sorts_in_either_direction() {
+        assert_eq!(
+            TableSort::ascending("name").direction,
+            SortDirection::Ascending
+        );
+        assert_eq!(
+            TableSort::descending("name").direction,
+            SortDirection::Descending
+        );
+        assert_eq!(TableSort::ascending("name").column, "name");
+    }
+
+    #[test]
+    fn cycles_ascending_descending_then_unsorted() {
+        let sort = cycle(None, "name");
+        assert_eq!(sort, Some(TableSort::ascending("name")));
+        let sort = cycle(sort, "name");
+        assert_eq!(sort, Some(TableSort::descending("name")));
+        assert_eq!(cycle(sort, "name"), None);
+    }
+
+    #[test]
+    fn sorts_another_column_ascending() {
+        assert_eq!(
+            cycle(Some(TableSort::ascending("name")), "size"),
+            Some(TableSort::ascending("size"))
+        );
+        assert_eq!(
+            cycle(Some(TableSort::descending("name")), "size"),
+            Some(TableSort::ascending("size"))
+        );
+    }
+}
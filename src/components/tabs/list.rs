@@ -1,4 +1,10 @@
-use crate::{Selectable, components::tabs::TabsTrigger, primitives::h_flex};
+use crate::{
+    AccessibilityProps, Accessible, Direction, Role, Selectable,
+    components::tabs::{TabsTrigger, trigger_target},
+    is_in_disabled_scope,
+    primitives::h_flex,
+    scroll_into_view, scroll_target,
+};
 use gpui::{prelude::FluentBuilder, *};
 use smallvec::SmallVec;
 use std::rc::Rc;
@@ -6,18 +12,39 @@ use std::rc::Rc;
 #[derive(IntoElement)]
 pub struct TabsList {
     base: Div,
+    tabs_id: ElementId,
     triggers: SmallVec<[TabsTrigger; 1]>,
     pub(super) selected_index: Option<usize>,
     pub(super) on_change: Option<Rc<dyn Fn(&usize, &mut Window, &mut App)>>,
+    pub(super) activation: TabsActivation,
+    accessibility: AccessibilityProps,
+}
+
+/// Whether moving focus across the triggers with the arrow keys also selects their tab.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TabsActivation {
+    /// The focused tab is selected right away.
+    #[default]
+    Automatic,
+    /// The focused tab is selected with Enter or Space, for tabs that are slow to render.
+    Manual,
+}
+
+/// The focus handles of the triggers, kept across renders of a [`TabsList`].
+struct TabsListState {
+    focus_handles: Vec<FocusHandle>,
 }
 
 impl TabsList {
-    pub(super) fn new() -> Self {
+    pub(super) fn new(tabs_id: ElementId) -> Self {
         Self {
             base: h_flex(),
+            tabs_id,
             triggers: SmallVec::new(),
             selected_index: None,
             on_change: None,
+            activation: TabsActivation::default(),
+            accessibility: AccessibilityProps::new(Role::TabList),
         }
     }
 
@@ -32,25 +59,154 @@ impl TabsList {
     }
 }
 
+impl Accessible for TabsList {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
 impl Styled for TabsList {
     fn style(&mut self) -> &mut StyleRefinement {
         self.base.style()
     }
 }
 
+/// Returns the enabled tab the Left or Right arrow `key` selects from `from`, wrapping
+/// around. The keys follow reading order, so Left moves to the next tab in RTL.
+pub(super) fn adjacent_tab(
+    key: &str,
+    direction: Direction,
+    from: usize,
+    disabled: &[bool],
+) -> Option<usize> {
+    let forward = direction.arrow_forward(key)?;
+    let len = disabled.len();
+    if from >= len {
+        return None;
+    }
+    (1..len)
+        .map(|step| {
+            if forward {
+                (from + step) % len
+            } else {
+                (from + len - step) % len
+            }
+        })
+        .find(|ix| !disabled[*ix])
+}
+
+/// Returns the first or last enabled tab, for the Home and End keys.
+pub(super) fn edge_tab(key: &str, disabled: &[bool]) -> Option<usize> {
+    let enabled = |ix: &usize| !disabled[*ix];
+    match key {
+        "home" => (0..disabled.len()).find(enabled),
+        "end" => (0..disabled.len()).rev().find(enabled),
+        _ => None,
+    }
+}
+
 impl RenderOnce for TabsList {
-    fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let disabled: Rc<[bool]> = self
+            .triggers
+            .iter()
+            .map(|trigger| trigger.disabled || is_in_disabled_scope(app))
+            .collect();
+        let tabs_id = self.tabs_id.clone();
+        let activation = self.activation;
+
+        let state = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.tabs_id.clone()), "list".into()),
+            app,
+            |_, _| TabsListState {
+                focus_handles: Vec::new(),
+            },
+        );
+        // The focused trigger is the tab stop, or the selected one while none is.
+        let focus_handles: Rc<[FocusHandle]> = state.update(app, |state, cx| {
+            state
+                .focus_handles
+                .resize_with(disabled.len(), || cx.focus_handle());
+            let focused = state
+                .focus_handles
+                .iter()
+                .position(|handle| handle.is_focused(window));
+            let tab_stop = focused
+                .or(self.selected_index)
+                .filter(|ix| !disabled.get(*ix).copied().unwrap_or(true))
+                .or_else(|| disabled.iter().position(|disabled| !disabled));
+            for (ix, handle) in state.focus_handles.iter_mut().enumerate() {
+                let is_tab_stop = tab_stop == Some(ix);
+                if handle.tab_stop != is_tab_stop {
+                    *handle = handle.clone().tab_stop(is_tab_stop);
+                }
+            }
+            state.focus_handles.iter().cloned().collect()
+        });
+
         self.base
             .id("tabs-list")
-            .children(self.triggers.into_iter().enumerate().map(|(ix, trigger)| {
-                trigger
-                    .id(ix)
-                    .when_some(self.selected_index, |this, selected_ix| {
-                        this.selected(selected_ix == ix)
-                    })
-                    .when_some(self.on_change.clone(), move |this, on_click| {
-                        this.on_click(move |_, window, cx| on_click(&ix, window, cx))
-                    })
-            }))
+            .map(|this| {
+                let on_change = self.on_change.clone();
+                let selected = self.selected_index;
+                let focus_handles = focus_handles.clone();
+                this.on_key_down(move |event, window, app| {
+                    let key = event.keystroke.key.as_str();
+                    let focused = focus_handles
+                        .iter()
+                        .position(|handle| handle.is_focused(window));
+                    if let Some(focused) = focused
+                        && matches!(key, "enter" | "space")
+                    {
+                        if let Some(on_change) = &on_change {
+                            on_change(&focused, window, app);
+                        }
+                        app.stop_propagation();
+                        return;
+                    }
+
+                    let Some(from) = focused.or(selected) else {
+                        return;
+                    };
+                    let Some(ix) = adjacent_tab(key, Direction::current(app), from, &disabled)
+                        .or_else(|| edge_tab(key, &disabled))
+                    else {
+                        return;
+                    };
+                    if focused.is_some() {
+                        window.focus(&focus_handles[ix]);
+                    }
+                    // In manual activation, moving focus doesn't select the tab.
+                    if focused.is_none() || activation == TabsActivation::Automatic {
+                        if let Some(on_change) = &on_change {
+                            on_change(&ix, window, app);
+                        }
+                    }
+                    scroll_into_view(trigger_target(&tabs_id, ix), window, app);
+                    app.stop_propagation();
+                })
+            })
+            .children(
+                self.triggers
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, mut trigger)| {
+                        trigger.focus_handle = Some(focus_handles[ix].clone());
+                        trigger
+                            .id(ix)
+                            .relative()
+                            .child(scroll_target(trigger_target(&self.tabs_id, ix)))
+                            .when_some(self.selected_index, |this, selected_ix| {
+                                this.selected(selected_ix == ix)
+                            })
+                            .when_some(self.on_change.clone(), move |this, on_click| {
+                                this.on_click(move |_, window, cx| on_click(&ix, window, cx))
+                            })
+                    }),
+            )
     }
 }
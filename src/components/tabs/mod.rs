@@ -1,3 +1,4 @@
+use crate::{TabSelected, publish_event, scroll_into_view};
 use gpui::*;
 use std::rc::Rc;
 
@@ -7,12 +8,19 @@ mod trigger;
 pub use list::*;
 pub use trigger::*;
 
+#[cfg(test)]
+mod tests;
+
+/// Called with the index of the newly selected tab.
+type OnChange = Rc<dyn Fn(&usize, &mut Window, &mut App)>;
+
 #[derive(Action, Debug, Clone, Copy, PartialEq, Eq)]
 #[action(namespace = tab_bar, no_json)]
 pub struct SelectTab(usize);
 
 #[derive(IntoElement)]
 pub struct Tabs {
+    id: ElementId,
     base: Stateful<Div>,
     list: TabsList,
     on_change: Option<Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
@@ -21,9 +29,11 @@ pub struct Tabs {
 
 impl Tabs {
     pub fn new(id: impl Into<ElementId>) -> Self {
+        let id = id.into();
         Self {
-            base: div().id(id),
-            list: TabsList::new(),
+            id: id.clone(),
+            base: div().id(id.clone()),
+            list: TabsList::new(id),
             on_change: None,
             value: None,
         }
@@ -35,6 +45,12 @@ impl Tabs {
         self
     }
 
+    /// Whether the arrow keys select the tab they move focus to, or only focus it.
+    pub fn activation(mut self, activation: TabsActivation) -> Self {
+        self.list.activation = activation;
+        self
+    }
+
     pub fn value(mut self, value: usize) -> Self {
         self.value = Some(value);
         self
@@ -50,6 +66,11 @@ impl Tabs {
     }
 }
 
+/// Identifies the trigger of the tab at `ix` to [`scroll_into_view`].
+fn trigger_target(tabs_id: &ElementId, ix: usize) -> ElementId {
+    ElementId::NamedChild(Box::new(tabs_id.clone()), format!("trigger-{ix}").into())
+}
+
 impl Styled for Tabs {
     fn style(&mut self) -> &mut StyleRefinement {
         self.base.style()
@@ -57,16 +78,31 @@ impl Styled for Tabs {
 }
 
 impl RenderOnce for Tabs {
-    fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
-        self.base
-            .on_action({
-                let on_click = self.on_change;
-                move |action: &SelectTab, window, app| {
-                    if let Some(on_click) = on_click.clone() {
-                        on_click(&action.0, window, app);
-                    }
+    fn render(mut self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let tabs_id = self.id.clone();
+        let on_change: OnChange = {
+            let id = self.id;
+            let on_change = self.on_change;
+            Rc::new(move |index, window, app| {
+                publish_event(
+                    TabSelected {
+                        id: id.clone(),
+                        index: *index,
+                    },
+                    app,
+                );
+                if let Some(on_change) = on_change.as_ref() {
+                    on_change(index, window, app);
                 }
             })
+        };
+        self.list.on_change = Some(on_change.clone());
+
+        self.base
+            .on_action(move |action: &SelectTab, window, app| {
+                on_change(&action.0, window, app);
+                scroll_into_view(trigger_target(&tabs_id, action.0), window, app);
+            })
             .child(self.list)
     }
 }
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod arrow_navigation {
+    use crate::{
+        Direction,
+        components::tabs::list::{adjacent_tab, edge_tab},
+    };
+
+    #[test]
+    fn ltr_right_selects_the_next_tab() {
+        let disabled = [false; 3];
+        assert_eq!(adjacent_tab("right", Direction::Ltr, 0, &disabled), Some(1));
+        assert_eq!(adjacent_tab("left", Direction::Ltr, 1, &disabled), Some(0));
+    }
+
+    #[test]
+    fn rtl_left_selects_the_next_tab() {
+        let disabled = [false; 3];
+        assert_eq!(adjacent_tab("left", Direction::Rtl, 0, &disabled), Some(1));
+        assert_eq!(adjacent_tab("right", Direction::Rtl, 1, &disabled), Some(0));
+    }
+
+    #[test]
+    fn wraps_around_in_both_directions() {
+        let disabled = [false; 3];
+        assert_eq!(adjacent_tab("right", Direction::Ltr, 2, &disabled), Some(0));
+        assert_eq!(adjacent_tab("right", Direction::Rtl, 0, &disabled), Some(2));
+    }
+
+    #[test]
+    fn skips_disabled_tabs() {
+        let disabled = [false, true, false];
+        assert_eq!(adjacent_tab("right", Direction::Ltr, 0, &disabled), Some(2));
+        assert_eq!(adjacent_tab("left", Direction::Rtl, 0, &disabled), Some(2));
+        assert_eq!(
+            adjacent_tab("right", Direction::Ltr, 0, &[false, true]),
+            None
+        );
+    }
+
+    #[test]
+    fn ignores_other_keys() {
+        assert_eq!(adjacent_tab("down", Direction::Ltr, 0, &[false; 3]), None);
+    }
+
+    #[test]
+    fn home_and_end_select_the_outer_enabled_tabs() {
+        let disabled = [true, false, false, true];
+        assert_eq!(edge_tab("home", &disabled), Some(1));
+        assert_eq!(edge_tab("end", &disabled), Some(2));
+        assert_eq!(edge_tab("right", &disabled), None);
+    }
+}
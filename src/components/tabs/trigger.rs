@@ -1,4 +1,8 @@
-use crate::{Disableable, Selectable};
+use crate::{
+    AccessibilityProps, Accessible, Disableable, FocusableStyled, InteractiveStateHandlers,
+    InteractiveStateStyled, Role, Selectable, apply_interactive_state, is_focus_visible,
+    is_in_disabled_scope, track_interaction,
+};
 use gpui::{prelude::FluentBuilder, *};
 use smallvec::SmallVec;
 use std::rc::Rc;
@@ -11,7 +15,12 @@ pub struct TabsTrigger {
     pub(super) disabled: bool,
     pub(super) selected: bool,
     on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    pub(super) focus_handle: Option<FocusHandle>,
     when_selected_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    accessibility: AccessibilityProps,
+    when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_focus_visible_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    interactive_state: InteractiveStateHandlers<Self>,
 }
 
 impl TabsTrigger {
@@ -22,8 +31,13 @@ impl TabsTrigger {
             disabled: false,
             selected: false,
             on_click: None,
+            focus_handle: None,
             id: 0.into(),
             when_selected_handler: None,
+            accessibility: AccessibilityProps::new(Role::Tab),
+            when_disabled_handler: None,
+            when_focus_visible_handler: None,
+            interactive_state: InteractiveStateHandlers::default(),
         }
     }
 
@@ -46,6 +60,13 @@ impl TabsTrigger {
     }
 }
 
+impl FocusableStyled for TabsTrigger {
+    fn when_focus_visible(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_focus_visible_handler = Some(Box::new(handler));
+        self
+    }
+}
+
 impl ParentElement for TabsTrigger {
     fn extend(&mut self, children: impl IntoIterator<Item = AnyElement>) {
         self.children.extend(children);
@@ -58,6 +79,16 @@ impl Styled for TabsTrigger {
     }
 }
 
+impl Accessible for TabsTrigger {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
 impl Disableable for TabsTrigger {
     fn is_disabled(&self) -> bool {
         self.disabled
@@ -67,6 +98,11 @@ impl Disableable for TabsTrigger {
         self.disabled = disabled;
         self
     }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
 }
 
 impl Selectable for TabsTrigger {
@@ -84,6 +120,12 @@ impl Selectable for TabsTrigger {
     }
 }
 
+impl InteractiveStateStyled for TabsTrigger {
+    fn interactive_state_handlers_mut(&mut self) -> &mut InteractiveStateHandlers<Self> {
+        &mut self.interactive_state
+    }
+}
+
 impl InteractiveElement for TabsTrigger {
     fn interactivity(&mut self) -> &mut gpui::Interactivity {
         self.base.interactivity()
@@ -93,17 +135,49 @@ impl InteractiveElement for TabsTrigger {
 impl StatefulInteractiveElement for TabsTrigger {}
 
 impl RenderOnce for TabsTrigger {
-    fn render(mut self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        if is_in_disabled_scope(app) {
+            self.disabled = true;
+        }
+        if self.disabled {
+            if let Some(handler) = self.when_disabled_handler.take() {
+                self = handler(self);
+            }
+        }
+
         if self.selected {
             if let Some(handler) = self.when_selected_handler.take() {
                 self = handler(self);
             }
         }
 
-        self.base
-            .id(self.id)
+        let focused = self
+            .focus_handle
+            .as_ref()
+            .is_some_and(|focus_handle| focus_handle.is_focused(window));
+        if focused && is_focus_visible(app) {
+            if let Some(handler) = self.when_focus_visible_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        let (this, interaction) = apply_interactive_state(
+            self.id.clone(),
+            self.disabled,
+            false,
+            focused,
+            self,
+            window,
+            app,
+        );
+        self = this;
+
+        track_interaction(self.base.id(self.id), interaction)
             .when(!self.disabled, |this| {
-                this.when_some(self.on_click, |this, on_click| {
+                this.when_some(self.focus_handle, |this, focus_handle| {
+                    this.track_focus(&focus_handle)
+                })
+                .when_some(self.on_click, |this, on_click| {
                     this.on_click(move |event, window, cx| on_click(event, window, cx))
                 })
             })
@@ -0,0 +1,396 @@
+use crate::primitives::{
+    h_flex,
+    text_field::{
+        TextField,
+        actions::{Backspace, Enter, Paste, Redo, Undo},
+        text_field,
+    },
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+
+/// What a tag of a [`TagInput`] is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagContext {
+    pub value: SharedString,
+    pub index: usize,
+}
+
+/// A text field collecting its entries as a list of tags, shown before the field.
+///
+/// Enter turns the typed text into tags, and Backspace in an empty field removes the last
+/// one. Pasting text the [`TagInput::splitter`] breaks into several tokens, by default on
+/// commas and newlines, adds them all at once. Tokens failing the [`TagInput::validator`]
+/// are left in the field to be corrected, and duplicates are dropped.
+///
+/// Each of these edits calls [`TagInput::on_change`] once and is undone as a whole with
+/// the undo shortcut, until the text in the field is edited again.
+///
+/// # Examples
+///
+/// ```rust
+/// TagInput::new("recipients")
+///     .placeholder("Add emails")
+///     .tags(self.recipients.clone())
+///     .validator(|token| token.contains('@'))
+///     .on_change(cx.listener(|this, tags: &[SharedString], _, cx| {
+///         this.recipients = tags.to_vec();
+///         cx.notify();
+///     }))
+///     .render_tag(|tag, _, _| div().px_1().rounded_sm().bg(rgb(0xe5e7eb)).child(tag.value.clone()))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct TagInput {
+    id: ElementId,
+    base: Div,
+    tags: Option<Vec<SharedString>>,
+    default_tags: Vec<SharedString>,
+    placeholder: Option<SharedString>,
+    splitter: Rc<dyn Fn(&str) -> Vec<SharedString>>,
+    validator: Option<Rc<dyn Fn(&str) -> bool>>,
+    on_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+    render_tag: Option<Box<dyn Fn(&TagContext, &mut Window, &mut App) -> AnyElement>>,
+    field: Option<Box<dyn FnOnce(TextField) -> TextField>>,
+}
+
+/// The tags and the text being typed, kept across renders of a [`TagInput`].
+struct TagInputState {
+    tags: Vec<SharedString>,
+    draft: SharedString,
+    /// The tags and text before each edit, most recent last.
+    undo_stack: Vec<(Vec<SharedString>, SharedString)>,
+    redo_stack: Vec<(Vec<SharedString>, SharedString)>,
+    /// Whether the text was typed into since the last edit, which then undoes first.
+    draft_edited: bool,
+}
+
+impl TagInput {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: h_flex().flex_wrap().items_center(),
+            tags: None,
+            default_tags: Vec::new(),
+            placeholder: None,
+            splitter: Rc::new(split_tags),
+            validator: None,
+            on_change: None,
+            render_tag: None,
+            field: None,
+        }
+    }
+
+    /// Controls the tags.
+    pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.tags = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// The tags on first render when the tags are not controlled.
+    pub fn default_tags(mut self, tags: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.default_tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Breaks entered or pasted text into tokens, splitting on commas and newlines and
+    /// trimming whitespace by default.
+    pub fn splitter(mut self, splitter: impl Fn(&str) -> Vec<SharedString> + 'static) -> Self {
+        self.splitter = Rc::new(splitter);
+        self
+    }
+
+    /// Accepts the tokens that become tags.
+    pub fn validator(mut self, validator: impl Fn(&str) -> bool + 'static) -> Self {
+        self.validator = Some(Rc::new(validator));
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Renders each tag, the value is rendered when this isn't set.
+    pub fn render_tag<E: IntoElement>(
+        mut self,
+        render: impl Fn(&TagContext, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.render_tag = Some(Box::new(move |tag, window, app| {
+            render(tag, window, app).into_any_element()
+        }));
+        self
+    }
+
+    /// Customizes the text field the tags are typed in.
+    pub fn field(mut self, field: impl FnOnce(TextField) -> TextField + 'static) -> Self {
+        self.field = Some(Box::new(field));
+        self
+    }
+}
+
+impl Styled for TagInput {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Splits text on commas and newlines, trimming each token and dropping empty ones.
+pub(super) fn split_tags(text: &str) -> Vec<SharedString> {
+    text.split([',', '\n', '\r'])
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| SharedString::from(token.to_string()))
+        .collect()
+}
+
+/// Sorts tokens into the ones added after `tags` and the ones failing validation, dropping
+/// duplicates.
+pub(super) fn accept_tokens(
+    tags: &[SharedString],
+    tokens: Vec<SharedString>,
+    validator: Option<&dyn Fn(&str) -> bool>,
+) -> (Vec<SharedString>, Vec<SharedString>) {
+    let mut accepted: Vec<SharedString> = Vec::new();
+    let mut rejected = Vec::new();
+    for token in tokens {
+        if tags.contains(&token) || accepted.contains(&token) {
+            continue;
+        }
+        if validator.is_none_or(|validator| validator(&token)) {
+            accepted.push(token);
+        } else {
+            rejected.push(token);
+        }
+    }
+    (accepted, rejected)
+}
+
+/// The options of a rendered tag input together with its state, shared by its listeners.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct TagInputController {
+    state: Entity<TagInputState>,
+    controlled: bool,
+    splitter: Rc<dyn Fn(&str) -> Vec<SharedString>>,
+    validator: Option<Rc<dyn Fn(&str) -> bool>>,
+    on_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+}
+
+impl TagInputController {
+    /// Replaces the tags and text as one undoable edit.
+    fn apply(
+        &self,
+        tags: Vec<SharedString>,
+        draft: SharedString,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        let changed = self.state.update(app, |state, cx| {
+            let changed = state.tags != tags;
+            let previous = (state.tags.clone(), state.draft.clone());
+            state.undo_stack.push(previous);
+            state.redo_stack.clear();
+            state.draft_edited = false;
+            state.draft = draft;
+            if !self.controlled {
+                state.tags = tags.clone();
+            }
+            cx.notify();
+            changed
+        });
+        if changed {
+            self.emit_change(&tags, window, app);
+        }
+    }
+
+    fn emit_change(&self, tags: &[SharedString], window: &mut Window, app: &mut App) {
+        if let Some(on_change) = &self.on_change {
+            on_change(tags, window, app);
+        }
+    }
+
+    /// Adds the valid tokens as tags, leaving the rest in the field after `draft`.
+    fn add_tokens(
+        &self,
+        tokens: Vec<SharedString>,
+        draft: &str,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        let tags = self.state.read(app).tags.clone();
+        let (accepted, rejected) = accept_tokens(&tags, tokens, self.validator.as_deref());
+        let draft: SharedString = Some(draft)
+            .filter(|draft| !draft.is_empty())
+            .into_iter()
+            .chain(rejected.iter().map(|token| token.as_ref()))
+            .collect::<Vec<_>>()
+            .join(", ")
+            .into();
+        let tags = tags.into_iter().chain(accepted).collect();
+        self.apply(tags, draft, window, app);
+    }
+
+    /// Adds the pasted tokens at once, or returns false to paste the text in the field
+    /// when it holds a single token.
+    fn paste(&self, window: &mut Window, app: &mut App) -> bool {
+        let Some(text) = app.read_from_clipboard().and_then(|item| item.text()) else {
+            return false;
+        };
+        let tokens = (self.splitter)(&text);
+        if tokens.len() == 1 && tokens[0] == text.trim() {
+            return false;
+        }
+
+        let draft = self.state.read(app).draft.clone();
+        self.add_tokens(tokens, &draft, window, app);
+        true
+    }
+
+    fn commit_draft(&self, window: &mut Window, app: &mut App) -> bool {
+        let draft = self.state.read(app).draft.clone();
+        if draft.trim().is_empty() {
+            return false;
+        }
+        self.add_tokens((self.splitter)(&draft), "", window, app);
+        true
+    }
+
+    fn remove_last(&self, window: &mut Window, app: &mut App) -> bool {
+        let state = self.state.read(app);
+        if !state.draft.is_empty() || state.tags.is_empty() {
+            return false;
+        }
+        let mut tags = state.tags.clone();
+        tags.pop();
+        self.apply(tags, SharedString::default(), window, app);
+        true
+    }
+
+    /// Undoes or redoes the last edit, unless the text was typed into since.
+    fn restore(&self, undo: bool, window: &mut Window, app: &mut App) -> bool {
+        let restored = self.state.update(app, |state, cx| {
+            if state.draft_edited {
+                return None;
+            }
+            let (tags, draft) = if undo {
+                state.undo_stack.pop()?
+            } else {
+                state.redo_stack.pop()?
+            };
+            let current = (state.tags.clone(), state.draft.clone());
+            if undo {
+                state.redo_stack.push(current);
+            } else {
+                state.undo_stack.push(current);
+            }
+            let changed = state.tags != tags;
+            state.draft = draft;
+            if !self.controlled {
+                state.tags = tags.clone();
+            }
+            cx.notify();
+            Some(changed.then_some(tags))
+        });
+        match restored {
+            Some(changed) => {
+                if let Some(tags) = changed {
+                    self.emit_change(&tags, window, app);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl RenderOnce for TagInput {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let default_tags = self.default_tags;
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| TagInputState {
+            tags: default_tags,
+            draft: SharedString::default(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            draft_edited: false,
+        });
+        let (tags, draft) = state.update(app, |state, _| {
+            if let Some(tags) = self.tags.clone() {
+                state.tags = tags;
+            }
+            (state.tags.clone(), state.draft.clone())
+        });
+
+        let controller = TagInputController {
+            state: state.clone(),
+            controlled: self.tags.is_some(),
+            splitter: self.splitter,
+            validator: self.validator,
+            on_change: self.on_change,
+        };
+
+        let mut rendered = Vec::with_capacity(tags.len());
+        for (ix, value) in tags.into_iter().enumerate() {
+            let context = TagContext { value, index: ix };
+            rendered.push(match &self.render_tag {
+                Some(render_tag) => render_tag(&context, window, app),
+                None => context.value.into_any_element(),
+            });
+        }
+
+        let field = text_field(ElementId::NamedChild(Box::new(self.id), "field".into()))
+            .flex_1()
+            .value(draft)
+            .when_some(self.placeholder, |this, placeholder| {
+                this.placeholder(placeholder)
+            })
+            .on_input({
+                let state = state.clone();
+                move |event, _, app| {
+                    state.update(app, |state, _| {
+                        state.draft = event.value.clone();
+                        state.draft_edited = true;
+                    });
+                }
+            });
+        let field = match self.field {
+            Some(customize) => customize(field),
+            None => field,
+        };
+
+        // The field is inside, so these run before it handles the same actions.
+        let on_action = |handle: fn(&TagInputController, &mut Window, &mut App) -> bool| {
+            let controller = controller.clone();
+            move |window: &mut Window, app: &mut App| {
+                if handle(&controller, window, app) {
+                    app.stop_propagation();
+                }
+            }
+        };
+        let paste = on_action(TagInputController::paste);
+        let enter = on_action(TagInputController::commit_draft);
+        let backspace = on_action(TagInputController::remove_last);
+        let undo = on_action(|controller, window, app| controller.restore(true, window, app));
+        let redo = on_action(|controller, window, app| controller.restore(false, window, app));
+
+        self.base
+            .capture_action(move |_: &Paste, window, app| paste(window, app))
+            .capture_action(move |_: &Enter, window, app| enter(window, app))
+            .capture_action(move |_: &Backspace, window, app| backspace(window, app))
+            .capture_action(move |_: &Undo, window, app| undo(window, app))
+            .capture_action(move |_: &Redo, window, app| redo(window, app))
+            .children(rendered)
+            .child(field)
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod pasted_tokens {
+    use crate::components::tag_input::{accept_tokens, split_tags};
+    use gpui::SharedString;
+
+    fn tokens(tokens: &[&'static str]) -> Vec<SharedString> {
+        tokens
+            .iter()
+            .map(|token| SharedString::from(*token))
+            .collect()
+    }
+
+    #[test]
+    fn splits_on_commas_and_newlines() {
+        assert_eq!(
+            split_tags("rust, gpui\nui,\r\n, lapis "),
+            tokens(&["rust", "gpui", "ui", "lapis"])
+        );
+    }
+
+    #[test]
+    fn drops_duplicates() {
+        let (accepted, rejected) =
+            accept_tokens(&tokens(&["rust"]), tokens(&["rust", "ui", "ui"]), None);
+        assert_eq!(accepted, tokens(&["ui"]));
+        assert!(rejected.is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_tokens() {
+        let validator = |token: &str| token.contains('@');
+        let (accepted, rejected) = accept_tokens(
+            &[],
+            tokens(&["a@example.com", "nobody", "b@example.com"]),
+            Some(&validator),
+        );
+        assert_eq!(accepted, tokens(&["a@example.com", "b@example.com"]));
+        assert_eq!(rejected, tokens(&["nobody"]));
+    }
+}
@@ -0,0 +1,388 @@
+use crate::{
+    Layer, MotionPreference, Overlay, OverlayId, Theme, WindowInfo, close_overlay, open_overlay,
+    primitives::{button, h_flex, v_flex},
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::{collections::HashMap, rc::Rc, time::Duration};
+
+const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+const ENTER_DURATION: Duration = Duration::from_millis(150);
+const TOAST_WIDTH: Pixels = px(356.);
+const VIEWPORT_MARGIN: Pixels = px(16.);
+
+/// A short notification shown in the corner of a window by the [`ToastManager`].
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct Toast {
+    title: SharedString,
+    description: Option<SharedString>,
+    progress: Option<f32>,
+    action: Option<(SharedString, Rc<dyn Fn(&mut Window, &mut App) + 'static>)>,
+    duration: Option<Duration>,
+}
+
+impl Toast {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            progress: None,
+            action: None,
+            duration: Some(DEFAULT_DURATION),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Shows the progress of an operation, from 0 to 1. The toast stays open until the
+    /// progress reaches 1.
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress.clamp(0., 1.));
+        self
+    }
+
+    /// Adds a button running `on_action`, which also dismisses the toast.
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        on_action: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.action = Some((label.into(), Rc::new(on_action)));
+        self
+    }
+
+    /// How long the toast stays open after it was last updated, 5 seconds by default.
+    /// `None` keeps it open until it is dismissed.
+    pub fn duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// What a toast is rendered with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToastContext {
+    pub handle: ToastHandle,
+    pub title: SharedString,
+    pub description: Option<SharedString>,
+    pub progress: Option<f32>,
+    /// The label of the toast's action, run with [`ToastHandle::run_action`].
+    pub action: Option<SharedString>,
+}
+
+/// Refers to a toast pushed with [`ToastManager::push`], to update it in place or dismiss
+/// it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastHandle(usize);
+
+impl ToastHandle {
+    /// Updates the toast in place, restarting its duration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// handle.update(
+    ///     |toast| toast.description(format!("{done} of {total} files")).progress(done as f32 / total as f32),
+    ///     app,
+    /// );
+    /// ```
+    pub fn update(&self, update: impl FnOnce(Toast) -> Toast, app: &mut App) {
+        let Some(entry) = ToastManager::entry_mut(*self, app) else {
+            return;
+        };
+        entry.toast = update(entry.toast.clone());
+        entry.epoch += 1;
+        ToastManager::schedule_dismiss(*self, app);
+        app.refresh_windows();
+    }
+
+    /// Sets the toast's progress, from 0 to 1.
+    pub fn set_progress(&self, progress: f32, app: &mut App) {
+        self.update(|toast| toast.progress(progress), app);
+    }
+
+    /// Runs the toast's action, if it has one, and dismisses it.
+    pub fn run_action(&self, window: &mut Window, app: &mut App) {
+        let action = ToastManager::entry_mut(*self, app).and_then(|entry| {
+            entry
+                .toast
+                .action
+                .as_ref()
+                .map(|(_, action)| action.clone())
+        });
+        self.dismiss(app);
+        if let Some(action) = action {
+            action(window, app);
+        }
+    }
+
+    pub fn dismiss(&self, app: &mut App) {
+        ToastManager::remove(*self, app);
+    }
+
+    /// Returns whether the toast is still shown.
+    pub fn is_open(&self, app: &App) -> bool {
+        app.try_global::<ToastManager>()
+            .is_some_and(|manager| manager.toasts.iter().any(|entry| entry.handle == *self))
+    }
+}
+
+struct ToastEntry {
+    handle: ToastHandle,
+    window: AnyWindowHandle,
+    toast: Toast,
+    /// Incremented on every update, so only the latest auto-dismiss timer closes the toast.
+    epoch: usize,
+}
+
+/// Shows toasts stacked in the top-right corner of each window, newest last.
+///
+/// Toasts fade in when shown, unless motion is reduced, see [`MotionPreference`].
+///
+/// Toasts render on the [`Layer::Toast`] overlay layer, so the window must be wrapped in a
+/// [`crate::LapislazuliProvider`].
+///
+/// # Examples
+///
+/// ```rust
+/// let upload = ToastManager::push(Toast::new("Uploading").progress(0.), window, cx);
+/// // Later, as the upload advances:
+/// upload.set_progress(0.5, cx);
+/// // And once it is done:
+/// upload.update(|toast| toast.title("Uploaded").progress(1.), cx);
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(Default)]
+pub struct ToastManager {
+    toasts: Vec<ToastEntry>,
+    overlays: HashMap<AnyWindowHandle, OverlayId>,
+    next_id: usize,
+    render: Option<Rc<dyn Fn(&ToastContext, &mut Window, &mut App) -> AnyElement + 'static>>,
+}
+
+impl Global for ToastManager {}
+
+impl ToastManager {
+    /// Shows a toast in the current window.
+    pub fn push(toast: Toast, window: &mut Window, app: &mut App) -> ToastHandle {
+        let window_handle = window.window_handle();
+        let manager = app.default_global::<ToastManager>();
+        let handle = ToastHandle(manager.next_id);
+        manager.next_id += 1;
+        manager.toasts.push(ToastEntry {
+            handle,
+            window: window_handle,
+            toast,
+            epoch: 0,
+        });
+
+        if !manager.overlays.contains_key(&window_handle) {
+            let overlay = Overlay::new(Layer::Toast, move |window, app| {
+                render_toasts(window_handle, window, app)
+            })
+            .dismiss_on_escape(false)
+            .dismiss_on_outside_click(false);
+            let overlay = open_overlay(overlay, window, app);
+            app.global_mut::<ToastManager>()
+                .overlays
+                .insert(window_handle, overlay);
+        }
+
+        Self::schedule_dismiss(handle, app);
+        window.refresh();
+        handle
+    }
+
+    /// Dismisses every toast in every window.
+    pub fn dismiss_all(app: &mut App) {
+        let handles: Vec<ToastHandle> = app
+            .try_global::<ToastManager>()
+            .map(|manager| manager.toasts.iter().map(|entry| entry.handle).collect())
+            .unwrap_or_default();
+        for handle in handles {
+            Self::remove(handle, app);
+        }
+    }
+
+    /// Replaces how toasts are rendered. By default they are styled with the [`Theme`].
+    pub fn set_render<E: IntoElement>(
+        render: impl Fn(&ToastContext, &mut Window, &mut App) -> E + 'static,
+        app: &mut App,
+    ) {
+        app.default_global::<ToastManager>().render = Some(Rc::new(move |cx, window, app| {
+            render(cx, window, app).into_any_element()
+        }));
+    }
+
+    fn entry_mut(handle: ToastHandle, app: &mut App) -> Option<&mut ToastEntry> {
+        app.default_global::<ToastManager>()
+            .toasts
+            .iter_mut()
+            .find(|entry| entry.handle == handle)
+    }
+
+    /// Dismisses the toast once its duration elapses, unless it was updated meanwhile or
+    /// is still in progress.
+    fn schedule_dismiss(handle: ToastHandle, app: &mut App) {
+        let Some(entry) = Self::entry_mut(handle, app) else {
+            return;
+        };
+        let Some(duration) = entry.toast.duration else {
+            return;
+        };
+        if entry.toast.progress.is_some_and(|progress| progress < 1.) {
+            return;
+        }
+
+        let epoch = entry.epoch;
+        app.spawn(async move |cx| {
+            Timer::after(duration).await;
+            cx.update(|app| {
+                if Self::entry_mut(handle, app).is_some_and(|entry| entry.epoch == epoch) {
+                    Self::remove(handle, app);
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn remove(handle: ToastHandle, app: &mut App) {
+        let Some(manager) = app.try_global::<ToastManager>() else {
+            return;
+        };
+        let Some(window) = manager
+            .toasts
+            .iter()
+            .find(|entry| entry.handle == handle)
+            .map(|entry| entry.window)
+        else {
+            return;
+        };
+
+        let manager = app.global_mut::<ToastManager>();
+        manager.toasts.retain(|entry| entry.handle != handle);
+        // Close the overlay along with the last toast of its window.
+        let overlay = if manager.toasts.iter().any(|entry| entry.window == window) {
+            None
+        } else {
+            manager.overlays.remove(&window)
+        };
+        if let Some(overlay) = overlay {
+            close_overlay(overlay, app);
+        }
+        app.refresh_windows();
+    }
+}
+
+fn render_toasts(window_handle: AnyWindowHandle, window: &mut Window, app: &mut App) -> Div {
+    let Some(manager) = app.try_global::<ToastManager>() else {
+        return div();
+    };
+    let render = manager.render.clone();
+    let contexts: Vec<ToastContext> = manager
+        .toasts
+        .iter()
+        .filter(|entry| entry.window == window_handle)
+        .map(|entry| ToastContext {
+            handle: entry.handle,
+            title: entry.toast.title.clone(),
+            description: entry.toast.description.clone(),
+            progress: entry.toast.progress,
+            action: entry.toast.action.as_ref().map(|(label, _)| label.clone()),
+        })
+        .collect();
+
+    let viewport = WindowInfo::current(window, app).viewport_size;
+    let reduced = MotionPreference::current(app).is_reduced();
+    let mut toasts = Vec::with_capacity(contexts.len());
+    for cx in &contexts {
+        let toast = match &render {
+            Some(render) => render(cx, window, app),
+            None => render_default(cx, app).into_any_element(),
+        };
+        toasts.push(if reduced {
+            toast
+        } else {
+            div()
+                .child(toast)
+                .with_animation(
+                    ("toast", cx.handle.0),
+                    Animation::new(ENTER_DURATION).with_easing(ease_out_quint()),
+                    |this, delta| this.opacity(delta),
+                )
+                .into_any_element()
+        });
+    }
+
+    v_flex()
+        .w(TOAST_WIDTH)
+        .gap_2()
+        .mt(VIEWPORT_MARGIN)
+        .ml((viewport.width - TOAST_WIDTH - VIEWPORT_MARGIN).max(px(0.)))
+        .children(toasts)
+}
+
+fn render_default(cx: &ToastContext, app: &App) -> impl IntoElement {
+    let theme = Theme::of(app);
+    let handle = cx.handle;
+
+    v_flex()
+        .gap_1()
+        .p(theme.spacing.md)
+        .rounded(theme.radii.md)
+        .border_1()
+        .border_color(theme.colors.border)
+        .bg(theme.colors.surface)
+        .text_color(theme.colors.foreground)
+        .text_size(theme.font_sizes.sm)
+        .child(
+            h_flex()
+                .justify_between()
+                .gap_2()
+                .child(
+                    div()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .child(cx.title.clone()),
+                )
+                .when_some(cx.action.clone(), |this, label| {
+                    this.child(
+                        button(("toast-action", handle.0))
+                            .text_color(theme.colors.primary)
+                            .on_click(move |_, window, app| handle.run_action(window, app))
+                            .child(label),
+                    )
+                }),
+        )
+        .when_some(cx.description.clone(), |this, description| {
+            this.child(
+                div()
+                    .text_color(theme.colors.muted_foreground)
+                    .child(description),
+            )
+        })
+        .when_some(cx.progress, |this, progress| {
+            this.child(
+                div()
+                    .h(px(4.))
+                    .w_full()
+                    .rounded(theme.radii.full)
+                    .bg(theme.colors.muted)
+                    .child(
+                        div()
+                            .h_full()
+                            .w(relative(progress))
+                            .rounded(theme.radii.full)
+                            .bg(theme.colors.primary),
+                    ),
+            )
+        })
+}
@@ -0,0 +1,358 @@
+use crate::{
+    Direction, FocusableStyled,
+    components::{Menu, MenuEntries, MenuItem},
+    focus_group::FocusGroup,
+    is_focus_visible,
+    primitives::h_flex,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+
+/// A control of a [`Toolbar`], listed by its label in the overflow menu when it doesn't fit.
+#[allow(clippy::type_complexity)]
+pub struct ToolbarItem {
+    label: SharedString,
+    base: Div,
+    has_children: bool,
+    on_select: Option<Rc<dyn Fn(&mut Window, &mut App) + 'static>>,
+    when_focus_visible_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+}
+
+impl ToolbarItem {
+    /// Creates an item, the label is rendered when the item has no children.
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            base: div().flex_none(),
+            has_children: false,
+            on_select: None,
+            when_focus_visible_handler: None,
+        }
+    }
+
+    /// Called when the item is clicked, activated with Enter/Space or picked from the
+    /// overflow menu.
+    pub fn on_select(mut self, on_select: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_select = Some(Rc::new(on_select));
+        self
+    }
+}
+
+impl FocusableStyled for ToolbarItem {
+    fn when_focus_visible(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_focus_visible_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl ParentElement for ToolbarItem {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        let mut elements = elements.into_iter().peekable();
+        self.has_children |= elements.peek().is_some();
+        self.base.extend(elements);
+    }
+}
+
+impl Styled for ToolbarItem {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// A row of controls sharing a single tab stop.
+///
+/// Tab focuses the item focused last, ArrowLeft/ArrowRight/Home/End move focus across the
+/// items and Enter/Space activates the focused one. Items that don't fit the width of the
+/// toolbar move, from the end, into a [`Menu`] opened from an overflow trigger. Give the
+/// element a width, for instance with `w_full()`, for the items to overflow.
+///
+/// # Examples
+///
+/// ```rust
+/// Toolbar::new("formatting")
+///     .w_full()
+///     .gap_1()
+///     .item(ToolbarItem::new("Bold").child("B").on_select(|_, _| println!("bold")))
+///     .item(ToolbarItem::new("Italic").child("I").on_select(|_, _| println!("italic")))
+///     .render_overflow(|_, _, _| span("⋯"))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Toolbar {
+    id: ElementId,
+    base: Div,
+    items: Vec<ToolbarItem>,
+    render_overflow: Option<Box<dyn FnOnce(usize, &mut Window, &mut App) -> AnyElement>>,
+    overflow_menu: Option<Box<dyn FnOnce(Menu) -> Menu>>,
+}
+
+/// Focus handles and measurements kept across renders of a [`Toolbar`].
+struct ToolbarState {
+    focus_handles: Vec<FocusHandle>,
+    overflow_focus_handle: FocusHandle,
+    /// The slot that is the toolbar's tab stop.
+    active: usize,
+    /// The width of each item the last time it was rendered.
+    widths: Vec<Option<Pixels>>,
+    /// The bounds of the first item, to measure the space between items.
+    first_bounds: Option<Bounds<Pixels>>,
+    /// The space between two items, measured once two of them are rendered.
+    gap: Pixels,
+    overflow_width: Option<Pixels>,
+    /// How many items are rendered, the others being in the overflow menu.
+    visible: usize,
+}
+
+impl ToolbarState {
+    fn measure(&mut self, ix: usize, bounds: Bounds<Pixels>) {
+        self.widths[ix] = Some(bounds.size.width);
+        match ix {
+            0 => self.first_bounds = Some(bounds),
+            1 => {
+                if let Some(first) = self.first_bounds {
+                    // The second item is on the left of the first one in RTL.
+                    self.gap = if bounds.left() >= first.right() {
+                        bounds.left() - first.right()
+                    } else {
+                        first.left() - bounds.right()
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Toolbar {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: h_flex().items_center(),
+            items: Vec::new(),
+            render_overflow: None,
+            overflow_menu: None,
+        }
+    }
+
+    pub fn item(mut self, item: ToolbarItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = ToolbarItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Renders the trigger of the overflow menu, given how many items it lists. An ellipsis
+    /// is rendered when this isn't set.
+    pub fn render_overflow<E: IntoElement>(
+        mut self,
+        render: impl FnOnce(usize, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.render_overflow = Some(Box::new(move |count, window, app| {
+            render(count, window, app).into_any_element()
+        }));
+        self
+    }
+
+    /// Styles the menu listing the items that don't fit.
+    pub fn overflow_menu(mut self, style: impl FnOnce(Menu) -> Menu + 'static) -> Self {
+        self.overflow_menu = Some(Box::new(style));
+        self
+    }
+}
+
+impl Styled for Toolbar {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Returns how many items fit the available width, keeping room for the overflow trigger
+/// when some don't.
+fn visible_count(
+    widths: &[Option<Pixels>],
+    gap: Pixels,
+    overflow_width: Pixels,
+    available: Pixels,
+) -> usize {
+    // Items that were never rendered are assumed to fit until they are measured.
+    let width = |count: usize| {
+        (0..count).fold(px(0.), |total, ix| total + widths[ix].unwrap_or_default())
+            + gap * count.saturating_sub(1) as f32
+    };
+    let len = widths.len();
+    if width(len) <= available {
+        return len;
+    }
+    (0..len)
+        .rev()
+        .find(|&count| width(count) + gap + overflow_width <= available)
+        .unwrap_or(0)
+}
+
+impl RenderOnce for Toolbar {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let len = self.items.len();
+        let state = window.use_keyed_state(self.id.clone(), app, |_, cx| ToolbarState {
+            focus_handles: Vec::new(),
+            overflow_focus_handle: cx.focus_handle(),
+            active: 0,
+            widths: Vec::new(),
+            first_bounds: None,
+            gap: px(0.),
+            overflow_width: None,
+            visible: usize::MAX,
+        });
+
+        let (group, visible) = state.update(app, |state, cx| {
+            while state.focus_handles.len() < len {
+                state.focus_handles.push(cx.focus_handle());
+            }
+            state.widths.resize(len, None);
+
+            let visible = state.visible.min(len);
+            let mut handles = state.focus_handles[..visible].to_vec();
+            if visible < len {
+                handles.push(state.overflow_focus_handle.clone());
+            }
+            // The tab stop follows focus, and stays on a rendered slot.
+            if let Some(focused) = handles.iter().position(|handle| handle.is_focused(window)) {
+                state.active = focused;
+            }
+            state.active = state.active.min(handles.len().saturating_sub(1));
+            (FocusGroup::new(handles, state.active), visible)
+        });
+
+        let mut items = self.items;
+        let overflowed = items.split_off(visible);
+        let mut children = Vec::with_capacity(visible + 1);
+        for (ix, mut item) in items.into_iter().enumerate() {
+            let focus_handle = group.handle(ix).clone();
+            if focus_handle.is_focused(window)
+                && is_focus_visible(app)
+                && let Some(handler) = item.when_focus_visible_handler.take()
+            {
+                item = handler(item);
+            }
+
+            let label = item.label.clone();
+            let base = item.base;
+            children.push(
+                base.id(("toolbar-item", ix))
+                    .relative()
+                    .track_focus(&focus_handle)
+                    .when_some(item.on_select, |this, on_select| {
+                        this.on_click({
+                            let on_select = on_select.clone();
+                            move |_, window, app| on_select(window, app)
+                        })
+                        .on_key_down(move |event, window, app| {
+                            if matches!(event.keystroke.key.as_str(), "enter" | "space") {
+                                app.stop_propagation();
+                                on_select(window, app);
+                            }
+                        })
+                    })
+                    .when(!item.has_children, |this| this.child(label))
+                    .child({
+                        let state = state.clone();
+                        canvas(
+                            move |bounds, _, app| {
+                                state.update(app, |state, _| state.measure(ix, bounds))
+                            },
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full()
+                    })
+                    .into_any_element(),
+            );
+        }
+
+        if !overflowed.is_empty() {
+            let trigger = match self.render_overflow {
+                Some(render_overflow) => render_overflow(overflowed.len(), window, app),
+                None => "…".into_any_element(),
+            };
+
+            let mut menu = Menu::new(ElementId::NamedChild(
+                Box::new(self.id.clone()),
+                "overflow".into(),
+            ))
+            .trigger(
+                div()
+                    .id("toolbar-overflow")
+                    .relative()
+                    .track_focus(group.handle(visible))
+                    .child(trigger)
+                    .child({
+                        let state = state.clone();
+                        canvas(
+                            move |bounds, _, app| {
+                                state.update(app, |state, _| {
+                                    state.overflow_width = Some(bounds.size.width)
+                                })
+                            },
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full()
+                    }),
+            );
+            for item in overflowed {
+                let on_select = item.on_select;
+                menu = menu.item(MenuItem::new(item.label).on_select(move |window, app| {
+                    if let Some(on_select) = &on_select {
+                        on_select(window, app);
+                    }
+                }));
+            }
+            if let Some(style) = self.overflow_menu {
+                menu = style(menu);
+            }
+            children.push(div().flex_none().child(menu).into_any_element());
+        }
+
+        self.base
+            .id(self.id)
+            .relative()
+            .overflow_hidden()
+            .when(Direction::current(app).is_rtl(), |this| {
+                this.flex_row_reverse()
+            })
+            .on_key_down(move |event, window, app| {
+                if group.key_down(event, window, app) {
+                    app.stop_propagation();
+                }
+            })
+            .children(children)
+            .child(
+                // Measured after the items, to move them into the menu once their widths
+                // are known.
+                canvas(
+                    move |bounds, window, app| {
+                        state.update(app, |state, _| {
+                            let visible = visible_count(
+                                &state.widths,
+                                state.gap,
+                                state.overflow_width.unwrap_or_default(),
+                                bounds.size.width,
+                            );
+                            if visible != state.visible {
+                                state.visible = visible;
+                                window.request_animation_frame();
+                            }
+                        })
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod visible_count {
+    use crate::components::toolbar::visible_count;
+    use gpui::{Pixels, px};
+
+    const GAP: Pixels = px(8.);
+    const OVERFLOW: Pixels = px(30.);
+
+    /// Four 40px items, which take 184px with their gaps.
+    fn visible(available: f32) -> usize {
+        visible_count(&[Some(px(40.)); 4], GAP, OVERFLOW, px(available))
+    }
+
+    #[test]
+    fn shows_every_item_that_fits() {
+        assert_eq!(visible(184.), 4);
+        assert_eq!(visible(1000.), 4);
+    }
+
+    #[test]
+    fn keeps_room_for_the_overflow_trigger() {
+        assert_eq!(visible(183.), 3);
+        assert_eq!(visible(173.), 2);
+        assert_eq!(visible(78.), 1);
+    }
+
+    #[test]
+    fn shows_no_item_when_none_fits_beside_the_trigger() {
+        assert_eq!(visible(77.), 0);
+        assert_eq!(visible(0.), 0);
+    }
+
+    #[test]
+    fn assumes_unmeasured_items_fit() {
+        let widths = [Some(px(40.)), None, None];
+        assert_eq!(visible_count(&widths, GAP, OVERFLOW, px(56.)), 3);
+    }
+
+    #[test]
+    fn shows_nothing_without_items() {
+        assert_eq!(visible_count(&[], GAP, OVERFLOW, px(0.)), 0);
+    }
+}
@@ -0,0 +1,529 @@
+use crate::{
+    Disableable, SelectionMode,
+    primitives::v_flex,
+    scroll_container, scroll_into_view, scroll_target,
+    selection::{self, pick, toggle},
+    typeahead::Typeahead,
+};
+use gpui::{prelude::FluentBuilder, *};
+use std::{collections::HashSet, rc::Rc};
+
+/// A node of a [`Tree`] and its children.
+#[derive(Clone, Debug)]
+pub struct TreeNode {
+    id: SharedString,
+    label: SharedString,
+    children: Vec<TreeNode>,
+    disabled: bool,
+}
+
+impl TreeNode {
+    /// Creates a node, the label is used for typeahead and rendered by default.
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            children: Vec::new(),
+            disabled: false,
+        }
+    }
+
+    pub fn child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoIterator<Item = TreeNode>) -> Self {
+        self.children.extend(children);
+        self
+    }
+}
+
+impl Disableable for TreeNode {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// What a [`Tree`] node is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeNodeContext {
+    pub id: SharedString,
+    pub label: SharedString,
+    /// Nesting level, `0` for root nodes.
+    pub depth: usize,
+    pub has_children: bool,
+    pub expanded: bool,
+    pub selected: bool,
+    /// Whether the node has the keyboard cursor while the tree is focused.
+    pub active: bool,
+    pub disabled: bool,
+}
+
+/// A visible node, in the order the nodes are rendered.
+#[derive(Clone)]
+struct FlatNode {
+    id: SharedString,
+    label: SharedString,
+    depth: usize,
+    parent: Option<usize>,
+    has_children: bool,
+    disabled: bool,
+}
+
+fn flatten(
+    nodes: &[TreeNode],
+    expanded: &HashSet<SharedString>,
+    depth: usize,
+    parent: Option<usize>,
+    flat: &mut Vec<FlatNode>,
+) {
+    for node in nodes {
+        let ix = flat.len();
+        flat.push(FlatNode {
+            id: node.id.clone(),
+            label: node.label.clone(),
+            depth,
+            parent,
+            has_children: !node.children.is_empty(),
+            disabled: node.disabled,
+        });
+        if expanded.contains(&node.id) {
+            flatten(&node.children, expanded, depth + 1, Some(ix), flat);
+        }
+    }
+}
+
+/// A headless tree view.
+///
+/// The tree is a single tab stop. ArrowUp/ArrowDown move across the visible nodes,
+/// ArrowRight expands a node or moves to its first child, ArrowLeft collapses it or
+/// moves to its parent, Home/End jump to the first and last node and typing jumps to
+/// the next node whose label starts with the typed text. Enter selects the active node
+/// and Space toggles it in [`SelectionMode::Multiple`].
+///
+/// # Examples
+///
+/// ```rust
+/// Tree::new("files")
+///     .selection_mode(SelectionMode::Multiple)
+///     .default_expanded(["src"])
+///     .node(TreeNode::new("src", "src").child(TreeNode::new("src/lib.rs", "lib.rs")))
+///     .node(TreeNode::new("Cargo.toml", "Cargo.toml"))
+///     .render_node(|node, _, _| {
+///         h_flex()
+///             .pl(px(node.depth as f32 * 12.))
+///             .when(node.selected, |this| this.bg(rgb(0xdbeafe)))
+///             .child(node.label.clone())
+///     })
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Tree {
+    id: ElementId,
+    base: Div,
+    nodes: Vec<TreeNode>,
+    selection_mode: SelectionMode,
+    selected: Option<Vec<SharedString>>,
+    default_expanded: Vec<SharedString>,
+    expand_on_click: bool,
+    on_selection_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+    on_expand_change: Option<Rc<dyn Fn(&SharedString, bool, &mut Window, &mut App) + 'static>>,
+    render_node: Option<Box<dyn Fn(&TreeNodeContext, &mut Window, &mut App) -> AnyElement>>,
+}
+
+/// Expansion, selection and cursor kept across renders of a [`Tree`].
+struct TreeState {
+    focus_handle: FocusHandle,
+    expanded: HashSet<SharedString>,
+    selected: Vec<SharedString>,
+    anchor: Option<SharedString>,
+    active: Option<SharedString>,
+    typeahead: Typeahead,
+    scroll_handle: ScrollHandle,
+}
+
+impl Tree {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: v_flex(),
+            nodes: Vec::new(),
+            selection_mode: SelectionMode::Single,
+            selected: None,
+            default_expanded: Vec::new(),
+            expand_on_click: true,
+            on_selection_change: None,
+            on_expand_change: None,
+            render_node: None,
+        }
+    }
+
+    pub fn node(mut self, node: TreeNode) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    pub fn nodes(mut self, nodes: impl IntoIterator<Item = TreeNode>) -> Self {
+        self.nodes.extend(nodes);
+        self
+    }
+
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Controls the ids of the selected nodes.
+    pub fn selected(mut self, ids: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.selected = Some(ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// The ids of the nodes expanded on first render.
+    pub fn default_expanded(
+        mut self,
+        ids: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.default_expanded = ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether clicking a node with children also toggles it, `true` by default.
+    pub fn expand_on_click(mut self, expand: bool) -> Self {
+        self.expand_on_click = expand;
+        self
+    }
+
+    pub fn on_selection_change(
+        mut self,
+        on_change: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_selection_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Called with the node id and its new expanded state.
+    pub fn on_expand_change(
+        mut self,
+        on_change: impl Fn(&SharedString, bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_expand_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Renders each visible node, the label is rendered when this isn't set.
+    pub fn render_node<E: IntoElement>(
+        mut self,
+        render: impl Fn(&TreeNodeContext, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.render_node = Some(Box::new(move |node, window, app| {
+            render(node, window, app).into_any_element()
+        }));
+        self
+    }
+}
+
+impl Styled for Tree {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// The visible nodes of a rendered tree together with its state, shared by its listeners.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct TreeController {
+    id: ElementId,
+    flat: Rc<[FlatNode]>,
+    state: Entity<TreeState>,
+    selection_mode: SelectionMode,
+    controlled: bool,
+    on_selection_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+    on_expand_change: Option<Rc<dyn Fn(&SharedString, bool, &mut Window, &mut App) + 'static>>,
+}
+
+impl TreeController {
+    fn active(&self, app: &App) -> Option<usize> {
+        let active = self.state.read(app).active.as_ref()?;
+        self.flat.iter().position(|node| node.id == *active)
+    }
+
+    fn set_active(&self, ix: usize, app: &mut App) {
+        let id = self.flat[ix].id.clone();
+        self.state.update(app, |state, cx| {
+            state.active = Some(id);
+            cx.notify();
+        });
+    }
+
+    /// Identifies a node to [`scroll_into_view`].
+    fn node_target(&self, ix: usize) -> ElementId {
+        ElementId::NamedChild(Box::new(self.id.clone()), self.flat[ix].id.clone())
+    }
+
+    /// Moves the cursor to a node with the keyboard, scrolling it into view.
+    fn reveal(&self, ix: usize, window: &mut Window, app: &mut App) {
+        self.set_active(ix, app);
+        scroll_into_view(self.node_target(ix), window, app);
+    }
+
+    /// Returns the next enabled node in the given direction, without wrapping.
+    fn step(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        selection::step(self.flat.len(), from, forward, false, |ix| {
+            !self.flat[ix].disabled
+        })
+    }
+
+    fn set_expanded(&self, ix: usize, expanded: bool, window: &mut Window, app: &mut App) {
+        let node = &self.flat[ix];
+        if !node.has_children {
+            return;
+        }
+
+        let changed = self.state.update(app, |state, cx| {
+            let changed = if expanded {
+                state.expanded.insert(node.id.clone())
+            } else {
+                state.expanded.remove(&node.id)
+            };
+            cx.notify();
+            changed
+        });
+        if changed && let Some(on_expand_change) = &self.on_expand_change {
+            on_expand_change(&node.id, expanded, window, app);
+        }
+    }
+
+    fn set_selection(
+        &self,
+        selected: Vec<SharedString>,
+        anchor: SharedString,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        self.state.update(app, |state, cx| {
+            if !self.controlled {
+                state.selected = selected.clone();
+            }
+            state.anchor = Some(anchor);
+            cx.notify();
+        });
+        if let Some(on_selection_change) = &self.on_selection_change {
+            on_selection_change(&selected, window, app);
+        }
+    }
+
+    fn select(&self, ix: usize, modifiers: &Modifiers, window: &mut Window, app: &mut App) {
+        let order: Vec<SharedString> = self.flat.iter().map(|node| node.id.clone()).collect();
+        let state = self.state.read(app);
+        let (selected, anchor) = pick(
+            self.selection_mode,
+            &state.selected,
+            state.anchor.as_ref(),
+            &self.flat[ix].id,
+            &order,
+            modifiers,
+        );
+        self.set_selection(selected, anchor, window, app);
+    }
+
+    fn toggle_selected(&self, ix: usize, window: &mut Window, app: &mut App) {
+        let id = &self.flat[ix].id;
+        let selected = toggle(&self.state.read(app).selected, id);
+        self.set_selection(selected, id.clone(), window, app);
+    }
+
+    /// Moves the cursor, extending the selection when Shift is held.
+    fn move_to(
+        &self,
+        ix: Option<usize>,
+        modifiers: &Modifiers,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        let Some(ix) = ix else {
+            return;
+        };
+        self.reveal(ix, window, app);
+        if modifiers.shift && self.selection_mode == SelectionMode::Multiple {
+            self.select(ix, modifiers, window, app);
+        }
+    }
+
+    /// Handles a key press on the focused tree, returning whether it was consumed.
+    fn key_down(&self, event: &KeyDownEvent, window: &mut Window, app: &mut App) -> bool {
+        let modifiers = &event.keystroke.modifiers;
+        let active = self.active(app);
+        match event.keystroke.key.as_str() {
+            "down" => self.move_to(self.step(active, true), modifiers, window, app),
+            "up" => self.move_to(self.step(active, false), modifiers, window, app),
+            "home" => self.move_to(self.step(None, true), modifiers, window, app),
+            "end" => self.move_to(self.step(None, false), modifiers, window, app),
+            "right" => {
+                let Some(ix) = active else {
+                    return false;
+                };
+                let node = &self.flat[ix];
+                if !node.has_children {
+                    return false;
+                }
+                let expanded = self.state.read(app).expanded.contains(&node.id);
+                if expanded {
+                    // The first child directly follows its expanded parent.
+                    self.move_to(
+                        self.step(Some(ix), true),
+                        &Modifiers::default(),
+                        window,
+                        app,
+                    );
+                } else {
+                    self.set_expanded(ix, true, window, app);
+                }
+            }
+            "left" => {
+                let Some(ix) = active else {
+                    return false;
+                };
+                let node = &self.flat[ix];
+                let expanded = self.state.read(app).expanded.contains(&node.id);
+                if expanded {
+                    self.set_expanded(ix, false, window, app);
+                } else if let Some(parent) = node.parent {
+                    self.reveal(parent, window, app);
+                } else {
+                    return false;
+                }
+            }
+            "enter" => {
+                let Some(ix) = active else {
+                    return false;
+                };
+                self.select(ix, modifiers, window, app);
+            }
+            "space" => {
+                let Some(ix) = active else {
+                    return false;
+                };
+                if self.selection_mode == SelectionMode::Multiple {
+                    self.toggle_selected(ix, window, app);
+                } else {
+                    self.select(ix, modifiers, window, app);
+                }
+            }
+            _ => {
+                let Some(text) = Typeahead::input(&event.keystroke) else {
+                    return false;
+                };
+                let query = self
+                    .state
+                    .update(app, |state, _| state.typeahead.push(text).to_string());
+                let found = Typeahead::find(&query, self.flat.len(), active, |ix| {
+                    let node = &self.flat[ix];
+                    (!node.disabled).then_some(node.label.as_ref())
+                });
+                if let Some(ix) = found {
+                    self.reveal(ix, window, app);
+                }
+            }
+        }
+        true
+    }
+}
+
+impl RenderOnce for Tree {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let default_expanded = self.default_expanded;
+        let state = window.use_keyed_state(self.id.clone(), app, |_, cx| TreeState {
+            focus_handle: cx.focus_handle().tab_stop(true),
+            expanded: default_expanded.into_iter().collect(),
+            selected: Vec::new(),
+            anchor: None,
+            active: None,
+            typeahead: Typeahead::default(),
+            scroll_handle: ScrollHandle::new(),
+        });
+        if let Some(selected) = self.selected.clone() {
+            state.update(app, |state, _| state.selected = selected);
+        }
+
+        let mut flat = Vec::new();
+        flatten(&self.nodes, &state.read(app).expanded, 0, None, &mut flat);
+        let controller = TreeController {
+            id: self.id.clone(),
+            flat: flat.into(),
+            state: state.clone(),
+            selection_mode: self.selection_mode,
+            controlled: self.selected.is_some(),
+            on_selection_change: self.on_selection_change,
+            on_expand_change: self.on_expand_change,
+        };
+
+        let focus_handle = state.read(app).focus_handle.clone();
+        let scroll_handle = state.read(app).scroll_handle.clone();
+        let focused = focus_handle.contains_focused(window, app);
+        let active = controller.active(app);
+
+        let (expanded, selected) = {
+            let state = state.read(app);
+            (state.expanded.clone(), state.selected.clone())
+        };
+
+        let mut rows = Vec::with_capacity(controller.flat.len());
+        for (ix, node) in controller.flat.iter().enumerate() {
+            let context = TreeNodeContext {
+                id: node.id.clone(),
+                label: node.label.clone(),
+                depth: node.depth,
+                has_children: node.has_children,
+                expanded: expanded.contains(&node.id),
+                selected: selected.contains(&node.id),
+                active: focused && active == Some(ix),
+                disabled: node.disabled,
+            };
+            let content = match &self.render_node {
+                Some(render_node) => render_node(&context, window, app),
+                None => node.label.clone().into_any_element(),
+            };
+
+            rows.push(
+                div()
+                    .id(("tree-node", ix))
+                    .relative()
+                    .when(!node.disabled, |this| {
+                        let controller = controller.clone();
+                        let focus_handle = focus_handle.clone();
+                        let expand_on_click = self.expand_on_click;
+                        let expanded = context.expanded;
+                        this.on_click(move |event, window, app| {
+                            window.focus(&focus_handle);
+                            controller.set_active(ix, app);
+                            controller.select(ix, &event.modifiers(), window, app);
+                            if expand_on_click {
+                                controller.set_expanded(ix, !expanded, window, app);
+                            }
+                        })
+                    })
+                    .child(content)
+                    .child(scroll_target(controller.node_target(ix))),
+            );
+        }
+
+        self.base
+            .id(self.id)
+            .track_focus(&focus_handle)
+            .track_scroll(&scroll_handle)
+            .on_key_down(move |event, window, app| {
+                if controller.key_down(event, window, app) {
+                    app.stop_propagation();
+                }
+            })
+            .children(rows)
+            .child(scroll_container(&scroll_handle))
+    }
+}
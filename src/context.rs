@@ -1,42 +1,295 @@
-use crate::primitives::init;
+use crate::{
+    Appearance, AppearanceChanged, Density, Direction, InputModality, InputModalityChanged, Keymap,
+    MotionPreference, Theme, ThemeChanged, ThemeMode, WINDOW_INFO_DEBOUNCE, WindowInfoChanged,
+    disabled_binding, dismiss_topmost_on_escape, is_keyboard_interaction, overlay_stack,
+    primitives, prune_window_infos, scroll_lock_area, set_input_modality, set_theme,
+    update_window_info,
+};
 use gpui::{
-    AnyView, App, AppContext, Context, Entity, InteractiveElement, IntoElement, KeyBinding,
-    ParentElement, Render, Styled, Window, actions, div,
+    AnyView, App, AppContext, Context, Entity, EventEmitter, Global, InteractiveElement,
+    IntoElement, KeyBinding, KeyDownEvent, ParentElement, Render, Styled, Subscription, Timer,
+    Window, actions, div,
 };
 
 actions!(global, [Tab, TabPrev]);
 
+/// Returns `true` if the last interaction used the keyboard rather than a pointer,
+/// mirroring the `:focus-visible` heuristic from the web. Shortcuts such as `cmd-c` don't
+/// count as keyboard interaction.
+pub fn is_focus_visible(app: &App) -> bool {
+    InputModality::current(app).is_keyboard()
+}
+
+/// Marks that the default bindings and global actions have been registered.
+struct Initialized;
+
+impl Global for Initialized {}
+
+/// Registers the crate's default key bindings and global actions.
+///
+/// [`LapislazuliProvider`] calls this automatically. Call it directly for apps that
+/// keep their own root element or open several windows without wrapping each of them.
+pub fn init(app: &mut App) {
+    init_with_keymap(Keymap::default(), app);
+}
+
+/// Like [`init`], applying the given [`Keymap`] on top of the default bindings.
+///
+/// Can be called more than once: the defaults are registered on the first call, and each
+/// keymap that replaces them or unbinds an action disables the matching defaults.
+pub fn init_with_keymap(keymap: Keymap, app: &mut App) {
+    let mut defaults = primitives::default_bindings();
+    defaults.push(KeyBinding::new("tab", Tab, None));
+    defaults.push(KeyBinding::new("shift-tab", TabPrev, None));
+
+    if app.has_global::<Initialized>() {
+        let disabled = defaults
+            .iter()
+            .filter(|binding| keymap.removes_default(binding))
+            .map(disabled_binding)
+            .collect::<Vec<_>>();
+        app.bind_keys(disabled);
+    } else {
+        app.set_global(Initialized);
+        app.default_global::<InputModality>();
+        app.default_global::<Theme>();
+        app.default_global::<ThemeMode>();
+        app.default_global::<MotionPreference>();
+        app.default_global::<Direction>();
+        app.default_global::<Density>();
+        app.set_global(Appearance::from(app.window_appearance()));
+
+        defaults.retain(|binding| !keymap.removes_default(binding));
+        app.bind_keys(defaults);
+        app.on_window_closed(prune_window_infos).detach();
+
+        // Fallbacks for windows that are not wrapped in a provider.
+        app.on_action(|_: &Tab, app| {
+            set_input_modality(InputModality::Keyboard, app);
+            defer_in_active_window(app, Window::focus_next);
+        });
+        app.on_action(|_: &TabPrev, app| {
+            set_input_modality(InputModality::Keyboard, app);
+            defer_in_active_window(app, Window::focus_prev);
+        });
+    }
+
+    app.bind_keys(keymap.bindings);
+}
+
+/// Updates the input modality from outside the provider, such as when a focus trap moves
+/// focus, emitting [`InputModalityChanged`] when the window's root is a provider.
+pub(crate) fn change_input_modality(modality: InputModality, window: &mut Window, app: &mut App) {
+    match window.root::<LapislazuliProvider>().flatten() {
+        Some(provider) => provider.update(app, |provider, cx| {
+            provider.on_modality_change(modality, cx)
+        }),
+        None => {
+            set_input_modality(modality, app);
+        }
+    }
+}
+
+fn defer_in_active_window(app: &mut App, f: fn(&mut Window)) {
+    if let Some(window) = app.active_window() {
+        app.defer(move |app| {
+            window.update(app, |_, window, _| f(window)).ok();
+        });
+    }
+}
+
 pub struct LapislazuliProvider {
     view: AnyView,
+    /// Incremented on every bounds change, so only the last one emits [`WindowInfoChanged`].
+    bounds_epoch: usize,
+    /// The themes installed for each appearance the [`ThemeMode`] resolves to.
+    light_theme: Theme,
+    dark_theme: Theme,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl LapislazuliProvider {
-    pub fn new(view: impl Into<AnyView>, _window: &mut Window, app: &mut App) -> Entity<Self> {
-        init(app);
-        app.bind_keys([
-            KeyBinding::new("tab", Tab, None),
-            KeyBinding::new("shift-tab", TabPrev, None),
-        ]);
+    pub fn new(view: impl Into<AnyView>, window: &mut Window, app: &mut App) -> Entity<Self> {
+        Self::with_keymap(view, Keymap::default(), window, app)
+    }
+
+    /// Creates the provider, installing the given [`Theme`] instead of the default one.
+    pub fn with_theme(
+        view: impl Into<AnyView>,
+        theme: Theme,
+        window: &mut Window,
+        app: &mut App,
+    ) -> Entity<Self> {
+        Self::with_themes(view, theme.clone(), theme, window, app)
+    }
+
+    /// Creates the provider with a theme for each appearance, installing the one the current
+    /// [`ThemeMode`] resolves to.
+    ///
+    /// To also apply a [`Keymap`], create the provider with [`Self::with_keymap`] and call
+    /// [`Self::set_themes`] on it.
+    pub fn with_themes(
+        view: impl Into<AnyView>,
+        light: Theme,
+        dark: Theme,
+        window: &mut Window,
+        app: &mut App,
+    ) -> Entity<Self> {
+        let provider = Self::new(view, window, app);
+        provider.update(app, |provider, cx| provider.set_themes(light, dark, cx));
+        provider
+    }
+
+    /// Replaces the theme for each appearance, installing the one the current [`ThemeMode`]
+    /// resolves to.
+    pub fn set_themes(&mut self, light: Theme, dark: Theme, cx: &mut Context<Self>) {
+        self.light_theme = light;
+        self.dark_theme = dark;
+        if self.apply_theme(cx) {
+            cx.notify();
+        }
+    }
+
+    /// Switches between the light and dark themes, or makes them follow the system
+    /// appearance.
+    pub fn set_theme_mode(&mut self, mode: ThemeMode, cx: &mut Context<Self>) {
+        if ThemeMode::current(cx) == mode {
+            return;
+        }
+
+        cx.set_global(mode);
+        self.apply_theme(cx);
+        cx.emit(ThemeChanged {
+            mode,
+            appearance: mode.appearance(cx),
+        });
+        cx.notify();
+    }
+
+    /// Installs the theme for the appearance the mode resolves to, returning whether it
+    /// changed.
+    fn apply_theme(&self, cx: &mut Context<Self>) -> bool {
+        let theme = match ThemeMode::current(cx).appearance(cx) {
+            Appearance::Light => &self.light_theme,
+            Appearance::Dark => &self.dark_theme,
+        };
+        if cx.try_global::<Theme>() == Some(theme) {
+            return false;
+        }
+
+        set_theme(theme.clone(), cx);
+        true
+    }
+
+    /// Creates the provider, applying the given [`Keymap`] on top of the default bindings.
+    pub fn with_keymap(
+        view: impl Into<AnyView>,
+        keymap: Keymap,
+        window: &mut Window,
+        app: &mut App,
+    ) -> Entity<Self> {
+        init_with_keymap(keymap, app);
+        app.set_global(Appearance::from(window.appearance()));
+        update_window_info(window, app);
 
         let view = view.into();
-        app.new(|_cx| LapislazuliProvider { view })
+        app.new(|cx| {
+            let _subscriptions = vec![
+                cx.observe_window_appearance(window, Self::on_appearance_change),
+                cx.observe_window_bounds(window, Self::on_bounds_change),
+            ];
+            LapislazuliProvider {
+                view,
+                bounds_epoch: 0,
+                light_theme: Theme::light(),
+                dark_theme: Theme::dark(),
+                _subscriptions,
+            }
+        })
     }
 
-    fn on_tab(&mut self, _: &Tab, window: &mut Window, _: &mut Context<Self>) {
+    fn on_appearance_change(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let appearance = Appearance::from(window.appearance());
+        if Appearance::current(cx) == appearance {
+            return;
+        }
+
+        cx.set_global(appearance);
+        cx.emit(AppearanceChanged { appearance });
+        let mode = ThemeMode::current(cx);
+        if mode == ThemeMode::System && self.apply_theme(cx) {
+            cx.emit(ThemeChanged { mode, appearance });
+        }
+        cx.notify();
+    }
+
+    /// Records the window geometry once it stops changing, as resizing and moving report
+    /// new bounds on every frame.
+    fn on_bounds_change(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.bounds_epoch += 1;
+        let epoch = self.bounds_epoch;
+        cx.spawn_in(window, async move |this, cx| {
+            Timer::after(WINDOW_INFO_DEBOUNCE).await;
+            this.update_in(cx, |this, window, cx| {
+                if this.bounds_epoch != epoch {
+                    return;
+                }
+                if let Some(event) = update_window_info(window, cx) {
+                    cx.emit(event);
+                    cx.notify();
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn on_modality_change(&mut self, modality: InputModality, cx: &mut Context<Self>) {
+        if set_input_modality(modality, cx) {
+            cx.emit(InputModalityChanged { modality });
+            cx.notify();
+        }
+    }
+
+    fn on_tab(&mut self, _: &Tab, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_modality_change(InputModality::Keyboard, cx);
         window.focus_next();
     }
 
-    fn on_tab_prev(&mut self, _: &TabPrev, window: &mut Window, _: &mut Context<Self>) {
+    fn on_tab_prev(&mut self, _: &TabPrev, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_modality_change(InputModality::Keyboard, cx);
         window.focus_prev();
     }
 }
 
+impl EventEmitter<AppearanceChanged> for LapislazuliProvider {}
+
+impl EventEmitter<InputModalityChanged> for LapislazuliProvider {}
+
+impl EventEmitter<ThemeChanged> for LapislazuliProvider {}
+
+impl EventEmitter<WindowInfoChanged> for LapislazuliProvider {}
+
 impl Render for LapislazuliProvider {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         div()
             .size_full()
-            .child(self.view.clone())
+            .child(scroll_lock_area().size_full().child(self.view.clone()))
+            .child(overlay_stack())
             .id("lapislazuli-provider")
+            .on_key_down(|event, window, app| {
+                if event.keystroke.key == "escape" && dismiss_topmost_on_escape(window, app) {
+                    app.stop_propagation();
+                }
+            })
+            .capture_key_down(cx.listener(|this, event: &KeyDownEvent, _, cx| {
+                if is_keyboard_interaction(&event.keystroke) {
+                    this.on_modality_change(InputModality::Keyboard, cx);
+                }
+            }))
+            .capture_any_mouse_down(
+                cx.listener(|this, _, _, cx| this.on_modality_change(InputModality::Pointer, cx)),
+            )
             .on_action(cx.listener(Self::on_tab))
             .on_action(cx.listener(Self::on_tab_prev))
     }
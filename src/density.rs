@@ -0,0 +1,51 @@
+use gpui::{App, Global, Pixels, Rems, px, rems};
+
+/// How tightly the interface is laid out.
+///
+/// Components read it for their default paddings and hit targets when none were set
+/// explicitly, so data-dense apps can switch the whole UI scale with [`set_density`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Density {
+    Compact,
+    #[default]
+    Comfortable,
+    Spacious,
+}
+
+impl Density {
+    /// Returns the current density, or [`Density::Comfortable`] if none was set.
+    pub fn current(app: &App) -> Self {
+        app.try_global::<Density>().copied().unwrap_or_default()
+    }
+
+    /// The factor applied to spacing, relative to [`Density::Comfortable`].
+    pub fn scale(&self) -> f32 {
+        match self {
+            Density::Compact => 0.75,
+            Density::Comfortable => 1.0,
+            Density::Spacious => 1.25,
+        }
+    }
+
+    /// Vertical padding of single-line controls such as text fields.
+    pub fn control_padding(&self) -> Rems {
+        rems(0.25 * self.scale())
+    }
+
+    /// Minimum width and height of small pointer targets such as checkboxes.
+    pub fn hit_target(&self) -> Pixels {
+        match self {
+            Density::Compact => px(16.),
+            Density::Comfortable => px(20.),
+            Density::Spacious => px(24.),
+        }
+    }
+}
+
+impl Global for Density {}
+
+/// Sets the density and redraws all windows.
+pub fn set_density(density: Density, app: &mut App) {
+    app.set_global(density);
+    app.refresh_windows();
+}
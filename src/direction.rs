@@ -0,0 +1,42 @@
+use gpui::{App, Global};
+
+/// The layout direction of the interface.
+///
+/// Set it with [`set_direction`] for right-to-left locales such as Arabic or Hebrew.
+/// Text fields swap the meaning of the Left and Right keys, and components can read
+/// it with [`Direction::current`] to mirror their styling.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    /// Returns the current direction, or [`Direction::Ltr`] if none was set.
+    pub fn current(app: &App) -> Self {
+        app.try_global::<Direction>().copied().unwrap_or_default()
+    }
+
+    pub fn is_rtl(&self) -> bool {
+        *self == Direction::Rtl
+    }
+
+    /// Returns whether the Left or Right arrow `key` moves forward in reading order, or
+    /// `None` for any other key.
+    pub fn arrow_forward(&self, key: &str) -> Option<bool> {
+        match key {
+            "right" => Some(!self.is_rtl()),
+            "left" => Some(self.is_rtl()),
+            _ => None,
+        }
+    }
+}
+
+impl Global for Direction {}
+
+/// Sets the layout direction and redraws all windows.
+pub fn set_direction(direction: Direction, app: &mut App) {
+    app.set_global(direction);
+    app.refresh_windows();
+}
@@ -0,0 +1,127 @@
+use gpui::{
+    AnyElement, App, Bounds, Div, Element, ElementId, Global, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, ParentElement, Pixels, StyleRefinement, Styled, Window, div,
+};
+
+/// How many enabled [`DisabledScope`]s are currently being laid out.
+#[derive(Default)]
+struct DisabledScopes(usize);
+
+impl Global for DisabledScopes {}
+
+/// Returns whether the element being rendered is inside a disabled [`DisabledScope`].
+///
+/// Components call this when rendering, so it is only meaningful during layout.
+pub fn is_in_disabled_scope(app: &App) -> bool {
+    app.try_global::<DisabledScopes>()
+        .is_some_and(|scopes| scopes.0 > 0)
+}
+
+pub fn disabled_scope() -> DisabledScope {
+    DisabledScope {
+        base: Some(div()),
+        disabled: true,
+    }
+}
+
+/// A container that renders every lapislazuli component inside it as disabled.
+///
+/// Components in the scope suppress their handlers and apply their `when_disabled`
+/// styles, as if `.disabled(true)` had been called on each of them.
+pub struct DisabledScope {
+    base: Option<Div>,
+    disabled: bool,
+}
+
+impl DisabledScope {
+    /// Whether the scope disables its descendants, `true` by default.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl ParentElement for DisabledScope {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        if let Some(base) = self.base.as_mut() {
+            base.extend(elements);
+        }
+    }
+}
+
+impl Styled for DisabledScope {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base
+            .as_mut()
+            .expect("style accessed after layout")
+            .style()
+    }
+}
+
+impl IntoElement for DisabledScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for DisabledScope {
+    type RequestLayoutState = AnyElement;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        app: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut element = self.base.take().unwrap_or_else(div).into_any_element();
+
+        // Descendant components render while they are laid out, so the scope only has
+        // to be active for the duration of this call.
+        if self.disabled {
+            app.default_global::<DisabledScopes>().0 += 1;
+        }
+        let layout_id = element.request_layout(window, app);
+        if self.disabled {
+            app.global_mut::<DisabledScopes>().0 -= 1;
+        }
+
+        (layout_id, element)
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        app: &mut App,
+    ) -> Self::PrepaintState {
+        element.prepaint(window, app);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        element.paint(window, app);
+    }
+}
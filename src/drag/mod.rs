@@ -0,0 +1,49 @@
+use gpui::{Along, Axis, Bounds, Pixels, px};
+
+#[cfg(test)]
+mod tests;
+
+/// How far the pointer moves before pressing an item starts dragging it.
+pub(crate) const DRAG_THRESHOLD: Pixels = px(4.);
+
+/// Returns where an item dragged to `position` along the axis is dropped: the number of
+/// items, laid out in order, whose center is before it.
+pub(crate) fn drop_target(
+    bounds: impl IntoIterator<Item = Bounds<Pixels>>,
+    position: Pixels,
+    axis: Axis,
+) -> usize {
+    bounds
+        .into_iter()
+        .filter(|bounds| bounds.center().along(axis) < position)
+        .count()
+}
+
+/// Returns the index an item moved from `from` to the drop target ends up at, once it is no
+/// longer counted at its old position.
+pub(crate) fn moved_index(from: usize, target: usize) -> usize {
+    if target > from { target - 1 } else { target }
+}
+
+/// How close to the edge of its scroll container a dragged item starts scrolling it.
+pub(crate) const AUTO_SCROLL_EDGE: Pixels = px(32.);
+
+/// How far a drag scrolls its container on each frame at most, right at the edge.
+pub(crate) const AUTO_SCROLL_SPEED: Pixels = px(12.);
+
+/// Returns how far a drag at `position` scrolls a viewport spanning `start..end` along the
+/// axis on this frame: backwards near the start and forwards near the end, faster the
+/// closer the pointer is to the edge.
+pub(crate) fn auto_scroll_delta(start: Pixels, end: Pixels, position: Pixels) -> Pixels {
+    let edge = AUTO_SCROLL_EDGE.min((end - start) / 2.);
+    if edge <= px(0.) {
+        return px(0.);
+    }
+    if position < start + edge {
+        -AUTO_SCROLL_SPEED * ((start + edge - position) / edge).min(1.)
+    } else if position > end - edge {
+        AUTO_SCROLL_SPEED * ((position - (end - edge)) / edge).min(1.)
+    } else {
+        px(0.)
+    }
+}
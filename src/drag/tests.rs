@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod drop_target {
+    use crate::drag::drop_target;
+    use gpui::{Axis, Bounds, Pixels, point, px, size};
+
+    /// Three 20px rows stacked from the top.
+    fn rows() -> Vec<Bounds<Pixels>> {
+        (0..3)
+            .map(|ix| Bounds::new(point(px(0.), px(20. * ix as f32)), size(px(100.), px(20.))))
+            .collect()
+    }
+
+    #[test]
+    fn counts_the_items_centered_before_the_position() {
+        assert_eq!(drop_target(rows(), px(0.), Axis::Vertical), 0);
+        assert_eq!(drop_target(rows(), px(10.), Axis::Vertical), 0);
+        assert_eq!(drop_target(rows(), px(11.), Axis::Vertical), 1);
+        assert_eq!(drop_target(rows(), px(45.), Axis::Vertical), 2);
+        assert_eq!(drop_target(rows(), px(100.), Axis::Vertical), 3);
+    }
+
+    #[test]
+    fn follows_the_axis() {
+        assert_eq!(drop_target(rows(), px(60.), Axis::Horizontal), 3);
+        assert_eq!(drop_target(rows(), px(40.), Axis::Horizontal), 0);
+    }
+}
+
+#[cfg(test)]
+mod moved_index {
+    use crate::drag::moved_index;
+
+    #[test]
+    fn keeps_targets_before_the_item() {
+        assert_eq!(moved_index(3, 0), 0);
+        assert_eq!(moved_index(3, 3), 3);
+    }
+
+    #[test]
+    fn discounts_the_item_from_targets_after_it() {
+        assert_eq!(moved_index(0, 2), 1);
+        assert_eq!(moved_index(0, 1), 0);
+        assert_eq!(moved_index(1, 4), 3);
+    }
+}
+
+#[cfg(test)]
+mod auto_scroll_delta {
+    use crate::drag::{AUTO_SCROLL_EDGE, AUTO_SCROLL_SPEED, auto_scroll_delta};
+    use gpui::px;
+
+    #[test]
+    fn stays_still_away_from_the_edges() {
+        assert_eq!(auto_scroll_delta(px(0.), px(200.), px(100.)), px(0.));
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(200.), AUTO_SCROLL_EDGE),
+            px(0.)
+        );
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(200.), px(200.) - AUTO_SCROLL_EDGE),
+            px(0.)
+        );
+    }
+
+    #[test]
+    fn scrolls_backwards_near_the_start_and_forwards_near_the_end() {
+        assert!(auto_scroll_delta(px(0.), px(200.), px(10.)) < px(0.));
+        assert!(auto_scroll_delta(px(0.), px(200.), px(190.)) > px(0.));
+    }
+
+    #[test]
+    fn speeds_up_towards_the_edge() {
+        let half_edge = AUTO_SCROLL_EDGE / 2.;
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(200.), half_edge),
+            -AUTO_SCROLL_SPEED / 2.
+        );
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(200.), px(200.) - half_edge),
+            AUTO_SCROLL_SPEED / 2.
+        );
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(200.), px(0.)),
+            -AUTO_SCROLL_SPEED
+        );
+    }
+
+    #[test]
+    fn caps_the_speed_past_the_edge() {
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(200.), px(-50.)),
+            -AUTO_SCROLL_SPEED
+        );
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(200.), px(250.)),
+            AUTO_SCROLL_SPEED
+        );
+    }
+
+    #[test]
+    fn measures_from_the_viewport_origin() {
+        assert_eq!(
+            auto_scroll_delta(px(300.), px(500.), px(100.)),
+            -AUTO_SCROLL_SPEED
+        );
+        assert_eq!(auto_scroll_delta(px(300.), px(500.), px(400.)), px(0.));
+    }
+
+    #[test]
+    fn shrinks_the_edges_to_half_a_small_viewport() {
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(20.), px(5.)),
+            -AUTO_SCROLL_SPEED / 2.
+        );
+        assert_eq!(
+            auto_scroll_delta(px(0.), px(20.), px(15.)),
+            AUTO_SCROLL_SPEED / 2.
+        );
+        assert_eq!(auto_scroll_delta(px(0.), px(0.), px(0.)), px(0.));
+    }
+}
@@ -0,0 +1,111 @@
+use gpui::{App, ElementId, Global, SharedString, Subscription};
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+};
+
+type Listener = Rc<dyn Fn(&dyn Any, &mut App)>;
+
+#[derive(Default)]
+struct EventBusState {
+    listeners: HashMap<TypeId, Vec<(usize, Listener)>>,
+    next_id: usize,
+}
+
+/// Listeners are shared with their subscriptions so dropping one can unregister it
+/// without access to the app.
+#[derive(Default)]
+struct EventBus(Rc<RefCell<EventBusState>>);
+
+impl Global for EventBus {}
+
+/// Publishes an event to every listener subscribed to its type.
+pub fn publish_event<E: 'static>(event: E, app: &mut App) {
+    let Some(bus) = app.try_global::<EventBus>() else {
+        return;
+    };
+
+    let listeners = bus
+        .0
+        .borrow()
+        .listeners
+        .get(&TypeId::of::<E>())
+        .map(|listeners| {
+            listeners
+                .iter()
+                .map(|(_, listener)| listener.clone())
+                .collect()
+        })
+        .unwrap_or_else(Vec::new);
+
+    for listener in listeners {
+        listener(&event, app);
+    }
+}
+
+/// Calls `listener` for every published event of type `E` until the returned
+/// [`Subscription`] is dropped.
+///
+/// Components publish [`FieldChanged`], [`TabSelected`], [`OptionSelected`] and
+/// [`SectionActivated`], and apps can publish their own event types with [`publish_event`].
+pub fn subscribe_event<E: 'static>(
+    app: &mut App,
+    listener: impl Fn(&E, &mut App) + 'static,
+) -> Subscription {
+    let bus = app.default_global::<EventBus>().0.clone();
+    let type_id = TypeId::of::<E>();
+
+    let id = {
+        let mut state = bus.borrow_mut();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.listeners.entry(type_id).or_default().push((
+            id,
+            Rc::new(move |event, app| {
+                if let Some(event) = event.downcast_ref::<E>() {
+                    listener(event, app);
+                }
+            }),
+        ));
+        id
+    };
+
+    let bus = Rc::downgrade(&bus);
+    Subscription::new(move || {
+        if let Some(bus) = bus.upgrade()
+            && let Some(listeners) = bus.borrow_mut().listeners.get_mut(&type_id)
+        {
+            listeners.retain(|(listener_id, _)| *listener_id != id);
+        }
+    })
+}
+
+/// Published when a text field commits a new value.
+#[derive(Clone, Debug)]
+pub struct FieldChanged {
+    pub id: ElementId,
+    pub value: SharedString,
+}
+
+/// Published when a tab is selected.
+#[derive(Clone, Debug)]
+pub struct TabSelected {
+    pub id: ElementId,
+    pub index: usize,
+}
+
+/// Published when an option is picked in a [`crate::components::Select`].
+#[derive(Clone, Debug)]
+pub struct OptionSelected {
+    pub id: ElementId,
+    pub value: SharedString,
+}
+
+/// Published when the section being read in a [`crate::components::Scrollspy`] changes.
+#[derive(Clone, Debug)]
+pub struct SectionActivated {
+    pub id: ElementId,
+    pub section: SharedString,
+}
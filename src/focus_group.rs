@@ -0,0 +1,94 @@
+use crate::Direction;
+use gpui::{App, Axis, FocusHandle, KeyDownEvent, Window};
+use std::rc::Rc;
+
+/// Elements sharing a single tab stop, with the arrow keys moving focus among them, as in
+/// toolbars, menu bars and tab lists.
+///
+/// Only the active element is a tab stop, so Tab moves past the group and back to the
+/// element focused last. On a horizontal axis the Left and Right keys swap meaning in RTL.
+#[derive(Clone)]
+pub(crate) struct FocusGroup {
+    handles: Rc<[FocusHandle]>,
+    axis: Axis,
+    wrap: bool,
+}
+
+impl FocusGroup {
+    /// Creates a group over the handles, making the one at `active` its only tab stop.
+    pub(crate) fn new(handles: impl IntoIterator<Item = FocusHandle>, active: usize) -> Self {
+        let handles = handles
+            .into_iter()
+            .enumerate()
+            .map(|(ix, handle)| {
+                let tab_stop = ix == active;
+                if handle.tab_stop == tab_stop {
+                    handle
+                } else {
+                    handle.tab_stop(tab_stop)
+                }
+            })
+            .collect();
+        Self {
+            handles,
+            axis: Axis::Horizontal,
+            wrap: false,
+        }
+    }
+
+    pub(crate) fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Moves focus from the last element to the first and back.
+    pub(crate) fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub(crate) fn handle(&self, ix: usize) -> &FocusHandle {
+        &self.handles[ix]
+    }
+
+    /// Returns the position of the focused element, if any.
+    pub(crate) fn focused(&self, window: &Window) -> Option<usize> {
+        self.handles
+            .iter()
+            .position(|handle| handle.is_focused(window))
+    }
+
+    /// Moves focus for arrow keys and Home/End, returning whether the key was handled.
+    pub(crate) fn key_down(&self, event: &KeyDownEvent, window: &mut Window, app: &App) -> bool {
+        let Some(from) = self.focused(window) else {
+            return false;
+        };
+        let last = self.handles.len() - 1;
+        let direction = Direction::current(app);
+        let forward = match (event.keystroke.key.as_str(), self.axis) {
+            (key @ ("left" | "right"), Axis::Horizontal) => {
+                direction.arrow_forward(key).unwrap_or_default()
+            }
+            ("down", Axis::Vertical) => true,
+            ("up", Axis::Vertical) => false,
+            ("home", _) => {
+                window.focus(&self.handles[0]);
+                return true;
+            }
+            ("end", _) => {
+                window.focus(&self.handles[last]);
+                return true;
+            }
+            _ => return false,
+        };
+
+        let to = match (forward, self.wrap) {
+            (true, true) if from == last => 0,
+            (false, true) if from == 0 => last,
+            (true, _) => (from + 1).min(last),
+            (false, _) => from.saturating_sub(1),
+        };
+        window.focus(&self.handles[to]);
+        true
+    }
+}
@@ -0,0 +1,474 @@
+use crate::primitives::text_field::{ValidationState, actions::Enter};
+use gpui::*;
+use smallvec::SmallVec;
+use std::{collections::HashMap, rc::Rc, time::Duration};
+
+#[cfg(test)]
+mod tests;
+
+actions!(form, [SubmitForm]);
+
+/// The value of a named field of a [`Form`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FormValue {
+    Text(SharedString),
+    Checked(bool),
+    /// The value of the selected option, if any.
+    Selected(Option<SharedString>),
+}
+
+impl FormValue {
+    /// The text of a text field or the value of a selected option.
+    pub fn as_text(&self) -> Option<&SharedString> {
+        match self {
+            Self::Text(text) | Self::Selected(Some(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn is_checked(&self) -> bool {
+        *self == Self::Checked(true)
+    }
+}
+
+/// The values of the named fields of a [`Form`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormValues(HashMap<SharedString, FormValue>);
+
+impl FormValues {
+    pub fn get(&self, name: &str) -> Option<&FormValue> {
+        self.0.get(name)
+    }
+
+    /// The text of the field, empty when it has none.
+    pub fn text(&self, name: &str) -> SharedString {
+        self.get(name)
+            .and_then(FormValue::as_text)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn checked(&self, name: &str) -> bool {
+        self.get(name).is_some_and(FormValue::is_checked)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&SharedString, &FormValue)> {
+        self.0.iter()
+    }
+}
+
+/// Emitted when a [`Form`] is submitted, valid or not.
+#[derive(Clone, Debug)]
+pub struct SubmitEvent {
+    pub values: FormValues,
+    /// The message of every invalid field, by name.
+    pub errors: HashMap<SharedString, SharedString>,
+    /// The message of the form-level validator, when it failed.
+    pub form_error: Option<SharedString>,
+}
+
+impl SubmitEvent {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.form_error.is_none()
+    }
+}
+
+/// The values of a [`Form`] handed to its autosave callback.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormSnapshot {
+    pub id: ElementId,
+    pub values: FormValues,
+}
+
+/// How often a [`Form`] saves a draft of its values, and where to.
+#[allow(clippy::type_complexity)]
+struct FormAutosave {
+    interval: Duration,
+    callback: Rc<dyn Fn(&FormSnapshot, &mut Window, &mut App)>,
+}
+
+/// A field registered with the enclosing [`Form`] while it renders.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub(crate) struct FormField {
+    pub value: Rc<dyn Fn(&App) -> FormValue>,
+    /// The field's own validation, run on submit.
+    pub validate: Option<Rc<dyn Fn(&mut Window, &mut App) -> ValidationState>>,
+    /// Focused when the field is the first invalid one.
+    pub focus_handle: Option<FocusHandle>,
+}
+
+/// The fields registered during the last layout of a [`Form`], in layout order.
+struct FormState {
+    id: ElementId,
+    fields: Vec<(SharedString, FormField)>,
+    autosave: Option<FormAutosave>,
+    /// The values last handed to the autosave callback, or the ones first laid out.
+    saved_values: Option<FormValues>,
+    /// Checks the values for changes once per autosave interval, cancelled when dropped.
+    autosave_task: Option<(Duration, Task<()>)>,
+}
+
+impl FormState {
+    fn values(&self, app: &App) -> FormValues {
+        FormValues(
+            self.fields
+                .iter()
+                .map(|(name, field)| (name.clone(), (field.value)(app)))
+                .collect(),
+        )
+    }
+
+    /// Runs the autosave check every interval, restarting it if the interval changed.
+    fn schedule_autosave(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let interval = self.autosave.as_ref().map(|autosave| autosave.interval);
+        if self.autosave_task.as_ref().map(|(interval, _)| *interval) == interval {
+            return;
+        }
+
+        self.autosave_task = interval.map(|interval| {
+            // The executor's timer follows the test clock, unlike `Timer::after`.
+            let task = cx.spawn_in(window, async move |this, cx| {
+                loop {
+                    cx.background_executor().timer(interval).await;
+                    if this
+                        .update_in(cx, |state, window, cx| state.autosave(window, cx))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            });
+            (interval, task)
+        });
+    }
+
+    /// Hands the values to the autosave callback if they changed since the last save.
+    fn autosave(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(autosave) = &self.autosave else {
+            return;
+        };
+        let values = self.values(cx);
+        if self.saved_values.as_ref() == Some(&values) {
+            return;
+        }
+
+        let callback = autosave.callback.clone();
+        let snapshot = FormSnapshot {
+            id: self.id.clone(),
+            values: values.clone(),
+        };
+        self.saved_values = Some(values);
+        callback(&snapshot, window, cx);
+    }
+}
+
+/// The forms currently being laid out, innermost last.
+#[derive(Default)]
+struct FormScopes(Vec<Entity<FormState>>);
+
+impl Global for FormScopes {}
+
+/// Returns whether the element being rendered is inside a [`Form`].
+pub(crate) fn is_in_form(app: &App) -> bool {
+    app.try_global::<FormScopes>()
+        .is_some_and(|scopes| !scopes.0.is_empty())
+}
+
+/// Registers a named field with the innermost [`Form`] being laid out, if any.
+pub(crate) fn register_form_field(name: SharedString, field: FormField, app: &mut App) {
+    let Some(form) = app
+        .try_global::<FormScopes>()
+        .and_then(|scopes| scopes.0.last().cloned())
+    else {
+        return;
+    };
+    form.update(app, |form, _| form.fields.push((name, field)));
+}
+
+/// A container that collects the values of the named fields inside it on submit.
+///
+/// Text fields, checkboxes and selects given a `name` register themselves while they
+/// render. Pressing Enter in a text field or on a checkbox, or dispatching [`SubmitForm`] from
+/// any element inside, such as a submit button, validates every field and calls
+/// [`Form::on_submit`] once. Other Enter presses, such as on a button, are left to the
+/// focused element. The fields' own validators run first, then the ones added with
+/// [`Form::validate_field`], and last the form-level [`Form::validate`]. When a field is
+/// invalid, the first one is focused.
+///
+/// With [`Form::autosave`], a draft of the values is also saved while the form is edited.
+///
+/// # Examples
+///
+/// ```rust
+/// Form::new("sign-up")
+///     .validate_field("password", |value| match value.as_text() {
+///         Some(text) if text.len() >= 8 => ValidationState::Valid,
+///         _ => ValidationState::invalid("Use at least 8 characters"),
+///     })
+///     .validate(|values| {
+///         if values.text("password") == values.text("confirm") {
+///             ValidationState::Valid
+///         } else {
+///             ValidationState::invalid("The passwords don't match")
+///         }
+///     })
+///     .on_submit(cx.listener(|this, event: &SubmitEvent, _, cx| {
+///         if event.is_valid() {
+///             this.sign_up(&event.values, cx);
+///         }
+///     }))
+///     .child(text_field("email").name("email"))
+///     .child(text_field("password").name("password").masked(true))
+///     .child(text_field("confirm").name("confirm").masked(true))
+///     .child(checkbox("terms").name("terms"))
+///     .child(
+///         button("submit")
+///             .child("Sign up")
+///             .on_click(|_, window, app| window.dispatch_action(Box::new(SubmitForm), app)),
+///     )
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Form {
+    id: ElementId,
+    base: Div,
+    children: SmallVec<[AnyElement; 2]>,
+    field_validators: HashMap<SharedString, Rc<dyn Fn(&FormValue) -> ValidationState>>,
+    validate: Option<Rc<dyn Fn(&FormValues) -> ValidationState>>,
+    on_submit: Option<Rc<dyn Fn(&SubmitEvent, &mut Window, &mut App)>>,
+    autosave: Option<FormAutosave>,
+}
+
+impl Form {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            base: div(),
+            children: SmallVec::new(),
+            field_validators: HashMap::new(),
+            validate: None,
+            on_submit: None,
+            autosave: None,
+        }
+    }
+
+    /// Validates the value of the named field on submit, after the field's own validator.
+    pub fn validate_field(
+        mut self,
+        name: impl Into<SharedString>,
+        validate: impl Fn(&FormValue) -> ValidationState + 'static,
+    ) -> Self {
+        self.field_validators.insert(name.into(), Rc::new(validate));
+        self
+    }
+
+    /// Validates the values together on submit, once every field was validated.
+    pub fn validate(mut self, validate: impl Fn(&FormValues) -> ValidationState + 'static) -> Self {
+        self.validate = Some(Rc::new(validate));
+        self
+    }
+
+    pub fn on_submit(
+        mut self,
+        on_submit: impl Fn(&SubmitEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_submit = Some(Rc::new(on_submit));
+        self
+    }
+
+    /// Saves a draft of the values at most once per `interval`, whenever they changed
+    /// since the last save or, before the first one, since the form was first rendered.
+    pub fn autosave(
+        mut self,
+        interval: Duration,
+        callback: impl Fn(&FormSnapshot, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.autosave = Some(FormAutosave {
+            interval,
+            callback: Rc::new(callback),
+        });
+        self
+    }
+}
+
+impl ParentElement for Form {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Form {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct FormController {
+    state: Entity<FormState>,
+    field_validators: Rc<HashMap<SharedString, Rc<dyn Fn(&FormValue) -> ValidationState>>>,
+    validate: Option<Rc<dyn Fn(&FormValues) -> ValidationState>>,
+    on_submit: Option<Rc<dyn Fn(&SubmitEvent, &mut Window, &mut App)>>,
+}
+
+impl FormController {
+    fn submit(&self, window: &mut Window, app: &mut App) {
+        let fields = self.state.read(app).fields.clone();
+        let mut values = FormValues::default();
+        let mut errors = HashMap::new();
+        let mut first_invalid = None;
+
+        for (name, field) in fields {
+            let value = (field.value)(app);
+            let mut validation = match &field.validate {
+                Some(validate) => validate(window, app),
+                None => ValidationState::Valid,
+            };
+            if validation.is_valid()
+                && let Some(validate) = self.field_validators.get(&name)
+            {
+                validation = validate(&value);
+            }
+            if let Some(message) = validation.message() {
+                errors.insert(name.clone(), message.clone());
+                if first_invalid.is_none() {
+                    first_invalid = field.focus_handle;
+                }
+            }
+            values.0.insert(name, value);
+        }
+
+        let form_error = self
+            .validate
+            .as_ref()
+            .and_then(|validate| validate(&values).message().cloned());
+
+        if let Some(focus_handle) = first_invalid {
+            window.focus(&focus_handle);
+        }
+        if let Some(on_submit) = &self.on_submit {
+            let event = SubmitEvent {
+                values,
+                errors,
+                form_error,
+            };
+            on_submit(&event, window, app);
+        }
+    }
+}
+
+impl RenderOnce for Form {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let id = self.id.clone();
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| FormState {
+            id,
+            fields: Vec::new(),
+            autosave: None,
+            saved_values: None,
+            autosave_task: None,
+        });
+        state.update(app, |state, cx| {
+            state.autosave = self.autosave;
+            state.schedule_autosave(window, cx);
+        });
+        let controller = FormController {
+            state: state.clone(),
+            field_validators: Rc::new(self.field_validators),
+            validate: self.validate,
+            on_submit: self.on_submit,
+        };
+
+        let element = self
+            .base
+            .id(self.id)
+            .on_action({
+                // Text fields let Enter through once they committed their value.
+                let controller = controller.clone();
+                move |_: &Enter, window, app| controller.submit(window, app)
+            })
+            .on_action(move |_: &SubmitForm, window, app| controller.submit(window, app))
+            .children(self.children)
+            .into_any_element();
+
+        FormScope { state, element }
+    }
+}
+
+/// Registers the fields laid out inside a [`Form`] with its state.
+struct FormScope {
+    state: Entity<FormState>,
+    element: AnyElement,
+}
+
+impl IntoElement for FormScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for FormScope {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        app: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        // Fields render while they are laid out, registering again on every frame.
+        self.state.update(app, |state, _| state.fields.clear());
+        app.default_global::<FormScopes>()
+            .0
+            .push(self.state.clone());
+        let layout_id = self.element.request_layout(window, app);
+        app.global_mut::<FormScopes>().0.pop();
+
+        // Autosaving starts from the values the form is first laid out with.
+        if self.state.read(app).saved_values.is_none() {
+            let values = self.state.read(app).values(app);
+            self.state
+                .update(app, |state, _| state.saved_values = Some(values));
+        }
+
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        app: &mut App,
+    ) -> Self::PrepaintState {
+        self.element.prepaint(window, app);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        self.element.paint(window, app);
+    }
+}
@@ -0,0 +1,237 @@
+#[cfg(test)]
+mod submit {
+    use crate::{
+        Form, FormValue, SubmitEvent, SubmitForm,
+        primitives::{button, checkbox},
+    };
+    use gpui::{
+        AnyElement, Context, IntoElement, KeyUpEvent, Keystroke, ParentElement, Render,
+        TestAppContext, VisualTestContext, Window,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    struct Submits {
+        events: Rc<RefCell<Vec<SubmitEvent>>>,
+        field: fn() -> AnyElement,
+    }
+
+    impl Render for Submits {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let events = self.events.clone();
+            Form::new("form")
+                .on_submit(move |event, _, _| events.borrow_mut().push(event.clone()))
+                .child((self.field)())
+        }
+    }
+
+    /// Focuses the only field of a form, then presses `key` on it.
+    fn submits(field: fn() -> AnyElement, key: &str, cx: &mut TestAppContext) -> Vec<SubmitEvent> {
+        cx.update(crate::init);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let (_, cx) = cx.add_window_view(|_, _| Submits {
+            events: events.clone(),
+            field,
+        });
+        cx.update(|window, _| window.focus_next());
+        cx.run_until_parked();
+        press(key, cx);
+        events.take()
+    }
+
+    /// Presses and releases a key, since focused elements are clicked on release.
+    fn press(key: &str, cx: &mut VisualTestContext) {
+        cx.simulate_keystrokes(key);
+        cx.simulate_event(KeyUpEvent {
+            keystroke: Keystroke::parse(key).unwrap(),
+        });
+    }
+
+    #[gpui::test]
+    fn enter_on_a_submit_button_submits_once(cx: &mut TestAppContext) {
+        let field = || {
+            button("submit")
+                .child("Submit")
+                .on_click(|_, window, app| window.dispatch_action(Box::new(SubmitForm), app))
+                .into_any_element()
+        };
+        assert_eq!(submits(field, "enter", cx).len(), 1);
+    }
+
+    #[gpui::test]
+    fn enter_on_a_checkbox_submits_once_without_toggling(cx: &mut TestAppContext) {
+        let field = || checkbox("terms").name("terms").into_any_element();
+        let events = submits(field, "enter", cx);
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].values.get("terms"),
+            Some(&FormValue::Checked(false))
+        );
+    }
+}
+
+#[cfg(test)]
+mod autosave {
+    use crate::{Form, FormSnapshot, primitives::text_field::text_field};
+    use gpui::{
+        Context, IntoElement, ParentElement, Render, TestAppContext, VisualTestContext, Window,
+    };
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    const INTERVAL: Duration = Duration::from_secs(1);
+
+    struct Drafts {
+        saved: Rc<RefCell<Vec<FormSnapshot>>>,
+    }
+
+    impl Render for Drafts {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let saved = self.saved.clone();
+            Form::new("form")
+                .autosave(INTERVAL, move |snapshot, _, _| {
+                    saved.borrow_mut().push(snapshot.clone())
+                })
+                .child(text_field("title").name("title"))
+        }
+    }
+
+    /// Renders the form with its only field focused.
+    fn drafts(cx: &mut TestAppContext) -> (Rc<RefCell<Vec<FormSnapshot>>>, &mut VisualTestContext) {
+        cx.update(crate::init);
+        let saved = Rc::new(RefCell::new(Vec::new()));
+        let (_, cx) = cx.add_window_view(|_, _| Drafts {
+            saved: saved.clone(),
+        });
+        cx.update(|window, _| window.focus_next());
+        cx.run_until_parked();
+        (saved, cx)
+    }
+
+    fn wait(duration: Duration, cx: &mut VisualTestContext) {
+        cx.executor().advance_clock(duration);
+        cx.run_until_parked();
+    }
+
+    fn titles(saved: &RefCell<Vec<FormSnapshot>>) -> Vec<String> {
+        saved
+            .borrow()
+            .iter()
+            .map(|snapshot| snapshot.values.text("title").to_string())
+            .collect()
+    }
+
+    #[gpui::test]
+    fn saves_at_most_once_per_interval(cx: &mut TestAppContext) {
+        let (saved, cx) = drafts(cx);
+        cx.simulate_input("a");
+        wait(INTERVAL / 4, cx);
+        cx.simulate_input("b");
+        wait(INTERVAL / 4, cx);
+        cx.simulate_input("c");
+        assert!(saved.borrow().is_empty());
+
+        wait(INTERVAL / 2, cx);
+        assert_eq!(titles(&saved), ["abc"]);
+        assert_eq!(saved.borrow()[0].id, "form".into());
+    }
+
+    #[gpui::test]
+    fn saves_only_when_the_values_changed(cx: &mut TestAppContext) {
+        let (saved, cx) = drafts(cx);
+        wait(INTERVAL * 3, cx);
+        assert!(saved.borrow().is_empty());
+
+        cx.simulate_input("a");
+        wait(INTERVAL * 3, cx);
+        assert_eq!(titles(&saved), ["a"]);
+
+        cx.simulate_input("b");
+        wait(INTERVAL, cx);
+        assert_eq!(titles(&saved), ["a", "ab"]);
+    }
+}
+
+#[cfg(test)]
+mod focus_invalid {
+    use crate::{
+        Form, FormField, FormValue, SubmitEvent,
+        form::register_form_field,
+        primitives::{
+            checkbox,
+            text_field::{ValidationState, text_field},
+        },
+    };
+    use gpui::{
+        App, Context, IntoElement, KeyUpEvent, Keystroke, ParentElement, Render, RenderOnce,
+        TestAppContext, VisualTestContext, Window, div,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    /// A field that is always invalid and can't take focus.
+    #[derive(IntoElement)]
+    struct Unfocusable;
+
+    impl RenderOnce for Unfocusable {
+        fn render(self, _: &mut Window, app: &mut App) -> impl IntoElement {
+            let field = FormField {
+                value: Rc::new(|_| FormValue::Text("".into())),
+                validate: Some(Rc::new(|_, _| ValidationState::invalid("Never valid"))),
+                focus_handle: None,
+            };
+            register_form_field("hidden".into(), field, app);
+            div()
+        }
+    }
+
+    struct Signup {
+        events: Rc<RefCell<Vec<SubmitEvent>>>,
+    }
+
+    impl Render for Signup {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let events = self.events.clone();
+            Form::new("form")
+                .on_submit(move |event, _, _| events.borrow_mut().push(event.clone()))
+                .child(Unfocusable)
+                .child(
+                    text_field("email")
+                        .name("email")
+                        .validate(|value| match value.is_empty() {
+                            true => ValidationState::invalid("Required"),
+                            false => ValidationState::Valid,
+                        }),
+                )
+                .child(checkbox("terms").name("terms"))
+        }
+    }
+
+    fn press(key: &str, cx: &mut VisualTestContext) {
+        cx.simulate_keystrokes(key);
+        cx.simulate_event(KeyUpEvent {
+            keystroke: Keystroke::parse(key).unwrap(),
+        });
+    }
+
+    #[gpui::test]
+    fn focuses_the_first_invalid_field_that_can_be_focused(cx: &mut TestAppContext) {
+        cx.update(crate::init);
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let (_, cx) = cx.add_window_view(|_, _| Signup {
+            events: events.clone(),
+        });
+        // Submit from the checkbox, after the email field.
+        cx.update(|window, _| {
+            window.focus_next();
+            window.focus_next();
+        });
+        cx.run_until_parked();
+        press("enter", cx);
+        assert_eq!(events.borrow().len(), 1);
+
+        // Typing goes to the email field, which submits again on Enter.
+        cx.simulate_input("a@b");
+        press("enter", cx);
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].values.text("email"), "a@b");
+    }
+}
@@ -0,0 +1,53 @@
+use gpui::{App, Global, Keystroke};
+
+/// The kind of device used for the most recent interaction.
+///
+/// Kept up to date by [`crate::LapislazuliProvider`], and used to decide whether focus
+/// should be visible. Apps can also read it to hide hover-only affordances from
+/// keyboard users.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputModality {
+    #[default]
+    Pointer,
+    Keyboard,
+}
+
+impl InputModality {
+    /// Returns the current modality, or [`InputModality::Pointer`] if the provider was not initialized.
+    pub fn current(app: &App) -> Self {
+        app.try_global::<InputModality>()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn is_keyboard(&self) -> bool {
+        *self == InputModality::Keyboard
+    }
+}
+
+impl Global for InputModality {}
+
+/// Emitted by [`crate::LapislazuliProvider`] when the input modality changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InputModalityChanged {
+    pub modality: InputModality,
+}
+
+/// Whether a key press counts as keyboard interaction.
+///
+/// As with `:focus-visible` on the web, shortcuts held with Control, Alt or the platform key
+/// don't, so copying after clicking into a field leaves its focus ring hidden.
+pub(crate) fn is_keyboard_interaction(keystroke: &Keystroke) -> bool {
+    let modifiers = keystroke.modifiers;
+    !(modifiers.control || modifiers.alt || modifiers.platform)
+}
+
+/// Updates the current modality, returning whether it changed.
+pub(crate) fn set_input_modality(modality: InputModality, app: &mut App) -> bool {
+    if InputModality::current(app) == modality {
+        return false;
+    }
+
+    app.set_global(modality);
+    true
+}
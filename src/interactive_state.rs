@@ -0,0 +1,131 @@
+use crate::InteractiveStateStyled;
+use gpui::{App, ElementId, Entity, MouseButton, StatefulInteractiveElement, Window};
+
+/// The handlers of an [`crate::InteractiveStateStyled`] component.
+#[allow(clippy::type_complexity)]
+pub struct InteractiveStateHandlers<T> {
+    pub hovered: Option<Box<dyn FnOnce(T) -> T>>,
+    pub active: Option<Box<dyn FnOnce(T) -> T>>,
+    pub focused: Option<Box<dyn FnOnce(T) -> T>>,
+}
+
+impl<T> Default for InteractiveStateHandlers<T> {
+    fn default() -> Self {
+        Self {
+            hovered: None,
+            active: None,
+            focused: None,
+        }
+    }
+}
+
+impl<T> InteractiveStateHandlers<T> {
+    /// Applies the handlers matching the state of the component, hovered first and active
+    /// last so pressing wins over hovering.
+    fn apply(self, mut component: T, interaction: Interaction, focused: bool) -> T {
+        if interaction.hovered
+            && let Some(handler) = self.hovered
+        {
+            component = handler(component);
+        }
+        if focused && let Some(handler) = self.focused {
+            component = handler(component);
+        }
+        if interaction.active
+            && let Some(handler) = self.active
+        {
+            component = handler(component);
+        }
+        component
+    }
+
+    /// Whether hover and press need to be tracked for the handlers to apply.
+    fn tracks_pointer(&self) -> bool {
+        self.hovered.is_some() || self.active.is_some()
+    }
+}
+
+/// Whether a component is hovered or pressed with the mouse.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Interaction {
+    pub hovered: bool,
+    pub active: bool,
+}
+
+/// Applies the handlers of an [`InteractiveStateStyled`] component for its current state,
+/// returning the component and the state to pass on to [`track_interaction`].
+///
+/// `pressed` makes the component active without the mouse, such as a button held with the
+/// keyboard. Disabled components are never active.
+pub(crate) fn apply_interactive_state<T: InteractiveStateStyled>(
+    id: ElementId,
+    disabled: bool,
+    pressed: bool,
+    focused: bool,
+    mut component: T,
+    window: &mut Window,
+    app: &mut App,
+) -> (T, Option<Entity<Interaction>>) {
+    let handlers = std::mem::take(component.interactive_state_handlers_mut());
+    let interaction = use_interaction(&id, &handlers, window, app);
+    let mut current = interaction
+        .as_ref()
+        .map(|state| *state.read(app))
+        .unwrap_or_default();
+    current.active = (current.active || pressed) && !disabled;
+    (handlers.apply(component, current, focused), interaction)
+}
+
+/// Returns the hover and press state of a component, kept across renders, or `None` when
+/// none of its handlers needs it.
+fn use_interaction<T>(
+    id: &ElementId,
+    handlers: &InteractiveStateHandlers<T>,
+    window: &mut Window,
+    app: &mut App,
+) -> Option<Entity<Interaction>> {
+    handlers.tracks_pointer().then(|| {
+        let id = ElementId::NamedChild(Box::new(id.clone()), "interaction".into());
+        window.use_keyed_state(id, app, |_, _| Interaction::default())
+    })
+}
+
+/// Keeps the state returned by [`apply_interactive_state`] up to date with the pointer.
+///
+/// Attach it before the component's own mouse listeners, which may stop propagation.
+pub(crate) fn track_interaction<E: StatefulInteractiveElement>(
+    element: E,
+    state: Option<Entity<Interaction>>,
+) -> E {
+    let Some(state) = state else {
+        return element;
+    };
+
+    let set_active = |state: &Entity<Interaction>, active: bool, app: &mut App| {
+        state.update(app, |state, cx| {
+            if state.active != active {
+                state.active = active;
+                cx.notify();
+            }
+        })
+    };
+    element
+        .on_mouse_down(MouseButton::Left, {
+            let state = state.clone();
+            move |_, _, app| set_active(&state, true, app)
+        })
+        .on_mouse_up(MouseButton::Left, {
+            let state = state.clone();
+            move |_, _, app| set_active(&state, false, app)
+        })
+        .on_mouse_up_out(MouseButton::Left, {
+            let state = state.clone();
+            move |_, _, app| set_active(&state, false, app)
+        })
+        .on_hover(move |hovered, _, app| {
+            state.update(app, |state, cx| {
+                state.hovered = *hovered;
+                cx.notify();
+            })
+        })
+}
@@ -0,0 +1,84 @@
+use gpui::{Action, DummyKeyboardMapper, KeyBinding, NoAction};
+
+/// Keybinding overrides applied on top of the crate's default bindings.
+///
+/// Bindings added here take precedence over the defaults registered for text fields and
+/// Tab/Shift-Tab navigation, so rebinding an existing keystroke replaces its default action.
+/// Actions can also be left without their default keystrokes, for instance to move Redo to
+/// a single keystroke on every platform.
+///
+/// Every keymap applies, whether it is given to [`crate::init_with_keymap`] or to a
+/// [`crate::LapislazuliProvider`]. The defaults are registered once, and a later keymap that
+/// replaces them or unbinds an action disables the matching defaults from then on, along
+/// with any earlier binding for the same keystrokes.
+///
+/// # Example
+///
+/// ```rust
+/// let keymap = Keymap::new()
+///     .bind("ctrl-shift-z", text_field::actions::Redo, Some(text_field::CONTEXT))
+///     .unbind("ctrl-y", Some(text_field::CONTEXT))
+///     .unbind_action::<text_field::actions::ShowCharacterPalette>();
+///
+/// LapislazuliProvider::with_keymap(view, keymap, window, app)
+/// ```
+#[derive(Default)]
+pub struct Keymap {
+    pub(crate) bindings: Vec<KeyBinding>,
+    replace_defaults: bool,
+    unbound_actions: Vec<&'static str>,
+}
+
+/// Disables a binding that is already registered, for the same keystrokes and context.
+pub(crate) fn disabled_binding(binding: &KeyBinding) -> KeyBinding {
+    let keystrokes = binding
+        .keystrokes()
+        .iter()
+        .map(|keystroke| keystroke.unparse())
+        .collect::<Vec<_>>()
+        .join(" ");
+    KeyBinding::load(
+        &keystrokes,
+        Box::new(NoAction {}),
+        binding.predicate(),
+        false,
+        None,
+        &DummyKeyboardMapper,
+    )
+    .expect("registered keystrokes parse")
+}
+
+impl Keymap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds a keystroke sequence to an action within an optional key context.
+    pub fn bind(mut self, keystrokes: &str, action: impl Action, context: Option<&str>) -> Self {
+        self.bindings
+            .push(KeyBinding::new(keystrokes, action, context));
+        self
+    }
+
+    /// Disables a keystroke sequence within an optional key context.
+    pub fn unbind(self, keystrokes: &str, context: Option<&str>) -> Self {
+        self.bind(keystrokes, NoAction {}, context)
+    }
+
+    /// Skips every default binding of the action, keeping the ones added to this keymap.
+    pub fn unbind_action<A: Action>(mut self) -> Self {
+        self.unbound_actions.push(A::name_for_type());
+        self
+    }
+
+    /// Whether the keymap leaves the default binding out.
+    pub(crate) fn removes_default(&self, binding: &KeyBinding) -> bool {
+        self.replace_defaults || self.unbound_actions.contains(&binding.action().name())
+    }
+
+    /// Skips the default bindings so only the bindings in this keymap apply.
+    pub fn replace_defaults(mut self, replace_defaults: bool) -> Self {
+        self.replace_defaults = replace_defaults;
+        self
+    }
+}
@@ -0,0 +1,376 @@
+use gpui::{
+    AnyElement, AnyWindowHandle, App, Bounds, Element, ElementId, Empty, Global, GlobalElementId,
+    InspectorElementId, InteractiveElement, IntoElement, LayoutId, MouseButton, ParentElement,
+    Pixels, Styled, Window, div, prelude::FluentBuilder,
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// A named layer rendered above the application view.
+///
+/// Overlays on higher layers are drawn on top of lower ones; overlays on the same
+/// layer are stacked in the order they were opened.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Layer {
+    Popover,
+    Modal,
+    Toast,
+    Tooltip,
+    Custom(i32),
+}
+
+impl Layer {
+    pub fn order(&self) -> i32 {
+        match self {
+            Layer::Popover => 100,
+            Layer::Modal => 200,
+            Layer::Toast => 300,
+            Layer::Tooltip => 400,
+            Layer::Custom(order) => *order,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OverlayId(usize);
+
+/// An element pushed onto a [`Layer`] by [`open_overlay`].
+///
+/// The content is laid out from the top-left corner of the window, so position it
+/// with margins or absolute offsets.
+#[allow(clippy::type_complexity)]
+pub struct Overlay {
+    layer: Layer,
+    render: Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>,
+    dismiss_on_escape: bool,
+    dismiss_on_outside_click: bool,
+    modal: bool,
+    on_dismiss: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+}
+
+impl Overlay {
+    pub fn new<E: IntoElement>(
+        layer: Layer,
+        render: impl Fn(&mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        Self {
+            layer,
+            render: Rc::new(move |window, app| render(window, app).into_any_element()),
+            dismiss_on_escape: true,
+            dismiss_on_outside_click: true,
+            modal: false,
+            on_dismiss: None,
+        }
+    }
+
+    /// Whether pressing Escape closes the overlay while it is the topmost one.
+    pub fn dismiss_on_escape(mut self, dismiss: bool) -> Self {
+        self.dismiss_on_escape = dismiss;
+        self
+    }
+
+    /// Whether clicking outside the overlay closes it while it is the topmost one.
+    pub fn dismiss_on_outside_click(mut self, dismiss: bool) -> Self {
+        self.dismiss_on_outside_click = dismiss;
+        self
+    }
+
+    /// Renders a full-window backdrop behind the overlay that blocks pointer
+    /// interaction with everything underneath it, and locks scrolling of the
+    /// application view while the overlay is open.
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    /// Called when the overlay is closed by Escape or an outside click.
+    pub fn on_dismiss(mut self, on_dismiss: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_dismiss = Some(Rc::new(on_dismiss));
+        self
+    }
+}
+
+#[derive(Clone)]
+struct OverlayEntry {
+    id: OverlayId,
+    window: AnyWindowHandle,
+    overlay: Rc<Overlay>,
+}
+
+#[derive(Default)]
+struct Overlays {
+    entries: Vec<OverlayEntry>,
+    next_id: usize,
+}
+
+impl Global for Overlays {}
+
+/// Pushes an overlay on top of the current window's layer stack.
+pub fn open_overlay(overlay: Overlay, window: &mut Window, app: &mut App) -> OverlayId {
+    let overlays = app.default_global::<Overlays>();
+    let id = OverlayId(overlays.next_id);
+    overlays.next_id += 1;
+    overlays.entries.push(OverlayEntry {
+        id,
+        window: window.window_handle(),
+        overlay: Rc::new(overlay),
+    });
+
+    // Keep overlays sorted by layer, preserving insertion order within a layer.
+    overlays
+        .entries
+        .sort_by_key(|entry| entry.overlay.layer.order());
+    window.refresh();
+    id
+}
+
+/// Removes an overlay without calling its dismiss handler.
+pub fn close_overlay(id: OverlayId, app: &mut App) {
+    if let Some(overlays) = app.try_global::<Overlays>()
+        && overlays.entries.iter().any(|entry| entry.id == id)
+    {
+        app.global_mut::<Overlays>()
+            .entries
+            .retain(|entry| entry.id != id);
+        app.refresh_windows();
+    }
+}
+
+/// Returns whether the overlay is still open.
+pub fn is_overlay_open(id: OverlayId, app: &App) -> bool {
+    app.try_global::<Overlays>()
+        .is_some_and(|overlays| overlays.entries.iter().any(|entry| entry.id == id))
+}
+
+fn window_entries(window: &Window, app: &App) -> Vec<OverlayEntry> {
+    let handle = window.window_handle();
+    app.try_global::<Overlays>()
+        .map(|overlays| {
+            overlays
+                .entries
+                .iter()
+                .filter(|entry| entry.window == handle)
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns whether a modal overlay is open in the current window.
+pub(crate) fn has_modal_overlay(window: &Window, app: &App) -> bool {
+    window_entries(window, app)
+        .iter()
+        .any(|entry| entry.overlay.modal)
+}
+
+fn dismiss(entry: &OverlayEntry, window: &mut Window, app: &mut App) {
+    if !is_overlay_open(entry.id, app) {
+        return;
+    }
+
+    close_overlay(entry.id, app);
+    if let Some(on_dismiss) = &entry.overlay.on_dismiss {
+        on_dismiss(window, app);
+    }
+}
+
+/// Dismisses the topmost overlay in the window that closes on Escape.
+///
+/// Returns whether an overlay was dismissed.
+pub(crate) fn dismiss_topmost_on_escape(window: &mut Window, app: &mut App) -> bool {
+    let entries = window_entries(window, app);
+    match entries.last() {
+        Some(entry) if entry.overlay.dismiss_on_escape => {
+            dismiss(entry, window, app);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Renders the overlays of the current window, bottom layer first.
+fn render_overlays(window: &mut Window, app: &mut App) -> Vec<AnyElement> {
+    let entries = window_entries(window, app);
+    let topmost = entries.last().map(|entry| entry.id);
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let content = (entry.overlay.render)(window, app);
+            let dismissable = Some(entry.id) == topmost && entry.overlay.dismiss_on_outside_click;
+
+            div()
+                .absolute()
+                .inset_0()
+                .when(entry.overlay.modal, |this| {
+                    let entry = entry.clone();
+                    this.child(
+                        div()
+                            .id(("lapislazuli-overlay-backdrop", entry.id.0))
+                            .absolute()
+                            .inset_0()
+                            .occlude()
+                            .when(dismissable, |this| {
+                                this.on_mouse_down(MouseButton::Left, move |_, window, app| {
+                                    dismiss(&entry, window, app)
+                                })
+                            }),
+                    )
+                })
+                .child(
+                    div()
+                        .id(("lapislazuli-overlay", entry.id.0))
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .occlude()
+                        .when(dismissable, |this| {
+                            let entry = entry.clone();
+                            this.on_mouse_down_out(move |_, window, app| {
+                                dismiss(&entry, window, app)
+                            })
+                        })
+                        .child(content),
+                )
+                .into_any_element()
+        })
+        .collect()
+}
+
+/// The overlays of the current window, rendered above the application view by
+/// [`LapislazuliProvider`](crate::LapislazuliProvider).
+///
+/// The overlays are only rendered once the stack is laid out, after the application view,
+/// so those opened while rendering the view show up in the same frame.
+pub(crate) fn overlay_stack() -> OverlayStack {
+    OverlayStack
+}
+
+pub(crate) struct OverlayStack;
+
+impl IntoElement for OverlayStack {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for OverlayStack {
+    type RequestLayoutState = AnyElement;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        app: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut element = div()
+            .absolute()
+            .inset_0()
+            .children(render_overlays(window, app))
+            .into_any_element();
+        (element.request_layout(window, app), element)
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        app: &mut App,
+    ) -> Self::PrepaintState {
+        element.prepaint(window, app);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        element.paint(window, app);
+    }
+}
+
+/// The overlay a component keeps open through [`use_overlay`].
+struct ComponentOverlay {
+    id: Option<OverlayId>,
+    /// The content rendered by the component this frame, taken by the overlay.
+    content: Rc<RefCell<Option<AnyElement>>>,
+}
+
+/// Keeps an overlay on `layer` open while a component renders `content`, and closes it
+/// once the component renders `None` or stops being rendered.
+///
+/// `overlay` configures the overlay when it is opened; its content is always the one
+/// the component rendered in the current frame.
+pub(crate) fn use_overlay(
+    id: &ElementId,
+    layer: Layer,
+    content: Option<AnyElement>,
+    overlay: impl FnOnce(Overlay) -> Overlay,
+    window: &mut Window,
+    app: &mut App,
+) {
+    let state = window.use_keyed_state(
+        ElementId::NamedChild(Box::new(id.clone()), "overlay".into()),
+        app,
+        |_, cx| {
+            cx.on_release(|state: &mut ComponentOverlay, app| {
+                if let Some(id) = state.id.take() {
+                    close_overlay(id, app);
+                }
+            })
+            .detach();
+            ComponentOverlay {
+                id: None,
+                content: Rc::default(),
+            }
+        },
+    );
+
+    let (open_id, slot) = {
+        let state = state.read(app);
+        (
+            state.id.filter(|id| is_overlay_open(*id, app)),
+            state.content.clone(),
+        )
+    };
+    match content {
+        Some(content) => {
+            *slot.borrow_mut() = Some(content);
+            if open_id.is_none() {
+                let overlay = overlay(Overlay::new(layer, move |_, _| {
+                    slot.borrow_mut()
+                        .take()
+                        .unwrap_or_else(|| Empty.into_any_element())
+                }));
+                let id = open_overlay(overlay, window, app);
+                state.update(app, |state, _| state.id = Some(id));
+            }
+        }
+        None => {
+            slot.borrow_mut().take();
+            if let Some(id) = open_id {
+                close_overlay(id, app);
+            }
+            state.update(app, |state, _| state.id = None);
+        }
+    }
+}
@@ -1,7 +1,46 @@
+mod accessibility;
+mod appearance;
 pub mod components;
 mod context;
+mod density;
+mod direction;
+mod disabled_scope;
+mod drag;
+mod event_bus;
+mod focus_group;
+mod form;
+mod input_modality;
+mod interactive_state;
+mod keymap;
+mod layers;
+mod motion;
+mod open_state;
 pub mod primitives;
+mod scroll_into_view;
+mod scroll_lock;
+mod selection;
+mod theme;
 mod traits;
+mod typeahead;
+mod window_info;
 
+pub use accessibility::*;
+pub use appearance::*;
 pub use context::*;
+pub use density::*;
+pub use direction::*;
+pub use disabled_scope::*;
+pub use event_bus::*;
+pub use form::*;
+pub use input_modality::*;
+pub use interactive_state::*;
+pub use keymap::*;
+pub use layers::*;
+pub use motion::*;
+pub use open_state::*;
+pub use scroll_into_view::*;
+pub use scroll_lock::*;
+pub use selection::*;
+pub use theme::*;
 pub use traits::*;
+pub use window_info::*;
@@ -0,0 +1,49 @@
+use gpui::{App, Global};
+use std::time::Duration;
+
+const REDUCED_DURATION: Duration = Duration::from_millis(1);
+
+/// Whether the user prefers animations to be reduced.
+///
+/// gpui doesn't report the operating system setting, so apps that read it from the
+/// platform should apply it with [`set_motion_preference`]. Built-in animations,
+/// such as the text field cursor blink and toasts fading in, consult this before
+/// animating.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MotionPreference {
+    #[default]
+    NoPreference,
+    Reduce,
+}
+
+impl MotionPreference {
+    /// Returns the current preference, or [`MotionPreference::NoPreference`] if none was set.
+    pub fn current(app: &App) -> Self {
+        app.try_global::<MotionPreference>()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn is_reduced(&self) -> bool {
+        *self == MotionPreference::Reduce
+    }
+
+    /// Returns the duration an animation should run for, which is effectively instant when
+    /// motion is reduced.
+    pub fn duration(&self, duration: Duration) -> Duration {
+        if self.is_reduced() {
+            // gpui animations divide by their duration, so they can't be zero.
+            REDUCED_DURATION
+        } else {
+            duration
+        }
+    }
+}
+
+impl Global for MotionPreference {}
+
+/// Sets the motion preference and redraws all windows.
+pub fn set_motion_preference(preference: MotionPreference, app: &mut App) {
+    app.set_global(preference);
+    app.refresh_windows();
+}
@@ -0,0 +1,113 @@
+use gpui::{App, Context, ElementId, Entity, EventEmitter, Window};
+use std::rc::Rc;
+
+/// What caused a disclosure component to open or close.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpenReason {
+    Trigger,
+    EscapeKey,
+    OutsideClick,
+    Hover,
+    Programmatic,
+}
+
+/// Emitted by an [`OpenState`] when it is asked to open or close.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpenChangeEvent {
+    pub open: bool,
+    pub reason: OpenReason,
+}
+
+/// The open-related builder values of an [`crate::Openable`] component.
+#[allow(clippy::type_complexity)]
+#[derive(Clone, Default)]
+pub struct OpenProps {
+    pub open: Option<bool>,
+    pub default_open: bool,
+    pub on_open_change: Option<Rc<dyn Fn(&OpenChangeEvent, &mut Window, &mut App) + 'static>>,
+}
+
+/// Open state shared by disclosure components such as dialogs, popovers and menus.
+///
+/// When the component is controlled with `.open(..)`, requests to change the state only
+/// emit [`OpenChangeEvent`] and call `on_open_change`, leaving the value to the owner.
+/// Otherwise the state updates itself, starting from `.default_open(..)`.
+#[allow(clippy::type_complexity)]
+pub struct OpenState {
+    open: bool,
+    controlled: bool,
+    on_open_change: Option<Rc<dyn Fn(&OpenChangeEvent, &mut Window, &mut App) + 'static>>,
+}
+
+impl OpenState {
+    pub fn new(open: bool) -> Self {
+        Self {
+            open,
+            controlled: false,
+            on_open_change: None,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self, reason: OpenReason, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_open(true, reason, window, cx);
+    }
+
+    pub fn close(&mut self, reason: OpenReason, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_open(false, reason, window, cx);
+    }
+
+    pub fn toggle(&mut self, reason: OpenReason, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_open(!self.open, reason, window, cx);
+    }
+
+    pub fn set_open(
+        &mut self,
+        open: bool,
+        reason: OpenReason,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.open == open {
+            return;
+        }
+
+        if !self.controlled {
+            self.open = open;
+            cx.notify();
+        }
+
+        let event = OpenChangeEvent { open, reason };
+        cx.emit(event);
+        if let Some(on_open_change) = self.on_open_change.clone() {
+            on_open_change(&event, window, cx);
+        }
+    }
+
+    /// Applies the component's builder values, called on every render.
+    pub fn sync(&mut self, props: &OpenProps) {
+        if let Some(open) = props.open {
+            self.open = open;
+        }
+        self.controlled = props.open.is_some();
+        self.on_open_change = props.on_open_change.clone();
+    }
+}
+
+impl EventEmitter<OpenChangeEvent> for OpenState {}
+
+/// Returns the [`OpenState`] of the element with the given id, synced with its props.
+pub fn use_open_state(
+    id: impl Into<ElementId>,
+    props: &OpenProps,
+    window: &mut Window,
+    app: &mut App,
+) -> Entity<OpenState> {
+    let default_open = props.default_open;
+    let state = window.use_keyed_state(id, app, |_, _| OpenState::new(default_open));
+    state.update(app, |state, _| state.sync(props));
+    state
+}
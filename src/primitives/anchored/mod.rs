@@ -0,0 +1,373 @@
+//! Anchored positioning for floating elements
+//!
+//! Places a floating element next to an anchor's bounds, flipping it to the opposite
+//! side and shifting it along the anchor when it would overflow the window. Used by
+//! popovers, menus, tooltips and other floating components.
+
+use gpui::{
+    AnyElement, App, Bounds, Display, Element, ElementId, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, MouseDownEvent, ParentElement, Pixels, Point, Position, Size, Style,
+    Window, point, px,
+};
+use smallvec::SmallVec;
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+
+/// The side of the anchor the floating element is placed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Side {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    pub fn opposite(&self) -> Side {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    fn is_vertical(&self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+}
+
+/// How the floating element is aligned along the anchor's edge.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Align {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnchoredOptions {
+    pub side: Side,
+    pub align: Align,
+    /// Distance between the anchor and the floating element.
+    pub offset: Pixels,
+    /// Move to the opposite side when the preferred side overflows.
+    pub flip: bool,
+    /// Slide along the anchor to stay within the viewport.
+    pub shift: bool,
+    /// Minimum distance kept from the viewport edges.
+    pub padding: Pixels,
+}
+
+impl Default for AnchoredOptions {
+    fn default() -> Self {
+        Self {
+            side: Side::Bottom,
+            align: Align::Center,
+            offset: px(0.),
+            flip: true,
+            shift: true,
+            padding: px(0.),
+        }
+    }
+}
+
+/// The resolved position of a floating element.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    pub bounds: Bounds<Pixels>,
+    /// The side that was used, which differs from the preferred one after a flip.
+    pub side: Side,
+}
+
+/// Computes where to place a floating element of the given size next to the anchor.
+pub fn compute_placement(
+    anchor: Bounds<Pixels>,
+    floating: Size<Pixels>,
+    viewport: Bounds<Pixels>,
+    options: &AnchoredOptions,
+) -> Placement {
+    let mut side = options.side;
+    let mut origin = place(anchor, floating, side, options.align, options.offset);
+
+    if options.flip {
+        let overflow = main_axis_overflow(origin, floating, viewport, side, options.padding);
+        if overflow > px(0.) {
+            let flipped_side = side.opposite();
+            let flipped = place(
+                anchor,
+                floating,
+                flipped_side,
+                options.align,
+                options.offset,
+            );
+            let flipped_overflow =
+                main_axis_overflow(flipped, floating, viewport, flipped_side, options.padding);
+            if flipped_overflow < overflow {
+                side = flipped_side;
+                origin = flipped;
+            }
+        }
+    }
+
+    if options.shift {
+        let min = viewport.origin + point(options.padding, options.padding);
+        let max = viewport.bottom_right() - point(options.padding, options.padding);
+        if side.is_vertical() {
+            origin.x = clamp_axis(origin.x, floating.width, min.x, max.x);
+        } else {
+            origin.y = clamp_axis(origin.y, floating.height, min.y, max.y);
+        }
+    }
+
+    Placement {
+        bounds: Bounds::new(origin, floating),
+        side,
+    }
+}
+
+fn place(
+    anchor: Bounds<Pixels>,
+    floating: Size<Pixels>,
+    side: Side,
+    align: Align,
+    offset: Pixels,
+) -> Point<Pixels> {
+    let align_on = |start: Pixels, anchor_len: Pixels, floating_len: Pixels| match align {
+        Align::Start => start,
+        Align::Center => start + (anchor_len - floating_len) * 0.5,
+        Align::End => start + anchor_len - floating_len,
+    };
+
+    match side {
+        Side::Top => point(
+            align_on(anchor.left(), anchor.size.width, floating.width),
+            anchor.top() - floating.height - offset,
+        ),
+        Side::Bottom => point(
+            align_on(anchor.left(), anchor.size.width, floating.width),
+            anchor.bottom() + offset,
+        ),
+        Side::Left => point(
+            anchor.left() - floating.width - offset,
+            align_on(anchor.top(), anchor.size.height, floating.height),
+        ),
+        Side::Right => point(
+            anchor.right() + offset,
+            align_on(anchor.top(), anchor.size.height, floating.height),
+        ),
+    }
+}
+
+/// How far the element spills out of the viewport on the side it is placed on.
+fn main_axis_overflow(
+    origin: Point<Pixels>,
+    floating: Size<Pixels>,
+    viewport: Bounds<Pixels>,
+    side: Side,
+    padding: Pixels,
+) -> Pixels {
+    let overflow = match side {
+        Side::Top => viewport.top() + padding - origin.y,
+        Side::Bottom => origin.y + floating.height - (viewport.bottom() - padding),
+        Side::Left => viewport.left() + padding - origin.x,
+        Side::Right => origin.x + floating.width - (viewport.right() - padding),
+    };
+    overflow.max(px(0.))
+}
+
+/// Keeps a segment within `min..max`, preferring the start when it doesn't fit.
+fn clamp_axis(start: Pixels, len: Pixels, min: Pixels, max: Pixels) -> Pixels {
+    if start + len > max {
+        (max - len).max(min)
+    } else {
+        start.max(min)
+    }
+}
+
+pub fn floating(anchor: Bounds<Pixels>) -> Floating {
+    Floating {
+        anchor,
+        options: AnchoredOptions::default(),
+        children: SmallVec::new(),
+        on_mouse_down_out: None,
+    }
+}
+
+/// An element that positions its children next to an anchor using [`compute_placement`].
+///
+/// The anchor bounds are in window coordinates, e.g. captured from a trigger's
+/// `on_children_prepainted` or a `canvas`.
+#[allow(clippy::type_complexity)]
+pub struct Floating {
+    anchor: Bounds<Pixels>,
+    options: AnchoredOptions,
+    children: SmallVec<[AnyElement; 2]>,
+    on_mouse_down_out: Option<Rc<dyn Fn(&MouseDownEvent, &mut Window, &mut App)>>,
+}
+
+impl Floating {
+    pub fn side(mut self, side: Side) -> Self {
+        self.options.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.options.align = align;
+        self
+    }
+
+    pub fn offset(mut self, offset: Pixels) -> Self {
+        self.options.offset = offset;
+        self
+    }
+
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.options.flip = flip;
+        self
+    }
+
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.options.shift = shift;
+        self
+    }
+
+    pub fn padding(mut self, padding: Pixels) -> Self {
+        self.options.padding = padding;
+        self
+    }
+
+    pub fn options(mut self, options: AnchoredOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Called when a mouse button is pressed outside the placed bounds.
+    pub fn on_mouse_down_out(
+        mut self,
+        listener: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_mouse_down_out = Some(Rc::new(listener));
+        self
+    }
+}
+
+impl ParentElement for Floating {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl IntoElement for Floating {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+pub struct FloatingLayoutState {
+    child_layout_ids: SmallVec<[LayoutId; 2]>,
+}
+
+impl Element for Floating {
+    type RequestLayoutState = FloatingLayoutState;
+    type PrepaintState = Option<Bounds<Pixels>>;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        app: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let child_layout_ids = self
+            .children
+            .iter_mut()
+            .map(|child| child.request_layout(window, app))
+            .collect::<SmallVec<_>>();
+
+        let style = Style {
+            position: Position::Absolute,
+            display: Display::Flex,
+            ..Style::default()
+        };
+
+        let layout_id = window.request_layout(style, child_layout_ids.iter().copied(), app);
+        (layout_id, FloatingLayoutState { child_layout_ids })
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        app: &mut App,
+    ) -> Self::PrepaintState {
+        if request_layout.child_layout_ids.is_empty() {
+            return None;
+        }
+
+        let mut child_min = point(Pixels::MAX, Pixels::MAX);
+        let mut child_max = Point::default();
+        for child_layout_id in &request_layout.child_layout_ids {
+            let child_bounds = window.layout_bounds(*child_layout_id);
+            child_min = child_min.min(&child_bounds.origin);
+            child_max = child_max.max(&child_bounds.bottom_right());
+        }
+        let size: Size<Pixels> = (child_max - child_min).into();
+
+        let viewport = Bounds {
+            origin: Point::default(),
+            size: window.viewport_size(),
+        };
+        let placement = compute_placement(self.anchor, size, viewport, &self.options);
+
+        let offset = placement.bounds.origin - bounds.origin;
+        let offset = point(offset.x.round(), offset.y.round());
+        window.with_element_offset(offset, |window| {
+            for child in &mut self.children {
+                child.prepaint(window, app);
+            }
+        });
+
+        Some(placement.bounds)
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        app: &mut App,
+    ) {
+        for child in &mut self.children {
+            child.paint(window, app);
+        }
+
+        if let (Some(placed_bounds), Some(listener)) = (*prepaint, self.on_mouse_down_out.clone()) {
+            window.on_mouse_event(move |event: &MouseDownEvent, phase, window, app| {
+                if phase.bubble() && !placed_bounds.contains(&event.position) {
+                    listener(event, window, app);
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod placement {
+    use crate::primitives::anchored::{Align, AnchoredOptions, Side, compute_placement};
+    use gpui::{Bounds, point, px, size};
+
+    fn viewport() -> Bounds<gpui::Pixels> {
+        Bounds::new(point(px(0.), px(0.)), size(px(800.), px(600.)))
+    }
+
+    fn anchor_at(x: f32, y: f32) -> Bounds<gpui::Pixels> {
+        Bounds::new(point(px(x), px(y)), size(px(100.), px(30.)))
+    }
+
+    #[test]
+    fn places_below_centered_with_offset() {
+        let options = AnchoredOptions {
+            offset: px(4.),
+            ..Default::default()
+        };
+        let placement = compute_placement(
+            anchor_at(100., 100.),
+            size(px(50.), px(20.)),
+            viewport(),
+            &options,
+        );
+        assert_eq!(placement.side, Side::Bottom);
+        assert_eq!(placement.bounds.origin, point(px(125.), px(134.)));
+    }
+
+    #[test]
+    fn aligns_start_and_end() {
+        let floating = size(px(50.), px(20.));
+        let start = compute_placement(
+            anchor_at(100., 100.),
+            floating,
+            viewport(),
+            &AnchoredOptions {
+                side: Side::Right,
+                align: Align::Start,
+                ..Default::default()
+            },
+        );
+        assert_eq!(start.bounds.origin, point(px(200.), px(100.)));
+
+        let end = compute_placement(
+            anchor_at(100., 100.),
+            floating,
+            viewport(),
+            &AnchoredOptions {
+                side: Side::Top,
+                align: Align::End,
+                ..Default::default()
+            },
+        );
+        assert_eq!(end.bounds.origin, point(px(150.), px(80.)));
+    }
+
+    #[test]
+    fn flips_when_overflowing() {
+        let placement = compute_placement(
+            anchor_at(100., 560.),
+            size(px(50.), px(100.)),
+            viewport(),
+            &AnchoredOptions::default(),
+        );
+        assert_eq!(placement.side, Side::Top);
+        assert_eq!(placement.bounds.origin.y, px(460.));
+    }
+
+    #[test]
+    fn keeps_side_when_flip_is_disabled() {
+        let placement = compute_placement(
+            anchor_at(100., 560.),
+            size(px(50.), px(100.)),
+            viewport(),
+            &AnchoredOptions {
+                flip: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(placement.side, Side::Bottom);
+        assert_eq!(placement.bounds.origin.y, px(590.));
+    }
+
+    #[test]
+    fn shifts_within_viewport() {
+        let placement = compute_placement(
+            anchor_at(0., 100.),
+            size(px(200.), px(20.)),
+            viewport(),
+            &AnchoredOptions {
+                padding: px(8.),
+                ..Default::default()
+            },
+        );
+        assert_eq!(placement.bounds.origin.x, px(8.));
+
+        let placement = compute_placement(
+            anchor_at(750., 100.),
+            size(px(200.), px(20.)),
+            viewport(),
+            &AnchoredOptions {
+                padding: px(8.),
+                ..Default::default()
+            },
+        );
+        assert_eq!(placement.bounds.origin.x, px(592.));
+    }
+}
@@ -1,11 +1,19 @@
-use crate::{AutoFocusable, Disableable};
+use crate::{
+    AccessibilityProps, Accessible, AutoFocusable, ControlSize, Density, Disableable,
+    FocusableStyled, InteractiveStateHandlers, InteractiveStateStyled, Role, Sizeable, Theme,
+    apply_interactive_state, is_focus_visible, is_in_disabled_scope, track_interaction,
+};
 use gpui::{
-    AnyElement, App, ClickEvent, Div, ElementId, InteractiveElement, Interactivity, IntoElement,
-    ParentElement, RenderOnce, Stateful, StatefulInteractiveElement, StyleRefinement, Styled,
-    Window, div, prelude::FluentBuilder,
+    AnyElement, AnyWindowHandle, App, ClickEvent, Context, Div, ElementId, Entity, EventEmitter,
+    FocusHandle, InteractiveElement, Interactivity, IntoElement, Keystroke, KeystrokeEvent,
+    MouseButton, ParentElement, RenderOnce, Stateful, StatefulInteractiveElement, StyleRefinement,
+    Styled, Subscription, Timer, Window, div, prelude::FluentBuilder,
 };
 use smallvec::SmallVec;
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
+
+/// How long a button stays pressed after being activated by its key equivalent.
+const KEY_EQUIVALENT_PRESS_DURATION: Duration = Duration::from_millis(100);
 
 pub fn button(id: impl Into<ElementId>) -> Button {
     let id = id.into();
@@ -15,9 +23,19 @@ pub fn button(id: impl Into<ElementId>) -> Button {
         disabled: false,
         children: SmallVec::new(),
         on_click: None,
+        events: None,
+        on_press_start: None,
+        on_press_end: None,
         auto_focus: false,
         tab_index: 0,
         tab_stop: true,
+        key_equivalent: None,
+        when_pressed_handler: None,
+        when_focus_visible_handler: None,
+        interactive_state: InteractiveStateHandlers::default(),
+        accessibility: AccessibilityProps::new(Role::Button),
+        when_disabled_handler: None,
+        size: None,
     }
 }
 
@@ -29,9 +47,159 @@ pub struct Button {
     disabled: bool,
     children: SmallVec<[AnyElement; 2]>,
     on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    events: Option<Entity<ButtonEvents>>,
+    on_press_start: Option<Rc<dyn Fn(&PressEvent, &mut Window, &mut App) + 'static>>,
+    on_press_end: Option<Rc<dyn Fn(&PressEvent, &mut Window, &mut App) + 'static>>,
     auto_focus: bool,
     tab_index: isize,
     tab_stop: bool,
+    key_equivalent: Option<Keystroke>,
+    when_pressed_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_focus_visible_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    interactive_state: InteractiveStateHandlers<Self>,
+    accessibility: AccessibilityProps,
+    when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    size: Option<ControlSize>,
+}
+
+/// The input that started or ended a press.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PressSource {
+    Mouse,
+    Keyboard,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PressEvent {
+    pub source: PressSource,
+}
+
+/// An entity a [`Button`] emits its clicks through, so views can subscribe to them
+/// instead of passing a callback.
+///
+/// # Examples
+///
+/// ```rust
+/// let save_events = cx.new(|_| ButtonEvents);
+/// cx.subscribe(&save_events, |this, _, _: &ClickEvent, cx| this.save(cx))
+///     .detach();
+///
+/// // In render:
+/// button("save").events(&self.save_events).child("Save")
+/// ```
+pub struct ButtonEvents;
+
+impl EventEmitter<ClickEvent> for ButtonEvents {}
+
+#[allow(clippy::type_complexity)]
+struct ButtonState {
+    focus_handle: FocusHandle,
+    pressed: bool,
+    press_epoch: usize,
+    key_equivalent: Option<Keystroke>,
+    on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    _key_equivalent_subscription: Option<Subscription>,
+}
+
+impl ButtonState {
+    fn new(cx: &mut Context<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            pressed: false,
+            press_epoch: 0,
+            key_equivalent: None,
+            on_click: None,
+            _key_equivalent_subscription: None,
+        }
+    }
+
+    /// Starts listening for the key equivalent in the given window.
+    fn observe_key_equivalent(&mut self, window_handle: AnyWindowHandle, cx: &mut Context<Self>) {
+        if self._key_equivalent_subscription.is_some() {
+            return;
+        }
+
+        let this = cx.weak_entity();
+        self._key_equivalent_subscription = Some(cx.intercept_keystrokes(
+            move |event: &KeystrokeEvent, window, app| {
+                if window.window_handle() != window_handle {
+                    return;
+                }
+                let Some(this) = this.upgrade() else {
+                    return;
+                };
+
+                let state = this.read(app);
+                let Some(on_click) = state
+                    .key_equivalent
+                    .as_ref()
+                    .filter(|keystroke| keystroke_matches(keystroke, &event.keystroke))
+                    .and(state.on_click.clone())
+                else {
+                    return;
+                };
+
+                app.stop_propagation();
+                this.update(app, |state, cx| state.press(cx));
+                on_click(&ClickEvent::default(), window, app);
+            },
+        ));
+    }
+
+    /// Updates the pressed state, returning whether it changed.
+    fn set_pressed(&mut self, pressed: bool, cx: &mut Context<Self>) -> bool {
+        if self.pressed == pressed {
+            return false;
+        }
+
+        self.pressed = pressed;
+        self.press_epoch += 1;
+        cx.notify();
+        true
+    }
+
+    /// Briefly marks the button as pressed.
+    fn press(&mut self, cx: &mut Context<Self>) {
+        self.set_pressed(true, cx);
+
+        let epoch = self.press_epoch;
+        cx.spawn(async move |this, cx| {
+            Timer::after(KEY_EQUIVALENT_PRESS_DURATION).await;
+            if let Some(this) = this.upgrade() {
+                this.update(cx, |this, cx| {
+                    if this.press_epoch == epoch {
+                        this.pressed = false;
+                        cx.notify();
+                    }
+                })
+                .ok();
+            }
+        })
+        .detach();
+    }
+}
+
+fn keystroke_matches(target: &Keystroke, keystroke: &Keystroke) -> bool {
+    target.modifiers == keystroke.modifiers && target.key == keystroke.key
+}
+
+fn is_activation_key(key: &str) -> bool {
+    key == "space" || key == "enter"
+}
+
+#[allow(clippy::type_complexity)]
+fn update_pressed(
+    state: &Entity<ButtonState>,
+    pressed: bool,
+    source: PressSource,
+    callback: Option<&Rc<dyn Fn(&PressEvent, &mut Window, &mut App) + 'static>>,
+    window: &mut Window,
+    app: &mut App,
+) {
+    let changed = state.update(app, |state, cx| state.set_pressed(pressed, cx));
+    if changed && let Some(callback) = callback {
+        callback(&PressEvent { source }, window, app);
+    }
 }
 
 impl Button {
@@ -43,6 +211,31 @@ impl Button {
         self
     }
 
+    /// Emits every click, including those from the keyboard and the key equivalent, as a
+    /// [`ClickEvent`] on the entity.
+    pub fn events(mut self, events: &Entity<ButtonEvents>) -> Self {
+        self.events = Some(events.clone());
+        self
+    }
+
+    /// Called when the button starts being pressed with the mouse, Space or Enter.
+    pub fn on_press_start(
+        mut self,
+        on_press_start: impl Fn(&PressEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_press_start = Some(Rc::new(on_press_start));
+        self
+    }
+
+    /// Called when a press started by [`Button::on_press_start`] is released.
+    pub fn on_press_end(
+        mut self,
+        on_press_end: impl Fn(&PressEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_press_end = Some(Rc::new(on_press_end));
+        self
+    }
+
     pub fn tab_stop(mut self, tab_stop: bool) -> Self {
         self.tab_stop = tab_stop;
         self
@@ -52,6 +245,56 @@ impl Button {
         self.tab_index = tab_index;
         self
     }
+
+    /// Binds a keystroke (e.g. `"cmd-s"`) that activates the button while it is
+    /// rendered and enabled, regardless of which element in the window has focus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keystroke cannot be parsed.
+    pub fn key_equivalent(mut self, keystroke: &str) -> Self {
+        self.key_equivalent = Some(Keystroke::parse(keystroke).expect("invalid key equivalent"));
+        self
+    }
+
+    /// Conditionally modify the button while it is pressed, including the brief
+    /// press triggered by its key equivalent.
+    pub fn when_pressed(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_pressed_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl FocusableStyled for Button {
+    fn when_focus_visible(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_focus_visible_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+/// The button is active while [`Button::when_pressed`] applies, including presses from the
+/// keyboard.
+impl InteractiveStateStyled for Button {
+    fn interactive_state_handlers_mut(&mut self) -> &mut InteractiveStateHandlers<Self> {
+        &mut self.interactive_state
+    }
+}
+
+impl Accessible for Button {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl Sizeable for Button {
+    fn with_size(mut self, size: ControlSize) -> Self {
+        self.size = Some(size);
+        self
+    }
 }
 
 impl Disableable for Button {
@@ -63,6 +306,11 @@ impl Disableable for Button {
         self.disabled = disabled;
         self
     }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
 }
 
 impl AutoFocusable for Button {
@@ -93,18 +341,53 @@ impl InteractiveElement for Button {
 impl StatefulInteractiveElement for Button {}
 
 impl RenderOnce for Button {
-    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
-        let mut focus_handle = window
-            .use_keyed_state(self.id, app, |window, app| {
-                let focus_handle = app.focus_handle();
-                if self.auto_focus {
-                    focus_handle.focus(window);
-                }
-                focus_handle
-            })
-            .read(app)
-            .clone();
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        if is_in_disabled_scope(app) {
+            self.disabled = true;
+        }
+        if self.disabled {
+            if let Some(handler) = self.when_disabled_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        if let Some(size) = self.size {
+            let theme = Theme::of(app).with_density(Density::current(app));
+            self.base = self.base.h(size.height(&theme)).px(size.padding(&theme));
+        }
 
+        let state = window.use_keyed_state(self.id.clone(), app, |window, cx| {
+            let state = ButtonState::new(cx);
+            if self.auto_focus {
+                state.focus_handle.focus(window);
+            }
+            state
+        });
+
+        if let Some(events) = self.events.take() {
+            let on_click = self.on_click.take();
+            self.on_click = Some(Rc::new(
+                move |event: &ClickEvent, window: &mut Window, app: &mut App| {
+                    if let Some(on_click) = &on_click {
+                        on_click(event, window, app);
+                    }
+                    events.update(app, |_, cx| cx.emit(event.clone()));
+                },
+            ));
+        }
+
+        let window_handle = window.window_handle();
+        state.update(app, |state, cx| {
+            state.key_equivalent = self.key_equivalent.take().filter(|_| !self.disabled);
+            state.on_click = self.on_click.clone();
+            if state.key_equivalent.is_some() {
+                state.observe_key_equivalent(window_handle, cx);
+            }
+        });
+
+        let state_read = state.read(app);
+        let pressed = state_read.pressed;
+        let mut focus_handle = state_read.focus_handle.clone();
         if focus_handle.tab_stop != self.tab_stop {
             focus_handle = focus_handle.tab_stop(self.tab_stop);
         }
@@ -112,27 +395,95 @@ impl RenderOnce for Button {
             focus_handle = focus_handle.tab_index(self.tab_index);
         }
 
-        self.base
+        if pressed {
+            if let Some(handler) = self.when_pressed_handler.take() {
+                self = handler(self);
+            }
+        }
+        if focus_handle.is_focused(window) && is_focus_visible(app) {
+            if let Some(handler) = self.when_focus_visible_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        let focused = focus_handle.is_focused(window);
+        let (this, interaction) = apply_interactive_state(
+            self.id.clone(),
+            self.disabled,
+            pressed,
+            focused,
+            self,
+            window,
+            app,
+        );
+        self = this;
+
+        track_interaction(self.base, interaction)
             .when(!self.disabled, |this| {
                 this.track_focus(&focus_handle)
-                    .when_some(self.on_click, |this, on_click| {
-                        this.map(|this| {
-                            let on_click = on_click.clone();
-                            this.on_key_up(move |event, window, app| {
-                                if event.keystroke.key == "space" {
-                                    (on_click)(&ClickEvent::default(), window, app);
-                                }
-                            })
+                    .map(|this| {
+                        let state = state.clone();
+                        let on_press_start = self.on_press_start.clone();
+                        this.on_mouse_down(MouseButton::Left, move |_, window, app| {
+                            let callback = on_press_start.as_ref();
+                            update_pressed(&state, true, PressSource::Mouse, callback, window, app);
+                        })
+                    })
+                    .map(|this| {
+                        let state = state.clone();
+                        let on_press_end = self.on_press_end.clone();
+                        this.on_mouse_up(MouseButton::Left, move |_, window, app| {
+                            let callback = on_press_end.as_ref();
+                            update_pressed(
+                                &state,
+                                false,
+                                PressSource::Mouse,
+                                callback,
+                                window,
+                                app,
+                            );
+                        })
+                    })
+                    .map(|this| {
+                        let state = state.clone();
+                        let on_press_end = self.on_press_end.clone();
+                        this.on_mouse_up_out(MouseButton::Left, move |_, window, app| {
+                            let callback = on_press_end.as_ref();
+                            update_pressed(
+                                &state,
+                                false,
+                                PressSource::Mouse,
+                                callback,
+                                window,
+                                app,
+                            );
+                        })
+                    })
+                    .map(|this| {
+                        let state = state.clone();
+                        let on_press_start = self.on_press_start.clone();
+                        this.on_key_down(move |event, window, app| {
+                            if !event.is_held && is_activation_key(&event.keystroke.key) {
+                                let callback = on_press_start.as_ref();
+                                let source = PressSource::Keyboard;
+                                update_pressed(&state, true, source, callback, window, app);
+                            }
                         })
-                        .map(|this| {
-                            let on_click = on_click.clone();
-                            this.on_key_down(move |event, window, app| {
-                                if event.keystroke.key == "enter" {
-                                    (on_click)(&ClickEvent::default(), window, app);
-                                }
-                            })
+                    })
+                    .map(|this| {
+                        let state = state.clone();
+                        let on_press_end = self.on_press_end.clone();
+                        this.on_key_up(move |event, window, app| {
+                            if is_activation_key(&event.keystroke.key) {
+                                let callback = on_press_end.as_ref();
+                                let source = PressSource::Keyboard;
+                                update_pressed(&state, false, source, callback, window, app);
+                            }
                         })
-                        .on_click(move |event, window, app| (on_click)(event, window, app))
+                    })
+                    // gpui also clicks focused elements on Space and Enter.
+                    .when_some(self.on_click, |this, on_click| {
+                        this.on_click(move |event, window, app| (on_click)(event, window, app))
                     })
             })
             .children(self.children)
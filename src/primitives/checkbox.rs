@@ -1,9 +1,15 @@
 use std::rc::Rc;
 
-use crate::{AutoFocusable, Disableable};
+use crate::{
+    AccessibilityProps, Accessible, AutoFocusable, ControlSize, Density, Disableable,
+    FocusableStyled, FormField, FormValue, InteractiveStateHandlers, InteractiveStateStyled, Role,
+    Sizeable, SubmitForm, Theme, apply_interactive_state, is_focus_visible, is_in_disabled_scope,
+    register_form_field, track_interaction,
+};
 use gpui::{
-    AnyElement, App, Div, ElementId, FocusHandle, Focusable, InteractiveElement, Interactivity,
-    IntoElement, ParentElement, RenderOnce, Stateful, StatefulInteractiveElement, StyleRefinement,
+    AnyElement, App, ClickEvent, Div, ElementId, Entity, EventEmitter, FocusHandle, Focusable,
+    InteractiveElement, Interactivity, IntoElement, KeyboardButton, KeyboardClickEvent,
+    ParentElement, RenderOnce, SharedString, Stateful, StatefulInteractiveElement, StyleRefinement,
     Styled, Window, div, prelude::FluentBuilder,
 };
 
@@ -15,12 +21,19 @@ pub fn checkbox(id: impl Into<ElementId>) -> Checkbox {
         disabled: false,
         checked: None,
         indeterminate: false,
+        name: None,
         on_change: None,
+        events: None,
         checked_indicator: div().into_any_element(),
         indeterminate_indicator: div().into_any_element(),
         auto_focus: false,
         tab_index: 0,
         tab_stop: true,
+        when_focus_visible_handler: None,
+        interactive_state: InteractiveStateHandlers::default(),
+        accessibility: AccessibilityProps::new(Role::Checkbox),
+        when_disabled_handler: None,
+        size: None,
     }
 }
 
@@ -29,6 +42,26 @@ pub struct ChangeEvent {
     pub checked: bool,
 }
 
+/// An entity a [`Checkbox`] emits its changes through, so views can subscribe to them
+/// instead of passing a callback.
+///
+/// # Examples
+///
+/// ```rust
+/// let terms_events = cx.new(|_| CheckboxEvents);
+/// cx.subscribe(&terms_events, |this, _, event: &ChangeEvent, cx| {
+///     this.accepted_terms = event.checked;
+///     cx.notify();
+/// })
+/// .detach();
+///
+/// // In render:
+/// checkbox("terms").checked(self.accepted_terms).events(&self.terms_events)
+/// ```
+pub struct CheckboxEvents;
+
+impl EventEmitter<ChangeEvent> for CheckboxEvents {}
+
 #[derive(Debug)]
 struct CheckboxState {
     checked: bool,
@@ -61,12 +94,19 @@ pub struct Checkbox {
     disabled: bool,
     checked: Option<bool>,
     indeterminate: bool,
+    name: Option<SharedString>,
     on_change: Option<Rc<dyn Fn(&ChangeEvent, &mut Window, &mut App) + 'static>>,
+    events: Option<Entity<CheckboxEvents>>,
     checked_indicator: AnyElement,
     indeterminate_indicator: AnyElement,
     auto_focus: bool,
     tab_index: isize,
     tab_stop: bool,
+    when_focus_visible_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    interactive_state: InteractiveStateHandlers<Self>,
+    accessibility: AccessibilityProps,
+    when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    size: Option<ControlSize>,
 }
 
 impl Checkbox {
@@ -78,6 +118,12 @@ impl Checkbox {
         self
     }
 
+    /// Emits every change as a [`ChangeEvent`] on the entity.
+    pub fn events(mut self, events: &Entity<CheckboxEvents>) -> Self {
+        self.events = Some(events.clone());
+        self
+    }
+
     pub fn checked(mut self, checked: bool) -> Self {
         self.checked = Some(checked);
         self
@@ -88,6 +134,12 @@ impl Checkbox {
         self
     }
 
+    /// Registers the checkbox with the enclosing [`crate::Form`] under this name.
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     pub fn checked_indicator(mut self, indicator: impl IntoElement) -> Self {
         self.checked_indicator = indicator.into_any_element();
         self
@@ -106,6 +158,36 @@ impl AutoFocusable for Checkbox {
     }
 }
 
+impl FocusableStyled for Checkbox {
+    fn when_focus_visible(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_focus_visible_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl InteractiveStateStyled for Checkbox {
+    fn interactive_state_handlers_mut(&mut self) -> &mut InteractiveStateHandlers<Self> {
+        &mut self.interactive_state
+    }
+}
+
+impl Accessible for Checkbox {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl Sizeable for Checkbox {
+    fn with_size(mut self, size: ControlSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
 impl Disableable for Checkbox {
     fn is_disabled(&self) -> bool {
         self.disabled
@@ -115,6 +197,11 @@ impl Disableable for Checkbox {
         self.disabled = disabled;
         self
     }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
 }
 
 impl Styled for Checkbox {
@@ -132,8 +219,22 @@ impl InteractiveElement for Checkbox {
 impl StatefulInteractiveElement for Checkbox {}
 
 impl RenderOnce for Checkbox {
-    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
-        let state = window.use_keyed_state(self.id, app, |_, app| CheckboxState::new(app));
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        if is_in_disabled_scope(app) {
+            self.disabled = true;
+        }
+        if self.disabled {
+            if let Some(handler) = self.when_disabled_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        if let Some(size) = self.size {
+            let theme = Theme::of(app);
+            self.base = self.base.size(size.indicator(&theme));
+        }
+
+        let state = window.use_keyed_state(self.id.clone(), app, |_, app| CheckboxState::new(app));
 
         state.update(app, |state, _| {
             if let Some(checked) = self.checked {
@@ -155,7 +256,55 @@ impl RenderOnce for Checkbox {
             focus_handle = focus_handle.tab_index(self.tab_index);
         }
 
-        let mut checkbox = self.base;
+        if let Some(name) = self.name.take() {
+            let field = FormField {
+                value: Rc::new({
+                    let state = state.clone();
+                    move |app| FormValue::Checked(state.read(app).checked)
+                }),
+                validate: None,
+                focus_handle: Some(focus_handle.clone()),
+            };
+            register_form_field(name, field, app);
+        }
+
+        if focus_handle.is_focused(window) && is_focus_visible(app) {
+            if let Some(handler) = self.when_focus_visible_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        let focused = focus_handle.is_focused(window);
+        let (this, interaction) = apply_interactive_state(
+            self.id.clone(),
+            self.disabled,
+            false,
+            focused,
+            self,
+            window,
+            app,
+        );
+        self = this;
+
+        if let Some(events) = self.events.take() {
+            let on_change = self.on_change.take();
+            self.on_change = Some(Rc::new(
+                move |event: &ChangeEvent, window: &mut Window, app: &mut App| {
+                    if let Some(on_change) = &on_change {
+                        on_change(event, window, app);
+                    }
+                    events.update(app, |_, cx| cx.emit(*event));
+                },
+            ));
+        }
+
+        let mut checkbox = track_interaction(self.base, interaction);
+
+        let style = checkbox.style();
+        if style.min_size.width.is_none() && style.min_size.height.is_none() {
+            let hit_target = Density::current(app).hit_target();
+            checkbox = checkbox.min_w(hit_target).min_h(hit_target);
+        }
 
         if indeterminate {
             checkbox = checkbox.child(self.indeterminate_indicator);
@@ -163,38 +312,34 @@ impl RenderOnce for Checkbox {
             checkbox = checkbox.child(self.checked_indicator);
         }
 
+        let on_change = self.on_change;
         checkbox.when(!self.disabled, |this| {
             this.track_focus(&focus_handle)
-                .map(|this| {
-                    let state = state.clone();
-                    this.on_key_up(move |event, _, app| {
-                        if event.keystroke.key == "space" {
-                            state.update(app, |state, cx| {
-                                state.checked = !state.checked;
-                                cx.notify();
-                            });
-                        }
-                    })
-                })
-                .on_click(move |_, _, app| {
+                // gpui also clicks focused elements on Space and Enter, but only Space
+                // toggles a checkbox, Enter submits the form it is in.
+                .on_click(move |event, window, app| {
+                    if is_enter_click(event) {
+                        window.dispatch_action(Box::new(SubmitForm), app);
+                        return;
+                    }
                     state.update(app, |state, cx| {
                         state.checked = !state.checked;
                         cx.notify();
                     });
-                })
-                .when_some(self.on_change, |this, on_change| {
-                    this.map(|this| {
-                        let on_change = on_change.clone();
-                        this.on_key_up(move |event, window, app| {
-                            if event.keystroke.key == "space" {
-                                (on_change)(&ChangeEvent { checked: !checked }, window, app);
-                            }
-                        })
-                    })
-                    .on_click(move |_, window, app| {
-                        (on_change)(&ChangeEvent { checked: !checked }, window, app)
-                    })
+                    if let Some(on_change) = &on_change {
+                        (on_change)(&ChangeEvent { checked: !checked }, window, app);
+                    }
                 })
         })
     }
 }
+
+fn is_enter_click(event: &ClickEvent) -> bool {
+    matches!(
+        event,
+        ClickEvent::Keyboard(KeyboardClickEvent {
+            button: KeyboardButton::Enter,
+            ..
+        })
+    )
+}
@@ -0,0 +1,240 @@
+use crate::{MotionPreference, OpenProps, OpenReason, Openable, use_open_state};
+use gpui::{prelude::FluentBuilder, *};
+use smallvec::SmallVec;
+use std::{rc::Rc, time::Duration};
+
+const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+/// A frame of a [`Collapsible`] opening or closing, passed to its animation hook.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CollapsibleAnimation {
+    /// Eased progress from closed (`0.0`) to open (`1.0`).
+    pub progress: f32,
+    /// The measured height of the content when fully open.
+    pub content_height: Pixels,
+    /// Whether the collapsible is opening rather than closing.
+    pub opening: bool,
+}
+
+impl CollapsibleAnimation {
+    /// The height of the content at this frame.
+    pub fn height(&self) -> Pixels {
+        self.content_height * self.progress
+    }
+}
+
+pub fn collapsible(id: impl Into<ElementId>) -> Collapsible {
+    Collapsible {
+        id: id.into(),
+        base: div(),
+        trigger: None,
+        children: SmallVec::new(),
+        open_props: OpenProps::default(),
+        duration: DEFAULT_DURATION,
+        easing: Rc::new(ease_in_out),
+        animate: Rc::new(|this, animation| this.h(animation.height())),
+    }
+}
+
+/// Shows or hides its content, animating the content's measured height in between.
+///
+/// The content is measured while rendered, so the animation runs from and to its natural
+/// height. When it was never shown, the first open spends a frame measuring it collapsed
+/// before animating. The default hook only animates the height; replace it with
+/// [`Collapsible::animate`] to also fade or slide the content.
+///
+/// # Examples
+///
+/// ```rust
+/// collapsible("details")
+///     .trigger(span("Details"))
+///     .animate(|content, animation| {
+///         content.h(animation.height()).opacity(animation.progress)
+///     })
+///     .child(span("More information"))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Collapsible {
+    id: ElementId,
+    base: Div,
+    trigger: Option<AnyElement>,
+    children: SmallVec<[AnyElement; 2]>,
+    open_props: OpenProps,
+    duration: Duration,
+    easing: Rc<dyn Fn(f32) -> f32>,
+    animate: Rc<dyn Fn(Div, CollapsibleAnimation) -> Div>,
+}
+
+/// Content measurement and the running transition of a [`Collapsible`].
+struct CollapsibleState {
+    content_height: Option<Pixels>,
+    was_open: bool,
+    transition: Option<usize>,
+    epoch: usize,
+}
+
+impl CollapsibleState {
+    /// Starts a transition and schedules it to be cleared once it finished.
+    fn start_transition(&mut self, duration: Duration, cx: &mut Context<Self>) {
+        self.epoch += 1;
+        let epoch = self.epoch;
+        self.transition = Some(epoch);
+
+        cx.spawn(async move |this, cx| {
+            Timer::after(duration).await;
+            this.update(cx, |state, cx| {
+                if state.transition == Some(epoch) {
+                    state.transition = None;
+                    cx.notify();
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+}
+
+impl Collapsible {
+    /// An element rendered above the content that toggles it when clicked.
+    pub fn trigger(mut self, trigger: impl IntoElement) -> Self {
+        self.trigger = Some(trigger.into_any_element());
+        self
+    }
+
+    /// How long opening and closing take, 200ms by default.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn easing(mut self, easing: impl Fn(f32) -> f32 + 'static) -> Self {
+        self.easing = Rc::new(easing);
+        self
+    }
+
+    /// Styles the content container on each frame of the transition.
+    pub fn animate(mut self, animate: impl Fn(Div, CollapsibleAnimation) -> Div + 'static) -> Self {
+        self.animate = Rc::new(animate);
+        self
+    }
+}
+
+impl Openable for Collapsible {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+impl ParentElement for Collapsible {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Collapsible {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Collapsible {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let open_state = use_open_state(self.id.clone(), &self.open_props, window, app);
+        let open = open_state.read(app).is_open();
+
+        let state = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.id.clone()), "collapsible".into()),
+            app,
+            |_, _| CollapsibleState {
+                content_height: None,
+                was_open: open,
+                transition: None,
+                epoch: 0,
+            },
+        );
+
+        let duration = MotionPreference::current(app).duration(self.duration);
+        let (transition, content_height) = state.update(app, |state, cx| {
+            if state.was_open != open {
+                state.was_open = open;
+                state.start_transition(duration, cx);
+            }
+            (state.transition, state.content_height)
+        });
+
+        // The content stays rendered while closing so it can be animated out.
+        let visible = open || transition.is_some();
+        let content = div()
+            .relative()
+            .child({
+                let state = state.clone();
+                canvas(
+                    move |bounds, window, app| {
+                        let waiting = state.update(app, |state, _| {
+                            let measured = state.content_height.replace(bounds.size.height);
+                            measured.is_none() && state.transition.is_some()
+                        });
+                        // Render again to start the transition that waited for this.
+                        if waiting {
+                            window.request_animation_frame();
+                        }
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .children(self.children);
+
+        self.base
+            .id(self.id)
+            .when_some(self.trigger, |this, trigger| {
+                this.child(
+                    div()
+                        .id("collapsible-trigger")
+                        .on_click(move |_, window, app| {
+                            open_state.update(app, |state, cx| {
+                                state.toggle(OpenReason::Trigger, window, cx)
+                            })
+                        })
+                        .child(trigger),
+                )
+            })
+            .when(visible, |this| {
+                let container = div().overflow_hidden().child(content);
+                match (transition, content_height) {
+                    // Keep the content collapsed while it is measured for the first time.
+                    (Some(_), None) => this.child((self.animate)(
+                        container,
+                        CollapsibleAnimation {
+                            progress: 0.,
+                            content_height: px(0.),
+                            opening: open,
+                        },
+                    )),
+                    (Some(epoch), Some(content_height)) => {
+                        let easing = self.easing;
+                        let animate = self.animate;
+                        this.child(container.with_animation(
+                            ("collapsible-transition", epoch),
+                            Animation::new(duration),
+                            move |container, delta| {
+                                let progress = easing(delta);
+                                let progress = if open { progress } else { 1. - progress };
+                                animate(
+                                    container,
+                                    CollapsibleAnimation {
+                                        progress,
+                                        content_height,
+                                        opening: open,
+                                    },
+                                )
+                            },
+                        ))
+                    }
+                    (None, _) => this.child(container),
+                }
+            })
+    }
+}
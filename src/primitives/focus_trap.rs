@@ -0,0 +1,113 @@
+use crate::{InputModality, Tab, TabPrev, change_input_modality};
+use gpui::{
+    AnyElement, App, Div, ElementId, FocusHandle, InteractiveElement, Interactivity, IntoElement,
+    ParentElement, RenderOnce, Stateful, StyleRefinement, Styled, Window, div,
+    prelude::FluentBuilder,
+};
+use smallvec::SmallVec;
+
+/// Upper bound on the tab stops visited while looking for one inside the trap.
+const MAX_FOCUS_STEPS: usize = 512;
+
+pub fn focus_trap(id: impl Into<ElementId>) -> FocusTrap {
+    let id = id.into();
+    FocusTrap {
+        id: id.clone(),
+        base: div().id(id),
+        children: SmallVec::new(),
+        active: true,
+        focus_handle: None,
+    }
+}
+
+/// Confines Tab/Shift-Tab navigation to its children while active.
+///
+/// Used by overlays such as dialogs and menus so focus can't escape to the content behind them.
+#[derive(IntoElement)]
+pub struct FocusTrap {
+    id: ElementId,
+    base: Stateful<Div>,
+    children: SmallVec<[AnyElement; 2]>,
+    active: bool,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl FocusTrap {
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    /// Tracks the given handle instead of an internal one, so the owner can move focus
+    /// into the trap.
+    pub fn focus_handle(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+}
+
+/// Moves focus to the next or previous tab stop inside the trap, wrapping around.
+pub(crate) fn cycle_focus(trap: &FocusHandle, forward: bool, window: &mut Window, app: &mut App) {
+    change_input_modality(InputModality::Keyboard, window, app);
+
+    let start = window.focused(app);
+    for _ in 0..MAX_FOCUS_STEPS {
+        if forward {
+            window.focus_next();
+        } else {
+            window.focus_prev();
+        }
+
+        let focused = window.focused(app);
+        if focused.as_ref() != Some(trap) && trap.contains_focused(window, app) {
+            return;
+        }
+        if focused == start {
+            return;
+        }
+    }
+}
+
+impl ParentElement for FocusTrap {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for FocusTrap {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl InteractiveElement for FocusTrap {
+    fn interactivity(&mut self) -> &mut Interactivity {
+        self.base.interactivity()
+    }
+}
+
+impl RenderOnce for FocusTrap {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let focus_handle = self.focus_handle.unwrap_or_else(|| {
+            window
+                .use_keyed_state(self.id, app, |_, app| app.focus_handle())
+                .read(app)
+                .clone()
+        });
+
+        self.base
+            .track_focus(&focus_handle)
+            .when(self.active, |this| {
+                this.map(|this| {
+                    let focus_handle = focus_handle.clone();
+                    this.on_action(move |_: &Tab, window, app| {
+                        cycle_focus(&focus_handle, true, window, app);
+                    })
+                })
+                .on_action(move |_: &TabPrev, window, app| {
+                    cycle_focus(&focus_handle, false, window, app);
+                })
+            })
+            .children(self.children)
+    }
+}
@@ -0,0 +1,229 @@
+use crate::primitives::{h_flex, span};
+use gpui::*;
+
+#[cfg(test)]
+mod tests;
+
+/// What a key of a [`Kbd`] is rendered with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KbdKeyContext {
+    /// The symbol or name shown for the key, such as "⌘" or "Ctrl".
+    pub label: SharedString,
+    pub modifier: bool,
+    /// Position of the keystroke the key belongs to, in a sequence such as "cmd-k cmd-s".
+    pub keystroke: usize,
+}
+
+pub fn kbd(keystrokes: impl Into<SharedString>) -> Kbd {
+    Kbd {
+        base: h_flex().flex_none().items_center().gap_1(),
+        keystrokes: keystrokes.into(),
+        key: None,
+    }
+}
+
+/// A keyboard shortcut, written like a gpui keybinding and shown the way the platform does.
+///
+/// On macOS modifiers are shown as symbols next to the key (⌘⇧P), elsewhere they are named and
+/// joined with "+" (Ctrl+Shift+P). Keystrokes are parsed with [`Keystroke::parse`], except
+/// that `cmd`, like `secondary`, stands for the command key on macOS and the control key
+/// elsewhere, so one string describes a shortcut on every platform. `super` and `win` always
+/// stand for the platform key. Keystrokes of a sequence are separated by whitespace.
+///
+/// # Examples
+///
+/// ```rust
+/// kbd("cmd-shift-p")
+///     .text_xs()
+///     .key(|key, _| key.px_1().rounded_sm().bg(rgb(0xf1f5f9)))
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Kbd {
+    base: Div,
+    keystrokes: SharedString,
+    key: Option<Box<dyn Fn(Div, &KbdKeyContext) -> Div>>,
+}
+
+impl Kbd {
+    /// Shows the keybinding of an action, if it is bound.
+    pub fn for_action(action: &dyn Action, window: &Window) -> Option<Self> {
+        let binding = window.highest_precedence_binding_for_action(action)?;
+        let keystrokes = binding
+            .keystrokes()
+            .iter()
+            .map(|keystroke| keystroke.unparse())
+            .collect::<Vec<_>>()
+            .join(" ");
+        Some(kbd(keystrokes))
+    }
+
+    /// Styles each key, modifiers included.
+    pub fn key(mut self, render: impl Fn(Div, &KbdKeyContext) -> Div + 'static) -> Self {
+        self.key = Some(Box::new(render));
+        self
+    }
+}
+
+impl Styled for Kbd {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// The platform whose conventions keys are shown with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Platform {
+    Mac,
+    Windows,
+    Linux,
+}
+
+impl Platform {
+    const CURRENT: Self = if cfg!(target_os = "macos") {
+        Platform::Mac
+    } else if cfg!(target_os = "windows") {
+        Platform::Windows
+    } else {
+        Platform::Linux
+    };
+}
+
+/// The modifiers of a keystroke in the platform's conventional order, with their names as
+/// they appear when a modifier is the key itself.
+fn modifiers(modifiers: &Modifiers) -> impl Iterator<Item = &'static str> {
+    [
+        (modifiers.function, "function"),
+        (modifiers.control, "control"),
+        (modifiers.alt, "alt"),
+        (modifiers.shift, "shift"),
+        (modifiers.platform, "platform"),
+    ]
+    .into_iter()
+    .filter_map(|(pressed, name)| pressed.then_some(name))
+}
+
+fn modifier_label(modifier: &str, platform: Platform) -> Option<&'static str> {
+    let label = match (modifier, platform) {
+        ("function", Platform::Mac) => "fn",
+        ("function", _) => "Fn",
+        ("control", Platform::Mac) => "⌃",
+        ("control", _) => "Ctrl",
+        ("alt", Platform::Mac) => "⌥",
+        ("alt", _) => "Alt",
+        ("shift", Platform::Mac) => "⇧",
+        ("shift", _) => "Shift",
+        ("platform", Platform::Mac) => "⌘",
+        ("platform", Platform::Windows) => "Win",
+        ("platform", Platform::Linux) => "Super",
+        _ => return None,
+    };
+    Some(label)
+}
+
+fn key_label(key: &str, platform: Platform) -> SharedString {
+    if let Some(label) = modifier_label(key, platform) {
+        return label.into();
+    }
+    let mac = platform == Platform::Mac;
+    let label = match (key, mac) {
+        ("enter", true) => "↩",
+        ("backspace", true) => "⌫",
+        ("delete", true) => "⌦",
+        ("escape", true) => "⎋",
+        ("tab", true) => "⇥",
+        ("pageup", true) => "⇞",
+        ("pagedown", true) => "⇟",
+        ("home", true) => "↖",
+        ("end", true) => "↘",
+        ("escape", false) => "Esc",
+        ("pageup", false) => "PgUp",
+        ("pagedown", false) => "PgDn",
+        ("up", _) => "↑",
+        ("down", _) => "↓",
+        ("left", _) => "←",
+        ("right", _) => "→",
+        (key, _) if key.chars().count() == 1 => return key.to_uppercase().into(),
+        (key, _) => return capitalize(key).into(),
+    };
+    label.into()
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Parses a keystroke like gpui keybindings, except that `cmd` reads as `secondary`.
+fn parse_keystroke(source: &str) -> Option<Keystroke> {
+    let source = source
+        .split('-')
+        .map(|component| match component.eq_ignore_ascii_case("cmd") {
+            true => "secondary",
+            false => component,
+        })
+        .collect::<Vec<_>>()
+        .join("-");
+    Keystroke::parse(&source).ok()
+}
+
+/// Returns the keys of a keystroke, modifiers first.
+fn keystroke_keys(keystroke: &Keystroke, index: usize, platform: Platform) -> Vec<KbdKeyContext> {
+    modifiers(&keystroke.modifiers)
+        .filter_map(|modifier| modifier_label(modifier, platform))
+        .map(|label| KbdKeyContext {
+            label: label.into(),
+            modifier: true,
+            keystroke: index,
+        })
+        .chain(Some(KbdKeyContext {
+            label: key_label(&keystroke.key, platform),
+            modifier: false,
+            keystroke: index,
+        }))
+        .collect()
+}
+
+/// Returns the keys of each keystroke in a sequence. A keystroke gpui can't parse is
+/// shown as a single key.
+fn parse(keystrokes: &str, platform: Platform) -> Vec<Vec<KbdKeyContext>> {
+    keystrokes
+        .split_whitespace()
+        .enumerate()
+        .map(|(index, source)| match parse_keystroke(source) {
+            Some(keystroke) => keystroke_keys(&keystroke, index, platform),
+            None => vec![KbdKeyContext {
+                label: source.to_string().into(),
+                modifier: false,
+                keystroke: index,
+            }],
+        })
+        .collect()
+}
+
+impl RenderOnce for Kbd {
+    fn render(self, _window: &mut Window, _app: &mut App) -> impl IntoElement {
+        let key = self.key;
+        self.base.children(
+            parse(&self.keystrokes, Platform::CURRENT)
+                .into_iter()
+                .map(|keys| {
+                    let mut keystroke = h_flex().items_center();
+                    for (ix, context) in keys.iter().enumerate() {
+                        if ix > 0 && Platform::CURRENT != Platform::Mac {
+                            keystroke = keystroke.child(span("+"));
+                        }
+                        let element = span(context.label.clone());
+                        keystroke = keystroke.child(match &key {
+                            Some(render) => render(element, context),
+                            None => element,
+                        });
+                    }
+                    keystroke
+                }),
+        )
+    }
+}
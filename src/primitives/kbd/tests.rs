@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod parse {
+    use crate::primitives::kbd::{Platform, parse};
+
+    /// Returns the labels of each keystroke in a sequence.
+    fn labels(keystrokes: &str, platform: Platform) -> Vec<Vec<String>> {
+        parse(keystrokes, platform)
+            .into_iter()
+            .map(|keys| keys.into_iter().map(|key| key.label.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn shows_modifiers_as_symbols_on_macos() {
+        assert_eq!(
+            labels("super-shift-alt-ctrl-fn-p", Platform::Mac),
+            [["fn", "⌃", "⌥", "⇧", "⌘", "P"]]
+        );
+    }
+
+    #[test]
+    fn names_modifiers_elsewhere() {
+        assert_eq!(
+            labels("super-shift-alt-ctrl-fn-p", Platform::Linux),
+            [["Fn", "Ctrl", "Alt", "Shift", "Super", "P"]]
+        );
+        assert_eq!(
+            labels("super-shift-alt-ctrl-fn-p", Platform::Windows),
+            [["Fn", "Ctrl", "Alt", "Shift", "Win", "P"]]
+        );
+    }
+
+    #[test]
+    fn shows_named_keys_the_way_the_platform_does() {
+        assert_eq!(
+            labels("enter escape pageup up", Platform::Mac),
+            [["↩"], ["⎋"], ["⇞"], ["↑"]]
+        );
+        assert_eq!(
+            labels("enter escape pageup up", Platform::Linux),
+            [["Enter"], ["Esc"], ["PgUp"], ["↑"]]
+        );
+    }
+
+    #[test]
+    fn reads_cmd_as_secondary() {
+        let platform = Platform::CURRENT;
+        assert_eq!(parse("cmd-s", platform), parse("secondary-s", platform));
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    #[test]
+    fn shows_cmd_as_ctrl_outside_macos() {
+        assert_eq!(labels("cmd-s", Platform::Linux), [["Ctrl", "S"]]);
+        assert_eq!(labels("cmd-ctrl-s", Platform::Windows), [["Ctrl", "S"]]);
+    }
+
+    #[test]
+    fn parses_keys_like_gpui() {
+        assert_eq!(labels("ctrl--", Platform::Linux), [["Ctrl", "-"]]);
+        assert_eq!(labels("P", Platform::Linux), [["Shift", "P"]]);
+        assert_eq!(labels("shift", Platform::Mac), [["⇧"]]);
+    }
+
+    #[test]
+    fn numbers_the_keystrokes_of_a_sequence() {
+        let keys = parse("ctrl-k ctrl-s", Platform::Linux);
+        let numbers: Vec<Vec<usize>> = keys
+            .iter()
+            .map(|keys| keys.iter().map(|key| key.keystroke).collect())
+            .collect();
+        assert_eq!(numbers, [[0, 0], [1, 1]]);
+        assert!(keys[0][0].modifier && !keys[0][1].modifier);
+    }
+
+    #[test]
+    fn shows_an_invalid_keystroke_as_written() {
+        assert_eq!(labels("ctrl-foo-bar", Platform::Linux), [["ctrl-foo-bar"]]);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{Disableable, is_in_disabled_scope};
+use gpui::{
+    AnyElement, App, Div, ElementId, InteractiveElement, Interactivity, IntoElement, MouseButton,
+    ParentElement, RenderOnce, SharedString, Stateful, StatefulInteractiveElement, StyleRefinement,
+    Styled, Window, div, prelude::FluentBuilder,
+};
+use smallvec::SmallVec;
+use std::rc::Rc;
+
+/// Follows the link, in a new window if the flag is set.
+type Navigate = Rc<dyn Fn(bool, &mut Window, &mut App)>;
+
+pub fn link(id: impl Into<ElementId>, href: impl Into<SharedString>) -> Link {
+    let id = id.into();
+    Link {
+        id: id.clone(),
+        base: div().id(id),
+        href: href.into(),
+        disabled: false,
+        children: SmallVec::new(),
+        on_navigate: None,
+        when_hovered_handler: None,
+        when_visited_handler: None,
+        when_disabled_handler: None,
+    }
+}
+
+/// Emitted when a link is activated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NavigateEvent {
+    pub href: SharedString,
+    /// Whether the link was opened with a middle click or while holding the
+    /// platform modifier (cmd on macOS, ctrl elsewhere).
+    pub new_window: bool,
+}
+
+#[derive(Default)]
+struct LinkState {
+    hovered: bool,
+    visited: bool,
+}
+
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct Link {
+    id: ElementId,
+    base: Stateful<Div>,
+    href: SharedString,
+    disabled: bool,
+    children: SmallVec<[AnyElement; 2]>,
+    on_navigate: Option<Rc<dyn Fn(&NavigateEvent, &mut Window, &mut App) + 'static>>,
+    when_hovered_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_visited_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+}
+
+impl Link {
+    /// Intercepts navigation. When set, the URL is no longer opened automatically
+    /// and the handler is responsible for calling [`App::open_url`] if desired.
+    pub fn on_navigate(
+        mut self,
+        on_navigate: impl Fn(&NavigateEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_navigate = Some(Rc::new(on_navigate));
+        self
+    }
+
+    /// Conditionally modify the link while the pointer is over it.
+    pub fn when_hovered(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_hovered_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Conditionally modify the link once it has been activated.
+    pub fn when_visited(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_visited_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Disableable for Link {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl ParentElement for Link {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Link {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl InteractiveElement for Link {
+    fn interactivity(&mut self) -> &mut Interactivity {
+        self.base.interactivity()
+    }
+}
+
+impl StatefulInteractiveElement for Link {}
+
+impl RenderOnce for Link {
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        if is_in_disabled_scope(app) {
+            self.disabled = true;
+        }
+        if self.disabled {
+            if let Some(handler) = self.when_disabled_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        let state = window.use_keyed_state(self.id.clone(), app, |_, _| LinkState::default());
+
+        let state_read = state.read(app);
+        let hovered = state_read.hovered;
+        let visited = state_read.visited;
+
+        if visited {
+            if let Some(handler) = self.when_visited_handler.take() {
+                self = handler(self);
+            }
+        }
+        if hovered {
+            if let Some(handler) = self.when_hovered_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        let navigate: Navigate = {
+            let state = state.clone();
+            let href = self.href.clone();
+            let on_navigate = self.on_navigate.clone();
+            Rc::new(move |new_window, window, app| {
+                state.update(app, |state, cx| {
+                    if !state.visited {
+                        state.visited = true;
+                        cx.notify();
+                    }
+                });
+
+                let event = NavigateEvent {
+                    href: href.clone(),
+                    new_window,
+                };
+                if let Some(on_navigate) = &on_navigate {
+                    on_navigate(&event, window, app);
+                } else {
+                    app.open_url(&event.href);
+                }
+            })
+        };
+
+        self.base
+            .on_hover({
+                let state = state.clone();
+                move |hovered, _, app| {
+                    state.update(app, |state, cx| {
+                        state.hovered = *hovered;
+                        cx.notify();
+                    });
+                }
+            })
+            .when(!self.disabled, |this| {
+                this.map(|this| {
+                    let navigate = navigate.clone();
+                    this.on_mouse_up(MouseButton::Middle, move |_, window, app| {
+                        navigate(true, window, app);
+                    })
+                })
+                .on_click(move |event, window, app| {
+                    navigate(event.modifiers().secondary(), window, app);
+                })
+            })
+            .children(self.children)
+    }
+}
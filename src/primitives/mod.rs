@@ -1,17 +1,32 @@
-use gpui::{
-    App, Div, InteractiveElement, ParentElement, SharedString, Stateful,
-    StatefulInteractiveElement, Styled, div,
-};
+use crate::Direction;
+use gpui::{App, Div, ElementId, KeyBinding, ParentElement, SharedString, Styled, div};
 
+pub mod anchored;
 mod button;
 mod checkbox;
+mod collapsible;
+mod focus_trap;
+mod kbd;
+mod link;
+mod popover;
+mod skeleton;
+mod spinner;
 pub mod text_field;
+mod virtual_list;
 
 pub use button::*;
 pub use checkbox::*;
+pub use collapsible::*;
+pub use focus_trap::*;
+pub use kbd::*;
+pub use link::*;
+pub use popover::*;
+pub use skeleton::*;
+pub use spinner::*;
+pub use virtual_list::*;
 
-pub(super) fn init(app: &mut App) {
-    text_field::init(app);
+pub(super) fn default_bindings() -> Vec<KeyBinding> {
+    text_field::default_bindings()
 }
 
 /// Shorthand for creating a vertical flex `Div` element.
@@ -24,6 +39,23 @@ pub fn h_flex() -> Div {
     div().flex().flex_row()
 }
 
+/// Shorthand for creating a horizontal flex `Div` element that follows the layout
+/// [`Direction`], placing its first child at the logical start.
+///
+/// Children are laid out right to left when the direction is RTL.
+pub fn h_flex_start(app: &App) -> Div {
+    if Direction::current(app).is_rtl() {
+        div().flex().flex_row_reverse()
+    } else {
+        h_flex()
+    }
+}
+
+/// Like [`h_flex_start`], packing the children against the logical end.
+pub fn h_flex_end(app: &App) -> Div {
+    h_flex_start(app).justify_end()
+}
+
 /// Shorthand for creating a vertical flex `Div` element with center alignment.
 pub fn v_flex_center() -> Div {
     v_flex().justify_center().items_center()
@@ -39,10 +71,10 @@ pub fn span(text: impl Into<SharedString>) -> Div {
     div().child(text.into())
 }
 
-/// Shorthand for creating a `Div` element with an anchor (`<a>`) behavior.
-pub fn a(href: impl Into<SharedString>) -> Stateful<Div> {
-    let href: SharedString = href.into();
-    div().id("a").on_click(move |_, _, app| {
-        app.open_url(&href);
-    })
+/// Shorthand for creating a [`Link`] element with an anchor (`<a>`) behavior.
+///
+/// Like any stateful element, the id must be unique among its siblings, so links sharing an
+/// href each need their own id.
+pub fn a(id: impl Into<ElementId>, href: impl Into<SharedString>) -> Link {
+    link(id, href)
 }
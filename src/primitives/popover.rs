@@ -0,0 +1,213 @@
+use crate::{
+    Layer, OpenProps, OpenReason, OpenState, Openable,
+    primitives::anchored::{Align, AnchoredOptions, Side, floating},
+    use_open_state, use_overlay,
+};
+use gpui::{prelude::FluentBuilder, *};
+use smallvec::SmallVec;
+
+/// Floating content anchored to a trigger, the building block of popovers, selects,
+/// tooltips and date pickers.
+///
+/// Clicking the trigger toggles the content, which is rendered on the [`Layer::Popover`]
+/// layer next to it and flipped or shifted to stay within the window. Clicking outside
+/// both, or pressing Escape while it is the topmost overlay, closes it. The content can also be anchored to any bounds with
+/// [`AnchoredPopover::anchor`], such as the pointer position for a context menu.
+///
+/// # Examples
+///
+/// ```rust
+/// anchored_popover("share")
+///     .trigger(button("share-trigger").child("Share"))
+///     .side(Side::Top)
+///     .on_open_change(cx.listener(|this, event: &OpenChangeEvent, _, cx| {
+///         this.sharing = event.open;
+///         cx.notify();
+///     }))
+///     .open(self.sharing)
+///     .bg(rgb(0xffffff))
+///     .p_2()
+///     .child("Copy link")
+/// ```
+pub fn anchored_popover(id: impl Into<ElementId>) -> AnchoredPopover {
+    AnchoredPopover {
+        id: id.into(),
+        base: div(),
+        trigger: None,
+        anchor: None,
+        children: SmallVec::new(),
+        open_props: OpenProps::default(),
+        options: AnchoredOptions::default(),
+        dismiss_on_escape: true,
+        dismiss_on_outside_click: true,
+    }
+}
+
+#[derive(IntoElement)]
+pub struct AnchoredPopover {
+    id: ElementId,
+    base: Div,
+    trigger: Option<AnyElement>,
+    anchor: Option<Bounds<Pixels>>,
+    children: SmallVec<[AnyElement; 2]>,
+    open_props: OpenProps,
+    options: AnchoredOptions,
+    dismiss_on_escape: bool,
+    dismiss_on_outside_click: bool,
+}
+
+impl AnchoredPopover {
+    /// The element that toggles the content when clicked, and that it is anchored to.
+    pub fn trigger(mut self, trigger: impl IntoElement) -> Self {
+        self.trigger = Some(trigger.into_any_element());
+        self
+    }
+
+    /// Anchors the content to the given window bounds instead of the trigger.
+    pub fn anchor(mut self, anchor: Bounds<Pixels>) -> Self {
+        self.anchor = Some(anchor);
+        self
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.options.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.options.align = align;
+        self
+    }
+
+    /// Distance between the anchor and the content.
+    pub fn offset(mut self, offset: Pixels) -> Self {
+        self.options.offset = offset;
+        self
+    }
+
+    pub fn options(mut self, options: AnchoredOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Whether pressing Escape closes the content, true by default.
+    pub fn dismiss_on_escape(mut self, dismiss: bool) -> Self {
+        self.dismiss_on_escape = dismiss;
+        self
+    }
+
+    /// Whether clicking outside the trigger and the content closes it, true by default.
+    pub fn dismiss_on_outside_click(mut self, dismiss: bool) -> Self {
+        self.dismiss_on_outside_click = dismiss;
+        self
+    }
+}
+
+impl Openable for AnchoredPopover {
+    fn open_props_mut(&mut self) -> &mut OpenProps {
+        &mut self.open_props
+    }
+}
+
+impl ParentElement for AnchoredPopover {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for AnchoredPopover {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+fn set_open(
+    open_state: &Entity<OpenState>,
+    open: bool,
+    reason: OpenReason,
+    window: &mut Window,
+    app: &mut App,
+) {
+    open_state.update(app, |state, cx| state.set_open(open, reason, window, cx));
+}
+
+impl RenderOnce for AnchoredPopover {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let open_state = use_open_state(self.id.clone(), &self.open_props, window, app);
+        let open = open_state.read(app).is_open();
+
+        let trigger_bounds = window.use_keyed_state(
+            ElementId::NamedChild(Box::new(self.id.clone()), "trigger-bounds".into()),
+            app,
+            |_, _| None::<Bounds<Pixels>>,
+        );
+        let anchor = self.anchor.or(*trigger_bounds.read(app));
+        let has_trigger = self.trigger.is_some();
+
+        let content = anchor.filter(|_| open).map(|anchor| {
+            let trigger_bounds = *trigger_bounds.read(app);
+            let dismiss_on_outside_click = self.dismiss_on_outside_click;
+            let open_state = open_state.clone();
+            floating(anchor)
+                .options(self.options)
+                .on_mouse_down_out(move |event, window, app| {
+                    // The trigger toggles the content itself.
+                    let on_trigger =
+                        trigger_bounds.is_some_and(|bounds| bounds.contains(&event.position));
+                    if dismiss_on_outside_click && !on_trigger {
+                        set_open(&open_state, false, OpenReason::OutsideClick, window, app);
+                    }
+                })
+                .child(
+                    self.base
+                        .id(ElementId::NamedChild(
+                            Box::new(self.id.clone()),
+                            "content".into(),
+                        ))
+                        .occlude()
+                        .children(self.children),
+                )
+                .into_any_element()
+        });
+        // Outside clicks are handled by the content, which knows about the trigger.
+        use_overlay(
+            &self.id,
+            Layer::Popover,
+            content,
+            |overlay| {
+                let open_state = open_state.clone();
+                overlay
+                    .dismiss_on_outside_click(false)
+                    .dismiss_on_escape(self.dismiss_on_escape)
+                    .on_dismiss(move |window, app| {
+                        set_open(&open_state, false, OpenReason::EscapeKey, window, app)
+                    })
+            },
+            window,
+            app,
+        );
+
+        div()
+            .id(self.id)
+            .relative()
+            .when(has_trigger, |this| {
+                this.on_click(move |_, window, app| {
+                    set_open(&open_state, !open, OpenReason::Trigger, window, app);
+                })
+            })
+            .when(has_trigger, |this| {
+                this.child(
+                    canvas(
+                        move |bounds, _, app| {
+                            trigger_bounds
+                                .update(app, |trigger_bounds, _| *trigger_bounds = Some(bounds))
+                        },
+                        |_, _, _, _| {},
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+            })
+            .children(self.trigger)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{MotionPreference, primitives::h_flex};
+use gpui::*;
+use std::time::Duration;
+
+const DEFAULT_DURATION: Duration = Duration::from_millis(1500);
+
+/// How a [`Skeleton`] signals that content is loading.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SkeletonAnimation {
+    /// Fades the placeholder in and out.
+    #[default]
+    Pulse,
+    /// Sweeps a highlight across the placeholder.
+    Shimmer,
+}
+
+pub fn skeleton(id: impl Into<ElementId>) -> Skeleton {
+    Skeleton {
+        id: id.into(),
+        base: div(),
+        animation: SkeletonAnimation::default(),
+        duration: DEFAULT_DURATION,
+        highlight: hsla(0., 0., 1., 0.5),
+    }
+}
+
+/// A placeholder standing in for content that is still loading.
+///
+/// The placeholder takes the size and color it is styled with and loops its animation
+/// while rendered. The animation stops when motion is reduced, see [`MotionPreference`].
+///
+/// # Examples
+///
+/// ```rust
+/// v_flex()
+///     .gap_2()
+///     .child(skeleton("title").h_4().w_48().rounded_sm().bg(rgb(0xe2e8f0)))
+///     .child(
+///         skeleton("body")
+///             .animation(SkeletonAnimation::Shimmer)
+///             .h_16()
+///             .w_full()
+///             .rounded_sm()
+///             .bg(rgb(0xe2e8f0)),
+///     )
+/// ```
+#[derive(IntoElement)]
+pub struct Skeleton {
+    id: ElementId,
+    base: Div,
+    animation: SkeletonAnimation,
+    duration: Duration,
+    highlight: Hsla,
+}
+
+impl Skeleton {
+    pub fn animation(mut self, animation: SkeletonAnimation) -> Self {
+        self.animation = animation;
+        self
+    }
+
+    /// How long one loop of the animation takes, 1.5s by default.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// The color swept across the placeholder by [`SkeletonAnimation::Shimmer`],
+    /// translucent white by default.
+    pub fn highlight(mut self, color: impl Into<Hsla>) -> Self {
+        self.highlight = color.into();
+        self
+    }
+}
+
+impl Styled for Skeleton {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Skeleton {
+    fn render(self, _window: &mut Window, app: &mut App) -> impl IntoElement {
+        if MotionPreference::current(app).is_reduced() {
+            return self.base.id(self.id).into_any_element();
+        }
+
+        let animation = Animation::new(self.duration).repeat();
+        match self.animation {
+            SkeletonAnimation::Pulse => self
+                .base
+                .with_animation(
+                    self.id,
+                    animation.with_easing(pulsating_between(0.5, 1.)),
+                    |this, opacity| this.opacity(opacity),
+                )
+                .into_any_element(),
+            SkeletonAnimation::Shimmer => {
+                let clear = self.highlight.opacity(0.);
+                let band = h_flex()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .w_full()
+                    .child(div().flex_1().h_full().bg(linear_gradient(
+                        90.,
+                        linear_color_stop(clear, 0.),
+                        linear_color_stop(self.highlight, 1.),
+                    )))
+                    .child(div().flex_1().h_full().bg(linear_gradient(
+                        90.,
+                        linear_color_stop(self.highlight, 0.),
+                        linear_color_stop(clear, 1.),
+                    )));
+
+                self.base
+                    .id(self.id)
+                    .relative()
+                    .overflow_hidden()
+                    .child(
+                        band.with_animation("skeleton-shimmer", animation, |band, delta| {
+                            band.left(relative(delta * 2. - 1.))
+                        }),
+                    )
+                    .into_any_element()
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::MotionPreference;
+use gpui::{prelude::FluentBuilder, *};
+use std::{f32::consts::TAU, time::Duration};
+
+const DEFAULT_DURATION: Duration = Duration::from_millis(800);
+/// How much of the circle the default arc covers.
+const ARC_SWEEP: f32 = TAU * 0.75;
+
+pub fn spinner(id: impl Into<ElementId>) -> Spinner {
+    Spinner {
+        id: id.into(),
+        base: div().size_4().flex_none(),
+        icon: None,
+        duration: DEFAULT_DURATION,
+        color: None,
+        thickness: px(2.),
+    }
+}
+
+/// An indicator that something is in progress, spinning while rendered.
+///
+/// It draws an open arc in the current text color by default, or rotates the given icon.
+/// Only SVGs can be rotated in gpui, other elements don't spin. When motion is reduced,
+/// see [`MotionPreference`], the spinner stands still.
+///
+/// # Examples
+///
+/// ```rust
+/// h_flex()
+///     .gap_2()
+///     .child(spinner("saving").size_3().text_color(rgb(0x64748b)))
+///     .child("Saving…")
+/// ```
+#[derive(IntoElement)]
+pub struct Spinner {
+    id: ElementId,
+    base: Div,
+    icon: Option<Svg>,
+    duration: Duration,
+    color: Option<Hsla>,
+    thickness: Pixels,
+}
+
+impl Spinner {
+    /// Rotates the icon instead of drawing an arc.
+    pub fn icon(mut self, icon: Svg) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// How long one revolution takes, 800ms by default.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// The color of the arc, the current text color by default.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// The stroke width of the arc, 2px by default.
+    pub fn thickness(mut self, thickness: Pixels) -> Self {
+        self.thickness = thickness;
+        self
+    }
+}
+
+impl Styled for Spinner {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+/// Paints an open arc filling the bounds, starting at the given angle.
+fn paint_arc(
+    bounds: Bounds<Pixels>,
+    start: f32,
+    thickness: Pixels,
+    color: Option<Hsla>,
+    window: &mut Window,
+) {
+    let radius = bounds.size.width.min(bounds.size.height) / 2. - thickness / 2.;
+    if radius <= px(0.) {
+        return;
+    }
+
+    let center = bounds.center();
+    let at = |angle: f32| {
+        point(
+            center.x + radius * angle.cos(),
+            center.y + radius * angle.sin(),
+        )
+    };
+    let mut builder = PathBuilder::stroke(thickness);
+    builder.move_to(at(start));
+    builder.arc_to(
+        point(radius, radius),
+        px(0.),
+        ARC_SWEEP > TAU / 2.,
+        true,
+        at(start + ARC_SWEEP),
+    );
+    if let Ok(path) = builder.build() {
+        let color = color.unwrap_or_else(|| window.text_style().color);
+        window.paint_path(path, color);
+    }
+}
+
+impl RenderOnce for Spinner {
+    fn render(self, _window: &mut Window, app: &mut App) -> impl IntoElement {
+        let reduced = MotionPreference::current(app).is_reduced();
+        let (thickness, color) = (self.thickness, self.color);
+
+        match self.icon {
+            Some(icon) => {
+                let icon = icon.size_full();
+                self.base
+                    .child(if reduced {
+                        icon.into_any_element()
+                    } else {
+                        icon.with_animation(
+                            self.id,
+                            Animation::new(self.duration).repeat(),
+                            |icon, delta| {
+                                icon.with_transformation(Transformation::rotate(radians(
+                                    delta * TAU,
+                                )))
+                            },
+                        )
+                        .into_any_element()
+                    })
+                    .into_any_element()
+            }
+            None => {
+                let arc = move |angle: f32| {
+                    canvas(
+                        |_, _, _| {},
+                        move |bounds, _, window, _| {
+                            paint_arc(bounds, angle, thickness, color, window)
+                        },
+                    )
+                    .size_full()
+                };
+                self.base
+                    .when(reduced, |this| this.child(arc(0.)))
+                    .when(!reduced, |this| {
+                        this.child(div().size_full().with_animation(
+                            self.id,
+                            Animation::new(self.duration).repeat(),
+                            move |this, delta| this.child(arc(delta * TAU)),
+                        ))
+                    })
+                    .into_any_element()
+            }
+        }
+    }
+}
@@ -1,9 +1,52 @@
-use super::CONTEXT;
+use super::{CONTEXT, NUMBER_CONTEXT};
 use gpui::{Action, App, KeyBinding, actions};
 
-/// Initialize text field key bindings and actions
-pub fn init(app: &mut App) {
-    app.bind_keys([
+/// A set of text field key bindings applied on top of the defaults by [`init_with_preset`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Preset {
+    /// The platform's own bindings only.
+    #[default]
+    Platform,
+    /// Emacs-style bindings as in shells using readline, on every platform: Ctrl-A/Ctrl-E/
+    /// Ctrl-B/Ctrl-F move, Ctrl-K/Ctrl-U kill to the end or start, Ctrl-W kills the word
+    /// before the cursor, Ctrl-T transposes characters, Ctrl-Y yanks the last killed text and
+    /// Alt-Y replaces it with the kill before.
+    Readline,
+}
+
+/// Registers the bindings of the preset, after [`crate::init`] or the first
+/// [`crate::LapislazuliProvider`] so they take precedence over the defaults.
+pub fn init_with_preset(preset: Preset, app: &mut App) {
+    app.bind_keys(preset_bindings(preset));
+}
+
+/// The bindings a preset adds to the defaults.
+pub fn preset_bindings(preset: Preset) -> Vec<KeyBinding> {
+    match preset {
+        Preset::Platform => Vec::new(),
+        Preset::Readline => vec![
+            key_binding("ctrl-a", Home),
+            key_binding("ctrl-e", End),
+            key_binding("ctrl-b", Left),
+            key_binding("ctrl-f", Right),
+            key_binding("alt-b", WordLeft),
+            key_binding("alt-f", WordRight),
+            key_binding("ctrl-h", Backspace),
+            key_binding("ctrl-d", Delete),
+            key_binding("alt-d", DeleteWordRight),
+            key_binding("ctrl-k", DeleteToEnd),
+            key_binding("ctrl-u", DeleteToBeginning),
+            key_binding("ctrl-w", DeleteWordLeft),
+            key_binding("ctrl-t", Transpose),
+            key_binding("ctrl-y", Yank),
+            key_binding("alt-y", YankPop),
+        ],
+    }
+}
+
+/// The default text field key bindings
+pub(crate) fn default_bindings() -> Vec<KeyBinding> {
+    let mut bindings = vec![
         key_binding("left", Left),
         key_binding("right", Right),
         key_binding("home", Home),
@@ -13,17 +56,20 @@ pub fn init(app: &mut App) {
         key_binding("backspace", Backspace),
         key_binding("delete", Delete),
         key_binding("enter", Enter),
-    ]);
+        KeyBinding::new("up", Increment, Some(NUMBER_CONTEXT)),
+        KeyBinding::new("down", Decrement, Some(NUMBER_CONTEXT)),
+    ];
 
     #[cfg(target_os = "macos")]
-    macos_bindings(app);
+    bindings.extend(macos_bindings());
     #[cfg(not(target_os = "macos"))]
-    windows_linux_bindings(app);
+    bindings.extend(windows_linux_bindings());
+    bindings
 }
 
 #[cfg(not(target_os = "macos"))]
-fn windows_linux_bindings(app: &mut App) {
-    app.bind_keys([
+fn windows_linux_bindings() -> Vec<KeyBinding> {
+    vec![
         key_binding("ctrl-left", WordLeft),
         key_binding("ctrl-right", WordRight),
         key_binding("ctrl-a", SelectAll),
@@ -42,12 +88,12 @@ fn windows_linux_bindings(app: &mut App) {
         key_binding("ctrl-z", Undo),
         key_binding("ctrl-y", Redo),
         key_binding("ctrl-shift-z", Redo),
-    ]);
+    ]
 }
 
 #[cfg(target_os = "macos")]
-fn macos_bindings(app: &mut App) {
-    app.bind_keys([
+fn macos_bindings() -> Vec<KeyBinding> {
+    vec![
         key_binding("ctrl-b", Left),
         key_binding("ctrl-f", Right),
         key_binding("alt-left", WordLeft),
@@ -71,7 +117,7 @@ fn macos_bindings(app: &mut App) {
         key_binding("ctrl-cmd-space", ShowCharacterPalette),
         key_binding("cmd-z", Undo),
         key_binding("cmd-shift-z", Redo),
-    ]);
+    ]
 }
 
 fn key_binding(keystrokes: &str, action: impl Action) -> KeyBinding {
@@ -107,5 +153,10 @@ actions!(
         Undo,
         Redo,
         Enter,
+        Transpose,
+        Yank,
+        YankPop,
+        Increment,
+        Decrement,
     ]
 );
@@ -0,0 +1,19 @@
+use gpui::{App, ElementId, SharedString, Window};
+use std::{rc::Rc, time::Duration};
+
+/// The state of a text field handed to its autosave callback.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldSnapshot {
+    pub id: ElementId,
+    pub value: SharedString,
+    /// Whether the value differs from the one the field was last given with `.value(..)`,
+    /// or from an empty value if it never was.
+    pub dirty: bool,
+}
+
+/// How often a text field saves its draft, and where to.
+#[allow(clippy::type_complexity)]
+pub(super) struct Autosave {
+    pub(super) interval: Duration,
+    pub(super) callback: Rc<dyn Fn(&FieldSnapshot, &mut Window, &mut App) + 'static>,
+}
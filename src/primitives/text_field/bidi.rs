@@ -0,0 +1,174 @@
+use crate::Direction;
+use gpui::{Pixels, ShapedLine, px};
+use std::ops::Range;
+use unicode_bidi::{BidiInfo, Level};
+
+/// How the Left and Right arrow keys move the cursor through text mixing left-to-right and
+/// right-to-left runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CursorMovement {
+    /// Forward or backward in reading order, following the direction of the field, so the
+    /// cursor jumps across runs of the other direction.
+    #[default]
+    Logical,
+    /// Towards the pressed arrow on screen.
+    Visual,
+}
+
+/// A glyph of a [`VisualLine`] with the text it renders.
+#[derive(Clone, Debug)]
+struct VisualGlyph {
+    range: Range<usize>,
+    left: Pixels,
+    right: Pixels,
+    rtl: bool,
+}
+
+/// The glyphs of a shaped line in visual order, which differs from the logical order of
+/// the text where right-to-left runs are reordered.
+///
+/// Offsets are byte offsets in logical order. The caret at an offset sits on the leading
+/// edge of the character after it: its left edge in left-to-right runs and its right edge
+/// in right-to-left runs.
+pub(super) struct VisualLine {
+    glyphs: Vec<VisualGlyph>,
+    len: usize,
+}
+
+impl VisualLine {
+    pub(super) fn new(line: &ShapedLine, direction: Direction) -> Self {
+        let glyphs = line.runs.iter().flat_map(|run| {
+            run.glyphs
+                .iter()
+                .map(|glyph| (glyph.index, glyph.position.x))
+        });
+        Self::from_glyphs(&line.text, glyphs, line.width, direction)
+    }
+
+    /// Builds the line from the offset and position of each glyph.
+    pub(super) fn from_glyphs(
+        text: &str,
+        glyphs: impl IntoIterator<Item = (usize, Pixels)>,
+        width: Pixels,
+        direction: Direction,
+    ) -> Self {
+        let mut glyphs = glyphs.into_iter().collect::<Vec<_>>();
+        let mut starts = glyphs.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+        starts.sort_unstable();
+        starts.dedup();
+
+        let level = if direction.is_rtl() {
+            Level::rtl()
+        } else {
+            Level::ltr()
+        };
+        let levels = BidiInfo::new(text, Some(level)).levels;
+
+        glyphs.sort_by_key(|(_, x)| *x);
+        let rights = glyphs
+            .iter()
+            .skip(1)
+            .map(|(_, x)| *x)
+            .chain([width])
+            .collect::<Vec<_>>();
+        let glyphs = glyphs
+            .into_iter()
+            .zip(rights)
+            .map(|((start, left), right)| {
+                let end = starts
+                    .iter()
+                    .find(|&&next| next > start)
+                    .map_or(text.len(), |&next| next);
+                VisualGlyph {
+                    range: start..end,
+                    left,
+                    right,
+                    rtl: levels.get(start).is_some_and(|level| level.is_rtl()),
+                }
+            })
+            .collect();
+
+        Self {
+            glyphs,
+            len: text.len(),
+        }
+    }
+
+    /// Returns the x position of the caret at the offset.
+    pub(super) fn x_for_index(&self, index: usize) -> Pixels {
+        if index >= self.len {
+            // After the last character, on its trailing edge.
+            return self
+                .glyphs
+                .iter()
+                .find(|glyph| glyph.range.end == self.len)
+                .map_or(
+                    px(0.),
+                    |glyph| if glyph.rtl { glyph.left } else { glyph.right },
+                );
+        }
+
+        self.glyphs
+            .iter()
+            .find(|glyph| glyph.range.contains(&index))
+            .map_or(
+                px(0.),
+                |glyph| if glyph.rtl { glyph.right } else { glyph.left },
+            )
+    }
+
+    /// Returns the offset of the caret closest to the x position.
+    pub(super) fn closest_index_for_x(&self, x: Pixels) -> usize {
+        let Some(glyph) = self
+            .glyphs
+            .iter()
+            .find(|glyph| x < glyph.right)
+            .or(self.glyphs.last())
+        else {
+            return 0;
+        };
+
+        let before_middle = x < glyph.left + (glyph.right - glyph.left) * 0.5;
+        if before_middle != glyph.rtl {
+            glyph.range.start
+        } else {
+            glyph.range.end
+        }
+    }
+
+    /// Returns the horizontal extents covered by the characters in the range, from left to
+    /// right. A range spanning both directions may cover several disjoint extents.
+    pub(super) fn selection_ranges(&self, range: &Range<usize>) -> Vec<Range<Pixels>> {
+        let mut extents: Vec<Range<Pixels>> = Vec::new();
+        for glyph in &self.glyphs {
+            if !range.contains(&glyph.range.start) {
+                continue;
+            }
+            match extents.last_mut() {
+                Some(extent) if extent.end == glyph.left => extent.end = glyph.right,
+                _ => extents.push(glyph.left..glyph.right),
+            }
+        }
+        extents
+    }
+
+    /// Returns the offset of the closest caret position to the right or to the left of the
+    /// caret at `index` on screen, if any.
+    pub(super) fn visual_neighbor(&self, index: usize, right: bool) -> Option<usize> {
+        let x = self.x_for_index(index);
+        self.glyphs
+            .iter()
+            .map(|glyph| glyph.range.start)
+            .chain([self.len])
+            .filter(|&offset| offset != index)
+            .map(|offset| (offset, self.x_for_index(offset)))
+            .filter(|(_, offset_x)| if right { *offset_x > x } else { *offset_x < x })
+            .min_by(|(a, a_x), (b, b_x)| {
+                (*a_x - x)
+                    .abs()
+                    .cmp(&(*b_x - x).abs())
+                    .then(a.abs_diff(index).cmp(&b.abs_diff(index)))
+            })
+            .map(|(offset, _)| offset)
+    }
+}
@@ -0,0 +1,46 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The length of a text field's value, given to [`super::TextField::with_counter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CounterContext {
+    graphemes: usize,
+    words: usize,
+    max_length: Option<usize>,
+}
+
+impl CounterContext {
+    pub(super) fn new(text: &str, max_length: Option<usize>) -> Self {
+        Self {
+            graphemes: text.graphemes(true).count(),
+            words: text.unicode_words().count(),
+            max_length,
+        }
+    }
+
+    /// The number of characters as the user sees them, which is what `max_length` limits.
+    pub fn graphemes(&self) -> usize {
+        self.graphemes
+    }
+
+    pub fn words(&self) -> usize {
+        self.words
+    }
+
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// How many more characters fit, if the length is limited.
+    pub fn remaining(&self) -> Option<usize> {
+        self.max_length
+            .map(|max_length| max_length.saturating_sub(self.graphemes))
+    }
+
+    /// Formats the count as `12/100`, or just `12` when the length is not limited.
+    pub fn label(&self) -> String {
+        match self.max_length {
+            Some(max_length) => format!("{}/{max_length}", self.graphemes),
+            None => self.graphemes.to_string(),
+        }
+    }
+}
@@ -2,13 +2,16 @@
  * https://github.com/longbridge/gpui-component/blob/main/crates/ui/src/input/blink_cursor.rs
  */
 
+use crate::MotionPreference;
 use gpui::{Context, Timer};
 use std::time::Duration;
 
-static INTERVAL: Duration = Duration::from_millis(500);
+pub(super) static DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
 static PAUSE_DELAY: Duration = Duration::from_millis(500);
 
 pub struct Cursor {
+    interval: Duration,
+    blinks: bool,
     visible: bool,
     paused: bool,
     epoch: usize,
@@ -18,6 +21,8 @@ pub struct Cursor {
 impl Cursor {
     pub fn new() -> Self {
         Self {
+            interval: DEFAULT_INTERVAL,
+            blinks: true,
             visible: true,
             paused: false,
             epoch: 0,
@@ -31,6 +36,23 @@ impl Cursor {
         self.visible = true;
     }
 
+    /// Sets how long the cursor stays shown or hidden while blinking.
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+    }
+
+    /// Sets whether the cursor blinks, restarting the blinking if it was turned back on.
+    pub fn set_blinks(&mut self, blinks: bool, cx: &mut Context<Self>) {
+        if self.blinks == blinks {
+            return;
+        }
+        self.blinks = blinks;
+        if blinks && self.epoch != 0 {
+            let epoch = self.next_epoch();
+            self.blink(epoch, cx);
+        }
+    }
+
     /// Stop the blinking
     pub fn stop(&mut self) {
         self.epoch = 0;
@@ -48,12 +70,19 @@ impl Cursor {
             return;
         }
 
-        self.visible = !self.visible;
-        cx.notify();
+        // Keep the cursor steadily visible instead of blinking, but keep checking so it
+        // blinks again once the preference changes.
+        let steady = !self.blinks || MotionPreference::current(cx).is_reduced();
+        let visible = steady || !self.visible;
+        if visible != self.visible {
+            self.visible = visible;
+            cx.notify();
+        }
 
         let epoch = self.next_epoch();
+        let interval = self.interval;
         cx.spawn(async move |this, cx| {
-            Timer::after(INTERVAL).await;
+            Timer::after(interval).await;
             if let Some(this) = this.upgrade() {
                 this.update(cx, |this, cx| this.blink(epoch, cx)).ok();
             }
@@ -1,4 +1,4 @@
-use super::state::TextFieldState;
+use super::{bidi::VisualLine, state::TextFieldState};
 use gpui::*;
 use std::ops::Range;
 use unicode_segmentation::UnicodeSegmentation;
@@ -13,22 +13,32 @@ const MARKED_TEXT_UNDERLINE_THICKNESS: f32 = 1.0;
 /// - Cursor positioning and visibility
 /// - Text selection highlighting
 /// - Automatic scrolling to keep cursor visible
-/// - Placeholder text when empty
+/// - Placeholder text or element when empty
 /// - Marked text (IME composition) with underlines
 pub struct TextElement {
     state: Entity<TextFieldState>,
+    placeholder: Option<AnyElement>,
 }
 
 impl TextElement {
     pub fn new(state: Entity<TextFieldState>) -> Self {
-        Self { state }
+        Self {
+            state,
+            placeholder: None,
+        }
+    }
+
+    /// Shown instead of the placeholder text while the field is empty.
+    pub fn placeholder(mut self, placeholder: Option<AnyElement>) -> Self {
+        self.placeholder = placeholder;
+        self
     }
 }
 
 pub struct PrepaintState {
     line: Option<ShapedLine>,
     cursor: Option<PaintQuad>,
-    selection: Option<PaintQuad>,
+    selection: Vec<PaintQuad>,
 }
 
 impl IntoElement for TextElement {
@@ -71,56 +81,163 @@ impl TextElement {
         )
     }
 
+    /// Shapes the text shown by the field, returning it with the color of the text.
+    fn shape_line(&self, state: &TextFieldState, window: &mut Window) -> (ShapedLine, Hsla) {
+        let style = window.text_style();
+
+        let (display_text, text_color) = self.prepare_display_text(state, style.color);
+
+        let base_run = TextRun {
+            len: display_text.len(),
+            font: style.font(),
+            color: text_color,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+
+        let runs = self.create_text_runs(
+            state,
+            &display_text,
+            base_run,
+            state.marked_underline_color.unwrap_or(text_color),
+        );
+
+        let font_size = style.font_size.to_pixels(window.rem_size());
+        let line = window
+            .text_system()
+            .shape_line(display_text, font_size, &runs, None);
+        (line, text_color)
+    }
+
     fn create_text_runs(
         &self,
+        state: &TextFieldState,
         display_text: &str,
         base_run: TextRun,
-        marked_range: Option<&Range<usize>>,
-        is_masked: bool,
+        underline_color: Hsla,
     ) -> Vec<TextRun> {
-        // For masked text, we've already excluded marked text from display_text,
-        // so no need for marked text styling
-        if is_masked || marked_range.is_none() {
+        // Placeholders aren't highlighted, and masked text excludes the marked text.
+        if state.masked || state.value.is_empty() {
             return vec![base_run];
         }
 
-        if let Some(marked_range) = marked_range {
-            // Ensure marked_range doesn't exceed display_text bounds
-            let display_len = display_text.len();
-            if marked_range.start >= display_len || marked_range.end > display_len {
-                return vec![base_run];
-            }
+        let highlights = state
+            .highlighter
+            .as_ref()
+            .map(|highlighter| highlighter(display_text))
+            .unwrap_or_default();
+        text_runs(
+            display_text,
+            base_run,
+            highlights,
+            state.marked_range.clone(),
+            underline_color,
+        )
+    }
+}
 
-            vec![
-                TextRun {
-                    len: marked_range.start,
-                    ..base_run.clone()
-                },
-                TextRun {
-                    len: marked_range.end - marked_range.start,
-                    underline: Some(UnderlineStyle {
-                        color: Some(base_run.color),
-                        thickness: px(MARKED_TEXT_UNDERLINE_THICKNESS),
-                        wavy: false,
-                    }),
-                    ..base_run.clone()
-                },
-                TextRun {
-                    len: display_len - marked_range.end,
-                    ..base_run.clone()
-                },
-            ]
-            .into_iter()
-            .filter(|run| run.len > 0)
-            .collect()
-        } else {
-            vec![base_run]
+/// Splits the base run of `text` into the runs of its highlights, then underlines the
+/// marked text over them. Ranges that are empty, out of bounds or not on char boundaries
+/// are ignored.
+pub(super) fn text_runs(
+    text: &str,
+    base_run: TextRun,
+    highlights: Vec<(Range<usize>, HighlightStyle)>,
+    marked_range: Option<Range<usize>>,
+    underline_color: Hsla,
+) -> Vec<TextRun> {
+    let is_valid = |range: &Range<usize>| {
+        !range.is_empty()
+            && range.end <= text.len()
+            && text.is_char_boundary(range.start)
+            && text.is_char_boundary(range.end)
+    };
+
+    let mut runs = vec![base_run];
+    for (range, highlight) in highlights {
+        if is_valid(&range) {
+            runs = split_runs(runs, range, |run| highlight_run(run, &highlight));
         }
     }
+
+    if let Some(marked_range) = marked_range
+        && is_valid(&marked_range)
+    {
+        runs = split_runs(runs, marked_range, |run| {
+            run.underline = Some(UnderlineStyle {
+                color: Some(underline_color),
+                thickness: px(MARKED_TEXT_UNDERLINE_THICKNESS),
+                wavy: false,
+            });
+        });
+    }
+
+    runs
+}
+
+/// Splits the runs at the bounds of `range`, styling the runs within it.
+pub(super) fn split_runs(
+    runs: Vec<TextRun>,
+    range: Range<usize>,
+    style: impl Fn(&mut TextRun),
+) -> Vec<TextRun> {
+    let mut split = Vec::with_capacity(runs.len() + 2);
+    let mut start = 0;
+    for run in runs {
+        let end = start + run.len;
+        let inside_start = range.start.clamp(start, end);
+        let inside_end = range.end.clamp(inside_start, end);
+        for (piece, inside) in [
+            (start..inside_start, false),
+            (inside_start..inside_end, true),
+            (inside_end..end, false),
+        ] {
+            if piece.is_empty() {
+                continue;
+            }
+            let mut run = TextRun {
+                len: piece.len(),
+                ..run.clone()
+            };
+            if inside {
+                style(&mut run);
+            }
+            split.push(run);
+        }
+        start = end;
+    }
+    split
+}
+
+/// Applies a highlight to a run, the way [`TextStyle::highlight`] applies it to a style.
+fn highlight_run(run: &mut TextRun, highlight: &HighlightStyle) {
+    if let Some(weight) = highlight.font_weight {
+        run.font.weight = weight;
+    }
+    if let Some(style) = highlight.font_style {
+        run.font.style = style;
+    }
+    if let Some(color) = highlight.color {
+        run.color = run.color.blend(color);
+    }
+    if let Some(factor) = highlight.fade_out {
+        run.color.fade_out(factor);
+    }
+    if let Some(background_color) = highlight.background_color {
+        run.background_color = Some(background_color);
+    }
+    if let Some(underline) = highlight.underline {
+        run.underline = Some(underline);
+    }
+    if let Some(strikethrough) = highlight.strikethrough {
+        run.strikethrough = Some(strikethrough);
+    }
 }
 
 impl Element for TextElement {
-    type RequestLayoutState = ();
+    /// The placeholder element, when shown.
+    type RequestLayoutState = Option<AnyElement>;
     type PrepaintState = PrepaintState;
 
     fn id(&self) -> Option<ElementId> {
@@ -138,90 +255,89 @@ impl Element for TextElement {
         window: &mut Window,
         app: &mut App,
     ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut placeholder = self
+            .placeholder
+            .take()
+            .filter(|_| self.state.read(app).value.is_empty());
+        let placeholder_layout = placeholder
+            .as_mut()
+            .map(|placeholder| placeholder.request_layout(window, app));
+
+        let state = self.state.read(app);
+        // Fields sized to their content leave room for the cursor after the text.
+        let width = match state.auto_width {
+            Some((min, max)) => {
+                let (line, _) = self.shape_line(state, window);
+                (line.width + state.cursor_width).clamp(min, max).into()
+            }
+            None => relative(1.).into(),
+        };
         let style = Style {
             size: Size {
-                width: relative(1.).into(),
+                width,
                 height: window.line_height().into(),
             },
             ..Style::default()
         };
-        (window.request_layout(style, [], app), ())
+        (
+            window.request_layout(style, placeholder_layout, app),
+            placeholder,
+        )
     }
+
     fn prepaint(
         &mut self,
         _id: Option<&GlobalElementId>,
         _inspector_id: Option<&InspectorElementId>,
         bounds: Bounds<Pixels>,
-        _request_layout: &mut Self::RequestLayoutState,
+        placeholder: &mut Self::RequestLayoutState,
         window: &mut Window,
         app: &mut App,
     ) -> Self::PrepaintState {
-        let state = self.state.read(app);
-        let style = window.text_style();
-
-        let (display_text, text_color) = self.prepare_display_text(&state, style.color);
-
-        let base_run = TextRun {
-            len: display_text.len(),
-            font: style.font(),
-            color: text_color,
-            background_color: None,
-            underline: None,
-            strikethrough: None,
-        };
-
-        let runs = self.create_text_runs(
-            &display_text,
-            base_run,
-            state.marked_range.as_ref(),
-            state.masked,
-        );
+        if let Some(placeholder) = placeholder {
+            placeholder.prepaint(window, app);
+        }
 
-        let font_size = style.font_size.to_pixels(window.rem_size());
-        let line = window
-            .text_system()
-            .shape_line(display_text, font_size, &runs, None);
+        let (line, text_color) = self.shape_line(self.state.read(app), window);
 
-        if state.should_auto_scroll {
+        if self.state.read(app).should_auto_scroll {
             self.state.update(app, |state, _| {
                 state.auto_scroll_to_cursor(&line, bounds);
             });
         }
 
         let state = self.state.read(app);
-        let scroll_offset = state.scroll_handle.offset();
-        let cursor_pos = line.x_for_index(state.display_cursor_offset());
+        let visual_line = VisualLine::new(&line, state.direction);
+        let text_left = state.text_left(&line, bounds);
 
         let (selection, cursor) = if state.selected_range.is_empty() {
+            let cursor_pos = visual_line.x_for_index(state.display_cursor_offset());
             (
-                None,
+                Vec::new(),
                 Some(fill(
                     Bounds::new(
-                        point(bounds.left() + cursor_pos - scroll_offset.x, bounds.top()),
-                        size(px(CURSOR_WIDTH), bounds.bottom() - bounds.top()),
+                        point(text_left + cursor_pos, bounds.top()),
+                        size(state.cursor_width, bounds.bottom() - bounds.top()),
                     ),
-                    text_color,
+                    state.cursor_color.unwrap_or(text_color),
                 )),
             )
         } else {
-            let selection_range = state.display_selection_range();
-            (
-                Some(fill(
-                    Bounds::from_corners(
-                        point(
-                            bounds.left() + line.x_for_index(selection_range.start)
-                                - scroll_offset.x,
-                            bounds.top(),
-                        ),
-                        point(
-                            bounds.left() + line.x_for_index(selection_range.end) - scroll_offset.x,
-                            bounds.bottom(),
+            // Text in both directions can split the selection into several extents.
+            let selection = visual_line
+                .selection_ranges(&state.display_selection_range())
+                .into_iter()
+                .map(|extent| {
+                    fill(
+                        Bounds::from_corners(
+                            point(text_left + extent.start, bounds.top()),
+                            point(text_left + extent.end, bounds.bottom()),
                         ),
-                    ),
-                    state.selection_color,
-                )),
-                None,
-            )
+                        state.selection_color,
+                    )
+                })
+                .collect();
+            (selection, None)
         };
 
         PrepaintState {
@@ -236,7 +352,7 @@ impl Element for TextElement {
         _id: Option<&GlobalElementId>,
         _inspector_id: Option<&gpui::InspectorElementId>,
         bounds: Bounds<Pixels>,
-        _request_layout: &mut Self::RequestLayoutState,
+        placeholder: &mut Self::RequestLayoutState,
         prepaint: &mut Self::PrepaintState,
         window: &mut Window,
         app: &mut App,
@@ -249,16 +365,22 @@ impl Element for TextElement {
             app,
         );
 
-        if let Some(selection) = prepaint.selection.take() {
-            window.paint_quad(selection);
+        // The selection is kept while unfocused, so programmatic edits apply to it.
+        let selection = std::mem::take(&mut prepaint.selection);
+        if focus_handle.is_focused(window) {
+            for quad in selection {
+                window.paint_quad(quad);
+            }
         }
 
         let line = prepaint.line.take().unwrap();
-        let scroll_offset = state.scroll_handle.offset();
-        let text_origin = point(bounds.origin.x - scroll_offset.x, bounds.origin.y);
+        let text_origin = point(state.text_left(&line, bounds), bounds.origin.y);
 
         line.paint(text_origin, window.line_height(), window, app)
             .unwrap();
+        if let Some(placeholder) = placeholder {
+            placeholder.paint(window, app);
+        }
 
         if focus_handle.is_focused(window) && self.state.read(app).cursor_visible(window, app) {
             if let Some(cursor) = prepaint.cursor.take() {
@@ -1,4 +1,5 @@
-use gpui::SharedString;
+use gpui::{App, SharedString, Window};
+use std::{rc::Rc, time::Duration};
 
 pub struct InputEvent {
     pub value: SharedString,
@@ -6,4 +7,39 @@ pub struct InputEvent {
 
 pub struct ChangeEvent {
     pub value: SharedString,
+    /// The parsed value of a field with a [`super::NumberFormat`].
+    pub number: Option<f64>,
+}
+
+/// Emitted by [`super::TextFieldState`] when typed or pasted text doesn't fit the
+/// `max_length` of the field, with the part of it that was dropped.
+#[derive(Clone, Debug)]
+pub struct MaxLengthReached {
+    pub max_length: usize,
+    pub truncated: SharedString,
+}
+
+/// Emitted by [`super::TextFieldState`] when the user starts composing text with an input
+/// method, such as Japanese or Chinese text, which is marked until it is committed.
+#[derive(Clone, Debug)]
+pub struct CompositionStart;
+
+/// Emitted by [`super::TextFieldState`] when the marked text being composed changes.
+#[derive(Clone, Debug)]
+pub struct CompositionUpdate {
+    pub text: SharedString,
+}
+
+/// Emitted by [`super::TextFieldState`] when the composed text is committed or cancelled.
+#[derive(Clone, Debug)]
+pub struct CompositionEnd {
+    /// The value of the field once the composition ended.
+    pub value: SharedString,
+}
+
+/// An input callback called once typing pauses for `delay`.
+#[allow(clippy::type_complexity)]
+pub(super) struct DebouncedInput {
+    pub(super) delay: Duration,
+    pub(super) callback: Rc<dyn Fn(&InputEvent, &mut Window, &mut App) + 'static>,
 }
@@ -0,0 +1,121 @@
+use std::ops::Range;
+
+/// A slot of a [`FormatMask`] pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(&self, char: char) -> bool {
+        match self {
+            Slot::Digit => char.is_ascii_digit(),
+            Slot::Letter => char.is_alphabetic(),
+            Slot::Alphanumeric => char.is_alphanumeric(),
+            Slot::Literal(_) => false,
+        }
+    }
+}
+
+/// A pattern that text typed or pasted into a text field is formatted with, such as
+/// `(###) ###-####` for phone numbers.
+///
+/// `#` accepts a digit, `A` a letter and `*` either, and any other character is a
+/// literal inserted as soon as the user types past it. Characters that don't fit their
+/// slot are dropped, and a character matching the literal at its position is taken as
+/// that literal, so pasting an already formatted value works.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatMask {
+    slots: Vec<Slot>,
+}
+
+impl FormatMask {
+    pub fn new(pattern: &str) -> Self {
+        let slots = pattern
+            .chars()
+            .map(|char| match char {
+                '#' => Slot::Digit,
+                'A' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                char => Slot::Literal(char),
+            })
+            .collect();
+        Self { slots }
+    }
+
+    /// Whether the character at `char_ix` of formatted text is a literal of the pattern.
+    fn is_literal_at(&self, char_ix: usize) -> bool {
+        matches!(self.slots.get(char_ix), Some(Slot::Literal(_)))
+    }
+
+    /// Whether every slot of the pattern is filled.
+    pub fn is_complete(&self, text: &str) -> bool {
+        self.apply(text, 0).0.chars().count() == self.slots.len()
+    }
+
+    /// Formats the text, returning it with the cursor moved from the byte offset `cursor`
+    /// to after the last character that was typed before it.
+    pub fn apply(&self, text: &str, cursor: usize) -> (String, usize) {
+        let mut inputs = text.char_indices().peekable();
+        let mut formatted = String::new();
+        let mut formatted_cursor = 0;
+
+        for slot in &self.slots {
+            if let Slot::Literal(literal) = slot {
+                let Some(&(ix, char)) = inputs.peek() else {
+                    break;
+                };
+                formatted.push(*literal);
+                // Literals are matched by position, so a literal such as the `1` of
+                // `+1 (###)` is still accepted by the slots after it.
+                if char == *literal {
+                    inputs.next();
+                    if ix < cursor {
+                        formatted_cursor = formatted.len();
+                    }
+                }
+                continue;
+            }
+
+            let Some((ix, char)) = inputs.by_ref().find(|(_, char)| slot.accepts(*char)) else {
+                break;
+            };
+            formatted.push(char);
+            if ix < cursor {
+                formatted_cursor = formatted.len();
+            }
+        }
+
+        (formatted, formatted_cursor)
+    }
+
+    /// Extends a deletion of literals only, which would be inserted again, to the input
+    /// character before them. `text` is formatted, so its characters line up with the
+    /// slots of the pattern.
+    pub(super) fn deletion_range(
+        &self,
+        text: &str,
+        range: Range<usize>,
+        new_text: &str,
+    ) -> Range<usize> {
+        let first_char = text[..range.start].chars().count();
+        let deleted_chars = text[range.clone()].chars().count();
+        if !new_text.is_empty()
+            || range.is_empty()
+            || !(first_char..first_char + deleted_chars).all(|char_ix| self.is_literal_at(char_ix))
+        {
+            return range;
+        }
+
+        let start = text[..range.start]
+            .char_indices()
+            .enumerate()
+            .filter(|(char_ix, _)| !self.is_literal_at(*char_ix))
+            .last()
+            .map_or(range.start, |(_, (ix, _))| ix);
+        start..range.end
+    }
+}
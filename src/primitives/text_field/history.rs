@@ -182,6 +182,8 @@ impl Change {
 #[derive(Clone, Debug)]
 pub struct HistoryEntry {
     pub change: Change,
+    /// The group the change was made in, undone and redone together with it.
+    pub group: Option<usize>,
 }
 
 pub struct History {
@@ -189,6 +191,9 @@ pub struct History {
     redo_stack: Vec<HistoryEntry>,
     max_size: usize,
     can_merge: bool,
+    group: Option<usize>,
+    group_depth: usize,
+    next_group: usize,
 }
 
 impl Default for History {
@@ -208,9 +213,59 @@ impl History {
             redo_stack: Vec::new(),
             max_size,
             can_merge: true,
+            group: None,
+            group_depth: 0,
+            next_group: 0,
         }
     }
 
+    /// Sets how many changes are kept, dropping the oldest ones past it.
+    pub fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size;
+        let excess = self.undo_stack.len().saturating_sub(max_size);
+        self.undo_stack.drain(..excess);
+    }
+
+    /// Starts a group of changes that undo and redo as one, until the matching
+    /// [`History::end_group`]. Nested groups join the outermost one.
+    pub fn begin_group(&mut self) {
+        self.group_depth += 1;
+        if self.group_depth == 1 {
+            self.group = Some(self.next_group);
+            self.next_group += 1;
+            self.prevent_merge();
+        }
+    }
+
+    pub fn end_group(&mut self) {
+        self.group_depth = self.group_depth.saturating_sub(1);
+        if self.group_depth == 0 && self.group.take().is_some() {
+            self.prevent_merge();
+        }
+    }
+
+    /// How many steps can be undone, counting a group as one.
+    pub fn undo_len(&self) -> usize {
+        Self::steps(&self.undo_stack)
+    }
+
+    /// How many steps can be redone, counting a group as one.
+    pub fn redo_len(&self) -> usize {
+        Self::steps(&self.redo_stack)
+    }
+
+    fn steps(stack: &[HistoryEntry]) -> usize {
+        let mut previous = None;
+        stack
+            .iter()
+            .filter(|entry| {
+                let new_step = entry.group.is_none() || entry.group != previous;
+                previous = entry.group;
+                new_step
+            })
+            .count()
+    }
+
     pub fn push(&mut self, change: Change) {
         self.redo_stack.clear();
 
@@ -222,7 +277,10 @@ impl History {
             return;
         }
 
-        self.undo_stack.push(HistoryEntry { change });
+        self.undo_stack.push(HistoryEntry {
+            change,
+            group: self.group,
+        });
         if self.undo_stack.len() > self.max_size {
             self.undo_stack.remove(0);
         }
@@ -240,6 +298,28 @@ impl History {
         }
     }
 
+    /// Undoes the last change and the rest of its group, returning the changes to apply
+    /// in order.
+    pub fn undo_group(&mut self) -> Vec<Change> {
+        let group = self.undo_stack.last().and_then(|entry| entry.group);
+        let mut changes: Vec<Change> = self.undo().into_iter().collect();
+        while group.is_some() && self.undo_stack.last().map(|entry| entry.group) == Some(group) {
+            changes.extend(self.undo());
+        }
+        changes
+    }
+
+    /// Redoes the next change and the rest of its group, returning the changes to apply
+    /// in order.
+    pub fn redo_group(&mut self) -> Vec<Change> {
+        let group = self.redo_stack.last().and_then(|entry| entry.group);
+        let mut changes: Vec<Change> = self.redo().into_iter().collect();
+        while group.is_some() && self.redo_stack.last().map(|entry| entry.group) == Some(group) {
+            changes.extend(self.redo());
+        }
+        changes
+    }
+
     pub fn redo(&mut self) -> Option<Change> {
         self.prevent_merge();
         if let Some(entry) = self.redo_stack.pop() {
@@ -0,0 +1,43 @@
+use gpui::{Global, SharedString};
+use std::{collections::VecDeque, ops::Range};
+
+/// How many kills the ring keeps before dropping the oldest.
+pub const KILL_RING_CAPACITY: usize = 32;
+
+/// The text removed by kills and cuts in every text field, most recent first, as in Emacs.
+///
+/// [`super::actions::Yank`] inserts the most recent entry, and
+/// [`super::actions::YankPop`] right after replaces it with the one before, cycling back
+/// to the most recent after the oldest.
+#[derive(Debug, Default)]
+pub(super) struct KillRing {
+    entries: VecDeque<String>,
+}
+
+impl Global for KillRing {}
+
+impl KillRing {
+    pub fn push(&mut self, text: String) {
+        if text.is_empty() {
+            return;
+        }
+        self.entries.push_front(text);
+        self.entries.truncate(KILL_RING_CAPACITY);
+    }
+
+    /// Returns the entry `index` kills back, wrapping around the ring.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        Some(&self.entries[index % self.entries.len()])
+    }
+}
+
+/// The last yanked text, replaced by a yank-pop while the field is unchanged since.
+#[derive(Clone, Debug)]
+pub(super) struct Yanked {
+    pub index: usize,
+    pub range: Range<usize>,
+    pub value: SharedString,
+}
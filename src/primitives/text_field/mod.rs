@@ -1,28 +1,65 @@
 use crate::{
-    Disableable,
-    primitives::{h_flex_center, text_field::state::TextFieldState},
+    AccessibilityProps, Accessible, ControlSize, Density, Direction, Disableable, FocusableStyled,
+    FormField, FormValue, Role, Sizeable, Theme, is_focus_visible, is_in_disabled_scope,
+    is_in_form,
+    primitives::{
+        Button, button, h_flex_center,
+        text_field::{autosave::Autosave, cursor::DEFAULT_INTERVAL, events::DebouncedInput},
+    },
+    register_form_field,
 };
 use gpui::{
-    App, AppContext, CursorStyle, Div, ElementId, Entity, Focusable, Hsla, InteractiveElement,
-    Interactivity, IntoElement, MouseButton, ParentElement, RenderOnce, SharedString, Stateful,
-    StatefulInteractiveElement, StyleRefinement, Styled, Window, prelude::FluentBuilder,
+    AnyElement, App, AppContext, CursorStyle, Div, ElementId, Entity, FocusHandle, Focusable,
+    HighlightStyle, Hsla, InteractiveElement, Interactivity, IntoElement, MouseButton,
+    ParentElement, Pixels, RenderOnce, SharedString, Stateful, StatefulInteractiveElement,
+    StyleRefinement, Styled, Window, prelude::FluentBuilder,
 };
+use std::{ops::Range, rc::Rc, time::Duration};
 
-mod actions;
+pub mod actions;
+mod autosave;
+mod bidi;
+mod counter;
 mod cursor;
 mod element;
 mod events;
+mod format_mask;
 mod history;
+mod kill_ring;
+mod number;
 mod state;
 #[cfg(test)]
 mod tests;
 mod text_ops;
+mod validation;
 
-pub(super) use actions::init;
+pub(super) use actions::default_bindings;
+pub use autosave::FieldSnapshot;
+pub use bidi::CursorMovement;
+pub use counter::CounterContext;
 pub use events::*;
+pub use format_mask::FormatMask;
+pub use number::NumberFormat;
+pub use state::TextFieldState;
+pub use validation::*;
+
+/// Returns the ranges of a text field's value to highlight and how.
+pub(super) type Highlighter = Rc<dyn Fn(&str) -> Vec<(Range<usize>, HighlightStyle)>>;
+
+/// Called when an edit is truncated to the maximum length.
+pub(super) type OnMaxLengthReached = Box<dyn Fn(&MaxLengthReached, &mut Window, &mut App)>;
+
+/// Returns whether a value is valid, and why not.
+pub(super) type Validate = Rc<dyn Fn(&str) -> ValidationState>;
+
+/// Called when the validation state of a field changes.
+pub(super) type OnValidate = Box<dyn Fn(&ValidationEvent, &mut Window, &mut App)>;
 
 /// Context identifier for text field key bindings
-const CONTEXT: &str = "lp-text-field";
+pub const CONTEXT: &str = "lp-text-field";
+
+/// Context identifier for the key bindings of text fields with a [`NumberFormat`]
+pub const NUMBER_CONTEXT: &str = "lp-number-field";
 
 pub fn text_field(id: impl Into<ElementId>) -> TextField {
     let id = id.into();
@@ -31,17 +68,48 @@ pub fn text_field(id: impl Into<ElementId>) -> TextField {
         base: h_flex_center().id(id).cursor(CursorStyle::IBeam),
         disabled: false,
         value: None,
+        default_value: None,
+        name: None,
         on_input: None,
         on_change: None,
+        on_input_debounced: None,
+        auto_width: None,
         placeholder: None,
+        placeholder_element: None,
         placeholder_color: None,
         selection_color: None,
+        cursor_color: None,
+        cursor_width: None,
+        cursor_blink_interval: None,
+        cursor_blink: true,
+        marked_underline_color: None,
+        highlighter: None,
+        text_direction: None,
+        cursor_movement: CursorMovement::Logical,
         masked: false,
         mask: None,
         max_length: None,
+        on_max_length_reached: None,
+        read_only: false,
         validator: None,
+        validate: None,
+        validate_on: ValidateOn::default(),
+        on_validate: None,
+        number: None,
+        format_mask: None,
+        autosave: None,
+        leading: None,
+        trailing: None,
+        clearable: false,
+        counter: None,
+        state: None,
         tab_index: 0,
         tab_stop: true,
+        when_focus_visible_handler: None,
+        accessibility: AccessibilityProps::new(Role::TextField),
+        when_disabled_handler: None,
+        when_invalid_handler: None,
+        size: None,
     }
 }
 
@@ -51,25 +119,70 @@ pub struct TextField {
     base: Stateful<Div>,
     disabled: bool,
     value: Option<SharedString>,
+    default_value: Option<SharedString>,
+    name: Option<SharedString>,
     on_input: Option<Box<dyn Fn(&InputEvent, &mut Window, &mut App) + 'static>>,
     on_change: Option<Box<dyn Fn(&ChangeEvent, &mut Window, &mut App) + 'static>>,
+    on_input_debounced: Option<DebouncedInput>,
+    auto_width: Option<(Pixels, Pixels)>,
     placeholder: Option<SharedString>,
+    placeholder_element: Option<AnyElement>,
     placeholder_color: Option<Hsla>,
     selection_color: Option<Hsla>,
+    cursor_color: Option<Hsla>,
+    cursor_width: Option<Pixels>,
+    cursor_blink_interval: Option<Duration>,
+    cursor_blink: bool,
+    marked_underline_color: Option<Hsla>,
+    highlighter: Option<Highlighter>,
+    text_direction: Option<Direction>,
+    cursor_movement: CursorMovement,
     masked: bool,
     mask: Option<SharedString>,
     max_length: Option<usize>,
+    on_max_length_reached: Option<OnMaxLengthReached>,
+    read_only: bool,
     validator: Option<Box<dyn Fn(SharedString) -> bool + 'static>>,
+    validate: Option<Validate>,
+    validate_on: ValidateOn,
+    on_validate: Option<OnValidate>,
+    number: Option<NumberFormat>,
+    format_mask: Option<FormatMask>,
+    autosave: Option<Autosave>,
+    leading: Option<AnyElement>,
+    trailing: Option<AnyElement>,
+    clearable: bool,
+    counter: Option<Box<dyn FnOnce(CounterContext) -> AnyElement>>,
+    state: Option<Entity<TextFieldState>>,
     tab_index: isize,
     tab_stop: bool,
+    when_focus_visible_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    accessibility: AccessibilityProps,
+    when_disabled_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    when_invalid_handler: Option<Box<dyn FnOnce(Self) -> Self>>,
+    size: Option<ControlSize>,
 }
 
 impl TextField {
+    /// Controls the value. The field follows it on every render, and changes made outside
+    /// of the field are undoable like edits.
     pub fn value(mut self, value: impl Into<SharedString>) -> Self {
         self.value = Some(value.into());
         self
     }
 
+    /// The value on first render when the value is not controlled.
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.default_value = Some(value.into());
+        self
+    }
+
+    /// Registers the field with the enclosing [`crate::Form`] under this name.
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
     pub fn on_input(
         mut self,
         callback: impl Fn(&InputEvent, &mut Window, &mut App) + 'static,
@@ -78,6 +191,28 @@ impl TextField {
         self
     }
 
+    /// Called with the value once the user stops typing for `delay`, such as to search as
+    /// they type. Text still being composed with an input method doesn't count as input.
+    pub fn on_input_debounced(
+        mut self,
+        delay: Duration,
+        callback: impl Fn(&InputEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_input_debounced = Some(DebouncedInput {
+            delay,
+            callback: Rc::new(callback),
+        });
+        self
+    }
+
+    /// Sizes the text to its content between `min` and `max` instead of filling the field,
+    /// so the field grows as the user types, such as to rename an item inline. Past `max`
+    /// the text scrolls as usual. The bounds may be given in either order.
+    pub fn auto_width(mut self, min: Pixels, max: Pixels) -> Self {
+        self.auto_width = Some((min.min(max), min.max(max)));
+        self
+    }
+
     pub fn on_change(
         mut self,
         callback: impl Fn(&ChangeEvent, &mut Window, &mut App) + 'static,
@@ -91,16 +226,89 @@ impl TextField {
         self
     }
 
+    /// Shown while the field is empty instead of a [`Self::placeholder`] string, such as to
+    /// combine an icon with styled text.
+    pub fn placeholder_element(mut self, placeholder: impl IntoElement) -> Self {
+        self.placeholder_element = Some(placeholder.into_any_element());
+        self
+    }
+
+    /// Overrides the placeholder color of the [`Theme`].
     pub fn placeholder_color(mut self, color: impl Into<Hsla>) -> Self {
         self.placeholder_color = Some(color.into());
         self
     }
 
+    /// Overrides the selection color of the [`Theme`].
     pub fn selection_color(mut self, color: impl Into<Hsla>) -> Self {
         self.selection_color = Some(color.into());
         self
     }
 
+    pub fn cursor_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.cursor_color = Some(color.into());
+        self
+    }
+
+    pub fn cursor_width(mut self, width: Pixels) -> Self {
+        self.cursor_width = Some(width);
+        self
+    }
+
+    /// How long the cursor stays shown or hidden while blinking, 500ms by default.
+    pub fn cursor_blink_interval(mut self, interval: Duration) -> Self {
+        self.cursor_blink_interval = Some(interval);
+        self
+    }
+
+    /// Whether the cursor blinks while the field is focused. It never blinks when the user
+    /// prefers reduced motion.
+    pub fn cursor_blink(mut self, blink: bool) -> Self {
+        self.cursor_blink = blink;
+        self
+    }
+
+    /// The color of the underline of text being composed with an input method.
+    pub fn marked_underline_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.marked_underline_color = Some(color.into());
+        self
+    }
+
+    /// Styles ranges of the text, such as to highlight search terms or color tokens. The
+    /// ranges are byte ranges of the value, and ranges not on character boundaries are
+    /// ignored. Masked text and placeholders are not highlighted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// text_field("query").highlighter(|text| {
+    ///     text.match_indices("is:")
+    ///         .map(|(ix, term)| (ix..ix + term.len(), rgb(0x2563eb).into()))
+    ///         .collect()
+    /// })
+    /// ```
+    pub fn highlighter(
+        mut self,
+        highlighter: impl Fn(&str) -> Vec<(Range<usize>, HighlightStyle)> + 'static,
+    ) -> Self {
+        self.highlighter = Some(Rc::new(highlighter));
+        self
+    }
+
+    /// Overrides the direction of the text, which follows [`Direction::current`] by
+    /// default. Right-to-left text is aligned to the right of the field.
+    pub fn text_direction(mut self, direction: Direction) -> Self {
+        self.text_direction = Some(direction);
+        self
+    }
+
+    /// How the arrow keys move the cursor through text mixing both directions, in reading
+    /// order by default.
+    pub fn cursor_movement(mut self, movement: CursorMovement) -> Self {
+        self.cursor_movement = movement;
+        self
+    }
+
     pub fn masked(mut self, masked: bool) -> Self {
         self.masked = masked;
         self
@@ -111,16 +319,168 @@ impl TextField {
         self
     }
 
+    /// Limits the value to a number of characters as the user sees them, grapheme clusters.
+    /// Text that doesn't fit is truncated.
     pub fn max_length(mut self, max_length: usize) -> Self {
         self.max_length = Some(max_length);
         self
     }
 
+    /// Called when typed or pasted text is truncated to the [`Self::max_length`], such as to
+    /// flash a warning.
+    pub fn on_max_length_reached(
+        mut self,
+        callback: impl Fn(&MaxLengthReached, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_max_length_reached = Some(Box::new(callback));
+        self
+    }
+
+    /// Blocks edits but, unlike [`Disableable::disabled`], keeps the field focusable so its
+    /// value can still be selected and copied.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
     pub fn validator(mut self, validator: impl Fn(SharedString) -> bool + 'static) -> Self {
         self.validator = Some(Box::new(validator));
         self
     }
 
+    /// Checks the value when it is committed, or after every edit with
+    /// [`TextField::validate_on`]. The result is kept in [`TextFieldState::validation`],
+    /// and changes of it are reported to [`TextField::on_validate`] and emitted as a
+    /// [`ValidationEvent`] by the state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// text_field("email")
+    ///     .validate(|value| {
+    ///         if value.contains('@') {
+    ///             ValidationState::Valid
+    ///         } else {
+    ///             ValidationState::invalid("Enter an email address")
+    ///         }
+    ///     })
+    ///     .when_invalid(|this| this.border_color(rgb(0xef4444)))
+    /// ```
+    pub fn validate(mut self, validate: impl Fn(&str) -> ValidationState + 'static) -> Self {
+        self.validate = Some(Rc::new(validate));
+        self
+    }
+
+    pub fn validate_on(mut self, validate_on: ValidateOn) -> Self {
+        self.validate_on = validate_on;
+        self
+    }
+
+    pub fn on_validate(
+        mut self,
+        callback: impl Fn(&ValidationEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_validate = Some(Box::new(callback));
+        self
+    }
+
+    /// Conditionally modify the text field while its value is invalid.
+    pub fn when_invalid(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_invalid_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Turns the field into a number input: it only accepts numbers, steps its value with
+    /// the Up and Down arrows, and reports the parsed value in [`ChangeEvent::number`].
+    pub fn number(mut self, format: NumberFormat) -> Self {
+        self.number = Some(format);
+        self
+    }
+
+    /// Formats the text as it is typed or pasted with a pattern such as `##/##/####`, see
+    /// [`FormatMask`].
+    pub fn format_mask(mut self, pattern: &str) -> Self {
+        self.format_mask = Some(FormatMask::new(pattern));
+        self
+    }
+
+    /// Saves a draft of the field while it is edited, at most once per `interval`, and
+    /// when it loses focus. The callback is only called when the value changed since the
+    /// last save.
+    pub fn autosave(
+        mut self,
+        interval: Duration,
+        callback: impl Fn(&FieldSnapshot, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.autosave = Some(Autosave {
+            interval,
+            callback: Rc::new(callback),
+        });
+        self
+    }
+
+    /// Rendered before the text, such as a search icon.
+    ///
+    /// Pressing it focuses the field without moving the cursor.
+    pub fn leading(mut self, leading: impl IntoElement) -> Self {
+        self.leading = Some(leading.into_any_element());
+        self
+    }
+
+    /// Rendered after the text, such as a unit or a clear button.
+    ///
+    /// Pressing it focuses the field without moving the cursor.
+    pub fn trailing(mut self, trailing: impl IntoElement) -> Self {
+        self.trailing = Some(trailing.into_any_element());
+        self
+    }
+
+    /// Turns the [`Self::trailing`] element into a clear button, shown while the field has
+    /// text. Clearing is undoable and emits both an [`InputEvent`] and a [`ChangeEvent`].
+    pub fn clearable(mut self, clearable: bool) -> Self {
+        self.clearable = clearable;
+        self
+    }
+
+    /// Renders an element from the length of the value after the text, such as a `12/100`
+    /// counter, kept in sync as the user types.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// text_field("bio")
+    ///     .max_length(100)
+    ///     .with_counter(|counter| div().text_xs().child(counter.label()))
+    /// ```
+    pub fn with_counter<E: IntoElement>(
+        mut self,
+        counter: impl FnOnce(CounterContext) -> E + 'static,
+    ) -> Self {
+        self.counter = Some(Box::new(move |context| counter(context).into_any_element()));
+        self
+    }
+
+    /// Backs the field with a state created by the view, so it can read and edit the text,
+    /// cursor and selection outside of key and mouse events.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let message = cx.new(|cx| TextFieldState::new(window, cx));
+    ///
+    /// // In render:
+    /// text_field("message").state(&self.message)
+    /// button("insert-name")
+    ///     .on_click(cx.listener(|this, _, window, cx| {
+    ///         this.message.update(cx, |state, cx| state.insert_at_cursor("{name}", window, cx));
+    ///     }))
+    ///     .child("Insert name")
+    /// ```
+    pub fn state(mut self, state: &Entity<TextFieldState>) -> Self {
+        self.state = Some(state.clone());
+        self
+    }
+
     pub fn tab_stop(mut self, tab_stop: bool) -> Self {
         self.tab_stop = tab_stop;
         self
@@ -146,6 +506,30 @@ impl InteractiveElement for TextField {
 
 impl StatefulInteractiveElement for TextField {}
 
+impl FocusableStyled for TextField {
+    fn when_focus_visible(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_focus_visible_handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Accessible for TextField {
+    fn accessibility(&self) -> &AccessibilityProps {
+        &self.accessibility
+    }
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps {
+        &mut self.accessibility
+    }
+}
+
+impl Sizeable for TextField {
+    fn with_size(mut self, size: ControlSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
 impl Disableable for TextField {
     fn is_disabled(&self) -> bool {
         self.disabled
@@ -155,16 +539,38 @@ impl Disableable for TextField {
         self.disabled = disabled;
         self
     }
+
+    fn when_disabled(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.when_disabled_handler = Some(Box::new(handler));
+        self
+    }
 }
 
 impl RenderOnce for TextField {
-    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
-        let state = window
-            .use_keyed_state(self.id, app, |window, app| {
-                app.new(|cx| TextFieldState::new(window, cx))
-            })
-            .read(app)
-            .clone();
+    fn render(mut self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        if is_in_disabled_scope(app) {
+            self.disabled = true;
+        }
+        if self.disabled {
+            if let Some(handler) = self.when_disabled_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        if let Some(size) = self.size {
+            let theme = Theme::of(app).with_density(Density::current(app));
+            self.base = self.base.h(size.height(&theme)).px(size.padding(&theme));
+        }
+
+        let state = match self.state.take() {
+            Some(state) => state,
+            None => window
+                .use_keyed_state(self.id.clone(), app, |window, app| {
+                    app.new(|cx| TextFieldState::new(window, cx))
+                })
+                .read(app)
+                .clone(),
+        };
 
         let mut focus_handle = state.focus_handle(app);
         if focus_handle.tab_stop != self.tab_stop {
@@ -174,22 +580,114 @@ impl RenderOnce for TextField {
             focus_handle = focus_handle.tab_index(self.tab_index);
         }
 
-        state.update(app, |state, _cx| {
-            state.set_value(self.value);
+        if focus_handle.is_focused(window) && is_focus_visible(app) {
+            if let Some(handler) = self.when_focus_visible_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        if !state.read(app).validation().is_valid() {
+            if let Some(handler) = self.when_invalid_handler.take() {
+                self = handler(self);
+            }
+        }
+
+        let style = self.base.style();
+        if style.padding.top.is_none() && style.padding.bottom.is_none() {
+            self.base = self.base.py(Density::current(app).control_padding());
+        }
+
+        if let Some(name) = self.name.take() {
+            let field = FormField {
+                value: Rc::new({
+                    let state = state.clone();
+                    move |app| FormValue::Text(state.read(app).value.clone())
+                }),
+                validate: Some(Rc::new({
+                    let state = state.clone();
+                    move |window, app| {
+                        state.update(app, |state, cx| {
+                            state.run_validation(window, cx);
+                            state.validation().clone()
+                        })
+                    }
+                })),
+                focus_handle: Some(focus_handle.clone()),
+            };
+            register_form_field(name, field, app);
+        }
+        let in_form = is_in_form(app);
+        let direction = Direction::current(app);
+        let placeholder_color =
+            Theme::color_or(self.placeholder_color, |colors| colors.placeholder, app);
+        let selection_color = Theme::color_or(self.selection_color, |colors| colors.selection, app);
+
+        state.update(app, |state, cx| {
+            match self.value {
+                Some(value) => state.sync_value(value, cx),
+                None if !state.value_initialized => state.set_value(self.default_value),
+                None => {}
+            }
             state.on_input = self.on_input;
             state.on_change = self.on_change;
-            state.set_placeholder(self.placeholder);
-            state.set_placeholder_color(self.placeholder_color);
-            state.set_selection_color(self.selection_color);
+            state.on_input_debounced = self.on_input_debounced;
+            state.auto_width = self.auto_width;
+            state.set_placeholder(
+                self.placeholder
+                    .filter(|_| self.placeholder_element.is_none()),
+            );
+            state.placeholder_element = self.placeholder_element;
+            state.set_placeholder_color(Some(placeholder_color));
+            state.set_selection_color(Some(selection_color));
+            state.set_cursor_color(self.cursor_color);
+            state.set_cursor_width(self.cursor_width);
+            state.cursor.update(cx, |cursor, cx| {
+                cursor.set_interval(self.cursor_blink_interval.unwrap_or(DEFAULT_INTERVAL));
+                cursor.set_blinks(self.cursor_blink, cx);
+            });
+            state.set_marked_underline_color(self.marked_underline_color);
+            state.highlighter = self.highlighter;
+            state.direction = self.text_direction.unwrap_or(direction);
+            state.cursor_movement = self.cursor_movement;
             state.set_masked(self.masked);
             state.set_mask(self.mask);
             state.max_length = self.max_length;
+            state.on_max_length_reached = self.on_max_length_reached;
+            state.read_only = self.read_only;
             state.validator = self.validator;
+            state.validate = self.validate;
+            state.validate_on = self.validate_on;
+            state.on_validate = self.on_validate;
+            state.number = self.number;
+            state.format_mask = self.format_mask;
+            state.autosave = self.autosave;
+            state.accessibility = self.accessibility;
+            state.element_id = Some(self.id.clone());
+            state.in_form = in_form;
         });
 
+        let key_context = match self.number {
+            Some(_) => format!("{CONTEXT} {NUMBER_CONTEXT}"),
+            None => CONTEXT.to_string(),
+        };
+        let counter = self.counter.map(|counter| {
+            let state = state.read(app);
+            counter(CounterContext::new(&state.value, state.max_length))
+        });
+        let trailing = match self.trailing {
+            Some(trailing) if self.clearable => {
+                let can_clear =
+                    !self.disabled && !self.read_only && !state.read(app).value.is_empty();
+                can_clear.then(|| clear_button(&self.id, &state, trailing))
+            }
+            trailing => trailing,
+        };
+        let show_steppers =
+            !self.disabled && !self.read_only && self.number.is_some_and(|number| number.steppers);
+
         self.base
             .when(!self.disabled, |this| {
-                this.key_context(CONTEXT)
+                this.key_context(key_context.as_str())
                     .track_focus(&focus_handle)
                     .on_action(window.listener_for(&state, TextFieldState::backspace))
                     .on_action(window.listener_for(&state, TextFieldState::delete))
@@ -197,7 +695,7 @@ impl RenderOnce for TextField {
                     .on_action(window.listener_for(&state, TextFieldState::right))
                     .on_action(window.listener_for(&state, TextFieldState::select_left))
                     .on_action(window.listener_for(&state, TextFieldState::select_right))
-                    .on_action(window.listener_for(&state, TextFieldState::select_all))
+                    .on_action(window.listener_for(&state, TextFieldState::on_select_all))
                     .on_action(window.listener_for(&state, TextFieldState::home))
                     .on_action(window.listener_for(&state, TextFieldState::end))
                     .on_action(window.listener_for(&state, TextFieldState::show_character_palette))
@@ -217,6 +715,11 @@ impl RenderOnce for TextField {
                     .on_action(window.listener_for(&state, TextFieldState::undo))
                     .on_action(window.listener_for(&state, TextFieldState::redo))
                     .on_action(window.listener_for(&state, TextFieldState::enter))
+                    .on_action(window.listener_for(&state, TextFieldState::transpose))
+                    .on_action(window.listener_for(&state, TextFieldState::yank))
+                    .on_action(window.listener_for(&state, TextFieldState::yank_pop))
+                    .on_action(window.listener_for(&state, TextFieldState::increment))
+                    .on_action(window.listener_for(&state, TextFieldState::decrement))
                     .on_mouse_down(
                         MouseButton::Left,
                         window.listener_for(&state, TextFieldState::on_mouse_down),
@@ -232,6 +735,59 @@ impl RenderOnce for TextField {
                     .on_mouse_move(window.listener_for(&state, TextFieldState::on_mouse_move))
             })
             .on_scroll_wheel(window.listener_for(&state, TextFieldState::on_scroll_wheel))
+            .when_some(self.leading, |this, leading| {
+                this.child(slot(leading, &focus_handle, self.disabled))
+            })
             .child(state.clone())
+            .children(counter)
+            .when_some(trailing, |this, trailing| {
+                this.child(slot(trailing, &focus_handle, self.disabled))
+            })
+            .when(show_steppers, |this| {
+                this.child(stepper(&self.id, &state, false))
+                    .child(stepper(&self.id, &state, true))
+            })
     }
 }
+
+/// Wraps a leading or trailing element, which focuses the field when pressed but leaves
+/// the cursor where it was.
+fn slot(element: AnyElement, focus_handle: &FocusHandle, disabled: bool) -> Div {
+    let focus_handle = focus_handle.clone();
+    h_flex_center()
+        .flex_none()
+        .cursor(CursorStyle::Arrow)
+        .when(!disabled, |this| {
+            this.on_mouse_down(MouseButton::Left, move |_, window, app| {
+                window.focus(&focus_handle);
+                app.stop_propagation();
+            })
+        })
+        .child(element)
+}
+
+/// The trailing element of a clearable field, clearing it when clicked.
+fn clear_button(id: &ElementId, state: &Entity<TextFieldState>, element: AnyElement) -> AnyElement {
+    let state = state.clone();
+    h_flex_center()
+        .id(ElementId::NamedChild(Box::new(id.clone()), "clear".into()))
+        .on_click(move |_, window, app| {
+            state.update(app, |state, cx| state.clear(window, cx));
+        })
+        .child(element)
+        .into_any_element()
+}
+
+/// A button stepping the value of a field with a [`NumberFormat`] up or down.
+fn stepper(id: &ElementId, state: &Entity<TextFieldState>, up: bool) -> Button {
+    let name = if up { "increment" } else { "decrement" };
+    let state = state.clone();
+    button(ElementId::NamedChild(Box::new(id.clone()), name.into()))
+        .tab_stop(false)
+        .flex_none()
+        .px_1()
+        .child(if up { "+" } else { "−" })
+        .on_click(move |_, window, app| {
+            state.update(app, |state, cx| state.step(up, window, cx));
+        })
+}
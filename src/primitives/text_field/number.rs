@@ -0,0 +1,111 @@
+/// How a numeric text field parses, bounds and steps its value.
+///
+/// The decimal separator is `.` unless set with [`NumberFormat::decimal_separator`], e.g. to
+/// `,` for most of Europe. While typing, the field only accepts text that can still become a number, and the committed
+/// value is clamped to the bounds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NumberFormat {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    /// How much the arrow keys and stepper buttons change the value, 1 by default.
+    pub step: f64,
+    pub decimal_separator: char,
+    /// Whether increment and decrement buttons are rendered after the text.
+    pub steppers: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: 1.,
+            decimal_separator: '.',
+            steppers: false,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    pub fn steppers(mut self, steppers: bool) -> Self {
+        self.steppers = steppers;
+        self
+    }
+
+    /// Parses text written with the decimal separator, ignoring surrounding whitespace.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let text = text.trim();
+        if text.is_empty() || !self.is_partial(text) {
+            return None;
+        }
+        text.replace(self.decimal_separator, ".").parse().ok()
+    }
+
+    /// Formats a number with the decimal separator and no trailing zeros.
+    pub fn format(&self, number: f64) -> String {
+        // Steps such as 0.1 accumulate floating point error, which formatting hides.
+        let text = format!("{number:.10}");
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        let text = if text == "-0" { "0" } else { text };
+        text.replace('.', &self.decimal_separator.to_string())
+    }
+
+    pub fn clamp(&self, number: f64) -> f64 {
+        let number = self.min.map_or(number, |min| number.max(min));
+        self.max.map_or(number, |max| number.min(max))
+    }
+
+    /// Returns the value stepped up or down from `text`, starting from the bound or zero
+    /// when the text is not a number.
+    pub fn stepped(&self, text: &str, up: bool) -> f64 {
+        let number = match self.parse(text) {
+            Some(number) if up => number + self.step,
+            Some(number) => number - self.step,
+            None if up => self.min.unwrap_or(0.),
+            None => self.max.unwrap_or(0.),
+        };
+        self.clamp(number)
+    }
+
+    /// Whether the text is a number or the beginning of one, such as `-` or `1,`.
+    pub(super) fn is_partial(&self, text: &str) -> bool {
+        let allow_negative = self.min.is_none_or(|min| min < 0.);
+        let text = match text.strip_prefix('-') {
+            Some(rest) if allow_negative => rest,
+            _ => text,
+        };
+        let mut separators = 0;
+        text.chars().all(|char| {
+            if char == self.decimal_separator {
+                separators += 1;
+                separators == 1
+            } else {
+                char.is_ascii_digit()
+            }
+        })
+    }
+}
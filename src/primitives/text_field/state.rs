@@ -1,16 +1,26 @@
 use crate::{
-    Validatable,
+    AccessibilityProps, Direction, FieldChanged, Role, Validatable,
     primitives::text_field::{
         actions::*,
+        autosave::{Autosave, FieldSnapshot},
+        bidi::VisualLine,
         cursor::Cursor,
         element::{CURSOR_WIDTH, TextElement},
-        events::{ChangeEvent, InputEvent},
+        events::{
+            ChangeEvent, CompositionEnd, CompositionStart, CompositionUpdate, DebouncedInput,
+            InputEvent, MaxLengthReached,
+        },
+        format_mask::FormatMask,
         history::{Change, History},
+        kill_ring::{KillRing, Yanked},
+        number::NumberFormat,
         text_ops::TextOps,
+        validation::{ValidateOn, ValidationEvent, ValidationState},
         *,
     },
+    publish_event,
 };
-use gpui::*;
+use gpui::{prelude::FluentBuilder, *};
 use std::ops::Range;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -18,6 +28,14 @@ const DEFAULT_PLACEHOLDER_COLOR: u32 = 0x80808080;
 const DEFAULT_MASK: &str = "•";
 const DEFAULT_SELECTION_COLOR: u32 = 0x3390FF80;
 
+/// The unit a drag selection grows by, set by the number of clicks that started it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SelectionGranularity {
+    Character,
+    Word,
+    Line,
+}
+
 /// State management for text field components
 ///
 /// Handles text editing, cursor positioning, selection, and scrolling
@@ -29,12 +47,26 @@ pub struct TextFieldState {
     pub placeholder: SharedString,
     pub placeholder_color: Hsla,
     pub selection_color: Hsla,
+    /// The color of the cursor, the text color by default.
+    pub cursor_color: Option<Hsla>,
+    pub cursor_width: Pixels,
+    /// The color of the underline of text composed with an input method, the text color by
+    /// default.
+    pub marked_underline_color: Option<Hsla>,
+    pub(super) highlighter: Option<Highlighter>,
+    /// The direction of the text, which right-aligns it when right-to-left.
+    pub(super) direction: Direction,
+    pub(super) cursor_movement: CursorMovement,
     pub selected_range: Range<usize>,
     pub selection_reversed: bool,
     pub marked_range: Option<Range<usize>>,
     pub last_layout: Option<ShapedLine>,
     pub last_bounds: Option<Bounds<Pixels>>,
     pub selecting: bool,
+    /// What dragging after the click that started the selection extends it by.
+    granularity: SelectionGranularity,
+    /// The word or line selected by the click that started the selection.
+    selection_anchor: Range<usize>,
     pub scroll_handle: ScrollHandle,
     pub should_auto_scroll: bool,
     pub cursor: Entity<Cursor>,
@@ -42,10 +74,42 @@ pub struct TextFieldState {
     pub mask: SharedString,
     pub on_input: Option<Box<dyn Fn(&InputEvent, &mut Window, &mut App) + 'static>>,
     pub on_change: Option<Box<dyn Fn(&ChangeEvent, &mut Window, &mut App) + 'static>>,
+    pub(super) on_input_debounced: Option<DebouncedInput>,
+    /// Rendered instead of the placeholder text, taken by the next render of the state.
+    pub(super) placeholder_element: Option<AnyElement>,
+    /// The bounds of the text width when the field is sized to its content.
+    pub(super) auto_width: Option<(Pixels, Pixels)>,
+    /// The pending debounced input event, cancelled when dropped by the next input.
+    debounced_input: Option<Task<()>>,
     pub max_length: Option<usize>,
+    pub(super) on_max_length_reached: Option<OnMaxLengthReached>,
+    /// Blocks edits while keeping focus, selection and copy working.
+    pub read_only: bool,
     pub validator: Option<Box<dyn Fn(SharedString) -> bool>>,
+    pub(super) validate: Option<Validate>,
+    pub(super) validate_on: ValidateOn,
+    pub(super) on_validate: Option<OnValidate>,
+    validation: ValidationState,
+    pub number: Option<NumberFormat>,
+    pub format_mask: Option<FormatMask>,
+    pub accessibility: AccessibilityProps,
+    /// The id of the field rendering this state, used when publishing [`FieldChanged`].
+    pub element_id: Option<ElementId>,
+    pub(super) autosave: Option<Autosave>,
+    /// The value last given to the field, which autosaved drafts are compared against.
+    pristine_value: SharedString,
+    /// Whether a value was given to the field, after which it is no longer reset.
+    pub(super) value_initialized: bool,
+    /// Whether the field is inside a [`crate::Form`], which Enter submits.
+    pub(super) in_form: bool,
+    saved_value: Option<SharedString>,
+    autosave_scheduled: bool,
     history: History,
     ignore_history: bool,
+    /// The last yank, which [`YankPop`] can replace.
+    last_yank: Option<Yanked>,
+    /// Set while the formatted text replaces the value, which must not be formatted again.
+    formatting: bool,
     focus_select: bool,
     _subscriptions: [Subscription; 4],
 }
@@ -87,22 +151,52 @@ impl TextFieldState {
             placeholder: SharedString::default(),
             placeholder_color: rgba(DEFAULT_PLACEHOLDER_COLOR).into(),
             selection_color: rgba(DEFAULT_SELECTION_COLOR).into(),
+            cursor_color: None,
+            cursor_width: px(CURSOR_WIDTH),
+            marked_underline_color: None,
+            highlighter: None,
+            direction: Direction::Ltr,
+            cursor_movement: CursorMovement::Logical,
             selected_range: 0..0,
             selection_reversed: false,
             marked_range: None,
             last_layout: None,
             last_bounds: None,
             selecting: false,
+            granularity: SelectionGranularity::Character,
+            selection_anchor: 0..0,
             scroll_handle: ScrollHandle::new(),
             should_auto_scroll: false,
             masked: false,
             mask: SharedString::new(DEFAULT_MASK),
             on_input: None,
             on_change: None,
+            on_input_debounced: None,
+            debounced_input: None,
+            auto_width: None,
+            placeholder_element: None,
             max_length: None,
+            on_max_length_reached: None,
+            read_only: false,
             validator: None,
+            validate: None,
+            validate_on: ValidateOn::default(),
+            on_validate: None,
+            validation: ValidationState::Valid,
+            number: None,
+            format_mask: None,
+            accessibility: AccessibilityProps::new(Role::TextField),
+            element_id: None,
+            autosave: None,
+            pristine_value: SharedString::default(),
+            value_initialized: false,
+            in_form: false,
+            saved_value: None,
+            autosave_scheduled: false,
             history: History::new(),
             ignore_history: false,
+            last_yank: None,
+            formatting: false,
             focus_select: true,
             cursor,
             _subscriptions,
@@ -136,18 +230,107 @@ impl TextFieldState {
         }
     }
 
+    /// Set the cursor color
+    pub fn set_cursor_color(&mut self, color: Option<impl Into<Hsla>>) {
+        self.cursor_color = color.map(Into::into);
+    }
+
+    /// Set the cursor width
+    pub fn set_cursor_width(&mut self, width: Option<Pixels>) {
+        self.cursor_width = width.unwrap_or(px(CURSOR_WIDTH));
+    }
+
+    /// Set the underline color of marked text
+    pub fn set_marked_underline_color(&mut self, color: Option<impl Into<Hsla>>) {
+        self.marked_underline_color = color.map(Into::into);
+    }
+
     /// Set the value of the text field
     pub fn set_value(&mut self, value: Option<impl Into<SharedString>>) {
         if let Some(value) = value {
             let value = value.into();
+            self.value_initialized = true;
             if value != self.value {
                 self.value = value;
+                self.selected_range = self.value.len()..self.value.len();
+                self.selection_reversed = false;
                 self.emitted_value = self.value.clone();
+                self.pristine_value = self.value.clone();
+                self.saved_value = None;
                 self.history.clear();
             }
         }
     }
 
+    /// Follows the value of a controlled field. The first value initializes the field, and
+    /// later changes made outside of it are applied as an undoable edit, keeping the history.
+    pub(super) fn sync_value(&mut self, value: SharedString, cx: &mut Context<Self>) {
+        if !self.value_initialized {
+            self.set_value(Some(value));
+            return;
+        }
+        if value == self.value {
+            return;
+        }
+
+        let (range, new_text) = TextOps::diff(&self.value, &value);
+        let new_len = new_text.len();
+        self.history.prevent_merge();
+        self.push_history(new_text, &range);
+        self.history.prevent_merge();
+
+        let cursor = self.cursor_offset();
+        let cursor = if cursor <= range.start {
+            cursor
+        } else if cursor >= range.end {
+            cursor - range.len() + new_len
+        } else {
+            range.start + new_len
+        };
+        self.value = value;
+        self.selected_range = cursor..cursor;
+        self.selection_reversed = false;
+        self.end_composition(cx);
+        self.emitted_value = self.value.clone();
+        self.last_layout = None;
+    }
+
+    /// Sets how many changes can be undone, 100 by default.
+    pub fn set_history_depth(&mut self, depth: usize) {
+        self.history.set_max_size(depth);
+    }
+
+    /// Starts grouping changes, so that programmatic edits made until [`Self::end_group`]
+    /// undo and redo as one step. Groups can be nested.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// state.update(cx, |state, cx| {
+    ///     state.begin_group();
+    ///     state.replace_text_in_range(Some(0..0), "(", window, cx);
+    ///     state.replace_text_in_range(Some(4..4), ")", window, cx);
+    ///     state.end_group();
+    /// });
+    /// ```
+    pub fn begin_group(&mut self) {
+        self.history.begin_group();
+    }
+
+    pub fn end_group(&mut self) {
+        self.history.end_group();
+    }
+
+    /// How many steps can be undone, for instance to disable an Undo button at zero.
+    pub fn history_len(&self) -> usize {
+        self.history.undo_len()
+    }
+
+    /// How many undone steps can be redone.
+    pub fn redo_len(&self) -> usize {
+        self.history.redo_len()
+    }
+
     /// Mask or unmask the text field (e.g., for passwords)
     pub fn set_masked(&mut self, masked: bool) {
         if self.masked != masked {
@@ -187,7 +370,6 @@ impl TextFieldState {
 
     fn on_blur(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         if !self.focus_handle.is_focused(window) {
-            self.selected_range = 0..0;
             self.history.prevent_merge();
         }
         self.cursor.update(cx, |cursor, _| {
@@ -201,9 +383,19 @@ impl TextFieldState {
         })
         .detach();
         self.on_change(window, cx);
+        self.autosave(window, cx);
     }
 
     fn on_change(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let number = self.number.and_then(|format| {
+            let number = format.parse(&self.value)?;
+            let clamped = format.clamp(number);
+            if clamped != number {
+                self.set_text(&format.format(clamped), window, cx);
+            }
+            Some(clamped)
+        });
+        self.run_validation(window, cx);
         if self.value == self.emitted_value {
             return;
         }
@@ -214,11 +406,123 @@ impl TextFieldState {
             callback(
                 &ChangeEvent {
                     value: self.value.clone(),
+                    number,
                 },
                 window,
                 cx,
             );
         }
+
+        if let Some(id) = self.element_id.clone() {
+            publish_event(
+                FieldChanged {
+                    id,
+                    value: self.value.clone(),
+                },
+                cx,
+            );
+        }
+    }
+
+    /// The result of the last [`super::TextField::validate`] check.
+    pub fn validation(&self) -> &ValidationState {
+        &self.validation
+    }
+
+    /// Runs the validation check, reporting the result if it changed.
+    pub(crate) fn run_validation(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(validate) = self.validate.clone() else {
+            return;
+        };
+        let validation = validate(&self.value);
+        if validation == self.validation {
+            return;
+        }
+
+        self.validation = validation;
+        let event = ValidationEvent {
+            value: self.value.clone(),
+            state: self.validation.clone(),
+        };
+        if let Some(callback) = &self.on_validate {
+            callback(&event, window, cx);
+        }
+        cx.emit(event);
+        cx.notify();
+    }
+
+    /// Saves the draft once the autosave interval elapsed, unless a save is already due.
+    fn schedule_autosave(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(autosave) = &self.autosave else {
+            return;
+        };
+        if self.autosave_scheduled {
+            return;
+        }
+
+        self.autosave_scheduled = true;
+        let interval = autosave.interval;
+        cx.spawn_in(window, async move |this, cx| {
+            Timer::after(interval).await;
+            this.update_in(cx, |state, window, cx| {
+                state.autosave_scheduled = false;
+                state.autosave(window, cx);
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    /// Hands the field's state to the autosave callback if the value changed since the
+    /// last save.
+    fn autosave(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let (Some(autosave), Some(id)) = (&self.autosave, &self.element_id) else {
+            return;
+        };
+        if *self.saved_value.as_ref().unwrap_or(&self.pristine_value) == self.value {
+            return;
+        }
+
+        let callback = autosave.callback.clone();
+        let snapshot = FieldSnapshot {
+            id: id.clone(),
+            value: self.value.clone(),
+            dirty: self.value != self.pristine_value,
+        };
+        self.saved_value = Some(self.value.clone());
+        callback(&snapshot, window, cx);
+    }
+
+    /// Clears the marked text, ending the composition in progress if any.
+    fn end_composition(&mut self, cx: &mut Context<Self>) {
+        if self.marked_range.take().is_some() {
+            cx.emit(CompositionEnd {
+                value: self.value.clone(),
+            });
+        }
+    }
+
+    /// Restarts the delay of the debounced input callback.
+    fn debounce_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(debounced) = &self.on_input_debounced else {
+            return;
+        };
+
+        let delay = debounced.delay;
+        self.debounced_input = Some(cx.spawn_in(window, async move |this, cx| {
+            Timer::after(delay).await;
+            this.update_in(cx, |state, window, cx| {
+                state.debounced_input = None;
+                if let Some(debounced) = &state.on_input_debounced {
+                    let callback = debounced.callback.clone();
+                    let event = InputEvent {
+                        value: state.value.clone(),
+                    };
+                    callback(&event, window, cx);
+                }
+            })
+            .ok();
+        }));
     }
 
     fn pause_cursor_blink(&mut self, cx: &mut Context<Self>) {
@@ -237,6 +541,48 @@ impl TextFieldState {
 
     /// Move cursor left by one grapheme cluster
     pub(super) fn left(&mut self, _: &Left, _: &mut Window, cx: &mut Context<Self>) {
+        self.move_horizontally(false, cx);
+    }
+
+    /// Move cursor right by one grapheme cluster
+    pub(super) fn right(&mut self, _: &Right, _: &mut Window, cx: &mut Context<Self>) {
+        self.move_horizontally(true, cx);
+    }
+
+    /// Moves the cursor one grapheme left or right, on screen with
+    /// [`CursorMovement::Visual`] and in reading order otherwise.
+    fn move_horizontally(&mut self, right: bool, cx: &mut Context<Self>) {
+        if self.cursor_movement == CursorMovement::Visual
+            && !self.masked
+            && let Some(line) = &self.last_layout
+        {
+            let visual_line = VisualLine::new(line, self.direction);
+            let offset = if self.selected_range.is_empty() {
+                visual_line.visual_neighbor(self.cursor_offset(), right)
+            } else {
+                // Collapse the selection to its edge on that side.
+                let start = visual_line.x_for_index(self.selected_range.start);
+                let end = visual_line.x_for_index(self.selected_range.end);
+                Some(if (start < end) == right {
+                    self.selected_range.end
+                } else {
+                    self.selected_range.start
+                })
+            };
+            if let Some(offset) = offset {
+                self.move_to(offset, cx);
+            }
+            return;
+        }
+
+        if right == self.direction.is_rtl() {
+            self.move_backward(cx);
+        } else {
+            self.move_forward(cx);
+        }
+    }
+
+    fn move_backward(&mut self, cx: &mut Context<Self>) {
         if self.selected_range.is_empty() {
             self.move_to(
                 TextOps::previous_boundary(&self.value, self.cursor_offset()),
@@ -247,8 +593,7 @@ impl TextFieldState {
         }
     }
 
-    /// Move cursor right by one grapheme cluster
-    pub(super) fn right(&mut self, _: &Right, _: &mut Window, cx: &mut Context<Self>) {
+    fn move_forward(&mut self, cx: &mut Context<Self>) {
         if self.selected_range.is_empty() {
             self.move_to(
                 TextOps::next_boundary(&self.value, self.selected_range.end),
@@ -261,16 +606,32 @@ impl TextFieldState {
 
     /// Move cursor left by one word
     pub(super) fn word_left(&mut self, _: &WordLeft, _: &mut Window, cx: &mut Context<Self>) {
-        let new_offset = TextOps::previous_word_boundary(&self.value, self.cursor_offset());
+        let new_offset = self.word_boundary(!self.direction.is_rtl());
         self.move_to(new_offset, cx);
     }
 
     /// Move cursor right by one word
     pub(super) fn word_right(&mut self, _: &WordRight, _: &mut Window, cx: &mut Context<Self>) {
-        let new_offset = TextOps::next_word_boundary(&self.value, self.cursor_offset());
+        let new_offset = self.word_boundary(self.direction.is_rtl());
         self.move_to(new_offset, cx);
     }
 
+    fn grapheme_boundary(&self, backward: bool) -> usize {
+        if backward {
+            TextOps::previous_boundary(&self.value, self.cursor_offset())
+        } else {
+            TextOps::next_boundary(&self.value, self.cursor_offset())
+        }
+    }
+
+    fn word_boundary(&self, backward: bool) -> usize {
+        if backward {
+            TextOps::previous_word_boundary(&self.value, self.cursor_offset())
+        } else {
+            TextOps::next_word_boundary(&self.value, self.cursor_offset())
+        }
+    }
+
     /// Move cursor to the beginning of the field
     pub(super) fn home(&mut self, _: &Home, _: &mut Window, cx: &mut Context<Self>) {
         self.move_to(0, cx);
@@ -294,24 +655,110 @@ impl TextFieldState {
         cx.notify();
     }
 
+    // ============================================================================
+    // Programmatic Editing
+    // ============================================================================
+
+    /// The byte offset of the cursor in the value.
+    pub fn cursor_position(&self) -> usize {
+        self.cursor_offset()
+    }
+
+    /// The selected byte range of the value, empty when nothing is selected.
+    ///
+    /// The selection is kept while the field is unfocused, though focusing it with the
+    /// keyboard selects all of the text.
+    pub fn selection(&self) -> Range<usize> {
+        self.selected_range.clone()
+    }
+
+    /// Selects a byte range of the value, clamped to it and to character boundaries. A
+    /// range starting after its end selects backwards.
+    pub fn set_selection(&mut self, range: Range<usize>, cx: &mut Context<Self>) {
+        let start = self.clamp_offset(range.start);
+        let end = self.clamp_offset(range.end);
+        self.selection_reversed = start > end;
+        self.selected_range = start.min(end)..start.max(end);
+        self.should_auto_scroll = true;
+        self.history.prevent_merge();
+        self.pause_cursor_blink(cx);
+        cx.notify();
+    }
+
+    /// Moves the cursor to a byte offset of the value, clearing the selection.
+    pub fn set_cursor_position(&mut self, offset: usize, cx: &mut Context<Self>) {
+        self.set_selection(offset..offset, cx);
+    }
+
+    pub fn select_all(&mut self, cx: &mut Context<Self>) {
+        self.set_selection(0..self.value.len(), cx);
+    }
+
+    /// Replaces the selection with the text as a single undoable edit, leaving the cursor
+    /// after it.
+    pub fn insert_at_cursor(&mut self, text: &str, window: &mut Window, cx: &mut Context<Self>) {
+        self.history.prevent_merge();
+        self.replace_text_in_range(None, text, window, cx);
+        self.history.prevent_merge();
+    }
+
+    /// Applies an edit to a field with a [`FormatMask`] by replacing the whole value with
+    /// its formatted result, so undoing restores the previous formatted value.
+    fn replace_formatted(
+        &mut self,
+        format_mask: &FormatMask,
+        range_utf16: Option<Range<usize>>,
+        new_text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let range = range_utf16
+            .as_ref()
+            .map(|range_utf16| TextOps::range_from_utf16(&self.value, range_utf16))
+            .or(self.marked_range.clone())
+            .unwrap_or(self.selected_range.clone());
+        let range = format_mask.deletion_range(&self.value, range, new_text);
+        let new_value = format!(
+            "{}{}{}",
+            &self.value[..range.start],
+            new_text,
+            &self.value[range.end..]
+        );
+        let (formatted, cursor) = format_mask.apply(&new_value, range.start + new_text.len());
+
+        if formatted != self.value {
+            let whole_value = TextOps::range_to_utf16(&self.value, &(0..self.value.len()));
+            self.history.prevent_merge();
+            self.formatting = true;
+            self.replace_text_in_range(Some(whole_value), &formatted, window, cx);
+            self.formatting = false;
+        }
+        self.end_composition(cx);
+        self.set_cursor_position(cursor, cx);
+    }
+
+    fn clamp_offset(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.value.len());
+        while !self.value.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+
     // ============================================================================
     // Text Selection Actions
     // ============================================================================
 
     /// Extend selection left by one grapheme cluster
     pub(super) fn select_left(&mut self, _: &SelectLeft, _: &mut Window, cx: &mut Context<Self>) {
-        self.select_to(
-            TextOps::previous_boundary(&self.value, self.cursor_offset()),
-            cx,
-        );
+        let new_offset = self.grapheme_boundary(!self.direction.is_rtl());
+        self.select_to(new_offset, cx);
     }
 
     /// Extend selection right by one grapheme cluster
     pub(super) fn select_right(&mut self, _: &SelectRight, _: &mut Window, cx: &mut Context<Self>) {
-        self.select_to(
-            TextOps::next_boundary(&self.value, self.cursor_offset()),
-            cx,
-        );
+        let new_offset = self.grapheme_boundary(self.direction.is_rtl());
+        self.select_to(new_offset, cx);
     }
 
     /// Extend selection left by one word
@@ -321,7 +768,7 @@ impl TextFieldState {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let new_offset = TextOps::previous_word_boundary(&self.value, self.cursor_offset());
+        let new_offset = self.word_boundary(!self.direction.is_rtl());
         self.history.prevent_merge();
         self.select_to(new_offset, cx);
     }
@@ -333,7 +780,7 @@ impl TextFieldState {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let new_offset = TextOps::next_word_boundary(&self.value, self.cursor_offset());
+        let new_offset = self.word_boundary(self.direction.is_rtl());
         self.history.prevent_merge();
         self.select_to(new_offset, cx);
     }
@@ -359,9 +806,8 @@ impl TextFieldState {
     }
 
     /// Select all text in the field
-    pub(super) fn select_all(&mut self, _: &SelectAll, _: &mut Window, cx: &mut Context<Self>) {
-        self.move_to(0, cx);
-        self.select_to(self.value.len(), cx);
+    pub(super) fn on_select_all(&mut self, _: &SelectAll, _: &mut Window, cx: &mut Context<Self>) {
+        self.select_all(cx);
     }
 
     /// Extend selection to a specific offset
@@ -383,9 +829,7 @@ impl TextFieldState {
 
     /// Select the word at the given offset
     fn select_word(&mut self, offset: usize, cx: &mut Context<Self>) {
-        let start = TextOps::previous_word_boundary(&self.value, offset);
-        let end = TextOps::next_word_boundary(&self.value, offset);
-        self.selected_range = start..end;
+        self.selected_range = TextOps::word_range(&self.value, offset);
         self.selection_reversed = false;
         cx.notify();
     }
@@ -439,14 +883,17 @@ impl TextFieldState {
             let selected_text = self.value[self.selected_range.clone()].to_string();
             cx.write_to_clipboard(ClipboardItem::new_string(selected_text));
             self.history.prevent_merge();
-            self.replace_text_in_range(None, "", window, cx);
+            self.kill_selection(window, cx);
         }
     }
 
     pub(super) fn undo(&mut self, _: &Undo, window: &mut Window, cx: &mut Context<Self>) {
+        if self.read_only {
+            return;
+        }
         self.ignore_history = true;
 
-        if let Some(change) = self.history.undo() {
+        for change in self.history.undo_group() {
             self.replace_text_in_range(
                 Some(TextOps::range_to_utf16(&self.value, &change.range())),
                 &change.text(),
@@ -459,8 +906,11 @@ impl TextFieldState {
     }
 
     pub(super) fn redo(&mut self, _: &Redo, window: &mut Window, cx: &mut Context<Self>) {
+        if self.read_only {
+            return;
+        }
         self.ignore_history = true;
-        if let Some(change) = self.history.redo() {
+        for change in self.history.redo_group() {
             self.replace_text_in_range(
                 Some(TextOps::range_to_utf16(&self.value, &change.range())),
                 &change.text(),
@@ -515,7 +965,7 @@ impl TextFieldState {
             self.selected_range = word_start..cursor_pos;
         }
         self.history.prevent_merge();
-        self.replace_text_in_range(None, "", window, cx);
+        self.kill_selection(window, cx);
     }
 
     /// Delete word to the right of cursor
@@ -531,7 +981,7 @@ impl TextFieldState {
             self.selected_range = cursor_pos..word_end;
         }
         self.history.prevent_merge();
-        self.replace_text_in_range(None, "", window, cx);
+        self.kill_selection(window, cx);
     }
 
     /// Delete from cursor to beginning of text field
@@ -545,7 +995,7 @@ impl TextFieldState {
             let cursor_pos = self.cursor_offset();
             self.selected_range = 0..cursor_pos;
         }
-        self.replace_text_in_range(None, "", window, cx);
+        self.kill_selection(window, cx);
     }
 
     /// Delete from cursor to end of text field
@@ -559,11 +1009,117 @@ impl TextFieldState {
             let cursor_pos = self.cursor_offset();
             self.selected_range = cursor_pos..self.value.len();
         }
+        self.kill_selection(window, cx);
+    }
+
+    /// Deletes the selection, pushing it into the [`KillRing`] unless the field is masked.
+    ///
+    /// Read-only fields keep their text and leave the kill ring alone, so cutting from them
+    /// only copies.
+    fn kill_selection(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.read_only {
+            return;
+        }
+        if !self.selected_range.is_empty() && !self.masked {
+            let text = self.value[self.selected_range.clone()].to_string();
+            cx.default_global::<KillRing>().push(text);
+        }
         self.replace_text_in_range(None, "", window, cx);
     }
 
+    /// Inserts the most recent kill at the cursor
+    pub(super) fn yank(&mut self, _: &Yank, window: &mut Window, cx: &mut Context<Self>) {
+        self.yank_entry(0, window, cx);
+    }
+
+    /// Replaces the text just yanked with the kill before it
+    pub(super) fn yank_pop(&mut self, _: &YankPop, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(yanked) = self.last_yank.clone() else {
+            return;
+        };
+        let cursor = yanked.range.end;
+        if yanked.value != self.value || self.selected_range != (cursor..cursor) {
+            self.last_yank = None;
+            return;
+        }
+        self.selected_range = yanked.range;
+        self.yank_entry(yanked.index + 1, window, cx);
+    }
+
+    fn yank_entry(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(text) = cx
+            .try_global::<KillRing>()
+            .and_then(|ring| ring.get(index))
+            .map(str::to_string)
+        else {
+            return;
+        };
+        let start = self.selected_range.start;
+        self.history.prevent_merge();
+        self.replace_text_in_range(None, &text, window, cx);
+        self.last_yank = self.value[start..]
+            .starts_with(text.as_str())
+            .then(|| Yanked {
+                index,
+                range: start..start + text.len(),
+                value: self.value.clone(),
+            });
+    }
+
+    /// Swaps the characters around the cursor, moving it past them
+    pub(super) fn transpose(&mut self, _: &Transpose, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.selected_range.is_empty() {
+            return;
+        }
+        let Some((range, text)) = TextOps::transpose(&self.value, self.cursor_offset()) else {
+            return;
+        };
+        self.history.prevent_merge();
+        self.selected_range = range;
+        self.replace_text_in_range(None, &text, window, cx);
+    }
+
     pub(super) fn enter(&mut self, _: &Enter, window: &mut Window, cx: &mut Context<Self>) {
         self.on_change(window, cx);
+        // Lets the enclosing form submit.
+        if self.in_form {
+            cx.propagate();
+        }
+    }
+
+    pub(super) fn increment(&mut self, _: &Increment, window: &mut Window, cx: &mut Context<Self>) {
+        self.step(true, window, cx);
+    }
+
+    pub(super) fn decrement(&mut self, _: &Decrement, window: &mut Window, cx: &mut Context<Self>) {
+        self.step(false, window, cx);
+    }
+
+    /// Steps the value of a numeric field up or down and commits it.
+    pub(super) fn step(&mut self, up: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(format) = self.number else {
+            return;
+        };
+        let text = format.format(format.stepped(&self.value, up));
+        self.set_text(&text, window, cx);
+        self.on_change(window, cx);
+    }
+
+    /// Clears the text as an undoable edit and commits it.
+    pub fn clear(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_text("", window, cx);
+        self.on_change(window, cx);
+    }
+
+    /// Replaces the whole text as a single undoable edit.
+    fn set_text(&mut self, text: &str, window: &mut Window, cx: &mut Context<Self>) {
+        if self.value == text {
+            return;
+        }
+        self.history.prevent_merge();
+        let range = TextOps::range_to_utf16(&self.value, &(0..self.value.len()));
+        self.replace_text_in_range(Some(range), text, window, cx);
+        self.history.prevent_merge();
     }
 
     // ============================================================================
@@ -574,23 +1130,27 @@ impl TextFieldState {
     pub(super) fn on_mouse_down(
         &mut self,
         event: &MouseDownEvent,
-        window: &mut Window,
+        _: &mut Window,
         cx: &mut Context<Self>,
     ) {
         self.selecting = true;
         self.focus_select = false;
 
-        // Handle multi-click selection
+        // Handle multi-click selection, which dragging then extends by the same unit
         if event.click_count > 1 {
             if event.click_count % 2 == 0 {
                 // Double-click: select word
                 self.select_word(self.index_for_mouse_position(event.position), cx);
+                self.granularity = SelectionGranularity::Word;
             } else {
                 // Triple-click: select all
-                self.select_all(&SelectAll, window, cx);
+                self.select_all(cx);
+                self.granularity = SelectionGranularity::Line;
             }
+            self.selection_anchor = self.selected_range.clone();
             return;
         }
+        self.granularity = SelectionGranularity::Character;
 
         // Single click: position cursor or extend selection
         let mouse_offset = self.index_for_mouse_position(event.position);
@@ -613,8 +1173,23 @@ impl TextFieldState {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.selecting {
-            self.select_to(self.index_for_mouse_position(event.position), cx);
+        if !self.selecting {
+            return;
+        }
+
+        let offset = self.index_for_mouse_position(event.position);
+        match self.granularity {
+            SelectionGranularity::Character => self.select_to(offset, cx),
+            SelectionGranularity::Word => {
+                let (range, reversed) =
+                    TextOps::extend_by_word(&self.value, &self.selection_anchor, offset);
+                self.selected_range = range;
+                self.selection_reversed = reversed;
+                self.should_auto_scroll = true;
+                cx.notify();
+            }
+            // The single line is already selected.
+            SelectionGranularity::Line => {}
         }
     }
 
@@ -662,7 +1237,7 @@ impl TextFieldState {
         if let (Some(layout), Some(bounds)) = (self.last_layout.as_ref(), self.last_bounds.as_ref())
         {
             let text_width = layout.width;
-            let visible_width = bounds.size.width - px(CURSOR_WIDTH);
+            let visible_width = bounds.size.width - self.cursor_width;
 
             offset.x = offset.x.max(px(0.0));
 
@@ -687,9 +1262,9 @@ impl TextFieldState {
         self.should_auto_scroll = false;
 
         let cursor_offset = self.display_cursor_offset();
-        let cursor_x = layout.x_for_index(cursor_offset);
+        let cursor_x = VisualLine::new(layout, self.direction).x_for_index(cursor_offset);
         let current_scroll = self.scroll_handle.offset();
-        let visible_width = bounds.size.width - px(CURSOR_WIDTH);
+        let visible_width = bounds.size.width - self.cursor_width;
         let text_width = layout.width;
         let visible_left = current_scroll.x;
         let visible_right = current_scroll.x + visible_width;
@@ -723,6 +1298,18 @@ impl TextFieldState {
     // Position and Index Calculation
     // ============================================================================
 
+    /// Get the x position of the start of the line, scrolled and aligned to the right when
+    /// right-to-left text fits the field
+    pub(super) fn text_left(&self, line: &ShapedLine, bounds: Bounds<Pixels>) -> Pixels {
+        let free_width = bounds.size.width - self.cursor_width - line.width;
+        let align = if self.direction.is_rtl() {
+            free_width.max(px(0.))
+        } else {
+            px(0.)
+        };
+        bounds.left() + align - self.scroll_handle.offset().x
+    }
+
     /// Get the current cursor offset
     fn cursor_offset(&self) -> usize {
         if self.selection_reversed {
@@ -787,8 +1374,8 @@ impl TextFieldState {
             return 0;
         };
 
-        let scroll_offset = self.scroll_handle.offset();
-        let display_index = line.closest_index_for_x(position.x - bounds.left() + scroll_offset.x);
+        let display_index = VisualLine::new(line, self.direction)
+            .closest_index_for_x(position.x - self.text_left(line, *bounds));
         self.display_to_actual_offset(display_index)
     }
 
@@ -846,8 +1433,13 @@ impl TextFieldState {
         &mut self,
         range_utf16: Option<Range<usize>>,
         new_text: &str,
+        window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Option<(String, String, Range<usize>)> {
+        if self.read_only {
+            return None;
+        }
+
         let range = range_utf16
             .as_ref()
             .map(|range_utf16| TextOps::range_from_utf16(&self.value, range_utf16))
@@ -866,12 +1458,20 @@ impl TextFieldState {
 
             if current_len + new_len > max_length {
                 let available_space = max_length.saturating_sub(current_len);
+                let byte_offset =
+                    TextOps::grapheme_offset_to_byte_offset(new_text, available_space);
+                let event = MaxLengthReached {
+                    max_length,
+                    truncated: new_text[byte_offset..].to_string().into(),
+                };
+                if let Some(on_max_length_reached) = &self.on_max_length_reached {
+                    on_max_length_reached(&event, window, cx);
+                }
+                cx.emit(event);
                 if available_space == 0 {
                     return None;
                 }
 
-                let byte_offset =
-                    TextOps::grapheme_offset_to_byte_offset(new_text, available_space);
                 &new_text[..byte_offset]
             } else {
                 new_text
@@ -880,15 +1480,21 @@ impl TextFieldState {
             new_text
         };
 
-        self.pause_cursor_blink(cx);
-        self.push_history(new_text, &range);
-
         let new_value = format!(
             "{}{}{}",
             &self.value[0..range.start],
             new_text,
             &self.value[range.end..]
         );
+        if let Some(format) = self.number
+            && !self.ignore_history
+            && !format.is_partial(new_value.trim())
+        {
+            return None;
+        }
+
+        self.pause_cursor_blink(cx);
+        self.push_history(new_text, &range);
 
         Some((new_text.into(), new_value, range))
     }
@@ -925,8 +1531,8 @@ impl EntityInputHandler for TextFieldState {
             .map(|range| TextOps::range_to_utf16(&self.value, range))
     }
 
-    fn unmark_text(&mut self, _: &mut Window, _: &mut Context<Self>) {
-        self.marked_range = None;
+    fn unmark_text(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        self.end_composition(cx);
     }
 
     fn replace_text_in_range(
@@ -936,8 +1542,16 @@ impl EntityInputHandler for TextFieldState {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if let Some(format_mask) = self.format_mask.clone()
+            && !self.formatting
+            && !self.ignore_history
+        {
+            self.replace_formatted(&format_mask, range_utf16, new_text, window, cx);
+            return;
+        }
+
         let (new_text, new_value, range) =
-            match self.prepare_replace_text(range_utf16, new_text, cx) {
+            match self.prepare_replace_text(range_utf16, new_text, window, cx) {
                 Some(result) => result,
                 None => return,
             };
@@ -945,7 +1559,7 @@ impl EntityInputHandler for TextFieldState {
         let new_cursor_pos = range.start + new_text.len();
         self.value = new_value.into();
         self.selected_range = new_cursor_pos..new_cursor_pos;
-        self.marked_range = None;
+        self.end_composition(cx);
         self.should_auto_scroll = true;
         self.last_layout = None;
         self.last_bounds = None;
@@ -959,6 +1573,11 @@ impl EntityInputHandler for TextFieldState {
                 cx,
             );
         }
+        if self.validate_on == ValidateOn::Input {
+            self.run_validation(window, cx);
+        }
+        self.debounce_input(window, cx);
+        self.schedule_autosave(window, cx);
         self.update_scroll_offset(None, cx);
     }
 
@@ -971,7 +1590,7 @@ impl EntityInputHandler for TextFieldState {
         cx: &mut Context<Self>,
     ) {
         let (new_text, new_value, range) =
-            match self.prepare_replace_text(range_utf16, new_text, cx) {
+            match self.prepare_replace_text(range_utf16, new_text, window, cx) {
                 Some(result) => result,
                 None => return,
             };
@@ -979,9 +1598,15 @@ impl EntityInputHandler for TextFieldState {
         self.value = new_value.into();
 
         if !new_text.is_empty() {
-            self.marked_range = Some(range.start..range.start + new_text.len());
+            let marked_range = range.start..range.start + new_text.len();
+            if self.marked_range.replace(marked_range).is_none() {
+                cx.emit(CompositionStart);
+            }
+            cx.emit(CompositionUpdate {
+                text: new_text.clone().into(),
+            });
         } else {
-            self.marked_range = None;
+            self.end_composition(cx);
         }
 
         self.selected_range = new_selected_range_utf16
@@ -1015,16 +1640,14 @@ impl EntityInputHandler for TextFieldState {
     ) -> Option<Bounds<Pixels>> {
         let last_layout = self.last_layout.as_ref()?;
         let range = TextOps::range_from_utf16(&self.value, &range_utf16);
+        let visual_line = VisualLine::new(last_layout, self.direction);
+        let text_left = self.text_left(last_layout, bounds);
+        let start = text_left + visual_line.x_for_index(range.start);
+        let end = text_left + visual_line.x_for_index(range.end);
 
         Some(Bounds::from_corners(
-            point(
-                bounds.left() + last_layout.x_for_index(range.start),
-                bounds.top(),
-            ),
-            point(
-                bounds.left() + last_layout.x_for_index(range.end),
-                bounds.bottom(),
-            ),
+            point(start.min(end), bounds.top()),
+            point(start.max(end), bounds.bottom()),
         ))
     }
 
@@ -1034,10 +1657,12 @@ impl EntityInputHandler for TextFieldState {
         _: &mut Window,
         _: &mut Context<Self>,
     ) -> Option<usize> {
-        let line_point = self.last_bounds?.localize(&point)?;
+        let bounds = self.last_bounds?;
+        bounds.localize(&point)?;
         let last_layout = self.last_layout.as_ref()?;
 
-        let utf8_index = last_layout.index_for_x(point.x - line_point.x)?;
+        let utf8_index = VisualLine::new(last_layout, self.direction)
+            .closest_index_for_x(point.x - self.text_left(last_layout, bounds));
         Some(TextOps::offset_to_utf16(&self.value, utf8_index))
     }
 }
@@ -1048,6 +1673,12 @@ impl Focusable for TextFieldState {
     }
 }
 
+impl EventEmitter<ValidationEvent> for TextFieldState {}
+impl EventEmitter<MaxLengthReached> for TextFieldState {}
+impl EventEmitter<CompositionStart> for TextFieldState {}
+impl EventEmitter<CompositionUpdate> for TextFieldState {}
+impl EventEmitter<CompositionEnd> for TextFieldState {}
+
 impl Validatable for TextFieldState {
     fn check_validity(&self) -> bool {
         if let Some(max_length) = self.max_length
@@ -1056,6 +1687,10 @@ impl Validatable for TextFieldState {
             return false;
         }
 
+        if !self.validation.is_valid() {
+            return false;
+        }
+
         if let Some(validator) = &self.validator {
             return validator(self.value.clone());
         }
@@ -1068,9 +1703,13 @@ impl Render for TextFieldState {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         div()
             .id("text-element")
-            .flex_1()
-            .flex_grow()
+            .map(|this| match self.auto_width {
+                Some(_) => this.flex_none(),
+                None => this.flex_1().flex_grow(),
+            })
             .overflow_x_hidden()
-            .child(TextElement::new(cx.entity().clone()))
+            .child(
+                TextElement::new(cx.entity().clone()).placeholder(self.placeholder_element.take()),
+            )
     }
 }
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod bidi {
+    use crate::{Direction, primitives::text_field::bidi::VisualLine};
+    use gpui::px;
+
+    /// "abc אבג" with 10px glyphs, the Hebrew letters shown right to left.
+    fn mixed_line() -> VisualLine {
+        let glyphs = [
+            (0, 0.),
+            (1, 10.),
+            (2, 20.),
+            (3, 30.),
+            (8, 40.),
+            (6, 50.),
+            (4, 60.),
+        ];
+        VisualLine::from_glyphs(
+            "abc אבג",
+            glyphs.map(|(index, x)| (index, px(x))),
+            px(70.),
+            Direction::Ltr,
+        )
+    }
+
+    #[test]
+    fn places_caret_on_leading_edge() {
+        let line = mixed_line();
+        assert_eq!(line.x_for_index(0), px(0.));
+        assert_eq!(line.x_for_index(3), px(30.));
+        assert_eq!(line.x_for_index(4), px(70.));
+        assert_eq!(line.x_for_index(8), px(50.));
+        assert_eq!(line.x_for_index(10), px(40.));
+    }
+
+    #[test]
+    fn finds_closest_index_in_right_to_left_run() {
+        let line = mixed_line();
+        assert_eq!(line.closest_index_for_x(px(66.)), 4);
+        assert_eq!(line.closest_index_for_x(px(61.)), 6);
+        assert_eq!(line.closest_index_for_x(px(4.)), 0);
+        assert_eq!(line.closest_index_for_x(px(90.)), 4);
+    }
+
+    #[test]
+    fn splits_selection_across_directions() {
+        let line = mixed_line();
+        assert_eq!(line.selection_ranges(&(4..8)), vec![px(50.)..px(70.)]);
+        assert_eq!(
+            line.selection_ranges(&(2..6)),
+            vec![px(20.)..px(40.), px(60.)..px(70.)]
+        );
+    }
+
+    #[test]
+    fn moves_visually() {
+        let line = mixed_line();
+        assert_eq!(line.visual_neighbor(3, true), Some(10));
+        assert_eq!(line.visual_neighbor(10, true), Some(8));
+        assert_eq!(line.visual_neighbor(4, true), None);
+        assert_eq!(line.visual_neighbor(4, false), Some(6));
+    }
+}
@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod counter {
+    use crate::primitives::text_field::CounterContext;
+
+    #[test]
+    fn counts_graphemes_and_words() {
+        let counter = CounterContext::new("héllo, wörld 👩‍👩‍👧", None);
+        assert_eq!(counter.graphemes(), 14);
+        assert_eq!(counter.words(), 2);
+        assert_eq!(counter.label(), "14");
+    }
+
+    #[test]
+    fn counts_against_max_length() {
+        let counter = CounterContext::new("abc", Some(5));
+        assert_eq!(counter.remaining(), Some(2));
+        assert_eq!(counter.label(), "3/5");
+        assert_eq!(CounterContext::new("abcdef", Some(5)).remaining(), Some(0));
+        assert_eq!(CounterContext::new("", None).remaining(), None);
+    }
+}
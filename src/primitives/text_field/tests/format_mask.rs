@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod format_mask {
+    use crate::primitives::text_field::FormatMask;
+
+    #[test]
+    fn inserts_literals_once_typed_past() {
+        let mask = FormatMask::new("##/##/####");
+        assert_eq!(mask.apply("12", 2), ("12".to_string(), 2));
+        assert_eq!(mask.apply("123", 3), ("12/3".to_string(), 4));
+        assert_eq!(mask.apply("(", 1), ("".to_string(), 0));
+    }
+
+    #[test]
+    fn normalizes_pasted_text() {
+        let mask = FormatMask::new("(###) ###-####");
+        let pasted = "555.123 4567";
+        assert_eq!(
+            mask.apply(pasted, pasted.len()),
+            ("(555) 123-4567".to_string(), 14)
+        );
+        assert!(mask.is_complete("(555) 123-4567"));
+        assert!(!mask.is_complete("555"));
+    }
+
+    #[test]
+    fn keeps_digits_equal_to_a_literal() {
+        let mask = FormatMask::new("+1 (###) ###-####");
+        let typed = "5551211111";
+        assert_eq!(
+            mask.apply(typed, typed.len()),
+            ("+1 (555) 121-1111".to_string(), 17)
+        );
+        let pasted = "+1 (555) 123-4567";
+        assert_eq!(
+            mask.apply(pasted, pasted.len()),
+            ("+1 (555) 123-4567".to_string(), 17)
+        );
+        // Formatting again keeps the value.
+        assert_eq!(
+            mask.apply("+1 (555) 121-1111", 17),
+            ("+1 (555) 121-1111".to_string(), 17)
+        );
+        assert!(mask.is_complete("+1 (555) 121-1111"));
+    }
+
+    #[test]
+    fn deleting_a_digit_equal_to_a_literal_deletes_only_it() {
+        let mask = FormatMask::new("+1 (###)");
+        assert_eq!(mask.deletion_range("+1 (51", 5..6, ""), 5..6);
+        // With no input before them, literals are deleted on their own.
+        assert_eq!(mask.deletion_range("+1 (5", 3..4, ""), 3..4);
+    }
+
+    #[test]
+    fn drops_characters_that_do_not_fit() {
+        let mask = FormatMask::new("AA-##");
+        assert_eq!(mask.apply("a1b23x", 6), ("ab-23".to_string(), 5));
+    }
+
+    #[test]
+    fn keeps_the_cursor_after_the_typed_character() {
+        let mask = FormatMask::new("##/##");
+        // Typing "9" at the start of "12/3".
+        assert_eq!(mask.apply("912/3", 1), ("91/23".to_string(), 1));
+    }
+
+    #[test]
+    fn deleting_a_literal_deletes_the_character_before_it() {
+        let mask = FormatMask::new("##/##");
+        assert_eq!(mask.deletion_range("12/3", 2..3, ""), 1..3);
+        assert_eq!(mask.deletion_range("12/3", 3..4, ""), 3..4);
+        assert_eq!(mask.deletion_range("12/3", 2..3, "4"), 2..3);
+    }
+}
@@ -634,4 +634,64 @@ mod history {
             }
         );
     }
+
+    #[test]
+    fn groups_undo_and_redo_as_one() {
+        let mut history = History::new();
+        insert_text(&mut history, "ab");
+        history.begin_group();
+        paste_text(&mut history, "(", 0..0);
+        history.begin_group();
+        paste_text(&mut history, ")", 3..3);
+        history.end_group();
+        history.end_group();
+        assert_eq!(history.undo_len(), 2);
+
+        let undo = history.undo_group();
+        assert_eq!(
+            undo,
+            vec![
+                Change::Delete {
+                    text: "".into(),
+                    range: 3..4
+                },
+                Change::Delete {
+                    text: "".into(),
+                    range: 0..1
+                },
+            ]
+        );
+        assert_eq!(history.undo_len(), 1);
+        assert_eq!(history.redo_len(), 1);
+
+        let redo = history.redo_group();
+        assert_eq!(redo.len(), 2);
+        assert_eq!(history.undo_len(), 2);
+    }
+
+    #[test]
+    fn changes_after_a_group_stay_separate() {
+        let mut history = History::new();
+        history.begin_group();
+        insert_text(&mut history, "ab");
+        history.end_group();
+        insert_text(&mut history, "c");
+        assert_eq!(history.undo_len(), 2);
+        assert_eq!(history.undo_group().len(), 1);
+        assert_eq!(history.undo_group().len(), 1);
+        assert_eq!(history.undo_len(), 0);
+    }
+
+    #[test]
+    fn max_size_drops_oldest() {
+        let mut history = History::new();
+        for ix in 0..5 {
+            paste_text(&mut history, "x", ix..ix);
+        }
+        history.set_max_size(2);
+        assert_eq!(history.undo_len(), 2);
+        history.undo();
+        history.undo();
+        assert_eq!(history.undo(), None);
+    }
 }
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod kill_ring {
+    use crate::primitives::text_field::kill_ring::{KILL_RING_CAPACITY, KillRing};
+
+    #[test]
+    fn most_recent_first() {
+        let mut ring = KillRing::default();
+        assert_eq!(ring.get(0), None);
+        ring.push("one".into());
+        ring.push("two".into());
+        assert_eq!(ring.get(0), Some("two"));
+        assert_eq!(ring.get(1), Some("one"));
+    }
+
+    #[test]
+    fn wraps_around() {
+        let mut ring = KillRing::default();
+        ring.push("one".into());
+        ring.push("two".into());
+        assert_eq!(ring.get(2), Some("two"));
+        assert_eq!(ring.get(3), Some("one"));
+    }
+
+    #[test]
+    fn ignores_empty_kills() {
+        let mut ring = KillRing::default();
+        ring.push("one".into());
+        ring.push(String::new());
+        assert_eq!(ring.get(0), Some("one"));
+        assert_eq!(ring.get(1), Some("one"));
+    }
+
+    #[test]
+    fn drops_oldest_past_capacity() {
+        let mut ring = KillRing::default();
+        for ix in 0..=KILL_RING_CAPACITY {
+            ring.push(ix.to_string());
+        }
+        assert_eq!(ring.get(0), Some(KILL_RING_CAPACITY.to_string().as_str()));
+        assert_eq!(ring.get(KILL_RING_CAPACITY - 1), Some("1"));
+        assert_eq!(ring.get(KILL_RING_CAPACITY), ring.get(0));
+    }
+}
+
+#[cfg(test)]
+mod kill_selection {
+    use crate::primitives::text_field::{
+        actions::{Cut, DeleteToEnd, Home, SelectAll},
+        kill_ring::KillRing,
+        text_field,
+    };
+    use gpui::{Context, IntoElement, Render, TestAppContext, VisualTestContext, Window};
+
+    struct Notes {
+        read_only: bool,
+    }
+
+    impl Render for Notes {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            text_field("notes")
+                .default_value("hello")
+                .read_only(self.read_only)
+        }
+    }
+
+    /// Renders the field focused.
+    fn notes(read_only: bool, cx: &mut TestAppContext) -> &mut VisualTestContext {
+        cx.update(crate::init);
+        let (_, cx) = cx.add_window_view(|_, _| Notes { read_only });
+        cx.update(|window, _| window.focus_next());
+        cx.run_until_parked();
+        cx
+    }
+
+    fn last_kill(cx: &mut VisualTestContext) -> Option<String> {
+        cx.update(|_, app| {
+            app.try_global::<KillRing>()
+                .and_then(|ring| ring.get(0))
+                .map(str::to_string)
+        })
+    }
+
+    #[gpui::test]
+    fn kills_into_the_ring(cx: &mut TestAppContext) {
+        let cx = notes(false, cx);
+        cx.dispatch_action(Home);
+        cx.dispatch_action(DeleteToEnd);
+        assert_eq!(last_kill(cx).as_deref(), Some("hello"));
+    }
+
+    #[gpui::test]
+    fn leaves_the_ring_alone_when_read_only(cx: &mut TestAppContext) {
+        let cx = notes(true, cx);
+        cx.dispatch_action(Home);
+        cx.dispatch_action(DeleteToEnd);
+        assert_eq!(last_kill(cx), None);
+    }
+
+    #[gpui::test]
+    fn only_copies_on_cut_when_read_only(cx: &mut TestAppContext) {
+        let cx = notes(true, cx);
+        cx.dispatch_action(SelectAll);
+        cx.dispatch_action(Cut);
+        assert_eq!(last_kill(cx), None);
+        assert_eq!(
+            cx.read_from_clipboard()
+                .and_then(|item| item.text())
+                .as_deref(),
+            Some("hello")
+        );
+    }
+}
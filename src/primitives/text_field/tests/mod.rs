@@ -1,2 +1,11 @@
+mod bidi;
+mod counter;
+mod format_mask;
 mod history;
+mod kill_ring;
+mod number;
+mod text_runs;
+mod transpose;
+mod validation;
+mod value_diff;
 mod word_boundaries;
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod number {
+    use crate::primitives::text_field::NumberFormat;
+
+    #[test]
+    fn parses_with_the_decimal_separator() {
+        let format = NumberFormat::new().decimal_separator(',');
+        assert_eq!(format.parse("1,5"), Some(1.5));
+        assert_eq!(format.parse(" -2 "), Some(-2.));
+        assert_eq!(format.parse("1.5"), None);
+        assert_eq!(format.parse("-"), None);
+    }
+
+    #[test]
+    fn accepts_partial_numbers_while_typing() {
+        let format = NumberFormat::new();
+        assert!(format.is_partial(""));
+        assert!(format.is_partial("-"));
+        assert!(format.is_partial("3."));
+        assert!(!format.is_partial("3.1.4"));
+        assert!(!format.is_partial("1e3"));
+        assert!(!NumberFormat::new().min(0.).is_partial("-1"));
+    }
+
+    #[test]
+    fn formats_without_trailing_zeros() {
+        let format = NumberFormat::new().decimal_separator(',');
+        assert_eq!(format.format(2.), "2");
+        assert_eq!(format.format(0.1 + 0.2), "0,3");
+        assert_eq!(format.format(-0.), "0");
+    }
+
+    #[test]
+    fn steps_within_the_bounds() {
+        let format = NumberFormat::new().min(0.).max(1.).step(0.25);
+        assert_eq!(format.stepped("0.5", true), 0.75);
+        assert_eq!(format.stepped("1", true), 1.);
+        assert_eq!(format.stepped("0", false), 0.);
+        assert_eq!(format.stepped("", true), 0.);
+        assert_eq!(format.stepped("", false), 1.);
+    }
+}
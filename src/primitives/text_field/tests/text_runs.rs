@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod text_runs {
+    use crate::primitives::text_field::element::{split_runs, text_runs};
+    use gpui::{FontWeight, HighlightStyle, Hsla, TextRun, black, blue, font, green, red};
+    use std::ops::Range;
+
+    fn base_run(len: usize) -> TextRun {
+        TextRun {
+            len,
+            font: font("Helvetica"),
+            color: black(),
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        }
+    }
+
+    fn background(color: Hsla) -> HighlightStyle {
+        HighlightStyle {
+            background_color: Some(color),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the byte range and background of each run, checking they cover `text`.
+    fn backgrounds(text: &str, runs: &[TextRun]) -> Vec<(Range<usize>, Option<Hsla>)> {
+        let mut start = 0;
+        let ranges = runs
+            .iter()
+            .map(|run| {
+                start += run.len;
+                (start - run.len..start, run.background_color)
+            })
+            .collect();
+        assert_eq!(start, text.len());
+        ranges
+    }
+
+    fn runs(
+        text: &str,
+        highlights: Vec<(Range<usize>, HighlightStyle)>,
+        marked_range: Option<Range<usize>>,
+    ) -> Vec<TextRun> {
+        text_runs(
+            text,
+            base_run(text.len()),
+            highlights,
+            marked_range,
+            green(),
+        )
+    }
+
+    #[test]
+    fn splits_a_run_around_the_range() {
+        let runs = split_runs(vec![base_run(9)], 2..5, |run| {
+            run.background_color = Some(red())
+        });
+        assert_eq!(
+            backgrounds("012345678", &runs),
+            [(0..2, None), (2..5, Some(red())), (5..9, None)]
+        );
+    }
+
+    #[test]
+    fn splits_every_run_the_range_spans() {
+        let runs = split_runs(vec![base_run(3), base_run(3)], 2..5, |run| {
+            run.background_color = Some(red())
+        });
+        assert_eq!(
+            backgrounds("012345", &runs),
+            [
+                (0..2, None),
+                (2..3, Some(red())),
+                (3..5, Some(red())),
+                (5..6, None)
+            ]
+        );
+    }
+
+    #[test]
+    fn applies_later_highlights_over_earlier_ones() {
+        let bold = HighlightStyle {
+            font_weight: Some(FontWeight::BOLD),
+            ..Default::default()
+        };
+        let runs = runs(
+            "hello world",
+            vec![
+                (0..7, background(red())),
+                (4..11, background(blue())),
+                (2..5, bold),
+            ],
+            None,
+        );
+        assert_eq!(
+            backgrounds("hello world", &runs),
+            [
+                (0..2, Some(red())),
+                (2..4, Some(red())),
+                (4..5, Some(blue())),
+                (5..7, Some(blue())),
+                (7..11, Some(blue())),
+            ]
+        );
+        let weights: Vec<FontWeight> = runs.iter().map(|run| run.font.weight).collect();
+        assert_eq!(
+            weights,
+            [
+                FontWeight::NORMAL,
+                FontWeight::BOLD,
+                FontWeight::BOLD,
+                FontWeight::NORMAL,
+                FontWeight::NORMAL,
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_ranges_off_char_boundaries_or_out_of_bounds() {
+        // "é" spans bytes 1..3.
+        let text = "héllo";
+        let runs = runs(
+            text,
+            vec![
+                (0..2, background(red())),
+                (2..4, background(red())),
+                (3..20, background(red())),
+                (3..3, background(red())),
+            ],
+            Some(2..5),
+        );
+        assert_eq!(backgrounds(text, &runs), [(0..6, None)]);
+        assert_eq!(runs[0].underline, None);
+    }
+
+    #[test]
+    fn keeps_multibyte_highlights_on_char_boundaries() {
+        let text = "héllo";
+        let runs = runs(text, vec![(1..3, background(red()))], None);
+        assert_eq!(
+            backgrounds(text, &runs),
+            [(0..1, None), (1..3, Some(red())), (3..6, None)]
+        );
+    }
+
+    #[test]
+    fn underlines_the_marked_text_over_the_highlights() {
+        let text = "hello world";
+        let runs = runs(text, vec![(0..5, background(red()))], Some(3..8));
+        assert_eq!(
+            backgrounds(text, &runs),
+            [
+                (0..3, Some(red())),
+                (3..5, Some(red())),
+                (5..8, None),
+                (8..11, None)
+            ]
+        );
+        let underlined: Vec<bool> = runs.iter().map(|run| run.underline.is_some()).collect();
+        assert_eq!(underlined, [false, true, true, false]);
+        assert_eq!(runs[1].underline.unwrap().color, Some(green()));
+    }
+}
@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod transpose {
+    use crate::primitives::text_field::text_ops::TextOps;
+
+    fn transposed(text: &str, offset: usize) -> Option<String> {
+        let (range, swapped) = TextOps::transpose(text, offset)?;
+        Some(format!(
+            "{}{}{}",
+            &text[..range.start],
+            swapped,
+            &text[range.end..]
+        ))
+    }
+
+    #[test]
+    fn swaps_around_cursor() {
+        assert_eq!(TextOps::transpose("abc", 1), Some((0..2, "ba".into())));
+        assert_eq!(transposed("abcd", 2).as_deref(), Some("acbd"));
+    }
+
+    #[test]
+    fn swaps_last_two_at_end() {
+        assert_eq!(TextOps::transpose("abc", 3), Some((1..3, "cb".into())));
+    }
+
+    #[test]
+    fn nothing_to_swap() {
+        assert_eq!(TextOps::transpose("", 0), None);
+        assert_eq!(TextOps::transpose("a", 1), None);
+        assert_eq!(TextOps::transpose("abc", 0), None);
+    }
+
+    #[test]
+    fn keeps_graphemes_whole() {
+        assert_eq!(transposed("e\u{301}a", 3).as_deref(), Some("ae\u{301}"));
+        assert_eq!(transposed("🎉a", 5).as_deref(), Some("a🎉"));
+    }
+}
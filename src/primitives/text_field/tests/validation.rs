@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod validation_state {
+    use crate::primitives::text_field::ValidationState;
+
+    #[test]
+    fn is_valid_by_default() {
+        assert_eq!(ValidationState::default(), ValidationState::Valid);
+        assert!(ValidationState::Valid.is_valid());
+        assert_eq!(ValidationState::Valid.message(), None);
+    }
+
+    #[test]
+    fn keeps_the_message_of_an_invalid_value() {
+        let state = ValidationState::invalid("Required");
+        assert!(!state.is_valid());
+        assert_eq!(
+            state.message().map(|message| message.as_ref()),
+            Some("Required")
+        );
+    }
+}
+
+#[cfg(test)]
+mod validate_on {
+    use crate::primitives::text_field::{ValidateOn, ValidationState, text_field};
+    use gpui::{
+        Context, IntoElement, Render, SharedString, TestAppContext, VisualTestContext, Window,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    type Validations = Rc<RefCell<Vec<(SharedString, ValidationState)>>>;
+
+    struct Email {
+        validate_on: ValidateOn,
+        validations: Validations,
+    }
+
+    impl Render for Email {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let validations = self.validations.clone();
+            text_field("email")
+                .validate(|value| match value.contains('@') {
+                    true => ValidationState::Valid,
+                    false => ValidationState::invalid("Enter an email address"),
+                })
+                .validate_on(self.validate_on)
+                .on_validate(move |event, _, _| {
+                    validations
+                        .borrow_mut()
+                        .push((event.value.clone(), event.state.clone()))
+                })
+        }
+    }
+
+    /// Renders the field focused, returning the validation changes it reports.
+    /// The window is activated, since blur listeners only run in an active window.
+    fn email(
+        validate_on: ValidateOn,
+        cx: &mut TestAppContext,
+    ) -> (Validations, &mut VisualTestContext) {
+        cx.update(crate::init);
+        let validations = Validations::default();
+        let (_, cx) = cx.add_window_view(|_, _| Email {
+            validate_on,
+            validations: validations.clone(),
+        });
+        cx.update(|window, _| {
+            window.activate_window();
+            window.focus_next();
+        });
+        cx.run_until_parked();
+        (validations, cx)
+    }
+
+    fn invalid() -> ValidationState {
+        ValidationState::invalid("Enter an email address")
+    }
+
+    #[gpui::test]
+    fn validates_after_every_edit_on_input(cx: &mut TestAppContext) {
+        let (validations, cx) = email(ValidateOn::Input, cx);
+        cx.simulate_input("a");
+        assert_eq!(*validations.borrow(), [("a".into(), invalid())]);
+
+        cx.simulate_input("b");
+        assert_eq!(
+            validations.borrow().len(),
+            1,
+            "unchanged results aren't reported"
+        );
+
+        cx.simulate_input("@");
+        assert_eq!(
+            validations.borrow().last(),
+            Some(&("ab@".into(), ValidationState::Valid))
+        );
+    }
+
+    #[gpui::test]
+    fn validates_when_committed_on_change(cx: &mut TestAppContext) {
+        let (validations, cx) = email(ValidateOn::Change, cx);
+        cx.simulate_input("ab");
+        assert!(validations.borrow().is_empty());
+
+        cx.simulate_keystrokes("enter");
+        assert_eq!(*validations.borrow(), [("ab".into(), invalid())]);
+
+        cx.simulate_input("@");
+        assert_eq!(validations.borrow().len(), 1);
+
+        // Blur listeners run once the next frame is drawn.
+        cx.update(|window, _| {
+            window.blur();
+            window.refresh();
+        });
+        cx.run_until_parked();
+        assert_eq!(
+            validations.borrow().last(),
+            Some(&("ab@".into(), ValidationState::Valid))
+        );
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod value_diff {
+    use crate::primitives::text_field::text_ops::TextOps;
+
+    fn apply(old: &str, new: &str) -> String {
+        let (range, text) = TextOps::diff(old, new);
+        format!("{}{}{}", &old[..range.start], text, &old[range.end..])
+    }
+
+    #[test]
+    fn equal_values() {
+        assert_eq!(TextOps::diff("hello", "hello"), (5..5, ""));
+    }
+
+    #[test]
+    fn insertion() {
+        assert_eq!(TextOps::diff("helo", "hello"), (3..3, "l"));
+        assert_eq!(TextOps::diff("", "hello"), (0..0, "hello"));
+        assert_eq!(TextOps::diff("world", "hello world"), (0..0, "hello "));
+    }
+
+    #[test]
+    fn deletion() {
+        assert_eq!(TextOps::diff("hello world", "hello"), (5..11, ""));
+        assert_eq!(TextOps::diff("hello", ""), (0..5, ""));
+    }
+
+    #[test]
+    fn replacement() {
+        assert_eq!(
+            TextOps::diff("hello world", "hello there"),
+            (6..11, "there")
+        );
+        assert_eq!(apply("abc", "xyz"), "xyz");
+    }
+
+    #[test]
+    fn multibyte_characters() {
+        assert_eq!(TextOps::diff("café", "cafe"), (3..5, "e"));
+        assert_eq!(apply("日本語", "日語"), "日語");
+        assert_eq!(apply("🎉🎉", "🎉a🎉"), "🎉a🎉");
+    }
+}
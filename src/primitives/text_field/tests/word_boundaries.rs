@@ -82,4 +82,14 @@ mod word_boundaries {
         test_boundaries("the quick-brown_fox42 jumps!", 21, 10, 27);
         test_boundaries("the quick-brown_fox42 jumps!", 27, 22, 28);
     }
+
+    #[test]
+    fn extends_selection_by_word() {
+        let text = "file_name_v2-final.txt";
+        let anchor = TextOps::word_range(text, 13);
+        assert_eq!(anchor, 12..18);
+        assert_eq!(TextOps::extend_by_word(text, &anchor, 13), (12..18, false));
+        assert_eq!(TextOps::extend_by_word(text, &anchor, 19), (12..22, false));
+        assert_eq!(TextOps::extend_by_word(text, &anchor, 0), (0..18, true));
+    }
 }
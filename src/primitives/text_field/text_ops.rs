@@ -40,6 +40,25 @@ impl TextOps {
             .unwrap_or(text.len())
     }
 
+    /// Returns the range of the two graphemes around the offset, or of the last two at the
+    /// end of the text, with the text swapping them
+    pub fn transpose(text: &str, offset: usize) -> Option<(Range<usize>, String)> {
+        let offset = if offset >= text.len() {
+            Self::previous_boundary(text, text.len())
+        } else {
+            offset
+        };
+        if offset == 0 {
+            return None;
+        }
+        let start = Self::previous_boundary(text, offset);
+        let end = Self::next_boundary(text, offset);
+        Some((
+            start..end,
+            format!("{}{}", &text[offset..end], &text[start..offset]),
+        ))
+    }
+
     /// Get the previous word boundary from the given offset
     pub fn previous_word_boundary(text: &str, offset: usize) -> usize {
         if offset == 0 {
@@ -140,6 +159,26 @@ impl TextOps {
         }
     }
 
+    /// Get the range of the word around the given offset, as selected by a double-click
+    pub fn word_range(text: &str, offset: usize) -> Range<usize> {
+        Self::previous_word_boundary(text, offset)..Self::next_word_boundary(text, offset)
+    }
+
+    /// Extend a selection of whole words to the word at the given offset, returning the
+    /// selection and whether it is reversed, so that it keeps `anchor` selected
+    pub fn extend_by_word(
+        text: &str,
+        anchor: &Range<usize>,
+        offset: usize,
+    ) -> (Range<usize>, bool) {
+        let word = Self::word_range(text, offset);
+        if word.start < anchor.start {
+            (word.start..anchor.end, true)
+        } else {
+            (anchor.start..anchor.end.max(word.end), false)
+        }
+    }
+
     /// Convert a grapheme offset to a byte offset
     pub fn grapheme_offset_to_byte_offset(text: &str, grapheme_offset: usize) -> usize {
         text.grapheme_indices(true)
@@ -189,4 +228,22 @@ impl TextOps {
     pub fn range_from_utf16(text: &str, range: &Range<usize>) -> Range<usize> {
         Self::offset_from_utf16(text, range.start)..Self::offset_from_utf16(text, range.end)
     }
+
+    /// Returns the smallest byte range of `old` to replace, and the text of `new` to
+    /// replace it with, to turn `old` into `new`
+    pub fn diff<'a>(old: &str, new: &'a str) -> (Range<usize>, &'a str) {
+        let prefix = old
+            .char_indices()
+            .zip(new.chars())
+            .find(|((_, old_char), new_char)| old_char != new_char)
+            .map_or(old.len().min(new.len()), |((ix, _), _)| ix);
+        let suffix = old[prefix..]
+            .chars()
+            .rev()
+            .zip(new[prefix..].chars().rev())
+            .take_while(|(old_char, new_char)| old_char == new_char)
+            .map(|(char, _)| char.len_utf8())
+            .sum::<usize>();
+        (prefix..old.len() - suffix, &new[prefix..new.len() - suffix])
+    }
 }
@@ -0,0 +1,44 @@
+use gpui::SharedString;
+
+/// Whether the value of a text field passed its [`super::TextField::validate`] check.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ValidationState {
+    #[default]
+    Valid,
+    /// Invalid, with a message explaining why.
+    Invalid(SharedString),
+}
+
+impl ValidationState {
+    pub fn invalid(message: impl Into<SharedString>) -> Self {
+        Self::Invalid(message.into())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        *self == Self::Valid
+    }
+
+    pub fn message(&self) -> Option<&SharedString> {
+        match self {
+            Self::Valid => None,
+            Self::Invalid(message) => Some(message),
+        }
+    }
+}
+
+/// When a text field runs its [`super::TextField::validate`] check.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidateOn {
+    /// After every edit.
+    Input,
+    /// When the value is committed, on blur or Enter.
+    #[default]
+    Change,
+}
+
+/// Emitted when the [`ValidationState`] of a text field changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationEvent {
+    pub value: SharedString,
+    pub state: ValidationState,
+}
@@ -0,0 +1,350 @@
+use crate::{primitives::v_flex, scroll_container};
+use gpui::*;
+use std::{cell::RefCell, ops::Range, rc::Rc};
+
+#[cfg(test)]
+mod tests;
+
+const DEFAULT_ITEM_HEIGHT: Pixels = px(24.);
+const DEFAULT_OVERSCAN: usize = 3;
+
+/// How the list sizes its items.
+#[derive(Clone, Copy)]
+enum ItemSizing {
+    Uniform(Pixels),
+    /// Items are laid out at their natural height, unmeasured items count as the estimate.
+    Measured(Pixels),
+}
+
+/// The item at the top of the viewport and how far it is scrolled past its top edge.
+#[derive(Clone, Copy)]
+struct ScrollAnchor {
+    ix: usize,
+    offset: Pixels,
+}
+
+#[derive(Default)]
+struct VirtualListScrollState {
+    base: ScrollHandle,
+    pending_scroll: Option<usize>,
+    visible_range: Range<usize>,
+    heights: Vec<Option<Pixels>>,
+    anchor: Option<ScrollAnchor>,
+    last_scroll_top: Option<Pixels>,
+}
+
+/// Scrolls a [`VirtualList`] and reports which items it rendered.
+#[derive(Clone, Default)]
+pub struct VirtualListScrollHandle(Rc<RefCell<VirtualListScrollState>>);
+
+impl VirtualListScrollHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scrolls just enough to bring the item into view on the next render.
+    ///
+    /// Notify the view that renders the list for the scroll to be applied.
+    pub fn scroll_to_index(&self, ix: usize) {
+        self.0.borrow_mut().pending_scroll = Some(ix);
+    }
+
+    /// Tells the list that the items in `range` were replaced by `count` new items.
+    ///
+    /// Measured heights move along with the items they belong to, and the item at the top
+    /// of the viewport stays in place, so prepending to a feed doesn't scroll it.
+    pub fn splice(&self, range: Range<usize>, count: usize) {
+        let mut state = self.0.borrow_mut();
+
+        let len = state.heights.len();
+        let measured = range.start.min(len)..range.end.min(len);
+        state
+            .heights
+            .splice(measured, std::iter::repeat_n(None, count));
+
+        if let Some(anchor) = state.anchor.as_mut() {
+            if anchor.ix >= range.end {
+                anchor.ix = anchor.ix - range.len() + count;
+            } else if anchor.ix >= range.start {
+                *anchor = ScrollAnchor {
+                    ix: range.start,
+                    offset: px(0.),
+                };
+            }
+        }
+    }
+
+    /// The current scroll offset, which grows more negative as the list scrolls down.
+    pub fn offset(&self) -> Point<Pixels> {
+        self.0.borrow().base.offset()
+    }
+
+    /// The items rendered in the last frame, overscan included.
+    pub fn visible_range(&self) -> Range<usize> {
+        self.0.borrow().visible_range.clone()
+    }
+
+    /// The handle of the scrolling element, to observe the list with utilities such as
+    /// [`crate::components::InfiniteScroll`].
+    pub fn scroll_handle(&self) -> ScrollHandle {
+        self.0.borrow().base.clone()
+    }
+}
+
+/// Item positions for one frame.
+enum ItemLayout {
+    Uniform {
+        height: Pixels,
+        count: usize,
+    },
+    /// The top of every item, followed by the bottom of the last one.
+    Measured(Vec<Pixels>),
+}
+
+impl ItemLayout {
+    fn new(sizing: ItemSizing, count: usize, heights: &[Option<Pixels>]) -> Self {
+        match sizing {
+            ItemSizing::Uniform(height) => Self::Uniform { height, count },
+            ItemSizing::Measured(estimate) => {
+                let mut tops = Vec::with_capacity(count + 1);
+                let mut top = px(0.);
+                tops.push(top);
+                for height in heights {
+                    top += height.unwrap_or(estimate);
+                    tops.push(top);
+                }
+                Self::Measured(tops)
+            }
+        }
+    }
+
+    fn count(&self) -> usize {
+        match self {
+            Self::Uniform { count, .. } => *count,
+            Self::Measured(tops) => tops.len() - 1,
+        }
+    }
+
+    fn top(&self, ix: usize) -> Pixels {
+        match self {
+            Self::Uniform { height, .. } => *height * ix as f32,
+            Self::Measured(tops) => tops[ix],
+        }
+    }
+
+    /// Returns the item at the given distance from the top of the list.
+    fn index_at(&self, y: Pixels) -> usize {
+        let ix = match self {
+            Self::Uniform { height, .. } => (y / *height).floor().max(0.) as usize,
+            Self::Measured(tops) => tops.partition_point(|top| *top <= y).saturating_sub(1),
+        };
+        ix.min(self.count().saturating_sub(1))
+    }
+}
+
+pub fn virtual_list<E: IntoElement>(
+    id: impl Into<ElementId>,
+    item_count: usize,
+    render_items: impl FnOnce(Range<usize>, &mut Window, &mut App) -> Vec<E> + 'static,
+) -> VirtualList {
+    VirtualList {
+        id: id.into(),
+        base: div(),
+        item_count,
+        sizing: ItemSizing::Uniform(DEFAULT_ITEM_HEIGHT),
+        overscan: DEFAULT_OVERSCAN,
+        scroll_handle: None,
+        render_items: Box::new(move |range, window, app| {
+            render_items(range, window, app)
+                .into_iter()
+                .map(IntoElement::into_any_element)
+                .collect()
+        }),
+    }
+}
+
+/// A vertically scrolling list that only renders the items in view.
+///
+/// By default every item has the same height, so the visible range is computed from the
+/// scroll offset without laying out the rest of the collection. Lists whose items vary in
+/// height, like chat logs, use [`VirtualList::estimated_item_height`] instead: items are
+/// measured as they are rendered and the estimate stands in for the others.
+///
+/// The builder receives the range of items to render and returns them in order.
+///
+/// # Examples
+///
+/// ```rust
+/// virtual_list("log", self.lines.len(), {
+///     let lines = self.lines.clone();
+///     move |range, _, _| range.map(|ix| span(lines[ix].clone())).collect()
+/// })
+/// .item_height(px(20.))
+/// .track_scroll(&self.scroll_handle)
+/// .size_full()
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(IntoElement)]
+pub struct VirtualList {
+    id: ElementId,
+    base: Div,
+    item_count: usize,
+    sizing: ItemSizing,
+    overscan: usize,
+    scroll_handle: Option<VirtualListScrollHandle>,
+    render_items: Box<dyn FnOnce(Range<usize>, &mut Window, &mut App) -> Vec<AnyElement>>,
+}
+
+impl VirtualList {
+    /// The height of every item, 24px by default.
+    pub fn item_height(mut self, height: Pixels) -> Self {
+        self.sizing = ItemSizing::Uniform(height);
+        self
+    }
+
+    /// Lays out items at their natural height, assuming the estimate until they are
+    /// measured.
+    ///
+    /// Scrolling stays anchored to the item at the top of the viewport while measurements
+    /// come in. Use [`VirtualListScrollHandle::splice`] to report inserted and removed
+    /// items so their measurements and the anchor follow them.
+    pub fn estimated_item_height(mut self, height: Pixels) -> Self {
+        self.sizing = ItemSizing::Measured(height);
+        self
+    }
+
+    /// How many items to render beyond each edge of the viewport, 3 by default.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    pub fn track_scroll(mut self, handle: &VirtualListScrollHandle) -> Self {
+        self.scroll_handle = Some(handle.clone());
+        self
+    }
+}
+
+impl Styled for VirtualList {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for VirtualList {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let handle = self.scroll_handle.unwrap_or_else(|| {
+            window
+                .use_keyed_state(self.id.clone(), app, |_, _| VirtualListScrollHandle::new())
+                .read(app)
+                .clone()
+        });
+
+        let mut state = handle.0.borrow_mut();
+        let base = state.base.clone();
+
+        // Before the first layout, assume the list may fill the window.
+        let viewport_height = match base.bounds().size.height {
+            height if height > px(0.) => height,
+            _ => window.viewport_size().height,
+        };
+
+        if let ItemSizing::Measured(_) = self.sizing {
+            state.heights.resize(self.item_count, None);
+        }
+        let layout = ItemLayout::new(self.sizing, self.item_count, &state.heights);
+
+        let mut scroll_top = -base.offset().y;
+        // Unless the user scrolled, keep the anchor in place while heights above it are
+        // corrected or items are inserted before it.
+        if state.last_scroll_top == Some(scroll_top)
+            && let Some(anchor) = state.anchor
+            && anchor.ix < self.item_count
+        {
+            scroll_top = layout.top(anchor.ix) + anchor.offset;
+        }
+
+        if let Some(ix) = state.pending_scroll.take()
+            && ix < self.item_count
+        {
+            let item_top = layout.top(ix);
+            let item_bottom = layout.top(ix + 1);
+            if item_top < scroll_top {
+                scroll_top = item_top;
+            } else if item_bottom > scroll_top + viewport_height {
+                scroll_top = item_bottom - viewport_height;
+            }
+        }
+
+        scroll_top = scroll_top.max(px(0.));
+        if scroll_top != -base.offset().y {
+            base.set_offset(point(px(0.), -scroll_top));
+        }
+
+        let (first, last) = if self.item_count == 0 {
+            state.anchor = None;
+            (0, 0)
+        } else {
+            let top_ix = layout.index_at(scroll_top);
+            let bottom_ix = layout.index_at(scroll_top + viewport_height);
+            state.anchor = Some(ScrollAnchor {
+                ix: top_ix,
+                offset: scroll_top - layout.top(top_ix),
+            });
+            (
+                top_ix.saturating_sub(self.overscan),
+                (bottom_ix + 1 + self.overscan).min(self.item_count),
+            )
+        };
+        state.last_scroll_top = Some(scroll_top);
+        state.visible_range = first..last;
+        drop(state);
+
+        let items = (self.render_items)(first..last, window, app);
+        let items = items.into_iter().zip(first..last).map(|(item, ix)| {
+            let row = div().relative().w_full().child(item);
+            match self.sizing {
+                ItemSizing::Uniform(height) => row.h(height),
+                ItemSizing::Measured(_) => row.child(
+                    canvas(
+                        {
+                            let handle = handle.clone();
+                            move |bounds, window, _| {
+                                let mut state = handle.0.borrow_mut();
+                                if let Some(height) = state.heights.get_mut(ix)
+                                    && *height != Some(bounds.size.height)
+                                {
+                                    *height = Some(bounds.size.height);
+                                    window.request_animation_frame();
+                                }
+                            }
+                        },
+                        |_, _, _, _| {},
+                    )
+                    .absolute()
+                    .size_full(),
+                ),
+            }
+        });
+
+        self.base
+            .id(self.id)
+            .overflow_y_scroll()
+            .track_scroll(&base)
+            .child(
+                div()
+                    .relative()
+                    .w_full()
+                    .h(layout.top(layout.count()))
+                    .child(
+                        v_flex()
+                            .absolute()
+                            .left_0()
+                            .right_0()
+                            .top(layout.top(first))
+                            .children(items),
+                    ),
+            )
+            .child(scroll_container(&base))
+    }
+}
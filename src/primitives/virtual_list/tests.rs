@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod splice {
+    use crate::primitives::virtual_list::{ScrollAnchor, VirtualListScrollHandle};
+    use gpui::{Pixels, px};
+
+    /// A handle whose first four items were measured, scrolled to the given item.
+    fn handle(anchor: usize) -> VirtualListScrollHandle {
+        let handle = VirtualListScrollHandle::new();
+        {
+            let mut state = handle.0.borrow_mut();
+            state.heights = vec![Some(px(10.)), Some(px(20.)), Some(px(30.)), Some(px(40.))];
+            state.anchor = Some(ScrollAnchor {
+                ix: anchor,
+                offset: px(5.),
+            });
+        }
+        handle
+    }
+
+    fn heights(handle: &VirtualListScrollHandle) -> Vec<Option<Pixels>> {
+        handle.0.borrow().heights.clone()
+    }
+
+    fn anchor(handle: &VirtualListScrollHandle) -> Option<(usize, Pixels)> {
+        let anchor = handle.0.borrow().anchor?;
+        Some((anchor.ix, anchor.offset))
+    }
+
+    #[test]
+    fn keeps_the_anchor_when_prepending() {
+        let handle = handle(2);
+        handle.splice(0..0, 2);
+        assert_eq!(
+            heights(&handle),
+            [
+                None,
+                None,
+                Some(px(10.)),
+                Some(px(20.)),
+                Some(px(30.)),
+                Some(px(40.))
+            ]
+        );
+        assert_eq!(anchor(&handle), Some((4, px(5.))));
+    }
+
+    #[test]
+    fn keeps_the_anchor_when_appending() {
+        let handle = handle(1);
+        handle.splice(4..4, 1);
+        assert_eq!(heights(&handle).len(), 5);
+        assert_eq!(anchor(&handle), Some((1, px(5.))));
+    }
+
+    #[test]
+    fn shifts_the_anchor_when_removing_items_above_it() {
+        let handle = handle(3);
+        handle.splice(0..2, 0);
+        assert_eq!(heights(&handle), [Some(px(30.)), Some(px(40.))]);
+        assert_eq!(anchor(&handle), Some((1, px(5.))));
+    }
+
+    #[test]
+    fn moves_the_anchor_to_the_start_of_a_replaced_range() {
+        let handle = handle(2);
+        handle.splice(1..3, 1);
+        assert_eq!(heights(&handle), [Some(px(10.)), None, Some(px(40.))]);
+        assert_eq!(anchor(&handle), Some((1, px(0.))));
+    }
+
+    #[test]
+    fn ignores_items_that_were_never_measured() {
+        let handle = handle(0);
+        handle.splice(3..10, 2);
+        assert_eq!(
+            heights(&handle),
+            [Some(px(10.)), Some(px(20.)), Some(px(30.)), None, None]
+        );
+        assert_eq!(anchor(&handle), Some((0, px(5.))));
+    }
+}
+
+#[cfg(test)]
+mod item_layout {
+    use crate::primitives::virtual_list::{ItemLayout, ItemSizing};
+    use gpui::px;
+
+    #[test]
+    fn stacks_uniform_items() {
+        let layout = ItemLayout::new(ItemSizing::Uniform(px(20.)), 5, &[]);
+        assert_eq!(layout.count(), 5);
+        assert_eq!(layout.top(3), px(60.));
+        assert_eq!(layout.index_at(px(59.)), 2);
+        assert_eq!(layout.index_at(px(60.)), 3);
+    }
+
+    #[test]
+    fn stacks_measured_items_with_the_estimate_for_the_others() {
+        let heights = [Some(px(10.)), None, Some(px(30.))];
+        let layout = ItemLayout::new(ItemSizing::Measured(px(20.)), 3, &heights);
+        assert_eq!(layout.count(), 3);
+        assert_eq!(layout.top(1), px(10.));
+        assert_eq!(layout.top(2), px(30.));
+        assert_eq!(layout.index_at(px(29.)), 1);
+        assert_eq!(layout.index_at(px(30.)), 2);
+    }
+
+    #[test]
+    fn clamps_positions_to_the_items() {
+        let layout = ItemLayout::new(ItemSizing::Uniform(px(20.)), 3, &[]);
+        assert_eq!(layout.index_at(px(-10.)), 0);
+        assert_eq!(layout.index_at(px(1000.)), 2);
+    }
+}
@@ -0,0 +1,159 @@
+use gpui::{
+    Along, AnyWindowHandle, App, Axis, Bounds, ElementId, FocusHandle, Global, IntoElement, Pixels,
+    ScrollHandle, Styled, Window, canvas, px,
+};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// An element [`scroll_into_view`] can reveal, identified by its focus handle or an id
+/// unique within the window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScrollTarget(ElementId);
+
+impl From<&FocusHandle> for ScrollTarget {
+    fn from(handle: &FocusHandle) -> Self {
+        Self(handle.into())
+    }
+}
+
+impl From<ElementId> for ScrollTarget {
+    fn from(id: ElementId) -> Self {
+        Self(id)
+    }
+}
+
+/// The target each window is scrolling to, with its bounds once it rendered.
+#[derive(Default)]
+struct PendingScrolls(HashMap<AnyWindowHandle, (ScrollTarget, Option<Bounds<Pixels>>)>);
+
+impl Global for PendingScrolls {}
+
+/// Scrolls the containers around an element so that it becomes visible, the next time
+/// the window is drawn.
+///
+/// The element must render a [`scroll_target`], and only containers rendering a
+/// [`scroll_container`] are scrolled, innermost first. Each container scrolls as little
+/// as needed. Keyboard navigation in the crate's components calls this for the item it
+/// moves to.
+pub fn scroll_into_view(target: impl Into<ScrollTarget>, window: &mut Window, app: &mut App) {
+    app.default_global::<PendingScrolls>()
+        .0
+        .insert(window.window_handle(), (target.into(), None));
+    window.refresh();
+}
+
+/// Marks the element it is a child of as a target of [`scroll_into_view`].
+///
+/// The element must be positioned, relatively or absolutely, as the marker fills it.
+pub fn scroll_target(target: impl Into<ScrollTarget>) -> impl IntoElement {
+    let target = target.into();
+    canvas(
+        move |bounds, window, app| {
+            let handle = window.window_handle();
+            let Some(pending) = app
+                .try_global::<PendingScrolls>()
+                .and_then(|pending| pending.0.get(&handle))
+            else {
+                return;
+            };
+            if pending.0 != target {
+                return;
+            }
+
+            app.global_mut::<PendingScrolls>()
+                .0
+                .get_mut(&handle)
+                .unwrap()
+                .1 = Some(bounds);
+            // The containers scroll during this frame, so the target is revealed once
+            // the next one is drawn.
+            window.on_next_frame(move |window, app| {
+                let pending = &mut app.global_mut::<PendingScrolls>().0;
+                if pending
+                    .get(&handle)
+                    .is_some_and(|(pending, _)| *pending == target)
+                {
+                    pending.remove(&handle);
+                }
+                window.refresh();
+            });
+        },
+        |_, _, _, _| {},
+    )
+    .absolute()
+    .top_0()
+    .left_0()
+    .size_full()
+}
+
+/// Lets [`scroll_into_view`] scroll the element it is a child of, which must track the
+/// scroll handle.
+///
+/// Render it after the other children, as it only sees targets rendered before it.
+pub fn scroll_container(scroll_handle: &ScrollHandle) -> impl IntoElement {
+    let scroll_handle = scroll_handle.clone();
+    canvas(
+        move |_, window, app| {
+            let handle = window.window_handle();
+            let Some(pending) = app.default_global::<PendingScrolls>().0.get_mut(&handle) else {
+                return;
+            };
+            let Some(target) = &mut pending.1 else {
+                return;
+            };
+
+            let viewport = scroll_handle.bounds();
+            let offset = scroll_handle.offset();
+            let max_offset = scroll_handle.max_offset();
+            let content = Bounds::new(viewport.origin + offset, viewport.size + max_offset);
+            if !content.intersects(target) {
+                return;
+            }
+
+            let mut scrolled = offset;
+            for axis in [Axis::Horizontal, Axis::Vertical] {
+                let start = viewport.origin.along(axis);
+                let end = start + viewport.size.along(axis);
+                let delta = reveal_delta(
+                    start,
+                    end,
+                    target.origin.along(axis),
+                    target.origin.along(axis) + target.size.along(axis),
+                );
+                let value = (offset.along(axis) + delta).clamp(-max_offset.along(axis), px(0.));
+                scrolled = scrolled.apply_along(axis, |_| value);
+            }
+            if scrolled == offset {
+                return;
+            }
+
+            // Outer containers reveal the target where this one moves it.
+            target.origin += scrolled - offset;
+            scroll_handle.set_offset(scrolled);
+        },
+        |_, _, _, _| {},
+    )
+    .absolute()
+    .top_0()
+    .left_0()
+    .size_full()
+}
+
+/// Returns how far to move content so that the span from `start` to `end` lies within the
+/// viewport, aligning its start when it doesn't fit.
+fn reveal_delta(
+    viewport_start: Pixels,
+    viewport_end: Pixels,
+    start: Pixels,
+    end: Pixels,
+) -> Pixels {
+    if start < viewport_start {
+        viewport_start - start
+    } else if end > viewport_end {
+        (viewport_end - end).max(viewport_start - start)
+    } else {
+        px(0.)
+    }
+}
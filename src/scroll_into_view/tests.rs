@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod reveal_delta {
+    use crate::scroll_into_view::reveal_delta;
+    use gpui::px;
+
+    #[test]
+    fn leaves_a_visible_span_in_place() {
+        assert_eq!(reveal_delta(px(0.), px(100.), px(20.), px(40.)), px(0.));
+        assert_eq!(reveal_delta(px(0.), px(100.), px(0.), px(100.)), px(0.));
+    }
+
+    #[test]
+    fn aligns_a_span_above_the_viewport_with_its_start() {
+        assert_eq!(reveal_delta(px(0.), px(100.), px(-30.), px(-10.)), px(30.));
+        assert_eq!(reveal_delta(px(0.), px(100.), px(-10.), px(20.)), px(10.));
+    }
+
+    #[test]
+    fn aligns_a_span_below_the_viewport_with_its_end() {
+        assert_eq!(reveal_delta(px(0.), px(100.), px(120.), px(140.)), px(-40.));
+        assert_eq!(reveal_delta(px(0.), px(100.), px(90.), px(110.)), px(-10.));
+    }
+
+    #[test]
+    fn aligns_a_span_larger_than_the_viewport_with_its_start() {
+        assert_eq!(reveal_delta(px(0.), px(100.), px(50.), px(250.)), px(-50.));
+        assert_eq!(reveal_delta(px(0.), px(100.), px(-50.), px(150.)), px(50.));
+    }
+
+    #[test]
+    fn measures_from_an_offset_viewport() {
+        assert_eq!(
+            reveal_delta(px(200.), px(300.), px(310.), px(330.)),
+            px(-30.)
+        );
+        assert_eq!(
+            reveal_delta(px(200.), px(300.), px(150.), px(170.)),
+            px(50.)
+        );
+    }
+}
+
+#[cfg(test)]
+mod scroll_into_view {
+    use crate::{scroll_container, scroll_into_view, scroll_target};
+    use gpui::{
+        Context, ElementId, InteractiveElement, IntoElement, ParentElement, Render, ScrollHandle,
+        StatefulInteractiveElement, Styled, TestAppContext, VisualTestContext, Window, div, px,
+    };
+
+    const ROW_HEIGHT: f32 = 20.;
+    const ROWS: usize = 20;
+
+    struct Rows {
+        scroll_handle: ScrollHandle,
+    }
+
+    impl Render for Rows {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .id("rows")
+                .h(px(ROW_HEIGHT * 2.5))
+                .overflow_y_scroll()
+                .track_scroll(&self.scroll_handle)
+                .children((0..ROWS).map(|ix| {
+                    div()
+                        .relative()
+                        .h(px(ROW_HEIGHT))
+                        .child(format!("Row {ix}"))
+                        .child(scroll_target(ElementId::from(("row", ix))))
+                }))
+                .child(scroll_container(&self.scroll_handle))
+        }
+    }
+
+    fn rows(cx: &mut TestAppContext) -> (ScrollHandle, &mut VisualTestContext) {
+        let scroll_handle = ScrollHandle::new();
+        let (_, cx) = cx.add_window_view(|_, _| Rows {
+            scroll_handle: scroll_handle.clone(),
+        });
+        cx.run_until_parked();
+        (scroll_handle, cx)
+    }
+
+    /// Requests a scroll to the row, then draws the frames that measure and reveal it.
+    fn reveal(ix: usize, cx: &mut VisualTestContext) {
+        cx.update(|window, app| scroll_into_view(ElementId::from(("row", ix)), window, app));
+        for _ in 0..2 {
+            cx.update(|window, _| window.refresh());
+            cx.run_until_parked();
+        }
+    }
+
+    #[gpui::test]
+    fn scrolls_just_enough_to_show_a_row_below(cx: &mut TestAppContext) {
+        let (scroll_handle, cx) = rows(cx);
+        reveal(ROWS - 1, cx);
+        assert_eq!(
+            scroll_handle.offset().y,
+            px(-ROW_HEIGHT * (ROWS as f32 - 2.5))
+        );
+    }
+
+    #[gpui::test]
+    fn scrolls_back_to_the_start_of_a_row_above(cx: &mut TestAppContext) {
+        let (scroll_handle, cx) = rows(cx);
+        reveal(ROWS - 1, cx);
+        reveal(3, cx);
+        assert_eq!(scroll_handle.offset().y, px(-ROW_HEIGHT * 3.));
+    }
+
+    #[gpui::test]
+    fn leaves_a_visible_row_in_place(cx: &mut TestAppContext) {
+        let (scroll_handle, cx) = rows(cx);
+        reveal(1, cx);
+        assert_eq!(scroll_handle.offset().y, px(0.));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::has_modal_overlay;
+use gpui::{
+    AnyElement, AnyWindowHandle, App, Div, Global, IntoElement, MouseMoveEvent, ParentElement,
+    RenderOnce, ScrollWheelEvent, Styled, Window, canvas, div, prelude::FluentBuilder,
+};
+use std::collections::HashMap;
+
+/// Number of active scroll locks per window.
+#[derive(Default)]
+struct ScrollLocks(HashMap<AnyWindowHandle, usize>);
+
+impl Global for ScrollLocks {}
+
+/// Prevents the content of the current window from scrolling until a matching
+/// [`unlock_scroll`] call.
+///
+/// Locks are counted, so nested dialogs can each take and release their own lock.
+/// Modal overlays lock scrolling automatically while they are open.
+pub fn lock_scroll(window: &mut Window, app: &mut App) {
+    *app.default_global::<ScrollLocks>()
+        .0
+        .entry(window.window_handle())
+        .or_default() += 1;
+    window.refresh();
+}
+
+/// Releases a lock taken with [`lock_scroll`].
+pub fn unlock_scroll(window: &mut Window, app: &mut App) {
+    let handle = window.window_handle();
+    let locks = app.default_global::<ScrollLocks>();
+    if let Some(count) = locks.0.get_mut(&handle) {
+        *count -= 1;
+        if *count == 0 {
+            locks.0.remove(&handle);
+        }
+        window.refresh();
+    }
+}
+
+/// Returns whether scrolling is locked in the current window.
+pub fn is_scroll_locked(window: &Window, app: &App) -> bool {
+    app.try_global::<ScrollLocks>()
+        .is_some_and(|locks| locks.0.contains_key(&window.window_handle()))
+        || has_modal_overlay(window, app)
+}
+
+pub fn scroll_lock_area() -> ScrollLockArea {
+    ScrollLockArea {
+        base: div(),
+        children: Vec::new(),
+        locked: None,
+    }
+}
+
+/// A container that swallows scroll-wheel and drag events over its content while
+/// scrolling is locked.
+///
+/// [`LapislazuliProvider`](crate::LapislazuliProvider) wraps the application view in
+/// one, so overlays rendered above it keep scrolling normally.
+#[derive(IntoElement)]
+pub struct ScrollLockArea {
+    base: Div,
+    children: Vec<AnyElement>,
+    locked: Option<bool>,
+}
+
+impl ScrollLockArea {
+    /// Overrides the window's lock state for this area.
+    pub fn locked(mut self, locked: bool) -> Self {
+        self.locked = Some(locked);
+        self
+    }
+}
+
+impl ParentElement for ScrollLockArea {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for ScrollLockArea {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for ScrollLockArea {
+    fn render(self, window: &mut Window, app: &mut App) -> impl IntoElement {
+        let locked = self.locked.unwrap_or_else(|| is_scroll_locked(window, app));
+
+        self.base
+            .relative()
+            .when(locked, |this| {
+                this.child(
+                    canvas(
+                        |_, _, _| {},
+                        |bounds, _, window, _| {
+                            // Capture listeners run before any scroll handler in the
+                            // content, which only listen during the bubble phase.
+                            window.on_mouse_event(
+                                move |event: &ScrollWheelEvent, phase, _, app| {
+                                    if phase.capture() && bounds.contains(&event.position) {
+                                        app.stop_propagation();
+                                    }
+                                },
+                            );
+                            window.on_mouse_event(move |event: &MouseMoveEvent, phase, _, app| {
+                                if phase.capture()
+                                    && event.dragging()
+                                    && bounds.contains(&event.position)
+                                {
+                                    app.stop_propagation();
+                                }
+                            });
+                        },
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+            })
+            .children(self.children)
+    }
+}
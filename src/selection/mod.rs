@@ -0,0 +1,87 @@
+use gpui::{Modifiers, SharedString};
+
+#[cfg(test)]
+mod tests;
+
+/// Whether a collection allows one or several selected items.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    #[default]
+    Single,
+    Multiple,
+}
+
+/// Returns the item a step away from `from` in the given direction that `enabled`
+/// accepts, or the first or last such item when nothing is current.
+///
+/// Arrow keys stop at the ends of lists, trees and tables, but go around in menus and
+/// comboboxes, which set `wrap`.
+pub(crate) fn step(
+    len: usize,
+    from: Option<usize>,
+    forward: bool,
+    wrap: bool,
+    enabled: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    let from = from.filter(|&from| from < len);
+    (1..=len)
+        .map(|offset| match (from, forward) {
+            (None, true) => offset - 1,
+            (None, false) => len - offset,
+            (Some(from), true) => (from + offset) % len,
+            (Some(from), false) => (from + len - offset) % len,
+        })
+        .take_while(|&ix| match from {
+            Some(from) if !wrap => (forward && ix > from) || (!forward && ix < from),
+            _ => true,
+        })
+        .find(|&ix| enabled(ix))
+}
+
+/// Returns the selection after the user picks `key`, following desktop conventions in
+/// [`SelectionMode::Multiple`]: the secondary modifier toggles the item and Shift
+/// extends the selection from `anchor` over `order`.
+///
+/// Also returns the anchor for the next range selection.
+pub(crate) fn pick(
+    mode: SelectionMode,
+    selected: &[SharedString],
+    anchor: Option<&SharedString>,
+    key: &SharedString,
+    order: &[SharedString],
+    modifiers: &Modifiers,
+) -> (Vec<SharedString>, SharedString) {
+    if mode == SelectionMode::Single {
+        return (vec![key.clone()], key.clone());
+    }
+
+    if modifiers.shift
+        && let Some(anchor) = anchor
+        && let Some(from) = order.iter().position(|item| item == anchor)
+        && let Some(to) = order.iter().position(|item| item == key)
+    {
+        let range = order[from.min(to)..=from.max(to)].to_vec();
+        return (range, anchor.clone());
+    }
+
+    if modifiers.secondary() {
+        return (toggle(selected, key), key.clone());
+    }
+
+    (vec![key.clone()], key.clone())
+}
+
+/// Adds the key to the selection, or removes it if it is already selected.
+pub(crate) fn toggle(selected: &[SharedString], key: &SharedString) -> Vec<SharedString> {
+    if selected.contains(key) {
+        selected
+            .iter()
+            .filter(|item| *item != key)
+            .cloned()
+            .collect()
+    } else {
+        let mut selected = selected.to_vec();
+        selected.push(key.clone());
+        selected
+    }
+}
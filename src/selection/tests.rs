@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod step {
+    use crate::selection::step;
+
+    #[test]
+    fn starts_from_the_ends_without_a_current_item() {
+        assert_eq!(step(4, None, true, false, |_| true), Some(0));
+        assert_eq!(step(4, None, false, false, |_| true), Some(3));
+    }
+
+    #[test]
+    fn skips_disabled_items() {
+        let enabled = |ix| ix != 1 && ix != 2;
+        assert_eq!(step(4, Some(0), true, false, enabled), Some(3));
+        assert_eq!(step(4, Some(3), false, false, enabled), Some(0));
+        assert_eq!(step(4, None, true, false, |ix| ix != 0), Some(1));
+    }
+
+    #[test]
+    fn stops_at_the_ends_without_wrapping() {
+        assert_eq!(step(4, Some(3), true, false, |_| true), None);
+        assert_eq!(step(4, Some(0), false, false, |_| true), None);
+        assert_eq!(step(4, Some(2), true, false, |ix| ix != 3), None);
+    }
+
+    #[test]
+    fn goes_around_when_wrapping() {
+        assert_eq!(step(4, Some(3), true, true, |_| true), Some(0));
+        assert_eq!(step(4, Some(0), false, true, |_| true), Some(3));
+        assert_eq!(step(4, Some(2), true, true, |ix| ix != 3), Some(0));
+    }
+
+    #[test]
+    fn returns_the_current_item_when_it_is_the_only_enabled_one() {
+        assert_eq!(step(3, Some(1), true, true, |ix| ix == 1), Some(1));
+        assert_eq!(step(3, Some(1), true, false, |ix| ix == 1), None);
+    }
+
+    #[test]
+    fn finds_nothing_in_an_empty_or_disabled_list() {
+        assert_eq!(step(0, None, true, true, |_| true), None);
+        assert_eq!(step(3, None, false, true, |_| false), None);
+    }
+
+    #[test]
+    fn ignores_a_current_item_past_the_end() {
+        assert_eq!(step(2, Some(5), true, false, |_| true), Some(0));
+    }
+}
+
+#[cfg(test)]
+mod pick {
+    use crate::selection::{SelectionMode, pick, toggle};
+    use gpui::{Modifiers, SharedString};
+
+    fn keys(keys: &[&'static str]) -> Vec<SharedString> {
+        keys.iter().copied().map(SharedString::from).collect()
+    }
+
+    fn order() -> Vec<SharedString> {
+        keys(&["a", "b", "c", "d"])
+    }
+
+    #[test]
+    fn replaces_the_selection_in_single_mode() {
+        let (selected, anchor) = pick(
+            SelectionMode::Single,
+            &keys(&["a"]),
+            Some(&"a".into()),
+            &"c".into(),
+            &order(),
+            &Modifiers::shift(),
+        );
+        assert_eq!(selected, keys(&["c"]));
+        assert_eq!(anchor, "c");
+    }
+
+    #[test]
+    fn replaces_the_selection_without_modifiers() {
+        let (selected, anchor) = pick(
+            SelectionMode::Multiple,
+            &keys(&["a", "b"]),
+            Some(&"a".into()),
+            &"d".into(),
+            &order(),
+            &Modifiers::none(),
+        );
+        assert_eq!(selected, keys(&["d"]));
+        assert_eq!(anchor, "d");
+    }
+
+    #[test]
+    fn toggles_with_the_secondary_modifier() {
+        let secondary = Modifiers::secondary_key();
+        let (selected, anchor) = pick(
+            SelectionMode::Multiple,
+            &keys(&["a"]),
+            None,
+            &"c".into(),
+            &order(),
+            &secondary,
+        );
+        assert_eq!(selected, keys(&["a", "c"]));
+        assert_eq!(anchor, "c");
+
+        let (selected, _) = pick(
+            SelectionMode::Multiple,
+            &selected,
+            Some(&anchor),
+            &"a".into(),
+            &order(),
+            &secondary,
+        );
+        assert_eq!(selected, keys(&["c"]));
+    }
+
+    #[test]
+    fn extends_from_the_anchor_with_shift() {
+        let (selected, anchor) = pick(
+            SelectionMode::Multiple,
+            &keys(&["c"]),
+            Some(&"c".into()),
+            &"a".into(),
+            &order(),
+            &Modifiers::shift(),
+        );
+        assert_eq!(selected, keys(&["a", "b", "c"]));
+        assert_eq!(anchor, "c", "the anchor stays for the next range");
+    }
+
+    #[test]
+    fn replaces_the_selection_with_shift_but_no_anchor() {
+        let (selected, anchor) = pick(
+            SelectionMode::Multiple,
+            &keys(&["a"]),
+            None,
+            &"b".into(),
+            &order(),
+            &Modifiers::shift(),
+        );
+        assert_eq!(selected, keys(&["b"]));
+        assert_eq!(anchor, "b");
+    }
+
+    #[test]
+    fn toggle_adds_or_removes_the_key() {
+        assert_eq!(toggle(&keys(&["a"]), &"b".into()), keys(&["a", "b"]));
+        assert_eq!(toggle(&keys(&["a", "b"]), &"a".into()), keys(&["b"]));
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{Appearance, Density};
+use gpui::{
+    AnyElement, App, Global, Hsla, IntoElement, Pixels, Rems, RenderOnce, Window, px, rems, rgb,
+    rgba,
+};
+
+/// Design tokens shared by an application's components.
+///
+/// Installed as a global by [`crate::LapislazuliProvider`], or with
+/// [`crate::LapislazuliProvider::with_theme`] for a custom one, and readable with
+/// `cx.global::<Theme>()` or the [`themed`] helper.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    pub colors: ThemeColors,
+    pub radii: ThemeRadii,
+    pub spacing: ThemeSpacing,
+    pub font_sizes: ThemeFontSizes,
+    pub control_heights: ThemeControlHeights,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeColors {
+    pub background: Hsla,
+    pub foreground: Hsla,
+    pub surface: Hsla,
+    pub muted: Hsla,
+    pub muted_foreground: Hsla,
+    pub primary: Hsla,
+    pub primary_foreground: Hsla,
+    pub secondary: Hsla,
+    pub secondary_foreground: Hsla,
+    pub accent: Hsla,
+    pub destructive: Hsla,
+    pub destructive_foreground: Hsla,
+    pub success: Hsla,
+    pub warning: Hsla,
+    pub border: Hsla,
+    pub ring: Hsla,
+    pub selection: Hsla,
+    pub placeholder: Hsla,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeRadii {
+    pub sm: Pixels,
+    pub md: Pixels,
+    pub lg: Pixels,
+    pub full: Pixels,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeSpacing {
+    pub xs: Rems,
+    pub sm: Rems,
+    pub md: Rems,
+    pub lg: Rems,
+    pub xl: Rems,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeFontSizes {
+    pub xs: Rems,
+    pub sm: Rems,
+    pub md: Rems,
+    pub lg: Rems,
+    pub xl: Rems,
+}
+
+impl Global for Theme {}
+
+impl Default for ThemeRadii {
+    fn default() -> Self {
+        Self {
+            sm: px(2.),
+            md: px(6.),
+            lg: px(8.),
+            full: px(9999.),
+        }
+    }
+}
+
+impl Default for ThemeSpacing {
+    fn default() -> Self {
+        Self {
+            xs: rems(0.25),
+            sm: rems(0.5),
+            md: rems(1.0),
+            lg: rems(1.5),
+            xl: rems(2.0),
+        }
+    }
+}
+
+impl ThemeSpacing {
+    /// Returns the spacing scaled for the given [`Density`].
+    pub fn with_density(&self, density: Density) -> Self {
+        let scale = |value: Rems| rems(value.0 * density.scale());
+        Self {
+            xs: scale(self.xs),
+            sm: scale(self.sm),
+            md: scale(self.md),
+            lg: scale(self.lg),
+            xl: scale(self.xl),
+        }
+    }
+}
+
+/// Heights of single-line controls for each [`ControlSize`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeControlHeights {
+    pub xs: Rems,
+    pub sm: Rems,
+    pub md: Rems,
+    pub lg: Rems,
+}
+
+impl ThemeControlHeights {
+    /// Returns the heights scaled for the given [`Density`].
+    pub fn with_density(&self, density: Density) -> Self {
+        let scale = |value: Rems| rems(value.0 * density.scale());
+        Self {
+            xs: scale(self.xs),
+            sm: scale(self.sm),
+            md: scale(self.md),
+            lg: scale(self.lg),
+        }
+    }
+}
+
+impl Default for ThemeControlHeights {
+    fn default() -> Self {
+        Self {
+            xs: rems(1.5),
+            sm: rems(2.0),
+            md: rems(2.5),
+            lg: rems(3.0),
+        }
+    }
+}
+
+impl Default for ThemeFontSizes {
+    fn default() -> Self {
+        Self {
+            xs: rems(0.75),
+            sm: rems(0.875),
+            md: rems(1.0),
+            lg: rems(1.25),
+            xl: rems(1.5),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl Theme {
+    pub fn light() -> Self {
+        Self {
+            colors: ThemeColors {
+                background: rgb(0xf8fafc).into(),
+                foreground: rgb(0x1e293b).into(),
+                surface: rgb(0xffffff).into(),
+                muted: rgb(0xf1f5f9).into(),
+                muted_foreground: rgb(0x64748b).into(),
+                primary: rgb(0x3b82f6).into(),
+                primary_foreground: rgb(0xffffff).into(),
+                secondary: rgb(0x64748b).into(),
+                secondary_foreground: rgb(0xffffff).into(),
+                accent: rgb(0x6366f1).into(),
+                destructive: rgb(0xef4444).into(),
+                destructive_foreground: rgb(0xffffff).into(),
+                success: rgb(0x10b981).into(),
+                warning: rgb(0xf59e0b).into(),
+                border: rgb(0xe2e8f0).into(),
+                ring: rgb(0x000000).into(),
+                selection: rgba(0x3390ff80).into(),
+                placeholder: rgb(0x9ca3af).into(),
+            },
+            radii: ThemeRadii::default(),
+            spacing: ThemeSpacing::default(),
+            font_sizes: ThemeFontSizes::default(),
+            control_heights: ThemeControlHeights::default(),
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            colors: ThemeColors {
+                background: rgb(0x0f172a).into(),
+                foreground: rgb(0xf1f5f9).into(),
+                surface: rgb(0x1e293b).into(),
+                muted: rgb(0x334155).into(),
+                muted_foreground: rgb(0x94a3b8).into(),
+                primary: rgb(0x60a5fa).into(),
+                primary_foreground: rgb(0x0f172a).into(),
+                secondary: rgb(0x475569).into(),
+                secondary_foreground: rgb(0xf1f5f9).into(),
+                accent: rgb(0x818cf8).into(),
+                destructive: rgb(0xf87171).into(),
+                destructive_foreground: rgb(0x0f172a).into(),
+                success: rgb(0x34d399).into(),
+                warning: rgb(0xfbbf24).into(),
+                border: rgb(0x334155).into(),
+                ring: rgb(0xffffff).into(),
+                selection: rgba(0x3390ff80).into(),
+                placeholder: rgb(0x64748b).into(),
+            },
+            radii: ThemeRadii::default(),
+            spacing: ThemeSpacing::default(),
+            font_sizes: ThemeFontSizes::default(),
+            control_heights: ThemeControlHeights::default(),
+        }
+    }
+
+    /// Returns the theme with its spacing and control heights scaled for the given
+    /// [`Density`]. Font sizes are kept, so text stays legible in compact layouts.
+    pub fn with_density(&self, density: Density) -> Self {
+        Self {
+            spacing: self.spacing.with_density(density),
+            control_heights: self.control_heights.with_density(density),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the built-in palette matching the given appearance.
+    pub fn for_appearance(appearance: Appearance) -> Self {
+        match appearance {
+            Appearance::Light => Self::light(),
+            Appearance::Dark => Self::dark(),
+        }
+    }
+
+    /// Returns the installed theme, or the default one if none was installed.
+    pub fn of(app: &App) -> Theme {
+        app.try_global::<Theme>().cloned().unwrap_or_default()
+    }
+
+    /// Returns the color set on a component, or the token picked from the installed theme
+    /// when none was set, so components default to the theme while staying overridable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let selection = Theme::color_or(self.selection_color, |colors| colors.selection, app);
+    /// ```
+    pub fn color_or(
+        color: Option<Hsla>,
+        token: impl FnOnce(&ThemeColors) -> Hsla,
+        app: &App,
+    ) -> Hsla {
+        color.unwrap_or_else(|| match app.try_global::<Theme>() {
+            Some(theme) => token(&theme.colors),
+            None => token(&Theme::default().colors),
+        })
+    }
+}
+
+/// Whether the light or the dark theme given to [`crate::LapislazuliProvider`] is installed.
+///
+/// Change it with [`crate::LapislazuliProvider::set_theme_mode`], which emits
+/// [`ThemeChanged`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ThemeMode {
+    #[default]
+    Light,
+    Dark,
+    /// Follows the [`Appearance`] reported by the operating system.
+    System,
+}
+
+impl ThemeMode {
+    /// Returns the current mode, or [`ThemeMode::Light`] if none was set.
+    pub fn current(app: &App) -> Self {
+        app.try_global::<ThemeMode>().copied().unwrap_or_default()
+    }
+
+    /// Returns the appearance the mode resolves to.
+    pub fn appearance(&self, app: &App) -> Appearance {
+        match self {
+            ThemeMode::Light => Appearance::Light,
+            ThemeMode::Dark => Appearance::Dark,
+            ThemeMode::System => Appearance::current(app),
+        }
+    }
+}
+
+impl Global for ThemeMode {}
+
+/// Emitted by [`crate::LapislazuliProvider`] when the [`ThemeMode`] changes, or when the
+/// system appearance changes while following it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThemeChanged {
+    pub mode: ThemeMode,
+    pub appearance: Appearance,
+}
+
+/// A size token shared by controls so they line up when mixed, see [`crate::Sizeable`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ControlSize {
+    Xs,
+    Sm,
+    #[default]
+    Md,
+    Lg,
+}
+
+impl ControlSize {
+    /// The height of single-line controls such as buttons and text fields.
+    pub fn height(&self, theme: &Theme) -> Rems {
+        let heights = &theme.control_heights;
+        match self {
+            ControlSize::Xs => heights.xs,
+            ControlSize::Sm => heights.sm,
+            ControlSize::Md => heights.md,
+            ControlSize::Lg => heights.lg,
+        }
+    }
+
+    /// The horizontal padding of single-line controls.
+    pub fn padding(&self, theme: &Theme) -> Rems {
+        let spacing = &theme.spacing;
+        match self {
+            ControlSize::Xs => spacing.xs,
+            ControlSize::Sm => spacing.sm,
+            ControlSize::Md => spacing.md,
+            ControlSize::Lg => spacing.lg,
+        }
+    }
+
+    /// The size of small square controls such as checkboxes, matching the font size.
+    pub fn indicator(&self, theme: &Theme) -> Rems {
+        let font_sizes = &theme.font_sizes;
+        match self {
+            ControlSize::Xs => font_sizes.xs,
+            ControlSize::Sm => font_sizes.sm,
+            ControlSize::Md => font_sizes.md,
+            ControlSize::Lg => font_sizes.lg,
+        }
+    }
+}
+
+/// Replaces the current theme and refreshes every window so the tree re-renders.
+pub fn set_theme(theme: Theme, app: &mut App) {
+    app.set_global(theme);
+    app.refresh_windows();
+}
+
+/// Returns the current [`Theme`] for rendering.
+///
+/// Changing the theme or the [`ThemeMode`] redraws every window, so views that read the
+/// theme while rendering pick up the new one.
+pub fn use_theme(app: &App) -> Theme {
+    Theme::of(app)
+}
+
+/// Builds an element from the current [`Theme`] at render time.
+///
+/// # Example
+///
+/// ```rust
+/// themed(|theme| span("Hello").text_color(theme.colors.foreground))
+/// ```
+pub fn themed<F, E>(f: F) -> Themed
+where
+    F: FnOnce(&Theme) -> E + 'static,
+    E: IntoElement,
+{
+    Themed {
+        builder: Box::new(move |theme| f(theme).into_any_element()),
+    }
+}
+
+#[derive(IntoElement)]
+pub struct Themed {
+    builder: Box<dyn FnOnce(&Theme) -> AnyElement>,
+}
+
+impl RenderOnce for Themed {
+    fn render(self, _window: &mut Window, app: &mut App) -> impl IntoElement {
+        let theme = Theme::of(app);
+        (self.builder)(&theme)
+    }
+}
@@ -1,4 +1,11 @@
-use gpui::{ElementId, IntoElement, ParentElement};
+use crate::{
+    AccessibilityProps, ControlSize, InteractiveStateHandlers, OpenChangeEvent, OpenProps, Role,
+};
+use gpui::{
+    AnyElement, App, Component, ElementId, Entity, IntoElement, ParentElement, RenderOnce,
+    SharedString, Window,
+};
+use std::rc::Rc;
 
 /// An element that can be disabled to prevent user interaction.
 pub trait Disableable: Sized {
@@ -9,7 +16,10 @@ pub trait Disableable: Sized {
     fn disabled(self, disabled: bool) -> Self;
 
     /// Conditionally modify the element if it is disabled.
-    fn when_disabled(self, handler: impl FnOnce(Self) -> Self) -> Self {
+    ///
+    /// Components that can be disabled by a [`crate::DisabledScope`] defer the handler
+    /// until they render.
+    fn when_disabled(self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
         if self.is_disabled() {
             handler(self)
         } else {
@@ -18,10 +28,140 @@ pub trait Disableable: Sized {
     }
 }
 
+/// An element that carries accessibility metadata.
+pub trait Accessible: Sized {
+    fn accessibility(&self) -> &AccessibilityProps;
+
+    fn accessibility_mut(&mut self) -> &mut AccessibilityProps;
+
+    /// Overrides the element's default role.
+    fn role(mut self, role: Role) -> Self {
+        self.accessibility_mut().role = Some(role);
+        self
+    }
+
+    /// Sets the accessible name, used when the element has no visible text.
+    fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.accessibility_mut().label = Some(label.into());
+        self
+    }
+
+    /// Sets a longer description of the element's purpose.
+    fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.accessibility_mut().description = Some(description.into());
+        self
+    }
+}
+
+/// An element that can be sized with the shared [`ControlSize`] tokens.
+///
+/// The tokens map to heights and paddings from the [`crate::Theme`], so controls of
+/// the same size line up. Elements without a size keep their own styling.
+pub trait Sizeable: Sized {
+    fn with_size(self, size: ControlSize) -> Self;
+
+    fn xs(self) -> Self {
+        self.with_size(ControlSize::Xs)
+    }
+
+    fn sm(self) -> Self {
+        self.with_size(ControlSize::Sm)
+    }
+
+    fn md(self) -> Self {
+        self.with_size(ControlSize::Md)
+    }
+
+    fn lg(self) -> Self {
+        self.with_size(ControlSize::Lg)
+    }
+}
+
+/// A disclosure component that can be opened and closed, backed by an [`crate::OpenState`].
+///
+/// Left uncontrolled, the component opens and closes itself, starting from
+/// [`Openable::default_open`]. Once controlled with [`Openable::open`], the value passed on
+/// each render is the state: the component only asks for changes through
+/// [`Openable::on_open_change`], and the owner applies them by rendering it with a new
+/// value. In both modes `on_open_change` is called once per actual change, with the
+/// [`crate::OpenReason`] behind it.
+pub trait Openable: Sized {
+    fn open_props_mut(&mut self) -> &mut OpenProps;
+
+    /// Controls the open state, leaving changes to `on_open_change`.
+    fn open(mut self, open: bool) -> Self {
+        self.open_props_mut().open = Some(open);
+        self
+    }
+
+    /// Sets the initial open state when the component is not controlled, ignored once it
+    /// is.
+    fn default_open(mut self, default_open: bool) -> Self {
+        self.open_props_mut().default_open = default_open;
+        self
+    }
+
+    /// Called when the component asks to open or close.
+    fn on_open_change(
+        mut self,
+        on_open_change: impl Fn(&OpenChangeEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.open_props_mut().on_open_change = Some(Rc::new(on_open_change));
+        self
+    }
+}
+
 pub trait Validatable: Sized {
     fn check_validity(&self) -> bool;
 }
 
+/// An element styled differently while it has focus from the keyboard.
+///
+/// Unlike gpui's `.focus(..)`, which also applies after the element is clicked,
+/// [`FocusableStyled::when_focus_visible`] follows [`crate::is_focus_visible`], so focus
+/// rings only show for keyboard users.
+pub trait FocusableStyled: Sized {
+    /// Conditionally modify the element while it is focused after keyboard navigation.
+    fn when_focus_visible(self, handler: impl FnOnce(Self) -> Self + 'static) -> Self;
+}
+
+/// A component that can be modified while it is hovered, pressed or focused.
+///
+/// Unlike gpui's `.hover(..)`, `.active(..)` and `.focus(..)`, which only refine styles, the
+/// handlers receive the whole component, so they can also use its own builders or change
+/// its children. When several apply, they run in that order, so the active one wins.
+///
+/// # Example
+///
+/// ```rust
+/// button("save")
+///     .child("Save")
+///     .when_hovered(|this| this.bg(rgb(0xe5e7eb)).child(kbd("cmd-s")))
+///     .when_active(|this| this.bg(rgb(0xd1d5db)))
+/// ```
+pub trait InteractiveStateStyled: Sized {
+    fn interactive_state_handlers_mut(&mut self) -> &mut InteractiveStateHandlers<Self>;
+
+    /// Conditionally modify the component while the pointer is over it.
+    fn when_hovered(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.interactive_state_handlers_mut().hovered = Some(Box::new(handler));
+        self
+    }
+
+    /// Conditionally modify the component while it is pressed, unless it is disabled.
+    fn when_active(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.interactive_state_handlers_mut().active = Some(Box::new(handler));
+        self
+    }
+
+    /// Conditionally modify the component while it has focus, however it was focused. Use
+    /// [`FocusableStyled::when_focus_visible`] for focus rings.
+    fn when_focused(mut self, handler: impl FnOnce(Self) -> Self + 'static) -> Self {
+        self.interactive_state_handlers_mut().focused = Some(Box::new(handler));
+        self
+    }
+}
+
 pub trait AutoFocusable: Sized {
     fn auto_focus(self, autofocus: bool) -> Self;
 }
@@ -79,4 +219,60 @@ where
         let elements = f(context);
         self.children(elements)
     }
+
+    /// Adds a child element built from an entity's state, read when the child renders.
+    ///
+    /// Unlike [`Self::child_with_context`], the closure sees the latest state on every
+    /// render instead of a snapshot taken when the parent was built.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// progress.child_with_state(&upload, |upload, _context| {
+    ///     span(format!("{} files left", upload.remaining))
+    /// })
+    /// ```
+    fn child_with_state<S, F, E>(self, state: &Entity<S>, f: F) -> Self
+    where
+        S: 'static,
+        F: FnOnce(&S, Context) -> E + 'static,
+        E: IntoElement,
+        Context: 'static,
+    {
+        let context = self.get_context();
+        self.child(WithState::new(state.clone(), move |state| {
+            f(state, context).into_any_element()
+        }))
+    }
+}
+
+/// Renders an element from an entity's state at render time, see
+/// [`ParentElementWithContext::child_with_state`].
+#[allow(clippy::type_complexity)]
+pub struct WithState<S: 'static> {
+    state: Entity<S>,
+    builder: Box<dyn FnOnce(&S) -> AnyElement>,
+}
+
+impl<S: 'static> WithState<S> {
+    fn new(state: Entity<S>, builder: impl FnOnce(&S) -> AnyElement + 'static) -> Self {
+        Self {
+            state,
+            builder: Box::new(builder),
+        }
+    }
+}
+
+impl<S: 'static> RenderOnce for WithState<S> {
+    fn render(self, _window: &mut Window, app: &mut App) -> impl IntoElement {
+        (self.builder)(self.state.read(app))
+    }
+}
+
+impl<S: 'static> IntoElement for WithState<S> {
+    type Element = Component<Self>;
+
+    fn into_element(self) -> Self::Element {
+        Component::new(self)
+    }
 }
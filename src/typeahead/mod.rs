@@ -0,0 +1,68 @@
+use gpui::Keystroke;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests;
+
+/// How long typed characters keep accumulating into the query.
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Accumulates typed characters to jump to the item whose label starts with them,
+/// as in menus, lists and trees.
+#[derive(Default)]
+pub(crate) struct Typeahead {
+    query: String,
+    last_input: Option<Instant>,
+}
+
+impl Typeahead {
+    /// Returns the text typed by the keystroke, if it should feed a typeahead query.
+    pub(crate) fn input(keystroke: &Keystroke) -> Option<&str> {
+        let modifiers = &keystroke.modifiers;
+        if modifiers.control || modifiers.platform {
+            return None;
+        }
+        keystroke
+            .key_char
+            .as_deref()
+            .filter(|text| !text.chars().any(char::is_control))
+    }
+
+    /// Appends the text to the query, starting over if the last input is too old.
+    pub(crate) fn push(&mut self, text: &str) -> &str {
+        let now = Instant::now();
+        if self
+            .last_input
+            .is_none_or(|last| now.duration_since(last) > TYPEAHEAD_TIMEOUT)
+        {
+            self.query.clear();
+        }
+        self.last_input = Some(now);
+        self.query.push_str(&text.to_lowercase());
+        &self.query
+    }
+
+    /// Returns the first item matching the query, searching from the current item.
+    ///
+    /// A single character cycles through the matches, a longer query refines the current
+    /// one. `label` returns `None` for items that can't be highlighted.
+    pub(crate) fn find<'a>(
+        query: &str,
+        len: usize,
+        current: Option<usize>,
+        label: impl Fn(usize) -> Option<&'a str>,
+    ) -> Option<usize> {
+        if len == 0 {
+            return None;
+        }
+
+        let start = match current {
+            Some(ix) if query.chars().count() == 1 => ix + 1,
+            Some(ix) => ix,
+            None => 0,
+        };
+        (0..len)
+            .map(|step| (start + step) % len)
+            .find(|&ix| label(ix).is_some_and(|label| label.to_lowercase().starts_with(query)))
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod input {
+    use crate::typeahead::Typeahead;
+    use gpui::{Keystroke, Modifiers};
+
+    fn input(modifiers: Modifiers, key_char: &str) -> Option<String> {
+        let keystroke = Keystroke {
+            modifiers,
+            key: key_char.to_lowercase(),
+            key_char: Some(key_char.into()),
+        };
+        Typeahead::input(&keystroke).map(str::to_string)
+    }
+
+    #[test]
+    fn accepts_printable_characters() {
+        assert_eq!(input(Modifiers::none(), "a").as_deref(), Some("a"));
+        assert_eq!(input(Modifiers::shift(), "A").as_deref(), Some("A"));
+        assert_eq!(input(Modifiers::none(), " ").as_deref(), Some(" "));
+    }
+
+    #[test]
+    fn ignores_shortcuts_and_control_characters() {
+        assert_eq!(input(Modifiers::control(), "a"), None);
+        assert_eq!(input(Modifiers::command(), "a"), None);
+        assert_eq!(input(Modifiers::none(), "\r"), None);
+        assert_eq!(input(Modifiers::none(), "\u{1b}"), None);
+    }
+
+    #[test]
+    fn ignores_keys_without_text() {
+        let keystroke = Keystroke::parse("escape").unwrap();
+        assert_eq!(Typeahead::input(&keystroke), None);
+    }
+}
+
+#[cfg(test)]
+mod push {
+    use crate::typeahead::Typeahead;
+
+    #[test]
+    fn accumulates_lowercase_input() {
+        let mut typeahead = Typeahead::default();
+        assert_eq!(typeahead.push("S"), "s");
+        assert_eq!(typeahead.push("aV"), "sav");
+    }
+}
+
+#[cfg(test)]
+mod find {
+    use crate::typeahead::Typeahead;
+
+    const LABELS: [&str; 5] = ["Open", "Save", "Save As", "Share", "Settings"];
+
+    fn find(query: &str, current: Option<usize>) -> Option<usize> {
+        Typeahead::find(query, LABELS.len(), current, |ix| Some(LABELS[ix]))
+    }
+
+    #[test]
+    fn finds_the_first_match_without_a_current_item() {
+        assert_eq!(find("s", None), Some(1));
+        assert_eq!(find("sh", None), Some(3));
+        assert_eq!(find("x", None), None);
+    }
+
+    #[test]
+    fn cycles_through_matches_of_a_single_character() {
+        assert_eq!(find("s", Some(1)), Some(2));
+        assert_eq!(find("s", Some(4)), Some(1));
+    }
+
+    #[test]
+    fn refines_the_current_match_with_a_longer_query() {
+        assert_eq!(find("save", Some(2)), Some(2));
+        assert_eq!(find("save", Some(3)), Some(1));
+    }
+
+    #[test]
+    fn ignores_case() {
+        assert_eq!(find("save a", None), Some(2));
+    }
+
+    #[test]
+    fn skips_items_without_a_label() {
+        let label = |ix: usize| (ix != 1).then_some(LABELS[ix]);
+        assert_eq!(Typeahead::find("s", LABELS.len(), None, label), Some(2));
+    }
+
+    #[test]
+    fn finds_nothing_in_an_empty_list() {
+        assert_eq!(Typeahead::find("s", 0, None, |_| None), None);
+    }
+}
@@ -0,0 +1,85 @@
+use gpui::{AnyWindowHandle, App, Bounds, Global, Pixels, Size, Window};
+use std::{collections::HashMap, time::Duration};
+
+/// How long the window geometry must settle before [`WindowInfoChanged`] is emitted.
+pub(crate) const WINDOW_INFO_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// The geometry of a window, kept up to date by [`crate::LapislazuliProvider`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WindowInfo {
+    /// The bounds of the window on its display.
+    pub bounds: Bounds<Pixels>,
+    /// The size of the window's content area.
+    pub viewport_size: Size<Pixels>,
+    pub fullscreen: bool,
+}
+
+impl WindowInfo {
+    /// Reads the geometry of the window as it is now.
+    pub fn read(window: &Window) -> Self {
+        Self {
+            bounds: window.bounds(),
+            viewport_size: window.viewport_size(),
+            fullscreen: window.is_fullscreen(),
+        }
+    }
+
+    /// Returns the geometry of the window as of the last [`WindowInfoChanged`] event, or
+    /// as it is now if the window is not wrapped in a provider.
+    pub fn current(window: &Window, app: &App) -> Self {
+        app.try_global::<WindowInfos>()
+            .and_then(|infos| infos.0.get(&window.window_handle()))
+            .copied()
+            .unwrap_or_else(|| Self::read(window))
+    }
+}
+
+/// The last geometry of each window wrapped in a provider.
+#[derive(Default)]
+struct WindowInfos(HashMap<AnyWindowHandle, WindowInfo>);
+
+impl Global for WindowInfos {}
+
+/// Records the window's geometry, returning the change if it differs from the last one.
+pub(crate) fn update_window_info(window: &Window, app: &mut App) -> Option<WindowInfoChanged> {
+    let info = WindowInfo::read(window);
+    let previous = app
+        .default_global::<WindowInfos>()
+        .0
+        .insert(window.window_handle(), info)
+        .unwrap_or_default();
+    (previous != info).then_some(WindowInfoChanged { previous, info })
+}
+
+/// Forgets the geometry of windows that were closed.
+pub(crate) fn prune_window_infos(app: &mut App) {
+    if !app.has_global::<WindowInfos>() {
+        return;
+    }
+    let windows = app.windows();
+    app.global_mut::<WindowInfos>()
+        .0
+        .retain(|window, _| windows.contains(window));
+}
+
+/// Emitted by [`crate::LapislazuliProvider`] once the geometry of its window settles after
+/// being resized, moved or toggled to fullscreen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowInfoChanged {
+    pub previous: WindowInfo,
+    pub info: WindowInfo,
+}
+
+impl WindowInfoChanged {
+    pub fn resized(&self) -> bool {
+        self.previous.viewport_size != self.info.viewport_size
+    }
+
+    pub fn moved(&self) -> bool {
+        self.previous.bounds.origin != self.info.bounds.origin
+    }
+
+    pub fn fullscreen_toggled(&self) -> bool {
+        self.previous.fullscreen != self.info.fullscreen
+    }
+}